@@ -0,0 +1,62 @@
+//! Compares full reparsing against incremental reparsing for a small edit in a large module,
+//! the situation the `--fix` loop is in after each pass.
+//!
+//! Run with `cargo bench --bench incremental_parse`.
+
+use move_clippy::parser::{input_edit, parse_source, parse_source_incremental};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const FUNCTIONS: usize = 1_000;
+const ROUNDS: u32 = 20;
+
+/// A module of roughly `4 * FUNCTIONS` lines.
+fn large_module() -> String {
+    let mut src = String::from("module bench::large {\n");
+    for i in 0..FUNCTIONS {
+        src.push_str(&format!(
+            "    public fun f{i}(v: &mut vector<u64>): u64 {{\n        vector::push_back(v, {i});\n        vector::length(v)\n    }}\n"
+        ));
+    }
+    src.push_str("}\n");
+    src
+}
+
+fn time(mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        f();
+    }
+    start.elapsed() / ROUNDS
+}
+
+fn main() {
+    let source = large_module();
+    let old_tree = parse_source(&source).expect("parse");
+
+    // One fix-style edit in the middle of the file.
+    let target = "vector::push_back(v, 500);";
+    let start = source.find(target).expect("edit target");
+    let end = start + target.len();
+    let replacement = "v.push_back(500);";
+    let edit = input_edit(&source, start, end, replacement);
+    let mut edited = source.clone();
+    edited.replace_range(start..end, replacement);
+
+    let full = time(|| {
+        black_box(parse_source(black_box(&edited)).expect("parse"));
+    });
+    let incremental = time(|| {
+        black_box(
+            parse_source_incremental(black_box(&edited), Some(&old_tree), &[edit]).expect("parse"),
+        );
+    });
+
+    println!(
+        "{} lines: full reparse {:?}, incremental reparse {:?} ({:.1}x)",
+        source.lines().count(),
+        full,
+        incremental,
+        full.as_secs_f64() / incremental.as_secs_f64()
+    );
+}
@@ -25,10 +25,12 @@
 
 use crate::diagnostics::Diagnostic;
 use crate::error::Result as ClippyResult;
+use crate::framework_catalog::{is_oracle_price_accessor, is_oracle_timestamp_accessor};
 use crate::lint::{
     AnalysisKind, FixDescriptor, LintCategory, LintDescriptor, LintSettings, RuleGroup,
     TypeSystemGap,
 };
+use crate::type_classifier::named_address;
 use move_compiler::shared::NumericalAddress;
 use move_compiler::{
     PreCompiledProgramInfo,
@@ -111,12 +113,12 @@ const UNCHECKED_DIV_V2_DIAG: DiagnosticInfo = custom(
     "division without zero-check validation",
 );
 
-const STALE_ORACLE_PRICE_V3_DIAG: DiagnosticInfo = custom(
+const STALE_ORACLE_PRICE_DIAG: DiagnosticInfo = custom(
     LINT_WARNING_PREFIX,
     Severity::Warning,
     CLIPPY_CATEGORY,
-    8, // stale_oracle_price_v3
-    "oracle price used without freshness validation",
+    9, // stale_oracle_price
+    "oracle price used in arithmetic without a dominating freshness check",
 );
 
 // ============================================================================
@@ -143,10 +145,10 @@ pub static UNCHECKED_DIVISION_V2: LintDescriptor = LintDescriptor {
     gap: Some(TypeSystemGap::ArithmeticSafety),
 };
 
-pub static STALE_ORACLE_PRICE_V3: LintDescriptor = LintDescriptor {
-    name: "stale_oracle_price_v3",
+pub static STALE_ORACLE_PRICE: LintDescriptor = LintDescriptor {
+    name: "stale_oracle_price",
     category: LintCategory::Security,
-    description: "Oracle price used without freshness validation (CFG-aware dataflow, requires --mode full --preview)",
+    description: "Oracle price used in arithmetic without a dominating freshness check (type-based CFG-aware, requires --mode full --preview)",
     group: RuleGroup::Preview,
     fix: FixDescriptor::none(),
     analysis: AnalysisKind::TypeBasedCFG,
@@ -1427,14 +1429,9 @@ impl SimpleAbsInt for DestroyZeroVerifierAI<'_> {
             }
             E::Constant(_) => return Some(vec![ZeroCheckValue::Unknown]),
             E::ModuleCall(call) => {
-                let module_sym = call.module.value.module.value();
-                let func_sym = call.name.value();
-                let module_name = module_sym.as_str();
-                let func_name = func_sym.as_str();
-
                 // Check for destroy_zero calls
-                if (module_name == "balance" || module_name == "coin")
-                    && func_name == "destroy_zero"
+                if (call.is(&SUI_ADDR, "balance", "destroy_zero")
+                    || call.is(&SUI_ADDR, "coin", "destroy_zero"))
                     && let Some(arg) = call.arguments.first()
                 {
                     let is_checked = self.is_zero_checked(state, arg);
@@ -1556,9 +1553,7 @@ impl DestroyZeroVerifierAI<'_> {
             }
             // Handle value() calls - `value(&b) == 0`
             UnannotatedExp_::ModuleCall(call) => {
-                let func_sym = call.name.value();
-                let func_name = func_sym.as_str();
-                if func_name == "value"
+                if (call.is(&SUI_ADDR, "balance", "value") || call.is(&SUI_ADDR, "coin", "value"))
                     && let Some(arg) = call.arguments.first()
                 {
                     return self.extract_var(arg).map(|v| (v, false)); // Conservative
@@ -1572,12 +1567,7 @@ impl DestroyZeroVerifierAI<'_> {
     fn extract_var_from_value_call(&self, e: &Exp) -> Option<Var> {
         match &e.exp.value {
             UnannotatedExp_::ModuleCall(call) => {
-                let module_sym = call.module.value.module.value();
-                let func_sym = call.name.value();
-                let module_name = module_sym.as_str();
-                let func_name = func_sym.as_str();
-                if (module_name == "balance" || module_name == "coin")
-                    && func_name == "value"
+                if (call.is(&SUI_ADDR, "balance", "value") || call.is(&SUI_ADDR, "coin", "value"))
                     && let Some(arg) = call.arguments.first()
                 {
                     self.extract_var(arg)
@@ -1746,19 +1736,13 @@ impl SimpleAbsInt for FreshAddressReuseVerifierAI<'_> {
         use UnannotatedExp_ as E;
 
         if let E::ModuleCall(call) = &e.exp.value {
-            let module_sym = call.module.value.module.value();
-            let func_sym = call.name.value();
-            let module_name = module_sym.as_str();
-            let func_name = func_sym.as_str();
-
             // Track fresh_object_address calls
-            if module_name == "tx_context" && func_name == "fresh_object_address" {
+            if call.is(&SUI_ADDR, "tx_context", "fresh_object_address") {
                 return Some(vec![FreshAddressValue::Fresh(e.exp.loc)]);
             }
 
             // Check for new_uid_from_address calls
-            if module_name == "object"
-                && func_name == "new_uid_from_address"
+            if call.is(&SUI_ADDR, "object", "new_uid_from_address")
                 && let Some(arg) = call.arguments.first()
                 && let Some(var) = self.extract_var(arg)
             {
@@ -2395,75 +2379,68 @@ impl SimpleExecutionContext for EscapeExecutionContext {
 }
 
 // ============================================================================
-// 7. Stale Oracle Price V3 (CFG-aware dataflow analysis)
+// 7. REMOVED: Stale Oracle Price V3
 // ============================================================================
+// Superseded by `stale_oracle_price` (section 8), which requires the freshness
+// check to dominate the use. Running both reported every stale price twice.
 
-/// Verifies that oracle prices from `get_price_unsafe` are validated for freshness
-/// before being used in business logic.
-///
-/// Tracks Price values through the CFG and reports when they are used without
-/// passing through a freshness validation function.
-pub struct StaleOraclePriceVerifier;
+// ============================================================================
+// 8. Stale Oracle Price (dominating freshness guard)
+// ============================================================================
 
-pub struct StaleOraclePriceVerifierAI<'a> {
-    info: &'a TypingProgramInfo,
+/// Reports oracle prices used in arithmetic when no freshness check dominates the use.
+///
+/// Price reads come from `framework_catalog::ORACLE_PRICE_ACCESSORS`. A read becomes
+/// validated once a guard (a branch into an abort/return block) compares that read's own
+/// publish time against a bound, e.g.
+/// `assert!(now - price::get_timestamp(&p) < MAX_AGE, E_STALE)`. Checking one price does
+/// not validate another. Joins are pessimistic, so a check on only some paths does not
+/// validate the price at the merge point.
+pub struct OracleFreshnessVerifier;
+
+pub struct OracleFreshnessVerifierAI<'a> {
     context: &'a CFGContext<'a>,
     /// Labels whose blocks immediately exit (abort/return)
     exit_blocks: BTreeSet<Label>,
 }
 
-/// Abstract value: tracks whether a Price has been freshness-validated
+/// Abstract value: Unread → Read → Validated, plus markers for timestamp-derived values
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
-pub enum PriceValidationValue {
-    /// Not a tracked price value
+pub enum FreshnessValue {
+    /// Not derived from an oracle read
     #[default]
-    NotTracked,
-    /// Price from get_price_unsafe, not yet validated
-    Unvalidated(Loc),
-    /// Price has been validated for freshness
+    Unread,
+    /// Derived from the publish time of the read at `Loc`
+    Timestamp(Loc),
+    /// Comparison of the read at `Loc`'s publish time against a bound
+    FreshnessCheck(Loc),
+    /// Derived from the oracle read at `Loc` with no dominating freshness check
+    Read(Loc),
+    /// Derived from the oracle read at `Loc` after a dominating freshness check
     Validated(Loc),
 }
 
-pub struct PriceExecutionContext {
+pub struct FreshnessExecutionContext {
     diags: CompilerDiagnostics,
 }
 
 #[derive(Clone, Debug, Default)]
-pub struct PriceState {
-    locals: BTreeMap<Var, LocalState<PriceValidationValue>>,
+pub struct FreshnessState {
+    locals: BTreeMap<Var, LocalState<FreshnessValue>>,
 }
 
-/// Oracle modules and their unsafe price functions
-const UNSAFE_ORACLE_CALLS: &[(&str, &str)] = &[
-    ("pyth", "get_price_unsafe"),
-    ("price_info", "get_price_unsafe"),
-    ("switchboard", "get_price_unsafe"),
-    ("supra", "get_price_unsafe"),
-];
-
-/// Functions that validate price freshness
-const FRESHNESS_VALIDATION_CALLS: &[&str] = &[
-    "check_price_is_fresh",
-    "check_freshness",
-    "get_price_no_older_than",
-    "validate_freshness",
-    "assert_fresh",
-];
-
-impl SimpleAbsIntConstructor for StaleOraclePriceVerifier {
-    type AI<'a> = StaleOraclePriceVerifierAI<'a>;
+impl SimpleAbsIntConstructor for OracleFreshnessVerifier {
+    type AI<'a> = OracleFreshnessVerifierAI<'a>;
 
     fn new<'a>(
         context: &'a CFGContext<'a>,
         cfg: &ImmForwardCFG,
-        _init_state: &mut PriceState,
+        _init_state: &mut FreshnessState,
     ) -> Option<Self::AI<'a>> {
-        // Skip test functions
         if context.attributes.is_test_or_test_only() {
             return None;
         }
 
-        // Precompute exit blocks for guard detection
         let mut exit_blocks = BTreeSet::new();
         for lbl in cfg.block_labels() {
             if is_immediate_exit_block(cfg, lbl) {
@@ -2471,17 +2448,16 @@ impl SimpleAbsIntConstructor for StaleOraclePriceVerifier {
             }
         }
 
-        Some(StaleOraclePriceVerifierAI {
-            info: context.info,
+        Some(OracleFreshnessVerifierAI {
             context,
             exit_blocks,
         })
     }
 }
 
-impl SimpleAbsInt for StaleOraclePriceVerifierAI<'_> {
-    type State = PriceState;
-    type ExecutionContext = PriceExecutionContext;
+impl SimpleAbsInt for OracleFreshnessVerifierAI<'_> {
+    type State = FreshnessState;
+    type ExecutionContext = FreshnessExecutionContext;
 
     fn finish(
         &mut self,
@@ -2492,7 +2468,7 @@ impl SimpleAbsInt for StaleOraclePriceVerifierAI<'_> {
     }
 
     fn start_command(&self, _pre: &mut Self::State) -> Self::ExecutionContext {
-        PriceExecutionContext {
+        FreshnessExecutionContext {
             diags: CompilerDiagnostics::new(),
         }
     }
@@ -2511,56 +2487,27 @@ impl SimpleAbsInt for StaleOraclePriceVerifierAI<'_> {
         state: &mut Self::State,
         cmd: &Command,
     ) -> bool {
-        use Command_ as C;
-
         match &cmd.value {
-            C::Assign(_case, lvalues, rhs) => {
-                // Evaluate RHS to get its price validation state
-                let rhs_values = self.exp(context, state, rhs);
-
-                // Find if any RHS value is unvalidated
-                let rhs_unvalidated = rhs_values
-                    .iter()
-                    .find(|v| matches!(v, PriceValidationValue::Unvalidated(_)));
-
-                // Propagate to LHS variables
-                for lvalue in lvalues {
-                    if let LValue_::Var { var, .. } = &lvalue.value {
-                        if let Some(PriceValidationValue::Unvalidated(source_loc)) = rhs_unvalidated
-                        {
-                            // Mark variable as holding unvalidated price
-                            if let Some(local_state) = state.locals.get_mut(var) {
-                                if let LocalState::Available(avail_loc, _) = local_state {
-                                    *local_state = LocalState::Available(
-                                        *avail_loc,
-                                        PriceValidationValue::Unvalidated(*source_loc),
-                                    );
-                                }
-                            }
-                        }
-                    }
-                }
-
-                false // Let default handling continue
-            }
-            C::JumpIf {
+            // `assert!(cond, E)` compiles to JumpIf with an abort branch
+            Command_::JumpIf {
                 cond,
                 if_true,
                 if_false,
             } => {
-                // Visit condition
-                self.exp(context, state, cond);
+                let cond_value = self.exp(context, state, cond).first().copied();
 
-                // Check if one branch is an exit (abort) - this is a guard pattern
-                let true_is_abort = self.exit_blocks.contains(if_true);
-                let false_is_abort = self.exit_blocks.contains(if_false);
+                let true_is_exit = self.exit_blocks.contains(if_true);
+                let false_is_exit = self.exit_blocks.contains(if_false);
 
-                // If this is a guard pattern with freshness check, mark prices as validated
-                if true_is_abort || false_is_abort {
-                    self.mark_validated_from_guard(state, cond);
+                // The continuing branch is only reachable once the freshness check has
+                // passed, so the read it checked is validated from here on.
+                if (true_is_exit ^ false_is_exit)
+                    && let Some(FreshnessValue::FreshnessCheck(read_loc)) = cond_value
+                {
+                    mark_read_validated(state, read_loc);
                 }
 
-                true // Handled
+                true
             }
             _ => false,
         }
@@ -2571,190 +2518,373 @@ impl SimpleAbsInt for StaleOraclePriceVerifierAI<'_> {
         context: &mut Self::ExecutionContext,
         state: &mut Self::State,
         e: &Exp,
-    ) -> Option<Vec<PriceValidationValue>> {
+    ) -> Option<Vec<FreshnessValue>> {
+        use FreshnessValue::*;
         use UnannotatedExp_ as E;
 
         match &e.exp.value {
-            // Variable access - return tracked state
             E::Move { var, .. } | E::Copy { var, .. } | E::BorrowLocal(_, var) => {
                 if let Some(LocalState::Available(_, value)) = state.locals.get(var) {
                     return Some(vec![*value]);
                 }
             }
-            // Module call - detect sources and handle validation
+            // Field borrows, dereferences, casts and `!` keep the value of their operand
+            E::Borrow(_, inner, _, _)
+            | E::Dereference(inner)
+            | E::Cast(inner, _)
+            | E::UnaryExp(_, inner) => {
+                let value = self
+                    .exp(context, state, inner)
+                    .first()
+                    .copied()
+                    .unwrap_or_default();
+                return Some(vec![value]);
+            }
             E::ModuleCall(call) => {
+                let mut args = Unread;
+                for arg in &call.arguments {
+                    for value in self.exp(context, state, arg) {
+                        args = FreshnessState::join_value(&args, &value);
+                    }
+                }
+
+                let package_sym = named_address(&call.module.value.address).map(|n| n.value);
+                let package_name = package_sym.as_ref().map_or("", |s| s.as_str());
                 let module_sym = call.module.value.module.value();
                 let module_name = module_sym.as_str();
                 let func_sym = call.name.value();
                 let func_name = func_sym.as_str();
 
-                // Check if this is an unsafe oracle call (SOURCE)
-                let is_unsafe = UNSAFE_ORACLE_CALLS
-                    .iter()
-                    .any(|(m, f)| module_name == *m && func_name == *f);
-
-                if is_unsafe {
-                    // Mark the return value as unvalidated
-                    return Some(vec![PriceValidationValue::Unvalidated(e.exp.loc)]);
-                }
+                let value = if is_oracle_timestamp_accessor(package_name, module_name, func_name) {
+                    match args {
+                        // e.g. `price::get_timestamp(&p)` ties the publish time to `p`'s read
+                        Read(loc) | Validated(loc) => Timestamp(loc),
+                        // Not tied to any tracked read, so checking it validates nothing
+                        _ => Timestamp(e.exp.loc),
+                    }
+                } else if is_oracle_price_accessor(package_name, module_name, func_name) {
+                    match args {
+                        // e.g. `price::get_price(&p)` keeps the identity of `p`'s read
+                        Read(loc) => Read(loc),
+                        Validated(loc) => Validated(loc),
+                        _ => Read(e.exp.loc),
+                    }
+                } else {
+                    args
+                };
 
-                // Check if this is a freshness validation call
-                let is_validation = FRESHNESS_VALIDATION_CALLS
-                    .iter()
-                    .any(|f| func_name.contains(f));
-
-                if is_validation {
-                    // Process arguments - any unvalidated price passed here becomes validated
-                    for arg in &call.arguments {
-                        let arg_values = self.exp(context, state, arg);
-                        // Mark any variables passed as validated in state
-                        if let Some(var) = self.extract_var(arg) {
-                            if let Some(LocalState::Available(avail_loc, _)) =
-                                state.locals.get(&var)
-                            {
-                                state.locals.insert(
-                                    var,
-                                    LocalState::Available(
-                                        *avail_loc,
-                                        PriceValidationValue::Validated(e.exp.loc),
-                                    ),
-                                );
+                return Some(vec![value; value_count(&e.ty)]);
+            }
+            E::BinopExp(lhs, op, rhs) => {
+                let l = self
+                    .exp(context, state, lhs)
+                    .first()
+                    .copied()
+                    .unwrap_or_default();
+                let r = self
+                    .exp(context, state, rhs)
+                    .first()
+                    .copied()
+                    .unwrap_or_default();
+
+                let value = match op.value {
+                    BinOp_::Add | BinOp_::Sub | BinOp_::Mul | BinOp_::Div | BinOp_::Mod => {
+                        match (l, r) {
+                            (Read(source_loc), _) | (_, Read(source_loc)) => {
+                                self.report_unchecked_use(context, e.exp.loc, source_loc);
+                                // Reported once per expression tree
+                                Unread
                             }
+                            (Validated(loc), _) | (_, Validated(loc)) => Validated(loc),
+                            (Timestamp(loc), _) | (_, Timestamp(loc)) => Timestamp(loc),
+                            _ => Unread,
                         }
                     }
-                    return Some(vec![PriceValidationValue::Validated(e.exp.loc)]);
-                }
+                    BinOp_::Lt | BinOp_::Le | BinOp_::Gt | BinOp_::Ge => match (l, r) {
+                        (Timestamp(loc), _) | (_, Timestamp(loc)) => FreshnessCheck(loc),
+                        _ => Unread,
+                    },
+                    // Both conjuncts hold past the guard, so either check counts
+                    BinOp_::And => match (l, r) {
+                        (FreshnessCheck(loc), _) | (_, FreshnessCheck(loc)) => FreshnessCheck(loc),
+                        _ => Unread,
+                    },
+                    // Only one disjunct holds, so both must check the same read
+                    BinOp_::Or if l == r && matches!(l, FreshnessCheck(_)) => l,
+                    _ => Unread,
+                };
 
-                // For other module calls, let call_custom handle sink detection
-                return None; // Use default handling which will call call_custom
+                return Some(vec![value]);
             }
             _ => {}
         }
 
         None
     }
+}
 
-    fn call_custom(
+impl OracleFreshnessVerifierAI<'_> {
+    fn report_unchecked_use(
         &self,
-        context: &mut Self::ExecutionContext,
-        state: &mut Self::State,
-        loc: &Loc,
-        _return_ty: &Type,
-        call: &ModuleCall,
-        args: Vec<PriceValidationValue>,
-    ) -> Option<Vec<PriceValidationValue>> {
-        let module_sym = call.module.value.module.value();
-        let module_name = module_sym.as_str();
-        let func_sym = call.name.value();
-        let func_name = func_sym.as_str();
+        context: &mut FreshnessExecutionContext,
+        loc: Loc,
+        source_loc: Loc,
+    ) {
+        let is_dependency = self
+            .context
+            .env
+            .package_config(self.context.package)
+            .is_dependency;
+        if is_dependency {
+            return;
+        }
 
-        // Skip if this is an oracle call or validation call (handled in exp_custom)
-        let is_oracle = UNSAFE_ORACLE_CALLS
-            .iter()
-            .any(|(m, f)| module_name == *m && func_name == *f);
-        let is_validation = FRESHNESS_VALIDATION_CALLS
-            .iter()
-            .any(|f| func_name.contains(f));
+        let msg = "Oracle price used in arithmetic without a freshness check on every path. \
+                   Assert that its publish time is recent first, e.g. \
+                   `assert!(clock::timestamp_ms(clock) / 1000 - price::get_timestamp(&price) \
+                   <= MAX_AGE_SECS, E_STALE_PRICE)`.";
+        let help = "Price read here";
+        let d = diag!(STALE_ORACLE_PRICE_DIAG, (loc, msg), (source_loc, help),);
+        context.add_diag(d);
+    }
+}
 
-        if is_oracle || is_validation {
-            return None;
+/// Number of abstract values an expression of type `ty` produces.
+fn value_count(ty: &Type) -> usize {
+    match &ty.value {
+        Type_::Unit => 0,
+        Type_::Single(_) => 1,
+        Type_::Multiple(tys) => tys.len(),
+    }
+}
+
+fn mark_read_validated(state: &mut FreshnessState, read_loc: Loc) {
+    for local_state in state.locals.values_mut() {
+        if let LocalState::Available(_, value) = local_state
+            && *value == FreshnessValue::Read(read_loc)
+        {
+            *value = FreshnessValue::Validated(read_loc);
         }
+    }
+}
 
-        // Check if any argument is an unvalidated price (SINK detection)
-        for (idx, val) in args.iter().enumerate() {
-            if let PriceValidationValue::Unvalidated(source_loc) = val {
-                // Don't report if this is in dependency code
-                if self.is_root_source_loc(loc) {
-                    let msg = format!(
-                        "Unvalidated oracle price from `get_price_unsafe` passed to `{}::{}`. \
-                         The price may be stale. Consider validating freshness first with \
-                         `check_price_is_fresh` or use `get_price_no_older_than`.",
-                        module_name, func_name
-                    );
-                    let help = "Add freshness validation before using the price";
-                    let d = diag!(STALE_ORACLE_PRICE_V3_DIAG, (*loc, msg), (*source_loc, help),);
-                    context.add_diag(d);
-                }
-            }
+impl SimpleDomain for FreshnessState {
+    type Value = FreshnessValue;
+
+    fn new(_context: &CFGContext, locals: BTreeMap<Var, LocalState<Self::Value>>) -> Self {
+        FreshnessState { locals }
+    }
+
+    fn locals(&self) -> &BTreeMap<Var, LocalState<Self::Value>> {
+        &self.locals
+    }
+
+    fn locals_mut(&mut self) -> &mut BTreeMap<Var, LocalState<Self::Value>> {
+        &mut self.locals
+    }
+
+    fn join_value(v1: &Self::Value, v2: &Self::Value) -> Self::Value {
+        use FreshnessValue::*;
+        match (v1, v2) {
+            // Pessimistic: a price unchecked on any incoming path stays unchecked
+            (Read(loc), _) | (_, Read(loc)) => Read(*loc),
+            (Validated(loc), _) | (_, Validated(loc)) => Validated(*loc),
+            (a, b) if a == b => *a,
+            _ => Unread,
         }
+    }
 
-        None
+    fn join_impl(&mut self, _other: &Self, _result: &mut JoinResult) {}
+}
+
+impl SimpleExecutionContext for FreshnessExecutionContext {
+    fn add_diag(&mut self, d: CompilerDiagnostic) {
+        self.diags.add(d);
     }
 }
 
-impl StaleOraclePriceVerifierAI<'_> {
-    fn is_root_source_loc(&self, loc: &Loc) -> bool {
-        let is_dependency = self
-            .context
-            .env
-            .package_config(self.context.package)
-            .is_dependency;
-        !is_dependency
+// ============================================================================
+// 9. Unconsumed New Object (CFG-aware)
+// ============================================================================
+
+const UNCONSUMED_NEW_OBJECT_DIAG: DiagnosticInfo = custom(
+    LINT_WARNING_PREFIX,
+    Severity::Warning,
+    CLIPPY_CATEGORY,
+    14, // unconsumed_new_object
+    "newly created object destroyed without being transferred, shared, frozen, returned, or stored",
+);
+
+pub static UNCONSUMED_NEW_OBJECT: LintDescriptor = LintDescriptor {
+    name: "unconsumed_new_object",
+    category: LintCategory::Suspicious,
+    description: "Object created with object::new is destroyed on some path instead of being transferred, shared, frozen, returned, or stored (CFG-aware, requires --mode full --experimental)",
+    group: RuleGroup::Experimental,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBasedCFG,
+    gap: Some(TypeSystemGap::OwnershipViolation),
+};
+
+/// Reports objects created in a function that are destroyed again on some path.
+///
+/// A `UID` from `object::new` (and any struct packed around it) is tracked as fresh. An object
+/// can't be dropped, so the compiler already forces every fresh value into a transfer, a
+/// return, a field, or another call. The one way to lose it is to unpack it and pass the `UID`
+/// to `object::delete`; this verifier reports that on every path where it happens, e.g. an
+/// `else` branch that discards the object the other branch transfers. Values handed to another
+/// function are treated as consumed.
+pub struct UnconsumedNewObjectVerifier;
+
+pub struct UnconsumedNewObjectVerifierAI<'a> {
+    context: &'a CFGContext<'a>,
+}
+
+/// Abstract value: whether a value holds a `UID` created in this function
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum NewObjectValue {
+    /// Not created in this function
+    #[default]
+    NotNew,
+    /// Holds the `UID` created at this location
+    Fresh(Loc),
+}
+
+pub struct NewObjectExecutionContext {
+    diags: CompilerDiagnostics,
+}
+
+#[derive(Clone, Debug)]
+pub struct NewObjectState {
+    locals: BTreeMap<Var, LocalState<NewObjectValue>>,
+}
+
+impl SimpleAbsIntConstructor for UnconsumedNewObjectVerifier {
+    type AI<'a> = UnconsumedNewObjectVerifierAI<'a>;
+
+    fn new<'a>(
+        context: &'a CFGContext<'a>,
+        _cfg: &ImmForwardCFG,
+        _init_state: &mut NewObjectState,
+    ) -> Option<Self::AI<'a>> {
+        if context.attributes.is_test_or_test_only() {
+            return None;
+        }
+
+        Some(UnconsumedNewObjectVerifierAI { context })
     }
+}
 
-    /// Extract variable from an expression (for tracking through references)
-    fn extract_var(&self, e: &Exp) -> Option<Var> {
-        use UnannotatedExp_ as E;
-        match &e.exp.value {
-            E::Move { var, .. } | E::Copy { var, .. } | E::BorrowLocal(_, var) => Some(*var),
-            E::Dereference(inner) | E::Borrow(_, inner, _, _) => self.extract_var(inner),
-            _ => None,
+impl SimpleAbsInt for UnconsumedNewObjectVerifierAI<'_> {
+    type State = NewObjectState;
+    type ExecutionContext = NewObjectExecutionContext;
+
+    fn finish(
+        &mut self,
+        _final_states: BTreeMap<Label, Self::State>,
+        diags: CompilerDiagnostics,
+    ) -> CompilerDiagnostics {
+        diags
+    }
+
+    fn start_command(&self, _pre: &mut Self::State) -> Self::ExecutionContext {
+        NewObjectExecutionContext {
+            diags: CompilerDiagnostics::new(),
         }
     }
 
-    /// Mark any unvalidated prices in the guard condition as validated
-    /// This handles patterns like: assert!(check_price_is_fresh(&price, max_age), E_STALE)
-    fn mark_validated_from_guard(&self, state: &mut PriceState, cond: &Exp) {
+    fn finish_command(
+        &self,
+        context: Self::ExecutionContext,
+        _state: &mut Self::State,
+    ) -> CompilerDiagnostics {
+        context.diags
+    }
+
+    fn lvalue_custom(
+        &self,
+        context: &mut Self::ExecutionContext,
+        state: &mut Self::State,
+        l: &LValue,
+        value: &NewObjectValue,
+    ) -> bool {
+        // Unpacking a fresh object keeps its fields (in particular the `UID`) fresh; the
+        // default handling would reset them.
+        let (LValue_::Unpack(_, _, fields), NewObjectValue::Fresh(_)) = (&l.value, value) else {
+            return false;
+        };
+        for (_, field) in fields {
+            self.lvalue(context, state, field, *value);
+        }
+        true
+    }
+
+    fn exp_custom(
+        &self,
+        context: &mut Self::ExecutionContext,
+        state: &mut Self::State,
+        e: &Exp,
+    ) -> Option<Vec<NewObjectValue>> {
+        use NewObjectValue::*;
         use UnannotatedExp_ as E;
-        match &cond.exp.value {
-            // Function call in condition - check if it's a validation function
-            E::ModuleCall(call) => {
-                let func_sym = call.name.value();
-                let func_name = func_sym.as_str();
 
-                let is_validation = FRESHNESS_VALIDATION_CALLS
-                    .iter()
-                    .any(|f| func_name.contains(f));
-
-                if is_validation {
-                    // Mark any price variables passed to this function as validated
-                    for arg in &call.arguments {
-                        if let Some(var) = self.extract_var(arg) {
-                            if let Some(LocalState::Available(avail_loc, val)) =
-                                state.locals.get(&var)
-                            {
-                                if matches!(val, PriceValidationValue::Unvalidated(_)) {
-                                    state.locals.insert(
-                                        var,
-                                        LocalState::Available(
-                                            *avail_loc,
-                                            PriceValidationValue::Validated(cond.exp.loc),
-                                        ),
-                                    );
-                                }
-                            }
+        match &e.exp.value {
+            E::ModuleCall(call) if call.is(&SUI_ADDR, "object", "new") => {
+                for arg in &call.arguments {
+                    self.exp(context, state, arg);
+                }
+                Some(vec![Fresh(e.exp.loc)])
+            }
+            E::ModuleCall(call) if call.is(&SUI_ADDR, "object", "delete") => {
+                for arg in &call.arguments {
+                    for value in self.exp(context, state, arg) {
+                        if let Fresh(created) = value {
+                            self.report_destroyed(context, e.exp.loc, created);
                         }
                     }
                 }
+                Some(vec![NotNew; value_count(&e.ty)])
+            }
+            // A struct built around a fresh `UID` is the new object itself
+            E::Pack(_, _, fields) => {
+                let mut value = NotNew;
+                for (_, _, field) in fields {
+                    for field_value in self.exp(context, state, field) {
+                        value = NewObjectState::join_value(&value, &field_value);
+                    }
+                }
+                Some(vec![value])
             }
-            // Recursively check inside unary/binary expressions
-            E::UnaryExp(_, inner) => {
-                self.mark_validated_from_guard(state, inner);
-            }
-            E::BinopExp(lhs, _, rhs) => {
-                self.mark_validated_from_guard(state, lhs);
-                self.mark_validated_from_guard(state, rhs);
-            }
-            _ => {}
+            _ => None,
+        }
+    }
+}
+
+impl UnconsumedNewObjectVerifierAI<'_> {
+    fn report_destroyed(&self, context: &mut NewObjectExecutionContext, loc: Loc, created: Loc) {
+        let is_dependency = self
+            .context
+            .env
+            .package_config(self.context.package)
+            .is_dependency;
+        if is_dependency {
+            return;
         }
+
+        let msg = "Object created in this function is deleted on this path without being \
+                   transferred, shared, frozen, returned, or stored. Hand it to its owner \
+                   with `transfer::transfer` / `transfer::share_object`, or avoid creating it \
+                   on this path.";
+        let help = "Object created here";
+        let d = diag!(UNCONSUMED_NEW_OBJECT_DIAG, (loc, msg), (created, help),);
+        context.add_diag(d);
     }
 }
 
-impl SimpleDomain for PriceState {
-    type Value = PriceValidationValue;
+impl SimpleDomain for NewObjectState {
+    type Value = NewObjectValue;
 
     fn new(_context: &CFGContext, locals: BTreeMap<Var, LocalState<Self::Value>>) -> Self {
-        PriceState { locals }
+        NewObjectState { locals }
     }
 
     fn locals(&self) -> &BTreeMap<Var, LocalState<Self::Value>> {
@@ -2766,31 +2896,18 @@ impl SimpleDomain for PriceState {
     }
 
     fn join_value(v1: &Self::Value, v2: &Self::Value) -> Self::Value {
-        use PriceValidationValue::*;
+        use NewObjectValue::*;
         match (v1, v2) {
-            // Validated wins (optimistic - if any path validates, we're ok)
-            (Validated(loc), _) | (_, Validated(loc)) => Validated(*loc),
-            // Unvalidated is concerning
-            (Unvalidated(loc), _) | (_, Unvalidated(loc)) => Unvalidated(*loc),
-            // Not tracked
-            (NotTracked, NotTracked) => NotTracked,
+            // Fresh on any incoming path stays fresh
+            (Fresh(loc), _) | (_, Fresh(loc)) => Fresh(*loc),
+            _ => NotNew,
         }
     }
 
-    fn join_impl(&mut self, other: &Self, _result: &mut JoinResult) {
-        for (var, other_state) in &other.locals {
-            if let Some(self_state) = self.locals.get_mut(var) {
-                if let (LocalState::Available(_, v1), LocalState::Available(_, v2)) =
-                    (self_state, other_state)
-                {
-                    *v1 = Self::join_value(v1, v2);
-                }
-            }
-        }
-    }
+    fn join_impl(&mut self, _other: &Self, _result: &mut JoinResult) {}
 }
 
-impl SimpleExecutionContext for PriceExecutionContext {
+impl SimpleExecutionContext for NewObjectExecutionContext {
     fn add_diag(&mut self, d: CompilerDiagnostic) {
         self.diags.add(d);
     }
@@ -2807,8 +2924,10 @@ pub const ABSINT_CUSTOM_DIAG_CODE_MAP: &[(u8, &LintDescriptor)] = &[
     (4, &DESTROY_ZERO_UNCHECKED_V2), // DESTROY_ZERO_UNCHECKED_V2_DIAG
     (5, &FRESH_ADDRESS_REUSE_V2),    // FRESH_ADDRESS_REUSE_V2_DIAG
     // (6, &TAINTED_TRANSFER_RECIPIENT) - REMOVED: 100% FP rate
-    (7, &CAPABILITY_ESCAPE),     // CAPABILITY_ESCAPE_DIAG
-    (8, &STALE_ORACLE_PRICE_V3), // STALE_ORACLE_PRICE_V3_DIAG
+    (7, &CAPABILITY_ESCAPE), // CAPABILITY_ESCAPE_DIAG
+    // (8, &STALE_ORACLE_PRICE_V3) - REMOVED: superseded by STALE_ORACLE_PRICE
+    (9, &STALE_ORACLE_PRICE),     // STALE_ORACLE_PRICE_DIAG
+    (14, &UNCONSUMED_NEW_OBJECT), // UNCONSUMED_NEW_OBJECT_DIAG
 ];
 
 pub fn descriptor_for_diag_code(code: u8) -> Option<&'static LintDescriptor> {
@@ -2824,7 +2943,9 @@ static DESCRIPTORS: &[&LintDescriptor] = &[
     &FRESH_ADDRESS_REUSE_V2,
     // TAINTED_TRANSFER_RECIPIENT - REMOVED: 100% FP rate
     &CAPABILITY_ESCAPE,
-    &STALE_ORACLE_PRICE_V3,
+    // STALE_ORACLE_PRICE_V3 - REMOVED: superseded by STALE_ORACLE_PRICE
+    &STALE_ORACLE_PRICE,
+    &UNCONSUMED_NEW_OBJECT,
 ];
 
 /// Return all Phase II lint descriptors
@@ -2857,12 +2978,13 @@ pub fn create_visitors(
         visitors.push(Box::new(DestroyZeroVerifier) as Box<dyn AbstractInterpreterVisitor>);
         visitors.push(Box::new(FreshAddressReuseVerifier) as Box<dyn AbstractInterpreterVisitor>);
         // TaintedTransferRecipientVerifier removed - 100% FP rate
-        visitors.push(Box::new(StaleOraclePriceVerifier) as Box<dyn AbstractInterpreterVisitor>);
+        visitors.push(Box::new(OracleFreshnessVerifier) as Box<dyn AbstractInterpreterVisitor>);
     }
 
     if experimental {
         visitors.push(Box::new(UnusedCapabilityVerifier) as Box<dyn AbstractInterpreterVisitor>);
         visitors.push(Box::new(CapabilityEscapeVerifier) as Box<dyn AbstractInterpreterVisitor>);
+        visitors.push(Box::new(UnconsumedNewObjectVerifier) as Box<dyn AbstractInterpreterVisitor>);
     }
 
     visitors
@@ -2931,4 +3053,46 @@ mod tests {
         assert_eq!(DivState::join_value(&Validated, &Unknown), Unknown);
         assert_eq!(DivState::join_value(&Constant, &Validated), Validated);
     }
+
+    #[test]
+    fn test_new_object_value_join() {
+        use NewObjectValue::*;
+        let loc = Loc::invalid();
+
+        // Fresh wins (pessimistic - destroyed on one path is still reported)
+        assert_eq!(NewObjectState::join_value(&Fresh(loc), &NotNew), Fresh(loc));
+        assert_eq!(NewObjectState::join_value(&NotNew, &Fresh(loc)), Fresh(loc));
+        assert_eq!(NewObjectState::join_value(&NotNew, &NotNew), NotNew);
+    }
+
+    #[test]
+    fn test_freshness_value_join() {
+        use FreshnessValue::*;
+        let loc = Loc::invalid();
+
+        // Read wins (pessimistic - a check on one branch does not dominate the merge)
+        assert_eq!(
+            FreshnessState::join_value(&Read(loc), &Validated(loc)),
+            Read(loc)
+        );
+        assert_eq!(
+            FreshnessState::join_value(&Validated(loc), &Read(loc)),
+            Read(loc)
+        );
+        assert_eq!(FreshnessState::join_value(&Read(loc), &Unread), Read(loc));
+
+        assert_eq!(
+            FreshnessState::join_value(&Validated(loc), &Validated(loc)),
+            Validated(loc)
+        );
+        assert_eq!(
+            FreshnessState::join_value(&Timestamp, &Timestamp),
+            Timestamp
+        );
+        assert_eq!(
+            FreshnessState::join_value(&Timestamp, &FreshnessCheck),
+            Unread
+        );
+        assert_eq!(FreshnessState::join_value(&Unread, &Unread), Unread);
+    }
 }
@@ -1,3 +1,4 @@
+use crate::level::LintLevel;
 use clap::{Args as ClapArgs, Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
@@ -26,14 +27,48 @@ pub enum Command {
     /// List available lints.
     ListRules,
 
-    /// Explain a lint.
+    /// Explain a lint, or every lint in a category.
     Explain {
         /// Lint rule name.
-        rule: String,
+        #[arg(required_unless_present = "category")]
+        rule: Option<String>,
+
+        /// Explain every lint in this category (e.g. `security`), sorted by name.
+        #[arg(long, conflicts_with = "rule")]
+        category: Option<String>,
+    },
+
+    /// Write a commented `move-clippy.toml` template to the current directory.
+    InitConfig {
+        /// Overwrite an existing `move-clippy.toml` (or shadow a `.move-clippy.toml`).
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Check config files for unknown lint names, categories, and keys.
+    ///
+    /// Exits non-zero if any lint or category name is unknown.
+    ConfigCheck {
+        /// Check only this file instead of every config found from the current directory up.
+        #[arg(long, value_name = "FILE")]
+        config: Option<PathBuf>,
     },
 
     /// Triage findings - track, categorize, and report lint results.
     Triage(TriageCommand),
+
+    /// Lint, then re-lint `.move` files under PATH whenever they change.
+    Watch(WatchArgs),
+}
+
+#[derive(Debug, Clone, ClapArgs)]
+pub struct WatchArgs {
+    #[command(flatten)]
+    pub lint: LintArgs,
+
+    /// Wait this long after the last change before re-linting, in milliseconds.
+    #[arg(long, value_name = "MS", default_value_t = 200)]
+    pub debounce_ms: u64,
 }
 
 // ============================================================================
@@ -74,6 +109,14 @@ pub enum TriageAction {
         #[arg(long)]
         category: Option<String>,
 
+        /// Only findings detected on or after this date (YYYY-MM-DD, UTC).
+        #[arg(long, value_name = "DATE")]
+        since: Option<String>,
+
+        /// Only findings detected on or before this date (YYYY-MM-DD, UTC).
+        #[arg(long, value_name = "DATE")]
+        until: Option<String>,
+
         /// Maximum number of results to show.
         #[arg(long, default_value = "50")]
         limit: usize,
@@ -114,6 +157,37 @@ pub enum TriageAction {
         output: Option<PathBuf>,
     },
 
+    /// Export findings for review outside the CLI, e.g. in a spreadsheet.
+    Export {
+        /// Export format (csv).
+        #[arg(long, default_value = "csv")]
+        format: String,
+
+        /// Filter by status (needs_review, confirmed, false_positive, wont_fix).
+        #[arg(long)]
+        status: Option<String>,
+
+        /// Filter by lint name.
+        #[arg(long)]
+        lint: Option<String>,
+
+        /// Filter by repository.
+        #[arg(long)]
+        repo: Option<String>,
+
+        /// Filter by severity (critical, high, medium, low, info).
+        #[arg(long)]
+        severity: Option<String>,
+
+        /// Filter by category (security, style, etc.).
+        #[arg(long)]
+        category: Option<String>,
+
+        /// Output file (stdout if not specified).
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+
     /// Import findings from lint output (JSON format).
     Import {
         /// Path to JSON file with lint output.
@@ -158,6 +232,66 @@ pub enum TriageAction {
         sort: String,
     },
 
+    /// Compare this database against another triage database, or two lint runs.
+    ///
+    /// With OTHER, reports findings present in only one database and findings whose
+    /// statuses conflict, to help reconcile work from multiple reviewers.
+    ///
+    /// With --before/--after, compares two `--format json` lint outputs and reports
+    /// new, fixed, and unchanged findings.
+    Diff {
+        /// Path to the other triage database.
+        #[arg(
+            required_unless_present = "before",
+            conflicts_with_all = ["before", "after"]
+        )]
+        other: Option<PathBuf>,
+
+        /// JSON lint output from the baseline run.
+        #[arg(long, value_name = "FILE", requires = "after")]
+        before: Option<PathBuf>,
+
+        /// JSON lint output from the run to compare against the baseline.
+        #[arg(long, value_name = "FILE", requires = "before")]
+        after: Option<PathBuf>,
+
+        /// Output format for run diffs (md, json, text).
+        #[arg(long, default_value = "text")]
+        format: String,
+
+        /// Exit with code 1 if the `--after` run has new findings.
+        #[arg(long, requires = "after")]
+        fail_on_new: bool,
+
+        /// Use default exclude patterns (tests, deps, vendor).
+        #[arg(long)]
+        exclude_defaults: bool,
+
+        /// Additional glob patterns to exclude (can be repeated).
+        #[arg(long = "exclude", value_name = "PATTERN")]
+        exclude_patterns: Vec<String>,
+    },
+
+    /// Merge findings from other triage databases into a master database.
+    ///
+    /// Findings are matched by ID. Decided statuses win over needs_review, and the
+    /// later review wins between equally decided copies. Findings where two databases
+    /// record different decided statuses are reported and keep the master's status
+    /// unless --prefer names the database to trust.
+    Merge {
+        /// Master database to merge into (created if missing).
+        #[arg(long, value_name = "FILE")]
+        into: PathBuf,
+
+        /// Databases to merge, applied in order. Each must exist.
+        #[arg(required = true, value_name = "DB")]
+        dbs: Vec<PathBuf>,
+
+        /// Resolve status conflicts in favor of this database (one of DB).
+        #[arg(long, value_name = "DB")]
+        prefer: Option<PathBuf>,
+    },
+
     /// Bulk update multiple findings matching criteria.
     BulkUpdate {
         /// Filter by lint name.
@@ -196,6 +330,19 @@ pub struct LintArgs {
     #[arg(value_name = "PATH")]
     pub paths: Vec<PathBuf>,
 
+    /// Also lint the files listed in FILE, one per line (`-` reads the list from stdin).
+    ///
+    /// Blank lines, `#` comments, and non-`.move` paths are ignored, so
+    /// `git diff --name-only | move-clippy lint --files-from -` lints only changed files.
+    #[arg(long, value_name = "FILE")]
+    pub files_from: Option<PathBuf>,
+
+    /// Report diagnostics for stdin input under PATH instead of `stdin`.
+    ///
+    /// PATH is only a label for editor and CI integrations; nothing is read from it.
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["paths", "files_from"])]
+    pub stdin_filename: Option<PathBuf>,
+
     /// Lint mode.
     #[arg(long, value_enum, default_value_t = LintMode::Fast)]
     pub mode: LintMode,
@@ -207,6 +354,10 @@ pub struct LintArgs {
     pub package: Option<PathBuf>,
 
     /// Path to a move-clippy.toml config file. If omitted, move-clippy searches parent directories.
+    ///
+    /// Without this flag, fast-mode lints use every config from each file's directory upwards.
+    /// The `--mode full` semantic pass uses one config for the whole package, found from
+    /// `--package` (or the first PATH) upwards.
     #[arg(long, value_name = "FILE")]
     pub config: Option<PathBuf>,
 
@@ -222,10 +373,110 @@ pub struct LintArgs {
     #[arg(long, value_delimiter = ',')]
     pub skip: Vec<String>,
 
+    /// Run exactly the lints listed in FILE, one per line, in addition to --only.
+    ///
+    /// Blank lines and `#` comments are ignored and `-name` adds `name` to --skip. Listed
+    /// lints run even if the config's `disabled` names them.
+    #[arg(long, value_name = "FILE")]
+    pub rulelist: Option<PathBuf>,
+
+    /// Only run lints in these categories (comma-separated, e.g. `security,style`).
+    ///
+    /// Intersects with --only and --only-group.
+    #[arg(long, value_delimiter = ',', value_name = "CATEGORY")]
+    pub only_category: Vec<String>,
+
+    /// Only run lints in these tiers (comma-separated, e.g. `stable,preview`).
+    ///
+    /// Selecting `preview` or `experimental` also enables that tier.
+    /// Intersects with --only and --only-category.
+    #[arg(long, value_delimiter = ',', value_name = "GROUP")]
+    pub only_group: Vec<String>,
+
+    /// Only run lints that provide an auto-fix.
+    ///
+    /// Applied after --only, --skip, and category/tier selection. Combine with --fix
+    /// for a quick cleanup pass that applies exactly these lints.
+    #[arg(long)]
+    pub fixable_only: bool,
+
+    /// Only emit diagnostics at or above this level, in every output format.
+    ///
+    /// Filtered diagnostics are dropped before output and do not count toward
+    /// the exit code. Diagnostics from lints at `deny` are always kept, since
+    /// they fail the run.
+    #[arg(long, value_enum, value_name = "LEVEL")]
+    pub min_level: Option<MinLevel>,
+
+    /// Print an aggregate count of diagnostics per level.
+    ///
+    /// In pretty mode a trailing `summary:` line is printed. In JSON mode the output
+    /// becomes `{ "diagnostics": [...], "summary": {...} }` instead of a bare array.
+    #[arg(long)]
+    pub summary: bool,
+
+    /// Include the end of each diagnostic's span (`end_row`, `end_column`) and its `help`
+    /// text in JSON output.
+    ///
+    /// Off by default so the JSON schema stays backward compatible.
+    #[arg(long, visible_alias = "json-spans")]
+    pub json_full_span: bool,
+
+    /// Include every fix suggestion (message, replacement, applicability) in JSON output.
+    ///
+    /// Off by default so the JSON schema stays backward compatible.
+    #[arg(long)]
+    pub include_suggestions: bool,
+
+    /// Show at most N diagnostics across all files, then a count of the rest.
+    ///
+    /// Suppressed diagnostics still count toward the exit code and `--summary`.
+    #[arg(long, value_name = "N")]
+    pub max_diagnostics: Option<usize>,
+
+    /// Skip source files larger than BYTES, reporting one `note` per skipped file instead.
+    ///
+    /// The note is a diagnostic like any other: it counts toward `--max-diagnostics` and
+    /// `--summary`, is hidden by `--min-level warn`, and never fails the run. Unlimited by
+    /// default.
+    #[arg(long, value_name = "BYTES")]
+    pub max_file_size: Option<u64>,
+
+    /// Collapse findings with the same lint and message reported in several files into one.
+    ///
+    /// Pretty output shows the first location followed by `... and N more locations`; JSON
+    /// output adds an `occurrences` array listing every location. Only the display changes:
+    /// each occurrence still counts toward the exit code and `--summary`. Supported with
+    /// `pretty`, `rich`, and `json` output.
+    #[arg(long)]
+    pub dedup: bool,
+
+    /// Print wall time per lint (total ms, calls, average) to stderr when the run finishes.
+    ///
+    /// Syntactic rules are listed by lint name and semantic passes as `semantic::<pass>`,
+    /// slowest first. Timing is off unless this flag is set.
+    #[arg(long)]
+    pub timings: bool,
+
     /// Exit with code 1 if any diagnostics are emitted.
     #[arg(long)]
     pub deny_warnings: bool,
 
+    /// In full mode, report a package that fails to compile as a `note` and keep linting.
+    ///
+    /// The note carries the compiler's error text. Without this flag a compile failure
+    /// aborts the run.
+    #[arg(long)]
+    pub continue_on_error: bool,
+
+    /// Exit with code 1 if any diagnostic is in one of these categories (comma-separated,
+    /// e.g. `security,suspicious`), whatever its level.
+    ///
+    /// Category names are those of `LintCategory::as_str`: `style`, `modernization`, `naming`,
+    /// `test_quality`, `suspicious`, `security`. Combines with --deny-warnings.
+    #[arg(long, value_delimiter = ',', value_name = "CATEGORY")]
+    pub fail_on_category: Vec<String>,
+
     /// Skip files in test directories and test modules.
     ///
     /// Skips files in `/tests/` directories and files ending with `_tests.move` or `_test.move`.
@@ -233,6 +484,13 @@ pub struct LintArgs {
     #[arg(long)]
     pub skip_tests: bool,
 
+    /// Do not honor `.gitignore`/`.ignore` rules when walking directories.
+    ///
+    /// `.git`, `target` and `build` directories are still skipped. Files passed explicitly
+    /// are always linted.
+    #[arg(long)]
+    pub no_ignore: bool,
+
     /// Enable preview rules that are not yet stable.
     ///
     /// Preview rules may have higher false-positive rates or change behavior
@@ -254,6 +512,13 @@ pub struct LintArgs {
     #[arg(long)]
     pub show_tier: bool,
 
+    /// Print only diagnostics that fail the run, and no per-file count lines.
+    ///
+    /// With `--deny-warnings`, warnings fail the run and are printed too. The exit code is
+    /// unchanged. Only affects the human-readable formats (`pretty`, `rich`).
+    #[arg(long, short = 'q')]
+    pub quiet: bool,
+
     /// Apply safe auto-fixes to files.
     ///
     /// Only machine-applicable fixes are applied by default.
@@ -267,6 +532,14 @@ pub struct LintArgs {
     #[arg(long, requires = "fix")]
     pub fix_dry_run: bool,
 
+    /// Print all fixes as one unified patch on stdout instead of applying them (requires --fix).
+    ///
+    /// Paths are relative to the root of the current git repository (or the working directory
+    /// outside one), so the output can be fed to `git apply`. Files outside that root are an error.
+    /// Nothing is written to disk.
+    #[arg(long, requires = "fix", conflicts_with = "fix_dry_run")]
+    pub print_patch: bool,
+
     /// Apply unsafe fixes (requires --fix).
     ///
     /// Unsafe fixes may change runtime behavior. Review changes carefully
@@ -274,6 +547,17 @@ pub struct LintArgs {
     #[arg(long, requires = "fix")]
     pub unsafe_fixes: bool,
 
+    /// Write one report per linted source file into this directory.
+    ///
+    /// Reports mirror the source layout and use the selected --format, e.g.
+    /// `sources/foo.move` becomes `<DIR>/sources/foo.move.json`.
+    #[arg(long, value_name = "DIR")]
+    pub output_per_file: Option<PathBuf>,
+
+    /// Do not write per-file reports for files without diagnostics (requires --output-per-file).
+    #[arg(long, requires = "output_per_file")]
+    pub skip_clean_reports: bool,
+
     /// Skip creating .bak backup files before applying fixes (requires --fix).
     ///
     /// By default, move-clippy creates a .bak backup of each file before
@@ -288,9 +572,30 @@ pub enum LintMode {
     Full,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum MinLevel {
+    Warn,
+    Error,
+}
+
+impl MinLevel {
+    pub fn as_lint_level(self) -> LintLevel {
+        match self {
+            MinLevel::Warn => LintLevel::Warn,
+            MinLevel::Error => LintLevel::Error,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, ValueEnum)]
 pub enum OutputFormat {
     Pretty,
+    /// Pretty output with the offending source line, a caret underline and help text.
+    Rich,
     Json,
     Github,
+    /// Checkstyle XML, for Jenkins and GitLab warning plugins.
+    Checkstyle,
+    /// JUnit XML, for CI systems that render lint results as test results.
+    Junit,
 }
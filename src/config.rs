@@ -1,5 +1,22 @@
+//! Loading and merging `move-clippy.toml` configuration.
+//!
+//! Without `--config`, every directory from a linted file up to the filesystem root may hold a
+//! `move-clippy.toml` (or hidden `.move-clippy.toml`). All of them apply, merged so that nearer
+//! files override farther ones:
+//!
+//! - `disabled` lists are unioned.
+//! - Per-lint levels, `category_levels`, `known_addresses`, `thresholds`, and `[aliases]`
+//!   entries: nearest file wins.
+//! - `preview` and `unsafe_fixes` are OR-ed; any file can turn them on.
+//!
+//! An explicit `--config` path is used on its own and disables discovery.
+
 use crate::level::LintLevel;
-use anyhow::{Context, Result};
+use crate::lint::{
+    DEFAULT_ENTRY_MAX_PARAMS, DEFAULT_MAX_NESTING_DEPTH, LintCategory, RuleGroup, Thresholds,
+    all_known_lints, check_lint_name, resolve_lint_alias,
+};
+use anyhow::{Context, Result, anyhow};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -9,6 +26,12 @@ use std::path::{Path, PathBuf};
 pub struct MoveClippyConfig {
     #[serde(default)]
     pub lints: LintsConfig,
+
+    /// Team-specific lint names (e.g. `deadcode = "unused_package_function"`), from `[aliases]`.
+    ///
+    /// Accepted wherever a lint name is, such as `--only` and `--skip`.
+    #[serde(default)]
+    pub aliases: HashMap<String, String>,
 }
 
 /// Per-lint configuration under the `[lints]` section.
@@ -31,26 +54,118 @@ pub struct LintsConfig {
     #[serde(default)]
     pub unsafe_fixes: bool,
 
+    /// Additional well-known addresses (e.g. `deepbook = "0xdee9"`) that address-literal
+    /// lints should not flag. Merged with the framework defaults `0x0`, `0x1`, and `0x2`.
+    #[serde(default)]
+    pub known_addresses: HashMap<String, String>,
+
+    /// Default levels per category (e.g. `security = "error"`), from `[lints.category_levels]`.
+    ///
+    /// A lint without an explicit level takes its category's default.
+    #[serde(default)]
+    pub category_levels: HashMap<String, LintLevel>,
+
+    /// Numeric limits for threshold lints, from `[lints.thresholds]`.
+    #[serde(default)]
+    pub thresholds: Thresholds,
+
     /// Explicit per-lint levels (e.g. `modern_module_syntax = "error"`).
     #[serde(flatten)]
     pub levels: HashMap<String, LintLevel>,
 }
 
+impl LintsConfig {
+    /// Resolve `[lints.category_levels]` keys to categories.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if a key is not a known lint category.
+    pub fn parsed_category_levels(&self) -> Result<HashMap<LintCategory, LintLevel>> {
+        self.category_levels
+            .iter()
+            .map(|(name, level)| {
+                LintCategory::from_name(name)
+                    .map(|category| (category, *level))
+                    .ok_or_else(|| {
+                        anyhow!("unknown lint category in [lints.category_levels]: {name}")
+                    })
+            })
+            .collect()
+    }
+}
+
+impl MoveClippyConfig {
+    /// Check that every `[aliases]` entry points at a known lint and does not shadow one.
+    ///
+    /// # Errors
+    ///
+    /// Returns error naming the first offending alias.
+    pub fn validate_aliases(&self) -> Result<()> {
+        let known = all_known_lints();
+        let mut aliases: Vec<_> = self.aliases.iter().collect();
+        aliases.sort_unstable();
+        for (alias, target) in aliases {
+            if known.contains(alias.as_str()) {
+                return Err(anyhow!(
+                    "alias `{alias}` in [aliases] shadows the lint of the same name"
+                ));
+            }
+            if !known.contains(resolve_lint_alias(target)) {
+                return Err(anyhow!(
+                    "alias `{alias}` in [aliases] points at unknown lint `{target}`"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Merge `nearer` (a config closer to the linted file) on top of `self`.
+    #[must_use]
+    pub fn merge(mut self, nearer: MoveClippyConfig) -> MoveClippyConfig {
+        let MoveClippyConfig {
+            lints: nearer,
+            aliases,
+        } = nearer;
+        let lints = &mut self.lints;
+        for name in nearer.disabled {
+            if !lints.disabled.contains(&name) {
+                lints.disabled.push(name);
+            }
+        }
+        lints.preview |= nearer.preview;
+        lints.unsafe_fixes |= nearer.unsafe_fixes;
+        lints.known_addresses.extend(nearer.known_addresses);
+        lints.category_levels.extend(nearer.category_levels);
+        lints.levels.extend(nearer.levels);
+        lints.thresholds = lints.thresholds.merge(nearer.thresholds);
+        self.aliases.extend(aliases);
+        self
+    }
+}
+
 /// Default file name for configuration that `move-clippy` searches for.
 pub const DEFAULT_CONFIG_FILE_NAME: &str = "move-clippy.toml";
 
+/// Hidden variant of [`DEFAULT_CONFIG_FILE_NAME`], used when a directory has no visible config.
+pub const HIDDEN_CONFIG_FILE_NAME: &str = ".move-clippy.toml";
+
+fn config_file_in(dir: &Path) -> Option<PathBuf> {
+    [DEFAULT_CONFIG_FILE_NAME, HIDDEN_CONFIG_FILE_NAME]
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|candidate| candidate.is_file())
+}
+
 /// Walk up from `start_dir` to find the nearest `move-clippy.toml`, if any.
 #[must_use]
 pub fn find_config_file(start_dir: &Path) -> Option<PathBuf> {
-    let mut cur = Some(start_dir);
-    while let Some(dir) = cur {
-        let candidate = dir.join(DEFAULT_CONFIG_FILE_NAME);
-        if candidate.is_file() {
-            return Some(candidate);
-        }
-        cur = dir.parent();
-    }
-    None
+    start_dir.ancestors().find_map(config_file_in)
+}
+
+/// Walk up from `start_dir` collecting every config file, nearest first.
+#[must_use]
+pub fn find_config_files(start_dir: &Path) -> Vec<PathBuf> {
+    start_dir.ancestors().filter_map(config_file_in).collect()
 }
 
 /// Load and parse a configuration file from disk.
@@ -60,10 +175,194 @@ pub fn load_config_file(path: &Path) -> Result<MoveClippyConfig> {
         .with_context(|| format!("failed to read config file: {}", path.display()))?;
     let cfg: MoveClippyConfig = toml::from_str(&raw)
         .with_context(|| format!("failed to parse config file: {}", path.display()))?;
+    cfg.validate_aliases()
+        .with_context(|| format!("invalid config file: {}", path.display()))?;
     Ok(cfg)
 }
 
+/// One problem reported by [`check_config_file`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigProblem {
+    /// 1-based line of the offending key or value, when it can be found.
+    pub line: Option<usize>,
+    /// Unknown lint and category names are errors; unrecognized keys are warnings.
+    pub is_error: bool,
+    pub message: String,
+}
+
+/// The first 1-based line of `raw` matching `pred`, ignoring leading whitespace.
+fn find_line(raw: &str, pred: impl Fn(&str) -> bool) -> Option<usize> {
+    raw.lines()
+        .position(|line| pred(line.trim_start()))
+        .map(|idx| idx + 1)
+}
+
+/// Line defining `key`, as `key = ...` or as a `[key]`/`[parent.key]` table header.
+fn key_line(raw: &str, key: &str) -> Option<usize> {
+    find_line(raw, |line| {
+        let assigned = line
+            .strip_prefix(key)
+            .is_some_and(|rest| rest.trim_start().starts_with('='));
+        let header = line.starts_with('[')
+            && line
+                .trim_matches(|c| c == '[' || c == ']')
+                .rsplit('.')
+                .next()
+                .is_some_and(|last| last.trim() == key);
+        assigned || header
+    })
+}
+
+/// Validate a config file beyond what parsing checks.
+///
+/// Lint names in `disabled` and per-lint level keys go through the same check as `--only`
+/// (built-in aliases are accepted), and `[lints.category_levels]` keys must be categories.
+/// Top-level tables other than `[lints]` and `[aliases]` are reported as unknown keys.
+///
+/// # Errors
+///
+/// Returns error if the file cannot be read or does not parse as a config at all.
+pub fn check_config_file(path: &Path) -> Result<Vec<ConfigProblem>> {
+    let cfg = load_config_file(path)?;
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read config file: {}", path.display()))?;
+    let table: toml::Table = toml::from_str(&raw)
+        .with_context(|| format!("failed to parse config file: {}", path.display()))?;
+
+    let mut problems = Vec::new();
+
+    let mut top_keys: Vec<_> = table.keys().collect();
+    top_keys.sort_unstable();
+    for key in top_keys {
+        if key != "lints" && key != "aliases" {
+            problems.push(ConfigProblem {
+                line: key_line(&raw, key),
+                is_error: false,
+                message: format!("unknown key `{key}`"),
+            });
+        }
+    }
+
+    for name in &cfg.lints.disabled {
+        if let Err(err) = check_lint_name(name) {
+            problems.push(ConfigProblem {
+                line: find_line(&raw, |line| line.contains(&format!("\"{name}\""))),
+                is_error: true,
+                message: format!("{err} (in `disabled`)"),
+            });
+        }
+    }
+
+    let mut level_keys: Vec<_> = cfg.lints.levels.keys().collect();
+    level_keys.sort_unstable();
+    for name in level_keys {
+        if let Err(err) = check_lint_name(name) {
+            problems.push(ConfigProblem {
+                line: key_line(&raw, name),
+                is_error: true,
+                message: format!("{err} (in `[lints]`)"),
+            });
+        }
+    }
+
+    let mut categories: Vec<_> = cfg.lints.category_levels.keys().collect();
+    categories.sort_unstable();
+    for name in categories {
+        if LintCategory::from_name(name).is_none() {
+            problems.push(ConfigProblem {
+                line: key_line(&raw, name),
+                is_error: true,
+                message: format!("unknown lint category: {name} (in `[lints.category_levels]`)"),
+            });
+        }
+    }
+
+    problems.sort_by_key(|p| p.line);
+    Ok(problems)
+}
+
+/// Load and merge config files given nearest first, as returned by [`find_config_files`].
+#[must_use = "configuration may contain important settings"]
+pub fn load_merged_config(paths: &[PathBuf]) -> Result<MoveClippyConfig> {
+    paths
+        .iter()
+        .rev()
+        .try_fold(MoveClippyConfig::default(), |merged, path| {
+            Ok(merged.merge(load_config_file(path)?))
+        })
+}
+
+/// A commented starter config covering every `[lints]` key.
+///
+/// The file is a no-op as written: every setting is at its default and examples are
+/// commented out. Category names and the example lint come from the registry, so the
+/// template stays in sync with the lints this build knows about.
+#[must_use]
+pub fn config_template() -> String {
+    let mut stable: Vec<_> = crate::unified::unified_registry()
+        .descriptors()
+        .filter(|d| d.group == RuleGroup::Stable)
+        .map(|d| d.name)
+        .collect();
+    stable.sort_unstable();
+    let example_lint = stable.first().copied().unwrap_or("lint_name");
+    let categories = LintCategory::ALL
+        .iter()
+        .map(LintCategory::as_str)
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        r#"# move-clippy configuration.
+#
+# Every `{DEFAULT_CONFIG_FILE_NAME}` or `{HIDDEN_CONFIG_FILE_NAME}` between a linted file and the
+# filesystem root applies; nearer files override farther ones. `--config FILE` uses only FILE.
+# Run `move-clippy list-rules` for lint names and `move-clippy explain <lint>` for details.
+
+[lints]
+# Enable preview lints. They may have more false positives than stable lints.
+preview = false
+
+# Also apply fixes that may change runtime behavior when running with --fix.
+unsafe_fixes = false
+
+# Lints to turn off entirely.
+disabled = []
+# disabled = ["{example_lint}"]
+
+# Per-lint levels: "allow", "warn", "deny", or "error". These override category levels.
+# "deny" reports like a warning but makes the run exit non-zero.
+# {example_lint} = "error"
+
+[lints.category_levels]
+# Default level for every lint in a category.
+# Categories: {categories}.
+# security = "error"
+
+[lints.known_addresses]
+# Named addresses that address-literal lints should not flag.
+# 0x0, 0x1, and 0x2 are always known.
+# deepbook = "0xdee9"
+
+[lints.thresholds]
+# Maximum parameters for an `entry` function, not counting `TxContext` or `Clock`.
+# entry_max_params = {DEFAULT_ENTRY_MAX_PARAMS}
+# Maximum depth of nested blocks in a function body.
+# max_nesting_depth = {DEFAULT_MAX_NESTING_DEPTH}
+# Skip files larger than this many bytes (default: no limit).
+# max_file_size = 1048576
+
+[aliases]
+# Extra names for lints, usable with --only and --skip.
+# short_name = "{example_lint}"
+"#
+    )
+}
+
 /// Load configuration from an explicit path or by searching from `start_dir`.
+///
+/// When searching, all config files from `start_dir` upwards are merged and the returned
+/// path is the nearest one.
 #[must_use = "configuration may contain important settings"]
 pub fn load_config(
     explicit_path: Option<&Path>,
@@ -74,9 +373,10 @@ pub fn load_config(
         return Ok(Some((p.to_path_buf(), cfg)));
     }
 
-    let Some(p) = find_config_file(start_dir) else {
+    let paths = find_config_files(start_dir);
+    let Some(nearest) = paths.first().cloned() else {
         return Ok(None);
     };
-    let cfg = load_config_file(&p)?;
-    Ok(Some((p, cfg)))
+    let cfg = load_merged_config(&paths)?;
+    Ok(Some((nearest, cfg)))
 }
@@ -7,7 +7,8 @@
 // Architecture:
 // - CallGraph: Maps module -> called modules and tracks capability flows
 // - CrossModuleAnalyzer: Coordinates analysis across the entire program
-// - Advanced lints: transitive_capability_leak, flashloan_without_repay
+// - Advanced lints: transitive_capability_leak, flashloan_without_repay, store_in_shared_cap,
+//   unused_package_function
 
 // Keep these lints warning-clean while still allowing WIP helpers to live nearby.
 #![allow(dead_code)]
@@ -16,6 +17,7 @@
 use crate::lint::{
     AnalysisKind, FixDescriptor, LintCategory, LintDescriptor, RuleGroup, TypeSystemGap,
 };
+use crate::type_classifier::{is_capability_type_from_ty, is_coin_type, is_sui_framework_call};
 use move_compiler::{
     diag,
     diagnostics::{
@@ -55,6 +57,22 @@ const FLASHLOAN_REPAY_DIAG: DiagnosticInfo = custom(
     "flashloan borrowed but not repaid on all paths",
 );
 
+const STORE_IN_SHARED_CAP_DIAG: DiagnosticInfo = custom(
+    LINT_WARNING_PREFIX,
+    Severity::Warning,
+    CLIPPY_CATEGORY,
+    12, // store_in_shared_cap
+    "capability stored in a shared object",
+);
+
+const UNUSED_PACKAGE_FUNCTION_DIAG: DiagnosticInfo = custom(
+    LINT_WARNING_PREFIX,
+    Severity::Warning,
+    CLIPPY_CATEGORY,
+    13, // unused_package_function
+    "public(package) function is never called",
+);
+
 // NOTE: PRICE_MANIPULATION_DIAG removed - price_manipulation_window used name-based heuristics
 
 // ============================================================================
@@ -81,6 +99,26 @@ pub static FLASHLOAN_WITHOUT_REPAY: LintDescriptor = LintDescriptor {
     gap: Some(TypeSystemGap::TemporalOrdering),
 };
 
+pub static STORE_IN_SHARED_CAP: LintDescriptor = LintDescriptor {
+    name: "store_in_shared_cap",
+    category: LintCategory::Security,
+    description: "Capability stored in a struct field of an object that is shared (type-based cross-module, requires --mode full --experimental)",
+    group: RuleGroup::Experimental,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::CrossModule,
+    gap: Some(TypeSystemGap::CapabilityEscape),
+};
+
+pub static UNUSED_PACKAGE_FUNCTION: LintDescriptor = LintDescriptor {
+    name: "unused_package_function",
+    category: LintCategory::Style,
+    description: "`public(package)` function with no callers in the package (type-based cross-module, requires --mode full --experimental)",
+    group: RuleGroup::Experimental,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::CrossModule,
+    gap: None,
+};
+
 // ============================================================================
 // Call Graph Infrastructure
 // ============================================================================
@@ -630,20 +668,21 @@ pub fn lint_transitive_capability_leak(
 
             // If the callee has any by-value key+store parameters, a cross-module call
             // could leak a capability value into a public API.
-            let mut expects_cap_by_value = false;
-            for (_mut_, _param_var, param_ty) in &callee_fdef.signature.parameters {
-                if matches!(
-                    &param_ty.value,
-                    N::Type_::Apply(Some(abilities), _, _)
-                        if abilities.has_ability_(Ability_::Key) && abilities.has_ability_(Ability_::Store)
-                ) {
-                    expects_cap_by_value = true;
-                    break;
-                }
-            }
-            if !expects_cap_by_value {
+            let Some(cap_param_loc) = callee_fdef
+                .signature
+                .parameters
+                .iter()
+                .find(|(_mut_, _param_var, param_ty)| {
+                    matches!(
+                        &param_ty.value,
+                        N::Type_::Apply(Some(abilities), _, _)
+                            if abilities.has_ability_(Ability_::Key) && abilities.has_ability_(Ability_::Store)
+                    )
+                })
+                .map(|(_mut_, param_var, _param_ty)| param_var.loc)
+            else {
                 continue;
-            }
+            };
 
             let callee_symbol = callee_func.value();
             let callee_name = callee_symbol.as_str();
@@ -656,9 +695,10 @@ pub fn lint_transitive_capability_leak(
             diags.push(diag!(
                 TRANSITIVE_CAP_LEAK_DIAG,
                 (call.loc, msg),
+                (caller_func.loc(), "Capability flows out of this function"),
                 (
-                    callee_fdef.loc,
-                    "Public callee has key+store by-value param"
+                    cap_param_loc,
+                    "Public callee takes a key+store value by value here"
                 ),
                 (call.loc, help),
             ));
@@ -733,6 +773,10 @@ pub fn lint_flashloan_without_repay(
             diags.push(diag!(
                 FLASHLOAN_REPAY_DIAG,
                 (func_loc, msg),
+                (
+                    fdef.signature.return_type.loc,
+                    "Hot potato is returned here"
+                ),
                 (func_loc, help)
             ));
         }
@@ -742,9 +786,201 @@ pub fn lint_flashloan_without_repay(
 }
 
 // ============================================================================
-// 3. Price Manipulation Window Detection
+// 3. Capabilities Stored in Shared Objects
+// ============================================================================
+
+const SHARE_FUNCTIONS: &[&str] = &["share_object", "public_share_object"];
+
+/// Visit every module call under `exp` together with the call's location.
+fn for_each_module_call<'a>(exp: &'a T::Exp, f: &mut impl FnMut(&'a T::ModuleCall, Loc)) {
+    crate::semantic::lints::shared::for_each_exp(exp, &mut |e| {
+        if let T::UnannotatedExp_::ModuleCall(call) = &e.exp.value {
+            f(call, e.exp.loc);
+        }
+    });
+}
+
+/// Find a capability held by `ty`, directly or as a type argument (e.g. `Option<AdminCap>`).
+fn capability_in_type(ty: &N::Type_) -> Option<&N::Type_> {
+    if !is_coin_type(ty) && is_capability_type_from_ty(ty) {
+        return Some(ty);
+    }
+    match ty {
+        N::Type_::Apply(_, _, type_args) => {
+            type_args.iter().find_map(|t| capability_in_type(&t.value))
+        }
+        _ => None,
+    }
+}
+
+/// The first field of a root-package struct that holds a capability, as `(field, capability)`.
+fn capability_field(
+    info: &TypingProgramInfo,
+    root_modules: &BTreeSet<ModuleIdent>,
+    ty: &N::Type_,
+) -> Option<(String, String)> {
+    let N::Type_::Apply(_, type_name, _) = ty else {
+        return None;
+    };
+    let N::TypeName_::ModuleType(mident, struct_name) = &type_name.value else {
+        return None;
+    };
+    if !is_root_package_module(root_modules, mident) {
+        return None;
+    }
+    let sdef = info.modules.get(mident)?.structs.get(struct_name)?;
+    let N::StructFields::Defined(_, fields) = &sdef.fields else {
+        return None;
+    };
+    fields.iter().find_map(|(_, field, (_, (_, field_ty)))| {
+        capability_in_type(&field_ty.value).map(|cap| (field.to_string(), type_display(cap)))
+    })
+}
+
+fn type_display(ty: &N::Type_) -> String {
+    match ty {
+        N::Type_::Apply(_, type_name, _) => match &type_name.value {
+            N::TypeName_::ModuleType(_, name) => name.value().to_string(),
+            _ => format!("{type_name}"),
+        },
+        _ => "capability".to_string(),
+    }
+}
+
+/// Detect capabilities stored in fields of objects that are passed to `transfer::share_object`.
+///
+/// Struct field types come from `TypingProgramInfo`, so the share site and the struct (and the
+/// function that packed the capability into it) may live in different modules of the package.
+/// Once shared, anyone can take `&mut` to the object and reach the capability through it.
+pub fn lint_store_in_shared_cap(
+    program: &T::Program,
+    info: &TypingProgramInfo,
+) -> Vec<CompilerDiagnostic> {
+    let mut diags = Vec::new();
+    let root_modules = root_package_modules(program);
+
+    for (mident, mdef) in program.modules.key_cloned_iter() {
+        if !is_root_package_module(&root_modules, &mident) {
+            continue;
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+
+            for item in seq_items.iter() {
+                let (T::SequenceItem_::Seq(exp) | T::SequenceItem_::Bind(_, _, exp)) = &item.value
+                else {
+                    continue;
+                };
+                for_each_module_call(exp, &mut |call, loc| {
+                    let is_share = SHARE_FUNCTIONS
+                        .iter()
+                        .any(|function| is_sui_framework_call(call, "transfer", function));
+                    if !is_share {
+                        return;
+                    }
+                    let Some(shared_ty) = call.type_arguments.first() else {
+                        return;
+                    };
+                    let Some((field, cap)) =
+                        capability_field(info, &root_modules, &shared_ty.value)
+                    else {
+                        return;
+                    };
+
+                    let fn_symbol = fname.value();
+                    let shared_name = type_display(&shared_ty.value);
+                    let msg = format!(
+                        "{mident}::{} shares `{shared_name}`, whose field `{field}` holds capability `{cap}`",
+                        fn_symbol.as_str()
+                    );
+                    let help = "Anyone can take a mutable reference to a shared object and use the \
+                               capability inside it. Keep capabilities in owned objects, or store an ID \
+                               and check it against a separately owned capability.";
+
+                    diags.push(diag!(STORE_IN_SHARED_CAP_DIAG, (loc, msg), (loc, help)));
+                });
+            }
+        }
+    }
+
+    diags
+}
+
+// ============================================================================
+// 4. Unused Package Functions
+// ============================================================================
+
+/// Detect `public(package)` functions that no function in the root package calls.
+///
+/// Calls are collected from every root-package module, so a caller in a sibling module
+/// counts. `entry` functions (callable from transactions), test functions, and macros (inlined
+/// at the call site, so they never appear as a call) are skipped. A function only called from
+/// a macro body still looks unused, which is why this lint is experimental.
+pub fn lint_unused_package_function(
+    program: &T::Program,
+    info: &TypingProgramInfo,
+) -> Vec<CompilerDiagnostic> {
+    let mut diags = Vec::new();
+    let root_modules = root_package_modules(program);
+
+    let mut called: BTreeSet<(ModuleIdent, FunctionName)> = BTreeSet::new();
+    for (mident, mdef) in program.modules.key_cloned_iter() {
+        if !is_root_package_module(&root_modules, &mident) {
+            continue;
+        }
+        for (_fname, fdef) in mdef.functions.key_cloned_iter() {
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+            for item in seq_items.iter() {
+                let (T::SequenceItem_::Seq(exp) | T::SequenceItem_::Bind(_, _, exp)) = &item.value
+                else {
+                    continue;
+                };
+                for_each_module_call(exp, &mut |call, _loc| {
+                    called.insert((call.module, call.name));
+                });
+            }
+        }
+    }
+
+    for (mident, mdef) in program.modules.key_cloned_iter() {
+        if !is_root_package_module(&root_modules, &mident) {
+            continue;
+        }
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            if !matches!(fdef.visibility, Visibility::Package(_))
+                || fdef.entry.is_some()
+                || fdef.macro_.is_some()
+                || fdef.attributes.is_test_or_test_only()
+            {
+                continue;
+            }
+            if called.contains(&(mident, fname)) {
+                continue;
+            }
+
+            let loc = fname.loc();
+            let msg = format!(
+                "`{mident}::{}` is `public(package)` but is never called within the package",
+                fname.value().as_str()
+            );
+            let help = "Remove the function if it is leftover, or make it `public` if it is \
+                        meant to be called by other packages.";
+            diags.push(diag!(UNUSED_PACKAGE_FUNCTION_DIAG, (loc, msg), (loc, help)));
+        }
+    }
+
+    diags
+}
+
+// ============================================================================
+// 5. Price Manipulation Window Detection
 // ============================================================================
-// 3. Price Manipulation Window Detection - REMOVED
+// 5. Price Manipulation Window Detection - REMOVED
 // ============================================================================
 // NOTE: lint_price_manipulation_window and related functions removed - used
 // name-based heuristics (checking function names like "get_price", "oracle",
@@ -786,7 +1022,12 @@ fn type_is_key_store_value(ty: &Type_) -> bool {
 
 // Static slice for descriptors (avoids returning reference to temporary)
 // NOTE: PRICE_MANIPULATION_WINDOW removed - used name-based heuristics
-static DESCRIPTORS: &[&LintDescriptor] = &[&TRANSITIVE_CAPABILITY_LEAK, &FLASHLOAN_WITHOUT_REPAY];
+static DESCRIPTORS: &[&LintDescriptor] = &[
+    &TRANSITIVE_CAPABILITY_LEAK,
+    &FLASHLOAN_WITHOUT_REPAY,
+    &STORE_IN_SHARED_CAP,
+    &UNUSED_PACKAGE_FUNCTION,
+];
 
 /// ## Extension Point: Adding a cross-module lint
 ///
@@ -822,6 +1063,8 @@ pub fn run_cross_module_lints(
 
     diags.extend(lint_transitive_capability_leak(program, info));
     diags.extend(lint_flashloan_without_repay(program, info));
+    diags.extend(lint_store_in_shared_cap(program, info));
+    diags.extend(lint_unused_package_function(program, info));
     // NOTE: lint_price_manipulation_window removed - used name-based heuristics
 
     diags
@@ -2,6 +2,8 @@ use crate::level::LintLevel;
 use crate::lint::LintDescriptor;
 use tree_sitter::Range;
 
+pub mod report;
+
 /// A single lint finding produced by Move Clippy.
 #[derive(Debug, Clone)]
 #[must_use]
@@ -12,7 +14,13 @@ pub struct Diagnostic {
     pub span: Span,
     pub message: String,
     pub help: Option<String>,
-    pub suggestion: Option<Suggestion>,
+    /// Alternative fixes, most preferred first. Editors offer all of them; `--fix` applies
+    /// the first one that is auto-applicable (see [`Diagnostic::preferred_suggestion`]).
+    pub suggestions: Vec<Suggestion>,
+    /// Standalone notes, shown after the primary message.
+    pub notes: Vec<String>,
+    /// Secondary labeled spans in the same file as `span` (e.g. the source or sink of a flow).
+    pub labels: Vec<(Span, String)>,
 }
 
 impl PartialEq for Diagnostic {
@@ -23,29 +31,255 @@ impl PartialEq for Diagnostic {
             && self.span == other.span
             && self.message == other.message
             && self.help == other.help
-            && self.suggestion == other.suggestion
+            && self.suggestions == other.suggestions
+            && self.notes == other.notes
+            && self.labels == other.labels
     }
 }
 
 impl Eq for Diagnostic {}
 
+impl Diagnostic {
+    /// The first attached suggestion, if any.
+    #[deprecated(note = "diagnostics can carry several fixes; use `suggestions`")]
+    #[must_use]
+    pub fn suggestion(&self) -> Option<&Suggestion> {
+        self.suggestions.first()
+    }
+
+    /// The suggestion `--fix` applies: the first `MachineApplicable` one, falling back to
+    /// the first `MaybeIncorrect` one when `allow_unsafe` is set.
+    #[must_use]
+    pub fn preferred_suggestion(&self, allow_unsafe: bool) -> Option<&Suggestion> {
+        self.suggestions
+            .iter()
+            .find(|s| s.applicability == Applicability::MachineApplicable)
+            .or_else(|| {
+                self.suggestions
+                    .iter()
+                    .find(|s| s.applicability.is_auto_applicable(allow_unsafe))
+            })
+    }
+
+    /// rustc-style continuation lines for secondary labels and notes, each ending in a newline.
+    ///
+    /// Empty when the diagnostic has neither, so single-span output is unchanged.
+    #[must_use]
+    pub fn render_related(&self, file: &str) -> String {
+        let mut out = String::new();
+        for (span, label) in &self.labels {
+            out.push_str(&format!(
+                "  --> {file}:{}:{}: {label}\n",
+                span.start.row, span.start.column
+            ));
+        }
+        for note in &self.notes {
+            out.push_str(&format!("  = note: {note}\n"));
+        }
+        out
+    }
+}
+
+impl Diagnostic {
+    /// rustc-style block with the offending source line and a caret underline of `span`.
+    ///
+    /// `source` is the full text of `file`. If the span's line is not in `source`, the excerpt is
+    /// omitted and only the header, help, labels and notes are rendered. `color` enables ANSI
+    /// styling; callers decide it from `NO_COLOR` and tty detection.
+    #[must_use]
+    pub fn render_rich(&self, file: &str, source: &str, color: bool) -> String {
+        let paint = |code: &str, text: &str| {
+            if color {
+                format!("\x1b[{code}m{text}\x1b[0m")
+            } else {
+                text.to_string()
+            }
+        };
+        let level_code = match self.level {
+            LintLevel::Error => "1;31",
+            LintLevel::Note => "1;36",
+            LintLevel::Deny | LintLevel::Warn | LintLevel::Allow => "1;33",
+        };
+
+        let row = self.span.start.row;
+        let width = row.to_string().len();
+        let pad = " ".repeat(width);
+        let mut out = format!(
+            "{}{}\n",
+            paint(level_code, self.level.as_str()),
+            paint("1", &format!("[{}]: {}", self.lint.name, self.message))
+        );
+        out.push_str(&format!(
+            "{pad}{} {file}:{row}:{}\n",
+            paint("1;34", "-->"),
+            self.span.start.column
+        ));
+
+        if let Some(line) = source.lines().nth(row.saturating_sub(1)) {
+            let start = self.span.start.column.saturating_sub(1).min(line.len());
+            let end = if self.span.end.row == row {
+                self.span
+                    .end
+                    .column
+                    .saturating_sub(1)
+                    .clamp(start, line.len())
+            } else {
+                line.len()
+            };
+            // Keep tabs so the carets line up with the excerpt in any tab width.
+            let indent: String = line
+                .get(..start)
+                .unwrap_or_default()
+                .chars()
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect();
+            let carets = line.get(start..end).map_or(1, |s| s.chars().count()).max(1);
+
+            out.push_str(&format_gutter_line("", None, width, ""));
+            out.push_str(&format_gutter_line("", Some(row), width, line));
+            out.push_str(&format_gutter_line(
+                "",
+                None,
+                width,
+                &format!("{indent}{}", paint(level_code, &"^".repeat(carets))),
+            ));
+        }
+
+        if let Some(help) = &self.help {
+            out.push_str(&format!("{pad} = {}: {help}\n", paint("1", "help")));
+        }
+        for (span, label) in &self.labels {
+            out.push_str(&format!(
+                "{pad}{} {file}:{}:{}: {label}\n",
+                paint("1;34", "-->"),
+                span.start.row,
+                span.start.column
+            ));
+        }
+        for note in &self.notes {
+            out.push_str(&format!("{pad} = {}: {note}\n", paint("1", "note")));
+        }
+        out
+    }
+}
+
+/// One gutter line of a source excerpt: `{marker}{line_num:>width} | {text}`, newline-terminated.
+///
+/// A `None` line number leaves the gutter blank (used for caret and spacer lines).
+#[must_use]
+pub fn format_gutter_line(
+    marker: &str,
+    line_num: Option<usize>,
+    width: usize,
+    text: &str,
+) -> String {
+    let num = line_num.map(|n| n.to_string()).unwrap_or_default();
+    if text.is_empty() {
+        format!("{marker}{num:>width$} |\n")
+    } else {
+        format!("{marker}{num:>width$} | {text}\n")
+    }
+}
+
+/// Format a snippet with line numbers for display.
+///
+/// The `highlight_line` is the 1-based line number to highlight with '>'.
+#[must_use]
+pub fn format_snippet_with_lines(snippet: &str, start_line: u32, highlight_line: u32) -> String {
+    snippet
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let line_num = start_line + i as u32;
+            let marker = if line_num == highlight_line {
+                "> "
+            } else {
+                "  "
+            };
+            format_gutter_line(marker, Some(line_num as usize), 4, line)
+        })
+        .collect()
+}
+
 /// Optional machine- or human-applicable fix for a diagnostic.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Suggestion {
     pub message: String,
     pub replacement: String,
     pub applicability: Applicability,
+    /// Range the replacement is written over; `None` means the diagnostic's own span.
+    ///
+    /// A zero-width span inserts the replacement without deleting anything.
+    pub span: Option<Span>,
+}
+
+impl Suggestion {
+    /// Suggest inserting `line` as a new line above row `row` of `source`, indented like
+    /// that row (e.g. a guard `assert!` before a flagged statement).
+    ///
+    /// `line` is given without indentation or a trailing newline. Returns `None` if `row`
+    /// is not a line of `source`.
+    #[must_use]
+    pub fn insert_line_before(
+        source: &str,
+        row: usize,
+        line: &str,
+        message: impl Into<String>,
+        applicability: Applicability,
+    ) -> Option<Self> {
+        let target = source.lines().nth(row.checked_sub(1)?)?;
+        let indent = &target[..target.len() - target.trim_start().len()];
+        Some(Suggestion {
+            message: message.into(),
+            replacement: format!("{indent}{line}\n"),
+            applicability,
+            span: Some(Span::point(Position { row, column: 1 })),
+        })
+    }
+
+    /// The span this suggestion edits, for a diagnostic reported at `diagnostic_span`.
+    #[must_use]
+    pub fn edit_span(&self, diagnostic_span: Span) -> Span {
+        self.span.unwrap_or(diagnostic_span)
+    }
 }
 
 /// Applicability of an automated suggestion.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Applicability {
+    /// The replacement is known to be correct and is applied by `--fix`.
     MachineApplicable,
+    /// The replacement is probably correct but may change behavior.
+    /// Applied only with `--unsafe-fixes`.
     MaybeIncorrect,
+    /// The replacement contains placeholders (e.g. `TODO`) the user must fill in.
+    /// Never applied automatically; shown as a suggestion only.
     HasPlaceholders,
+    /// Applicability is unknown. Never applied automatically.
     Unspecified,
 }
 
+impl Applicability {
+    /// String representation used in JSON output.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Applicability::MachineApplicable => "machine-applicable",
+            Applicability::MaybeIncorrect => "maybe-incorrect",
+            Applicability::HasPlaceholders => "has-placeholders",
+            Applicability::Unspecified => "unspecified",
+        }
+    }
+
+    /// Whether the fixer may apply this suggestion without user edits.
+    pub fn is_auto_applicable(self, allow_unsafe: bool) -> bool {
+        match self {
+            Applicability::MachineApplicable => true,
+            Applicability::MaybeIncorrect => allow_unsafe,
+            Applicability::HasPlaceholders | Applicability::Unspecified => false,
+        }
+    }
+}
+
 /// Span in a Move source file (1-based row/column positions).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Span {
@@ -60,7 +294,59 @@ pub struct Position {
     pub column: usize,
 }
 
+impl Position {
+    /// Byte offset of this position in `source`.
+    ///
+    /// Columns count bytes, matching tree-sitter points and compiler locations. A column past
+    /// the end of its line clamps to the line end (before the newline). Returns `None` for a
+    /// row past the last line, a 0 row/column, or a column inside a multi-byte character.
+    #[must_use]
+    pub fn to_byte_offset(&self, source: &str) -> Option<usize> {
+        if self.row == 0 || self.column == 0 {
+            return None;
+        }
+        let line_start = match self.row {
+            1 => 0,
+            row => source
+                .match_indices('\n')
+                .nth(row - 2)
+                .map(|(i, _)| i + 1)?,
+        };
+        let rest = &source[line_start..];
+        let line = &rest[..rest.find('\n').unwrap_or(rest.len())];
+        let column_offset = (self.column - 1).min(line.len());
+        line.is_char_boundary(column_offset)
+            .then_some(line_start + column_offset)
+    }
+}
+
 impl Span {
+    /// Zero-width span at `pos`, used for pure insertions.
+    #[must_use]
+    pub fn point(pos: Position) -> Self {
+        Self {
+            start: pos,
+            end: pos,
+        }
+    }
+
+    /// Whether the span covers no text.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Byte range of this span in `source`, with both ends resolved by
+    /// [`Position::to_byte_offset`].
+    ///
+    /// Returns `None` if either end is outside `source` or the end precedes the start.
+    #[must_use]
+    pub fn byte_range(&self, source: &str) -> Option<std::ops::Range<usize>> {
+        let start = self.start.to_byte_offset(source)?;
+        let end = self.end.to_byte_offset(source)?;
+        (start <= end).then_some(start..end)
+    }
+
     /// Construct a `Span` from a tree-sitter range, converting to 1-based positions.
     #[must_use]
     pub fn from_range(range: Range) -> Self {
@@ -76,3 +362,362 @@ impl Span {
         }
     }
 }
+
+// ============================================================================
+// LSP Conversion (feature = "lsp")
+// ============================================================================
+
+/// Source name reported to editors for every diagnostic.
+#[cfg(feature = "lsp")]
+pub const LSP_SOURCE: &str = "move-clippy";
+
+#[cfg(feature = "lsp")]
+impl Position {
+    /// Convert to a 0-based LSP position.
+    ///
+    /// Columns are byte offsets within the line (as reported by tree-sitter), so
+    /// clients should negotiate the `utf-8` position encoding for exact ranges on
+    /// lines containing non-ASCII text.
+    #[must_use]
+    pub fn to_lsp_position(&self) -> lsp_types::Position {
+        lsp_types::Position {
+            line: self.row.saturating_sub(1) as u32,
+            character: self.column.saturating_sub(1) as u32,
+        }
+    }
+}
+
+#[cfg(feature = "lsp")]
+impl Span {
+    /// Convert to a 0-based LSP range.
+    #[must_use]
+    pub fn to_lsp_range(&self) -> lsp_types::Range {
+        lsp_types::Range {
+            start: self.start.to_lsp_position(),
+            end: self.end.to_lsp_position(),
+        }
+    }
+}
+
+#[cfg(feature = "lsp")]
+impl Diagnostic {
+    /// Convert to an `lsp_types::Diagnostic`.
+    ///
+    /// The `code` is the lint name and the `source` is always [`LSP_SOURCE`].
+    /// Help text and notes, when present, are appended to the message on their own lines.
+    #[must_use]
+    pub fn to_lsp_diagnostic(&self) -> lsp_types::Diagnostic {
+        let severity = match self.level {
+            LintLevel::Error => lsp_types::DiagnosticSeverity::ERROR,
+            LintLevel::Deny | LintLevel::Warn => lsp_types::DiagnosticSeverity::WARNING,
+            LintLevel::Note => lsp_types::DiagnosticSeverity::INFORMATION,
+            LintLevel::Allow => lsp_types::DiagnosticSeverity::HINT,
+        };
+
+        let mut message = match &self.help {
+            Some(help) => format!("{}\nhelp: {}", self.message, help),
+            None => self.message.clone(),
+        };
+        for note in &self.notes {
+            message.push_str(&format!("\nnote: {note}"));
+        }
+
+        lsp_types::Diagnostic {
+            range: self.span.to_lsp_range(),
+            severity: Some(severity),
+            code: Some(lsp_types::NumberOrString::String(
+                self.lint.name.to_string(),
+            )),
+            source: Some(LSP_SOURCE.to_string()),
+            message,
+            ..Default::default()
+        }
+    }
+
+    /// Convert the attached suggestions into quick-fix code actions for `uri`, one per
+    /// suggestion, so the editor can offer a choice.
+    ///
+    /// Only the suggestion `--fix` would apply is marked as preferred; suggestions with
+    /// placeholders are still offered so the editor can show them, but never preferred.
+    #[must_use]
+    pub fn to_lsp_code_actions(&self, uri: &lsp_types::Url) -> Vec<lsp_types::CodeAction> {
+        let preferred = self.preferred_suggestion(false);
+        self.suggestions
+            .iter()
+            .map(|suggestion| {
+                let edit = lsp_types::TextEdit {
+                    range: suggestion.edit_span(self.span).to_lsp_range(),
+                    new_text: suggestion.replacement.clone(),
+                };
+                let mut changes = std::collections::HashMap::new();
+                changes.insert(uri.clone(), vec![edit]);
+
+                lsp_types::CodeAction {
+                    title: suggestion.message.clone(),
+                    kind: Some(lsp_types::CodeActionKind::QUICKFIX),
+                    diagnostics: Some(vec![self.to_lsp_diagnostic()]),
+                    edit: Some(lsp_types::WorkspaceEdit {
+                        changes: Some(changes),
+                        ..Default::default()
+                    }),
+                    is_preferred: Some(preferred.is_some_and(|p| std::ptr::eq(p, suggestion))),
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(all(test, feature = "lsp"))]
+mod lsp_tests {
+    use super::*;
+
+    static TEST_LINT: crate::lint::LintDescriptor = crate::lint::LintDescriptor {
+        name: "empty_vector_literal",
+        category: crate::lint::LintCategory::Modernization,
+        description: "test",
+        group: crate::lint::RuleGroup::Stable,
+        fix: crate::lint::FixDescriptor::safe("Replace with vector[]"),
+        analysis: crate::lint::AnalysisKind::Syntactic,
+        gap: None,
+    };
+
+    fn diagnostic() -> Diagnostic {
+        Diagnostic {
+            lint: &TEST_LINT,
+            level: LintLevel::Error,
+            file: Some("sources/m.move".to_string()),
+            span: Span {
+                start: Position { row: 3, column: 9 },
+                end: Position { row: 3, column: 24 },
+            },
+            message: "Prefer `vector[]`".to_string(),
+            help: None,
+            suggestions: vec![Suggestion {
+                message: "Replace with `vector[]`".to_string(),
+                replacement: "vector[]".to_string(),
+                applicability: Applicability::MachineApplicable,
+                span: None,
+            }],
+            notes: Vec::new(),
+            labels: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_to_lsp_diagnostic_uses_zero_based_positions() {
+        let lsp = diagnostic().to_lsp_diagnostic();
+
+        assert_eq!(lsp.range.start, lsp_types::Position::new(2, 8));
+        assert_eq!(lsp.range.end, lsp_types::Position::new(2, 23));
+        assert_eq!(lsp.severity, Some(lsp_types::DiagnosticSeverity::ERROR));
+        assert_eq!(lsp.source.as_deref(), Some(LSP_SOURCE));
+        assert_eq!(
+            lsp.code,
+            Some(lsp_types::NumberOrString::String(
+                "empty_vector_literal".to_string()
+            ))
+        );
+        assert_eq!(lsp.message, "Prefer `vector[]`");
+    }
+
+    #[test]
+    fn test_to_lsp_code_action_maps_suggestion() {
+        let uri = lsp_types::Url::parse("file:///tmp/sources/m.move").unwrap();
+        let mut actions = diagnostic().to_lsp_code_actions(&uri);
+        assert_eq!(actions.len(), 1);
+        let action = actions.remove(0);
+
+        assert_eq!(action.kind, Some(lsp_types::CodeActionKind::QUICKFIX));
+        assert_eq!(action.is_preferred, Some(true));
+
+        let changes = action.edit.unwrap().changes.unwrap();
+        let edits = &changes[&uri];
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].new_text, "vector[]");
+        assert_eq!(edits[0].range.start, lsp_types::Position::new(2, 8));
+
+        let mut without_fix = diagnostic();
+        without_fix.suggestions.clear();
+        assert!(without_fix.to_lsp_code_actions(&uri).is_empty());
+    }
+
+    #[test]
+    fn test_to_lsp_code_actions_offers_every_suggestion() {
+        let uri = lsp_types::Url::parse("file:///tmp/sources/m.move").unwrap();
+        let mut diag = diagnostic();
+        diag.suggestions.insert(
+            0,
+            Suggestion {
+                message: "Annotate the element type".to_string(),
+                replacement: "vector<T>[]".to_string(),
+                applicability: Applicability::HasPlaceholders,
+                span: None,
+            },
+        );
+
+        let actions = diag.to_lsp_code_actions(&uri);
+        let titles: Vec<_> = actions.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec!["Annotate the element type", "Replace with `vector[]`"]
+        );
+        let preferred: Vec<_> = actions.iter().map(|a| a.is_preferred).collect();
+        assert_eq!(preferred, vec![Some(false), Some(true)]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TEST_LINT: crate::lint::LintDescriptor = crate::lint::LintDescriptor {
+        name: "transitive_capability_leak",
+        category: crate::lint::LintCategory::Security,
+        description: "test",
+        group: crate::lint::RuleGroup::Experimental,
+        fix: crate::lint::FixDescriptor::none(),
+        analysis: crate::lint::AnalysisKind::TypeBased,
+        gap: None,
+    };
+
+    fn span(row: usize, column: usize) -> Span {
+        Span {
+            start: Position { row, column },
+            end: Position {
+                row,
+                column: column + 4,
+            },
+        }
+    }
+
+    #[test]
+    fn test_render_related_lists_labels_then_notes() {
+        let diag = Diagnostic {
+            lint: &TEST_LINT,
+            level: LintLevel::Warn,
+            file: Some("sources/a.move".to_string()),
+            span: span(10, 5),
+            message: "Capability value may leak".to_string(),
+            help: None,
+            suggestions: Vec::new(),
+            notes: vec!["Pass the capability by reference instead.".to_string()],
+            labels: vec![
+                (span(3, 9), "capability created here".to_string()),
+                (span(7, 13), "passed to a public function here".to_string()),
+            ],
+        };
+
+        assert_eq!(
+            diag.render_related("sources/a.move"),
+            concat!(
+                "  --> sources/a.move:3:9: capability created here\n",
+                "  --> sources/a.move:7:13: passed to a public function here\n",
+                "  = note: Pass the capability by reference instead.\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_related_is_empty_without_labels_or_notes() {
+        let diag = Diagnostic {
+            lint: &TEST_LINT,
+            level: LintLevel::Warn,
+            file: None,
+            span: span(1, 1),
+            message: String::new(),
+            help: None,
+            suggestions: Vec::new(),
+            notes: Vec::new(),
+            labels: Vec::new(),
+        };
+        assert_eq!(diag.render_related("stdin"), "");
+    }
+
+    #[test]
+    fn test_render_rich_underlines_span_and_keeps_tabs() {
+        let diag = Diagnostic {
+            lint: &TEST_LINT,
+            level: LintLevel::Warn,
+            file: None,
+            span: span(2, 6),
+            message: "Capability value may leak".to_string(),
+            help: Some("Pass it by reference".to_string()),
+            suggestions: Vec::new(),
+            notes: Vec::new(),
+            labels: Vec::new(),
+        };
+        let source = "module a::m;\n\tlet vals = 1;\n";
+
+        assert_eq!(
+            diag.render_rich("a.move", source, false),
+            concat!(
+                "warning[transitive_capability_leak]: Capability value may leak\n",
+                " --> a.move:2:6\n",
+                "  |\n",
+                "2 | \tlet vals = 1;\n",
+                "  | \t    ^^^^\n",
+                "  = help: Pass it by reference\n",
+            )
+        );
+
+        let colored = diag.render_rich("a.move", source, true);
+        assert!(colored.starts_with("\x1b[1;33mwarning\x1b[0m"));
+        assert!(colored.contains("\x1b[1;33m^^^^\x1b[0m"));
+
+        // A span outside the source drops the excerpt but keeps the header and help.
+        let missing = diag.render_rich("a.move", "", false);
+        assert!(!missing.contains('^'));
+        assert!(missing.contains("= help: Pass it by reference"));
+    }
+
+    #[test]
+    fn test_position_to_byte_offset_counts_bytes() {
+        // "é" is 2 bytes, "🦀" is 4 bytes.
+        let source = "let é = 1;\n// 🦀🦀 x\n";
+        let at = |row, column| Position { row, column }.to_byte_offset(source);
+
+        assert_eq!(at(1, 1), Some(0));
+        assert_eq!(at(1, 8), Some(7), "`=` follows the 2-byte `é`");
+        assert_eq!(at(1, 6), None, "inside `é`");
+        let line2 = source.find("//").unwrap();
+        assert_eq!(at(2, 4), Some(line2 + 3), "first crab");
+        assert_eq!(at(2, 8), Some(line2 + 7), "second crab");
+        assert_eq!(&source[at(2, 13).unwrap()..], "x\n");
+
+        // Past the end of a line clamps to before its newline; the empty final line exists.
+        assert_eq!(at(1, 99), source.find('\n'));
+        assert_eq!(at(3, 1), Some(source.len()));
+        assert_eq!(at(3, 5), Some(source.len()));
+
+        assert_eq!(at(4, 1), None);
+        assert_eq!(at(0, 1), None);
+        assert_eq!(at(1, 0), None);
+    }
+
+    #[test]
+    fn test_span_byte_range_slices_multibyte_text() {
+        let source = "a🦀b\nçd";
+        let span = |start: (usize, usize), end: (usize, usize)| Span {
+            start: Position {
+                row: start.0,
+                column: start.1,
+            },
+            end: Position {
+                row: end.0,
+                column: end.1,
+            },
+        };
+
+        let range = span((1, 2), (1, 6)).byte_range(source).unwrap();
+        assert_eq!(&source[range], "🦀");
+        let range = span((1, 6), (2, 3)).byte_range(source).unwrap();
+        assert_eq!(&source[range], "b\nç");
+        let range = span((2, 1), (2, 50)).byte_range(source).unwrap();
+        assert_eq!(&source[range], "çd");
+
+        assert_eq!(span((1, 6), (1, 2)).byte_range(source), None);
+        assert_eq!(span((1, 3), (1, 6)).byte_range(source), None);
+        assert_eq!(span((1, 1), (5, 1)).byte_range(source), None);
+    }
+}
@@ -0,0 +1,255 @@
+//! Text renderers for diagnostics.
+//!
+//! Every renderer writes to an arbitrary [`Write`], so the binary prints to stdout while
+//! library users and tests can render into a buffer.
+
+use super::Diagnostic;
+use crate::level::LintLevel;
+use serde::Serialize;
+use std::io::{self, Write};
+
+/// Settings shared by the line-oriented renderers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReportOptions {
+    /// Prefix lint names with their group (`--show-tier`).
+    pub show_tier: bool,
+    /// Report warnings as errors (`--deny-warnings`).
+    pub deny_warnings: bool,
+}
+
+/// The file a diagnostic is reported against, falling back to `default_file`.
+fn file_of<'a>(diag: &'a Diagnostic, default_file: &'a str) -> &'a str {
+    diag.file.as_deref().unwrap_or(default_file)
+}
+
+/// Write `diags` in `pretty` format; diagnostics without a file are labeled `default_file`.
+pub fn write_pretty(
+    w: &mut dyn Write,
+    diags: &[Diagnostic],
+    default_file: &str,
+    opts: &ReportOptions,
+) -> io::Result<()> {
+    for diag in diags {
+        write_pretty_diagnostic(w, diag, file_of(diag, default_file), opts)?;
+    }
+    Ok(())
+}
+
+/// Write one diagnostic as `file:row:col: level: lint: message`, followed by its labels and
+/// notes.
+pub fn write_pretty_diagnostic(
+    w: &mut dyn Write,
+    diag: &Diagnostic,
+    file: &str,
+    opts: &ReportOptions,
+) -> io::Result<()> {
+    let tier_prefix = if opts.show_tier {
+        format!("[{}] ", diag.lint.group.as_str())
+    } else {
+        String::new()
+    };
+    write_pretty_line(
+        w,
+        file,
+        diag.span.start.row,
+        diag.span.start.column,
+        diag.level.as_str(),
+        &format!("{tier_prefix}{}", diag.lint.name),
+        &diag.message,
+    )?;
+    write!(w, "{}", diag.render_related(file))
+}
+
+/// Write one `file:row:col: level: lint: message` line.
+pub fn write_pretty_line(
+    w: &mut dyn Write,
+    file: &str,
+    row: usize,
+    column: usize,
+    level: &str,
+    lint: &str,
+    message: &str,
+) -> io::Result<()> {
+    writeln!(w, "{file}:{row}:{column}: {level}: {lint}: {message}")
+}
+
+/// Write `diags` as GitHub Actions workflow commands; diagnostics without a file are labeled
+/// `default_file`.
+pub fn write_github(
+    w: &mut dyn Write,
+    diags: &[Diagnostic],
+    default_file: &str,
+    opts: &ReportOptions,
+) -> io::Result<()> {
+    for diag in diags {
+        write_github_diagnostic(w, diag, file_of(diag, default_file), opts)?;
+    }
+    Ok(())
+}
+
+/// Write one diagnostic as a `::error`, `::warning` or `::notice` workflow command.
+pub fn write_github_diagnostic(
+    w: &mut dyn Write,
+    diag: &Diagnostic,
+    file: &str,
+    opts: &ReportOptions,
+) -> io::Result<()> {
+    let lint_name = if opts.show_tier {
+        format!("[{}]{}", diag.lint.group.as_str(), diag.lint.name)
+    } else {
+        diag.lint.name.to_string()
+    };
+    write_github_line(
+        w,
+        github_kind(diag, opts.deny_warnings),
+        file,
+        diag.span.start.row,
+        diag.span.start.column,
+        &lint_name,
+        &diag.message,
+    )
+}
+
+/// Write one `::kind file=..,line=..,col=..,title=..::message` workflow command, escaping the
+/// file and message.
+pub fn write_github_line(
+    w: &mut dyn Write,
+    kind: &str,
+    file: &str,
+    row: usize,
+    column: usize,
+    title: &str,
+    message: &str,
+) -> io::Result<()> {
+    writeln!(
+        w,
+        "::{kind} file={},line={row},col={column},title={title}::{}",
+        github_escape(file),
+        github_escape(message)
+    )
+}
+
+/// The workflow command for `diag`: `error` when it fails the run, `notice` for notes and
+/// `warning` otherwise.
+pub fn github_kind(diag: &Diagnostic, deny_warnings: bool) -> &'static str {
+    github_kind_for_level(diag.level, deny_warnings)
+}
+
+/// [`github_kind`] for a bare level.
+pub fn github_kind_for_level(level: LintLevel, deny_warnings: bool) -> &'static str {
+    if level.fails_run() || (deny_warnings && level == LintLevel::Warn) {
+        "error"
+    } else if level == LintLevel::Note {
+        "notice"
+    } else {
+        "warning"
+    }
+}
+
+/// Escape a workflow command value so `%` and line breaks survive the round trip.
+pub fn github_escape(s: &str) -> String {
+    s.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Write `value` as pretty-printed JSON followed by a newline.
+pub fn write_json<T: Serialize + ?Sized>(w: &mut dyn Write, value: &T) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut *w, value)?;
+    writeln!(w)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::{Position, Span};
+
+    static TEST_LINT: crate::lint::LintDescriptor = crate::lint::LintDescriptor {
+        name: "abilities_order",
+        category: crate::lint::LintCategory::Style,
+        description: "test",
+        group: crate::lint::RuleGroup::Stable,
+        fix: crate::lint::FixDescriptor::none(),
+        analysis: crate::lint::AnalysisKind::Syntactic,
+        gap: None,
+    };
+
+    fn diag(level: LintLevel, file: Option<&str>, message: &str) -> Diagnostic {
+        Diagnostic {
+            lint: &TEST_LINT,
+            level,
+            file: file.map(str::to_string),
+            span: Span {
+                start: Position { row: 4, column: 7 },
+                end: Position { row: 4, column: 12 },
+            },
+            message: message.to_string(),
+            help: None,
+            suggestions: Vec::new(),
+            notes: Vec::new(),
+            labels: Vec::new(),
+        }
+    }
+
+    fn render(f: impl FnOnce(&mut dyn Write) -> io::Result<()>) -> String {
+        let mut buf: Vec<u8> = Vec::new();
+        f(&mut buf).unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
+    #[test]
+    fn pretty_falls_back_to_default_file_and_shows_tier() {
+        let diags = [
+            diag(LintLevel::Warn, Some("sources/a.move"), "reorder abilities"),
+            diag(LintLevel::Error, None, "bad"),
+        ];
+        let opts = ReportOptions {
+            show_tier: true,
+            ..ReportOptions::default()
+        };
+        assert_eq!(
+            render(|w| write_pretty(w, &diags, "stdin", &opts)),
+            concat!(
+                "sources/a.move:4:7: warning: [stable] abilities_order: reorder abilities\n",
+                "stdin:4:7: error: [stable] abilities_order: bad\n",
+            )
+        );
+    }
+
+    #[test]
+    fn github_escapes_file_and_message() {
+        let diags = [diag(
+            LintLevel::Warn,
+            Some("dir%/a.move"),
+            "100% wrong\r\nsecond line",
+        )];
+        assert_eq!(
+            render(|w| write_github(w, &diags, "stdin", &ReportOptions::default())),
+            "::warning file=dir%25/a.move,line=4,col=7,title=abilities_order::100%25 wrong%0D%0Asecond line\n"
+        );
+    }
+
+    #[test]
+    fn github_kind_follows_level_and_deny_warnings() {
+        let warn = diag(LintLevel::Warn, None, "");
+        assert_eq!(github_kind(&warn, false), "warning");
+        assert_eq!(github_kind(&warn, true), "error");
+        assert_eq!(
+            github_kind(&diag(LintLevel::Note, None, ""), true),
+            "notice"
+        );
+        assert_eq!(
+            github_kind(&diag(LintLevel::Error, None, ""), false),
+            "error"
+        );
+    }
+
+    #[test]
+    fn json_is_pretty_printed_with_trailing_newline() {
+        let value = serde_json::json!({ "lint": "abilities_order" });
+        assert_eq!(
+            render(|w| write_json(w, &value)),
+            "{\n  \"lint\": \"abilities_order\"\n}\n"
+        );
+    }
+}
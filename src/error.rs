@@ -3,6 +3,7 @@
 //! Library code uses `Error` and `Result<T>`.
 //! Binary code (`main.rs`) uses `anyhow` for ergonomic CLI error handling.
 
+use crate::diagnostics::Position;
 use std::path::PathBuf;
 use thiserror::Error as ThisError;
 
@@ -18,6 +19,15 @@ pub enum Error {
         message: String,
     },
 
+    /// Move source code with a syntax error tree-sitter could not recover from.
+    #[error("parse error at {}:{}: near `{excerpt}`", position.row, position.column)]
+    ParseError {
+        /// 1-based position of the first `ERROR` or `MISSING` node.
+        position: Position,
+        /// Short excerpt of the source at that position.
+        excerpt: String,
+    },
+
     /// Semantic analysis failure (requires `--mode full`).
     #[error("semantic analysis failed: {message}")]
     Semantic {
@@ -76,6 +86,14 @@ impl Error {
         }
     }
 
+    /// Create a parse error pointing at a position in the source.
+    pub fn parse_error(position: Position, excerpt: impl Into<String>) -> Self {
+        Self::ParseError {
+            position,
+            excerpt: excerpt.into(),
+        }
+    }
+
     /// Create a semantic error.
     pub fn semantic(message: impl Into<String>) -> Self {
         Self::Semantic {
@@ -183,6 +201,12 @@ mod tests {
         assert_eq!(err.to_string(), "unknown lint: fake_lint");
     }
 
+    #[test]
+    fn test_parse_error_display() {
+        let err = Error::parse_error(Position { row: 3, column: 9 }, "let x = ;");
+        assert_eq!(err.to_string(), "parse error at 3:9: near `let x = ;`");
+    }
+
     #[test]
     fn test_error_with_context() {
         let err = Error::parse("syntax error").with_context("processing file.move");
@@ -3,7 +3,9 @@
 //! This module handles applying machine-applicable fix suggestions to source files.
 
 use crate::diagnostics::{Applicability, Diagnostic};
+use crate::parser::input_edit;
 use std::path::Path;
+use tree_sitter::InputEdit;
 
 /// Result of applying fixes to a source file.
 #[derive(Debug)]
@@ -14,6 +16,19 @@ pub struct FixResult {
     pub fixes_applied: usize,
     /// Fix suggestions that were skipped (not machine-applicable or unsafe).
     pub fixes_skipped: usize,
+    /// Skipped fixes that `allow_unsafe` would have applied. Also counted in `fixes_skipped`.
+    pub unsafe_skipped: usize,
+    /// Skipped fixes that overlap an edit applied in this pass. Also counted in
+    /// `fixes_skipped`.
+    pub overlaps_skipped: usize,
+    /// Diagnostics whose suggestions contain placeholders and are never auto-applied.
+    ///
+    /// These are also counted in `fixes_skipped`.
+    pub placeholders: Vec<Diagnostic>,
+    /// The applied edits, in application order, for incremental reparsing of `fixed_source`.
+    ///
+    /// See [`crate::parser::parse_source_incremental`].
+    pub edits: Vec<InputEdit>,
 }
 
 /// Error when applying fixes.
@@ -22,9 +37,6 @@ pub enum FixError {
     #[error("Cannot apply fixes to stdin - please specify a file path")]
     StdinNotSupported,
 
-    #[error("Overlapping fixes detected - cannot safely apply")]
-    OverlappingFixes,
-
     #[error("Invalid byte range: {0}..{1}")]
     InvalidRange(usize, usize),
 
@@ -58,7 +70,16 @@ impl SourceEdit {
 /// # Arguments
 /// * `source` - The original source code
 /// * `diagnostics` - Diagnostics with fix suggestions
-/// * `allow_unsafe` - Whether to apply unsafe fixes
+/// * `allow_unsafe` - Whether to apply `MaybeIncorrect` fixes
+///
+/// For a diagnostic with several suggestions, the one from
+/// [`Diagnostic::preferred_suggestion`] is applied. `HasPlaceholders` suggestions are never
+/// applied; diagnostics left with only those are returned in `FixResult::placeholders` so
+/// callers can print them. A diagnostic that `allow_unsafe` would fix is counted in
+/// `FixResult::unsafe_skipped` instead.
+///
+/// When two edits overlap, the one starting first is applied and the other is
+/// counted in `overlaps_skipped`, to be picked up by a later pass over the fixed source.
 ///
 /// # Returns
 /// A `FixResult` containing the modified source and statistics
@@ -70,31 +91,33 @@ pub fn apply_fixes(
     // Collect applicable edits
     let mut edits: Vec<SourceEdit> = Vec::new();
     let mut skipped = 0;
+    let mut unsafe_skipped = 0;
+    let mut overlaps_skipped = 0;
+    let mut placeholders = Vec::new();
 
     for diag in diagnostics {
-        let Some(suggestion) = &diag.suggestion else {
+        if diag.suggestions.is_empty() {
             continue;
-        };
+        }
 
         // Check applicability
-        match suggestion.applicability {
-            Applicability::MachineApplicable => {
-                // Always apply
+        let Some(suggestion) = diag.preferred_suggestion(allow_unsafe) else {
+            if diag.preferred_suggestion(true).is_some() {
+                unsafe_skipped += 1;
+            } else if diag
+                .suggestions
+                .iter()
+                .any(|s| s.applicability == Applicability::HasPlaceholders)
+            {
+                placeholders.push(diag.clone());
             }
-            Applicability::MaybeIncorrect | Applicability::HasPlaceholders => {
-                if !allow_unsafe {
-                    skipped += 1;
-                    continue;
-                }
-            }
-            Applicability::Unspecified => {
-                skipped += 1;
-                continue;
-            }
-        }
+            skipped += 1;
+            continue;
+        };
 
-        // Convert row/column span to byte offsets
-        let Some((start_byte, end_byte)) = span_to_bytes(source, &diag.span) else {
+        // Convert row/column span to byte offsets. Insertions carry their own zero-width span.
+        let Some((start_byte, end_byte)) = span_to_bytes(source, &suggestion.edit_span(diag.span))
+        else {
             skipped += 1;
             continue;
         };
@@ -111,30 +134,49 @@ pub fn apply_fixes(
             fixed_source: source.to_string(),
             fixes_applied: 0,
             fixes_skipped: skipped,
+            unsafe_skipped,
+            overlaps_skipped,
+            placeholders,
+            edits: Vec::new(),
         });
     }
 
-    // Sort edits by start position (descending) to apply from end to start
-    edits.sort_by(|a, b| b.start_byte.cmp(&a.start_byte));
+    // Sort edits by start position so overlaps can be resolved first-come, first-served
+    edits.sort_by_key(|e| (e.start_byte, e.end_byte));
 
-    // Check for overlapping edits
-    for window in edits.windows(2) {
-        // Since sorted descending, window[0].start > window[1].start
-        // Overlap if window[0].start < window[1].end
-        if window[0].start_byte < window[1].end_byte {
-            return Err(FixError::OverlappingFixes);
+    // Skip edits that intersect one already accepted in this pass. Skipped edits are
+    // re-reported when the file is re-linted, so the fix loop applies them later.
+    // A zero-width insertion sorts before a replacement starting at the same offset, so an
+    // insertion at either end of a replacement is kept; only one strictly inside is skipped.
+    let mut accepted: Vec<SourceEdit> = Vec::with_capacity(edits.len());
+    for edit in edits {
+        if accepted
+            .last()
+            .is_some_and(|prev| edit.start_byte < prev.end_byte)
+        {
+            skipped += 1;
+            overlaps_skipped += 1;
+            continue;
         }
+        accepted.push(edit);
     }
 
     // Apply edits from end to start
     let mut result = source.to_string();
-    let applied = edits.len();
+    let applied = accepted.len();
+    let mut tree_edits = Vec::with_capacity(applied);
 
-    for edit in edits {
+    for edit in accepted.into_iter().rev() {
         if edit.start_byte > result.len() || edit.end_byte > result.len() {
             return Err(FixError::InvalidRange(edit.start_byte, edit.end_byte));
         }
 
+        tree_edits.push(input_edit(
+            &result,
+            edit.start_byte,
+            edit.end_byte,
+            &edit.replacement,
+        ));
         result.replace_range(edit.start_byte..edit.end_byte, &edit.replacement);
     }
 
@@ -142,57 +184,19 @@ pub fn apply_fixes(
         fixed_source: result,
         fixes_applied: applied,
         fixes_skipped: skipped,
+        unsafe_skipped,
+        overlaps_skipped,
+        placeholders,
+        edits: tree_edits,
     })
 }
 
 /// Convert a row/column span to byte offsets.
 ///
-/// Spans use 1-based row and column numbers.
+/// Spans use 1-based row and column numbers; see [`crate::diagnostics::Span::byte_range`].
 fn span_to_bytes(source: &str, span: &crate::diagnostics::Span) -> Option<(usize, usize)> {
-    let mut byte_offset = 0;
-    let mut current_row = 1;
-    let mut current_col = 1;
-
-    let mut start_byte = None;
-    let mut end_byte = None;
-
-    for (i, c) in source.char_indices() {
-        // Check if we're at the start position
-        if current_row == span.start.row && current_col == span.start.column {
-            start_byte = Some(i);
-        }
-
-        // Check if we're at the end position
-        if current_row == span.end.row && current_col == span.end.column {
-            end_byte = Some(i);
-            break;
-        }
-
-        // Update position
-        if c == '\n' {
-            current_row += 1;
-            current_col = 1;
-        } else {
-            current_col += 1;
-        }
-
-        byte_offset = i + c.len_utf8();
-    }
-
-    // Handle end position at EOF
-    if end_byte.is_none() && current_row == span.end.row && current_col == span.end.column {
-        end_byte = Some(byte_offset);
-    }
-
-    // Handle end position after last char on line
-    if end_byte.is_none() && start_byte.is_some() {
-        end_byte = Some(source.len());
-    }
-
-    match (start_byte, end_byte) {
-        (Some(s), Some(e)) => Some((s, e)),
-        _ => None,
-    }
+    span.byte_range(source)
+        .map(|range| (range.start, range.end))
 }
 
 /// Generate a unified diff between original and fixed source.
@@ -203,6 +207,10 @@ pub fn format_diff(original: &str, fixed: &str, path: &Path) -> String {
 }
 
 /// Generate a unified diff with configurable context lines.
+///
+/// The output is a valid patch for `git apply`: hunk headers carry exact line counts, line
+/// endings are preserved, and a missing trailing newline is marked with
+/// `\ No newline at end of file`.
 pub fn format_diff_with_context(
     original: &str,
     fixed: &str,
@@ -211,100 +219,63 @@ pub fn format_diff_with_context(
 ) -> String {
     use std::fmt::Write;
 
-    let path_str = path.display().to_string();
-    let mut output = String::new();
-
-    writeln!(output, "--- a/{}", path_str).unwrap();
-    writeln!(output, "+++ b/{}", path_str).unwrap();
-
-    let orig_lines: Vec<&str> = original.lines().collect();
-    let fixed_lines: Vec<&str> = fixed.lines().collect();
-
-    // Find all changed line indices
-    let mut changes: Vec<(usize, Option<&str>, Option<&str>)> = Vec::new();
-    let max_len = orig_lines.len().max(fixed_lines.len());
-
-    for i in 0..max_len {
-        let orig = orig_lines.get(i).copied();
-        let fix = fixed_lines.get(i).copied();
-
-        if orig != fix {
-            changes.push((i, orig, fix));
-        }
-    }
-
-    if changes.is_empty() {
+    let orig_lines: Vec<&str> = original.split_inclusive('\n').collect();
+    let fixed_lines: Vec<&str> = fixed.split_inclusive('\n').collect();
+    let ops = diff_lines(&orig_lines, &fixed_lines);
+
+    let changed: Vec<usize> = ops
+        .iter()
+        .enumerate()
+        .filter(|(_, op)| op.kind != DiffKind::Equal)
+        .map(|(i, _)| i)
+        .collect();
+    if changed.is_empty() {
         return String::new();
     }
 
-    // Group changes into hunks with context
-    let mut hunks: Vec<(usize, usize, Vec<(usize, Option<&str>, Option<&str>)>)> = Vec::new();
-    let mut current_hunk_start = 0usize;
-    let mut current_hunk_end = 0usize;
-    let mut current_changes: Vec<(usize, Option<&str>, Option<&str>)> = Vec::new();
-
-    for (i, orig, fix) in changes {
-        let change_start = i.saturating_sub(context);
-        let change_end = (i + context + 1).min(max_len);
-
-        if current_changes.is_empty() {
-            // Start new hunk
-            current_hunk_start = change_start;
-            current_hunk_end = change_end;
-            current_changes.push((i, orig, fix));
-        } else if change_start <= current_hunk_end {
-            // Extend current hunk
-            current_hunk_end = change_end;
-            current_changes.push((i, orig, fix));
-        } else {
-            // Save current hunk, start new one
-            hunks.push((current_hunk_start, current_hunk_end, current_changes));
-            current_hunk_start = change_start;
-            current_hunk_end = change_end;
-            current_changes = vec![(i, orig, fix)];
+    // Group changes into hunks, merging those whose context would overlap
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for i in changed {
+        let start = i.saturating_sub(context);
+        let end = (i + context + 1).min(ops.len());
+        match hunks.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end,
+            _ => hunks.push((start, end)),
         }
     }
 
-    // Don't forget the last hunk
-    if !current_changes.is_empty() {
-        hunks.push((current_hunk_start, current_hunk_end, current_changes));
-    }
-
-    // Output each hunk
-    for (hunk_start, hunk_end, hunk_changes) in hunks {
-        let change_indices: std::collections::HashSet<usize> =
-            hunk_changes.iter().map(|(i, _, _)| *i).collect();
+    let path_str = path.display().to_string();
+    let mut output = String::new();
+    writeln!(output, "--- a/{}", path_str).unwrap();
+    writeln!(output, "+++ b/{}", path_str).unwrap();
 
-        // Calculate hunk sizes
-        let orig_size = hunk_end.min(orig_lines.len()).saturating_sub(hunk_start);
-        let fixed_size = hunk_end.min(fixed_lines.len()).saturating_sub(hunk_start);
+    for (start, end) in hunks {
+        let hunk = &ops[start..end];
+        let orig_size = hunk.iter().filter(|op| op.kind != DiffKind::Insert).count();
+        let fixed_size = hunk.iter().filter(|op| op.kind != DiffKind::Delete).count();
 
-        // Hunk header
+        // An empty range names the line before it, so it is not shifted to 1-based
+        let range_start = |pos: usize, size: usize| if size == 0 { pos } else { pos + 1 };
         writeln!(
             output,
             "@@ -{},{} +{},{} @@",
-            hunk_start + 1,
+            range_start(hunk[0].orig, orig_size),
             orig_size,
-            hunk_start + 1,
+            range_start(hunk[0].fixed, fixed_size),
             fixed_size
         )
         .unwrap();
 
-        // Output lines with context
-        for line_idx in hunk_start..hunk_end {
-            if change_indices.contains(&line_idx) {
-                // This is a changed line
-                if let Some(orig) = orig_lines.get(line_idx) {
-                    writeln!(output, "-{}", orig).unwrap();
-                }
-                if let Some(fix) = fixed_lines.get(line_idx) {
-                    writeln!(output, "+{}", fix).unwrap();
-                }
-            } else {
-                // Context line (unchanged)
-                if let Some(line) = orig_lines.get(line_idx) {
-                    writeln!(output, " {}", line).unwrap();
-                }
+        for op in hunk {
+            let (marker, line) = match op.kind {
+                DiffKind::Equal => (' ', orig_lines[op.orig]),
+                DiffKind::Delete => ('-', orig_lines[op.orig]),
+                DiffKind::Insert => ('+', fixed_lines[op.fixed]),
+            };
+            output.push(marker);
+            output.push_str(line);
+            if !line.ends_with('\n') {
+                output.push_str("\n\\ No newline at end of file\n");
             }
         }
     }
@@ -312,6 +283,119 @@ pub fn format_diff_with_context(
     output
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffKind {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// One step of a line diff, with the positions in both inputs before the step.
+#[derive(Debug, Clone, Copy)]
+struct DiffOp {
+    kind: DiffKind,
+    orig: usize,
+    fixed: usize,
+}
+
+/// Shortest line edit script from `a` to `b`.
+///
+/// The common prefix and suffix are matched directly; Myers' O(ND) algorithm diffs the rest,
+/// which keeps memory small for the handful of lines a round of fixes touches.
+fn diff_lines(a: &[&str], b: &[&str]) -> Vec<DiffOp> {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let mid_a = &a[prefix..a.len() - suffix];
+    let mid_b = &b[prefix..b.len() - suffix];
+
+    let equal = |orig, fixed| DiffOp {
+        kind: DiffKind::Equal,
+        orig,
+        fixed,
+    };
+    let mut ops: Vec<DiffOp> = (0..prefix).map(|i| equal(i, i)).collect();
+    ops.extend(myers(mid_a, mid_b).into_iter().map(|op| DiffOp {
+        orig: op.orig + prefix,
+        fixed: op.fixed + prefix,
+        ..op
+    }));
+    ops.extend((0..suffix).map(|i| equal(a.len() - suffix + i, b.len() - suffix + i)));
+    ops
+}
+
+fn myers(a: &[&str], b: &[&str]) -> Vec<DiffOp> {
+    let n = a.len() as isize;
+    let m = b.len() as isize;
+    let max = (n + m) as usize;
+    let offset = max as isize + 1;
+    let at = |k: isize| (k + offset) as usize;
+    // Whether the best path to diagonal `k` comes down from `k + 1` (an insertion)
+    let from_above =
+        |v: &[isize], k: isize, d: isize| k == -d || (k != d && v[at(k - 1)] < v[at(k + 1)]);
+
+    let mut v = vec![0isize; 2 * max + 3];
+    let mut trace = Vec::new();
+    'search: for d in 0..=max as isize {
+        trace.push(v.clone());
+        for k in (-d..=d).step_by(2) {
+            let mut x = if from_above(&v, k, d) {
+                v[at(k + 1)]
+            } else {
+                v[at(k - 1)] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[at(k)] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut ops = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let k = x - y;
+        let prev_k = if from_above(v, k, d) { k + 1 } else { k - 1 };
+        let prev_x = v[at(prev_k)];
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            ops.push(DiffOp {
+                kind: DiffKind::Equal,
+                orig: x as usize,
+                fixed: y as usize,
+            });
+        }
+        if d > 0 {
+            let kind = if x == prev_x {
+                DiffKind::Insert
+            } else {
+                DiffKind::Delete
+            };
+            ops.push(DiffOp {
+                kind,
+                orig: prev_x as usize,
+                fixed: prev_y as usize,
+            });
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    ops.reverse();
+    ops
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -355,11 +439,14 @@ mod tests {
             },
             message: "test".into(),
             help: None,
-            suggestion: Some(crate::diagnostics::Suggestion {
+            suggestions: vec![crate::diagnostics::Suggestion {
                 message: "Replace".into(),
                 replacement: "vector[]".into(),
                 applicability: Applicability::MachineApplicable,
-            }),
+                span: None,
+            }],
+            notes: Vec::new(),
+            labels: Vec::new(),
         };
 
         let result = apply_fixes(source, &[diag], false).unwrap();
@@ -367,6 +454,185 @@ mod tests {
         assert_eq!(result.fixes_applied, 1);
     }
 
+    #[test]
+    fn test_placeholder_fix_never_applied() {
+        let source = "let x = vector::empty();";
+        let diag = Diagnostic {
+            lint: &TEST_LINT,
+            level: crate::level::LintLevel::Warn,
+            file: None,
+            span: crate::diagnostics::Span {
+                start: crate::diagnostics::Position { row: 1, column: 5 },
+                end: crate::diagnostics::Position { row: 1, column: 6 },
+            },
+            message: "test".into(),
+            help: None,
+            suggestions: vec![crate::diagnostics::Suggestion {
+                message: "Rename".into(),
+                replacement: "TODO_name".into(),
+                applicability: Applicability::HasPlaceholders,
+                span: None,
+            }],
+            notes: Vec::new(),
+            labels: Vec::new(),
+        };
+
+        let result = apply_fixes(source, &[diag], true).unwrap();
+        assert_eq!(result.fixed_source, source);
+        assert_eq!(result.fixes_applied, 0);
+        assert_eq!(result.fixes_skipped, 1);
+        assert_eq!(result.unsafe_skipped, 0);
+        assert_eq!(result.placeholders.len(), 1);
+    }
+
+    #[test]
+    fn test_first_machine_applicable_suggestion_applied() {
+        let source = "let x = vector::empty();";
+        let suggestion = |replacement: &str, applicability| crate::diagnostics::Suggestion {
+            message: "Replace".into(),
+            replacement: replacement.into(),
+            applicability,
+            span: None,
+        };
+        let mut diag = fix_diag(9, 24, "vector[]");
+        diag.suggestions = vec![
+            suggestion("vector<TODO>[]", Applicability::HasPlaceholders),
+            suggestion("vector<u8>[]", Applicability::MaybeIncorrect),
+            suggestion("vector[]", Applicability::MachineApplicable),
+        ];
+
+        let result = apply_fixes(source, std::slice::from_ref(&diag), true).unwrap();
+        assert_eq!(result.fixed_source, "let x = vector[];");
+
+        // Without `allow_unsafe`, the skip is reported as unsafe rather than as a placeholder.
+        diag.suggestions.pop();
+        let result = apply_fixes(source, std::slice::from_ref(&diag), false).unwrap();
+        assert_eq!(result.fixed_source, source);
+        assert_eq!(result.unsafe_skipped, 1);
+        assert!(result.placeholders.is_empty());
+
+        let result = apply_fixes(source, &[diag], true).unwrap();
+        assert_eq!(result.fixed_source, "let x = vector<u8>[];");
+        assert!(result.placeholders.is_empty());
+    }
+
+    fn fix_diag(start_col: usize, end_col: usize, replacement: &str) -> Diagnostic {
+        Diagnostic {
+            lint: &TEST_LINT,
+            level: crate::level::LintLevel::Warn,
+            file: None,
+            span: crate::diagnostics::Span {
+                start: crate::diagnostics::Position {
+                    row: 1,
+                    column: start_col,
+                },
+                end: crate::diagnostics::Position {
+                    row: 1,
+                    column: end_col,
+                },
+            },
+            message: "test".into(),
+            help: None,
+            suggestions: vec![crate::diagnostics::Suggestion {
+                message: "Replace".into(),
+                replacement: replacement.into(),
+                applicability: Applicability::MachineApplicable,
+                span: None,
+            }],
+            notes: Vec::new(),
+            labels: Vec::new(),
+        }
+    }
+
+    /// Stand-in for re-linting: `(y)` -> `y` and `y` -> `z`, reported on single-line sources.
+    fn relint(source: &str) -> Vec<Diagnostic> {
+        let mut diags = Vec::new();
+        if let Some(i) = source.find("(y)") {
+            diags.push(fix_diag(i + 1, i + 4, "y"));
+        }
+        if let Some(i) = source.find('y') {
+            diags.push(fix_diag(i + 1, i + 2, "z"));
+        }
+        diags
+    }
+
+    #[test]
+    fn test_overlapping_fixes_deferred_to_next_pass() {
+        let source = "let x = (y);";
+
+        // The `y` -> `z` fix sits inside the `(y)` -> `y` fix.
+        let first = apply_fixes(source, &relint(source), false).unwrap();
+        assert_eq!(first.fixed_source, "let x = y;");
+        assert_eq!(first.fixes_applied, 1);
+        assert_eq!(first.fixes_skipped, 1);
+        assert_eq!(first.overlaps_skipped, 1);
+
+        let second = apply_fixes(&first.fixed_source, &relint(&first.fixed_source), false).unwrap();
+        assert_eq!(second.fixed_source, "let x = z;");
+        assert_eq!(second.fixes_applied, 1);
+        assert_eq!(second.fixes_skipped, 0);
+
+        assert!(relint(&second.fixed_source).is_empty());
+    }
+
+    #[test]
+    fn test_insert_guard_line_before_division() {
+        let source =
+            "fun share(total: u64, n: u64): u64 {\n    \tlet each = total / n;\n    each\n}\n";
+        let mut diag = fix_diag(1, 1, "");
+        diag.span = crate::diagnostics::Span {
+            start: crate::diagnostics::Position { row: 2, column: 17 },
+            end: crate::diagnostics::Position { row: 2, column: 26 },
+        };
+        diag.suggestions = vec![
+            crate::diagnostics::Suggestion::insert_line_before(
+                source,
+                2,
+                "assert!(n != 0, EDivideByZero);",
+                "Guard the divisor",
+                Applicability::MachineApplicable,
+            )
+            .unwrap(),
+        ];
+
+        let result = apply_fixes(source, &[diag], false).unwrap();
+        assert_eq!(
+            result.fixed_source,
+            "fun share(total: u64, n: u64): u64 {\n    \tassert!(n != 0, EDivideByZero);\n    \tlet each = total / n;\n    each\n}\n"
+        );
+        assert_eq!(result.fixes_applied, 1);
+    }
+
+    #[test]
+    fn test_insertion_does_not_overlap_adjacent_replacement() {
+        let source = "let x = vector::empty();";
+        let mut insert = fix_diag(9, 9, "");
+        insert.suggestions[0].replacement = "/* fixed */ ".into();
+        insert.suggestions[0].span = Some(crate::diagnostics::Span::point(
+            crate::diagnostics::Position { row: 1, column: 9 },
+        ));
+        let replace = fix_diag(9, 24, "vector[]");
+        let mut append = fix_diag(1, 1, "");
+        append.suggestions[0].replacement = "!".into();
+        append.suggestions[0].span = Some(crate::diagnostics::Span::point(
+            crate::diagnostics::Position { row: 1, column: 24 },
+        ));
+
+        let result = apply_fixes(source, &[replace, append, insert], false).unwrap();
+        assert_eq!(result.fixed_source, "let x = /* fixed */ vector[]!;");
+        assert_eq!(result.fixes_applied, 3);
+        assert_eq!(result.fixes_skipped, 0);
+
+        // An insertion strictly inside a replacement still conflicts with it.
+        let mut inside = fix_diag(1, 1, "");
+        inside.suggestions[0].span = Some(crate::diagnostics::Span::point(
+            crate::diagnostics::Position { row: 1, column: 12 },
+        ));
+        let result = apply_fixes(source, &[fix_diag(9, 24, "vector[]"), inside], false).unwrap();
+        assert_eq!(result.fixed_source, "let x = vector[];");
+        assert_eq!(result.fixes_skipped, 1);
+    }
+
     #[test]
     fn test_format_diff() {
         let original = "let x = vector::empty();\nlet y = 1;";
@@ -379,4 +645,94 @@ mod tests {
         assert!(diff.contains("-let x = vector::empty();"));
         assert!(diff.contains("+let x = vector[];"));
     }
+
+    /// Apply a single-file diff produced by `format_diff`, checking every context and removed
+    /// line against `original` and every hunk's line counts.
+    fn apply_diff(original: &str, diff: &str) -> String {
+        let orig: Vec<&str> = original.split_inclusive('\n').collect();
+        let mut out = String::new();
+        let mut pos = 0usize;
+        let mut lines = diff.split_inclusive('\n').skip(2).peekable();
+        while let Some(header) = lines.next() {
+            let counts: Vec<usize> = header
+                .trim_start_matches("@@ -")
+                .trim_end_matches(" @@\n")
+                .split(&[',', ' ', '+'][..])
+                .filter(|part| !part.is_empty())
+                .map(|part| part.parse().unwrap())
+                .collect();
+            let (orig_start, orig_len, fixed_len) = (counts[0], counts[1], counts[3]);
+            let hunk_pos = if orig_len == 0 {
+                orig_start
+            } else {
+                orig_start - 1
+            };
+            out.extend(orig[pos..hunk_pos].iter().copied());
+            pos = hunk_pos;
+            let (mut seen_orig, mut seen_fixed) = (0, 0);
+            while let Some(line) = lines.next_if(|l| !l.starts_with("@@")) {
+                let mut text = line[1..].to_string();
+                if lines.next_if(|l| l.starts_with('\\')).is_some() {
+                    text.pop();
+                }
+                if !line.starts_with('+') {
+                    assert_eq!(orig[pos], text, "context mismatch at line {}", pos + 1);
+                    pos += 1;
+                    seen_orig += 1;
+                }
+                if !line.starts_with('-') {
+                    out.push_str(&text);
+                    seen_fixed += 1;
+                }
+            }
+            assert_eq!((seen_orig, seen_fixed), (orig_len, fixed_len), "{header}");
+        }
+        out.extend(orig[pos..].iter().copied());
+        out
+    }
+
+    #[test]
+    fn test_format_diff_insertion_keeps_alignment() {
+        let diff = format_diff("a\nb\nc\n", "a\nx\nb\nc\n", Path::new("t.move"));
+        assert_eq!(
+            diff,
+            "--- a/t.move\n+++ b/t.move\n@@ -1,3 +1,4 @@\n a\n+x\n b\n c\n"
+        );
+    }
+
+    #[test]
+    fn test_format_diff_marks_missing_trailing_newline() {
+        let diff = format_diff("a\nb", "a\nc", Path::new("t.move"));
+        assert_eq!(
+            diff,
+            "--- a/t.move\n+++ b/t.move\n@@ -1,2 +1,2 @@\n a\n-b\n\\ No newline at end of file\n+c\n\\ No newline at end of file\n"
+        );
+    }
+
+    #[test]
+    fn test_format_diff_round_trips() {
+        let original: String = (1..=30).map(|i| format!("line {i}\n")).collect();
+        let mut fixed_lines: Vec<String> = original.lines().map(String::from).collect();
+        fixed_lines[1] = "changed 2".to_string();
+        fixed_lines.insert(15, "inserted".to_string());
+        fixed_lines.remove(25);
+        fixed_lines.push("appended".to_string());
+        let fixed = fixed_lines.join("\n");
+
+        for (before, after) in [
+            (original.as_str(), fixed.as_str()),
+            ("", "new\n"),
+            ("old\n", ""),
+            ("a\r\nb\r\n", "a\r\nc\r\n"),
+        ] {
+            for context in [0, 1, 3] {
+                let diff = format_diff_with_context(before, after, Path::new("t.move"), context);
+                assert_eq!(
+                    apply_diff(before, &diff),
+                    after,
+                    "context {context}:\n{diff}"
+                );
+            }
+        }
+    }
 }
@@ -1,6 +1,4 @@
-//! TODO: Centralized, type-grounded framework catalog.
-//!
-//! This module is intentionally a stub for now.
+//! Centralized, type-grounded framework catalog.
 //!
 //! Goal:
 //! - Replace ad-hoc module/function string matching spread across lints
@@ -15,3 +13,44 @@
 //! Notes:
 //! - Prefer matching on fully-qualified module identity where available.
 //! - Avoid name-based heuristics; keep allowlists explicit.
+
+/// A `(package, module, function)` triple, where `package` is the named address the
+/// package is built with (e.g. `pyth` in `pyth::price::get_price`).
+pub type PackageFunctionRef = (&'static str, &'static str, &'static str);
+
+// ============================================================================
+// Oracles
+// ============================================================================
+//
+// Oracle packages are published at different addresses per network, so these match on
+// the named address each package declares in its `Move.toml` rather than a numeric one.
+
+/// Calls that return an oracle price, or a struct holding one, without checking its age.
+///
+/// Passing an already freshness-checked value through one of these keeps it checked.
+pub const ORACLE_PRICE_ACCESSORS: &[PackageFunctionRef] = &[
+    // Pyth: `pyth::get_price_unsafe(&PriceInfoObject): Price`, then `price::get_price(&Price)`.
+    ("pyth", "pyth", "get_price_unsafe"),
+    ("pyth", "price", "get_price"),
+    // Switchboard on-demand: `aggregator::current_result(&Aggregator): &CurrentResult`.
+    ("switchboard", "aggregator", "current_result"),
+    ("switchboard", "current_result", "result"),
+];
+
+/// Calls that return the publish time of an oracle price.
+pub const ORACLE_TIMESTAMP_ACCESSORS: &[PackageFunctionRef] = &[
+    ("pyth", "price", "get_timestamp"),
+    ("switchboard", "current_result", "timestamp_ms"),
+];
+
+/// Returns true if `package::module::function` reads an oracle price
+/// (see [`ORACLE_PRICE_ACCESSORS`]).
+pub fn is_oracle_price_accessor(package: &str, module: &str, function: &str) -> bool {
+    ORACLE_PRICE_ACCESSORS.contains(&(package, module, function))
+}
+
+/// Returns true if `package::module::function` reads an oracle publish time
+/// (see [`ORACLE_TIMESTAMP_ACCESSORS`]).
+pub fn is_oracle_timestamp_accessor(package: &str, module: &str, function: &str) -> bool {
+    ORACLE_TIMESTAMP_ACCESSORS.contains(&(package, module, function))
+}
@@ -7,8 +7,12 @@ use serde::{Deserialize, Serialize};
 #[serde(rename_all = "lowercase")]
 pub enum LintLevel {
     Allow,
+    /// Informational; shown but never fails the run (e.g. a file skipped for its size).
+    Note,
     #[default]
     Warn,
+    /// Displayed like a warning, but fails the run (e.g. CI) like an error.
+    Deny,
     Error,
 }
 
@@ -17,8 +21,28 @@ impl LintLevel {
     pub fn as_str(&self) -> &'static str {
         match self {
             LintLevel::Allow => "allow",
+            LintLevel::Note => "note",
             LintLevel::Warn => "warning",
+            LintLevel::Deny => "deny",
             LintLevel::Error => "error",
         }
     }
+
+    /// Parse a level written by [`LintLevel::as_str`], as found in JSON reports.
+    pub fn from_output_str(s: &str) -> Option<Self> {
+        [
+            LintLevel::Allow,
+            LintLevel::Note,
+            LintLevel::Warn,
+            LintLevel::Deny,
+            LintLevel::Error,
+        ]
+        .into_iter()
+        .find(|level| level.as_str() == s)
+    }
+
+    /// Whether a diagnostic at this level should make the run exit with failure.
+    pub fn fails_run(&self) -> bool {
+        matches!(self, LintLevel::Deny | LintLevel::Error)
+    }
 }
@@ -74,8 +74,8 @@ pub use crate::diagnostics::{Diagnostic, Position, Span, Suggestion};
 pub use crate::error::{Error, Result};
 pub use crate::level::LintLevel;
 pub use crate::lint::{
-    AnalysisKind, LintCategory, LintDescriptor, LintName, LintRegistry, LintRule, LintSettings,
-    RuleGroup,
+    AnalysisKind, LintCategory, LintDescriptor, LintName, LintRegistry, LintRule, LintSelection,
+    LintSettings, RuleGroup,
 };
 
 // Unified registry
@@ -87,10 +87,11 @@ pub use crate::unified::{LintPhase, UnifiedLint, UnifiedLintRegistry, unified_re
 
 use anyhow::Result as AnyhowResult;
 use std::fmt;
-use tree_sitter::Tree;
+use tree_sitter::{InputEdit, Tree};
 
+use crate::instrument_lint;
 use crate::lint::LintContext;
-use crate::parser::parse_source;
+use crate::parser::{parse_source, parse_source_incremental};
 use crate::visitor::walk_tree;
 
 /// Engine orchestrates linting by parsing source and running registered rules.
@@ -165,25 +166,130 @@ impl LintEngine {
     /// Lint a single in-memory source string and return diagnostics.
     #[must_use = "diagnostics should be processed or reported"]
     pub fn lint_source(&self, source: &str) -> AnyhowResult<Vec<Diagnostic>> {
+        let mut diagnostics = Vec::new();
+        self.lint_source_with(source, |d| diagnostics.push(d))?;
+        Ok(diagnostics)
+    }
+
+    /// Lint a single in-memory source string, passing each diagnostic to `sink`.
+    ///
+    /// Diagnostics arrive in the same order [`Self::lint_source`] would return them,
+    /// so callers can stream results without collecting a whole directory in memory.
+    pub fn lint_source_with<F: FnMut(Diagnostic)>(
+        &self,
+        source: &str,
+        sink: F,
+    ) -> AnyhowResult<()> {
         let tree = parse_source(source)?;
-        self.run_rules(source, &tree)
+        self.run_rules(source, &tree).into_iter().for_each(sink);
+        Ok(())
+    }
+
+    /// Lint `source` after `edits` were applied to the text `old_tree` was parsed from.
+    ///
+    /// Returns the diagnostics and the new tree, which can be passed back in as `old_tree`
+    /// on the next call. Used by the `--fix` loop, where each pass changes only a few spans.
+    pub fn lint_source_incremental(
+        &self,
+        source: &str,
+        old_tree: Option<&Tree>,
+        edits: &[InputEdit],
+    ) -> AnyhowResult<(Vec<Diagnostic>, Tree)> {
+        let tree = parse_source_incremental(source, old_tree, edits)?;
+        let diagnostics = self.run_rules(source, &tree);
+        Ok((diagnostics, tree))
     }
 
-    fn run_rules(&self, source: &str, tree: &Tree) -> AnyhowResult<Vec<Diagnostic>> {
+    /// Lint a file on disk, passing each diagnostic (with `file` set to `path`) to `sink`.
+    ///
+    /// A file over [`LintSettings::max_file_size`] is not read; `sink` receives only the note
+    /// from [`Self::oversized_file_note`].
+    pub fn lint_file_with<F: FnMut(Diagnostic)>(
+        &self,
+        path: &std::path::Path,
+        mut sink: F,
+    ) -> AnyhowResult<()> {
+        if let Some(note) = self.oversized_file_note(path)? {
+            sink(note);
+            return Ok(());
+        }
+        let source = std::fs::read_to_string(path)?;
+        let file = path.display().to_string();
+        self.lint_source_with(&source, |mut d| {
+            d.file = Some(file.clone());
+            sink(d);
+        })
+    }
+
+    /// The note to report instead of linting `path`, if it exceeds the configured
+    /// [`LintSettings::max_file_size`].
+    ///
+    /// Only the file's metadata is read. The note sits at the start of the file and has
+    /// [`LintLevel::Note`], so it never fails a run.
+    pub fn oversized_file_note(&self, path: &std::path::Path) -> AnyhowResult<Option<Diagnostic>> {
+        let Some(max) = self.settings.max_file_size() else {
+            return Ok(None);
+        };
+        let len = std::fs::metadata(path)?.len();
+        if len <= max {
+            return Ok(None);
+        }
+        Ok(Some(Diagnostic {
+            lint: &crate::lint::FILE_TOO_LARGE,
+            level: LintLevel::Note,
+            file: Some(path.display().to_string()),
+            span: Span::point(Position { row: 1, column: 1 }),
+            message: format!("skipped: file exceeds max size ({len} bytes, limit {max})"),
+            help: None,
+            suggestions: Vec::new(),
+            notes: Vec::new(),
+            labels: Vec::new(),
+        }))
+    }
+
+    /// The diagnostic to report instead of linting `path` when linting failed with `err`
+    /// because the file does not parse (see [`Error::ParseError`]).
+    ///
+    /// The diagnostic sits at the first syntax error and has [`LintLevel::Error`], so a
+    /// multi-file run can carry on and still fail. Returns `None` for any other error.
+    #[must_use]
+    pub fn parse_error_diagnostic(
+        path: &std::path::Path,
+        err: &anyhow::Error,
+    ) -> Option<Diagnostic> {
+        let Error::ParseError { position, excerpt } = err.downcast_ref::<Error>()? else {
+            return None;
+        };
+        Some(Diagnostic {
+            lint: &crate::lint::PARSE_ERROR,
+            level: LintLevel::Error,
+            file: Some(path.display().to_string()),
+            span: Span::point(*position),
+            message: format!("skipped: syntax error near `{excerpt}`"),
+            help: None,
+            suggestions: Vec::new(),
+            notes: Vec::new(),
+            labels: Vec::new(),
+        })
+    }
+
+    fn run_rules(&self, source: &str, tree: &Tree) -> Vec<Diagnostic> {
         let mut ctx = LintContext::new(source, self.settings.clone());
         let root = tree.root_node();
 
         ctx.precollect_item_directives(root);
 
         for rule in self.registry.rules() {
-            rule.check(root, source, &mut ctx);
+            instrument_lint!(rule.descriptor().name, {
+                rule.check(root, source, &mut ctx)
+            });
         }
 
         // Walk tree to allow visitor-style rules later.
         // This keeps traversal centralized and extendable.
         walk_tree(root, source, &mut ctx);
 
-        Ok(ctx.into_diagnostics())
+        ctx.into_diagnostics()
     }
 }
 
@@ -274,6 +380,18 @@ impl LintEngineBuilder {
         self
     }
 
+    /// Skip files larger than `bytes` instead of linting them.
+    ///
+    /// Each skipped file yields one note-level diagnostic (see
+    /// [`LintEngine::oversized_file_note`]), which is useful to keep a huge generated file
+    /// from stalling CI. The note counts toward `--max-diagnostics` in the CLI but never fails
+    /// the run, even with `--deny-warnings`. By default there is no limit.
+    #[must_use]
+    pub fn max_file_size(mut self, bytes: u64) -> Self {
+        self.settings = self.settings.with_max_file_size(bytes);
+        self
+    }
+
     /// Only run these specific lints.
     ///
     /// Takes any iterator of strings. When set, only lints in this list
@@ -431,6 +549,86 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_lint_source_with_matches_lint_source() {
+        let engine = create_default_engine();
+        let src = "module test::m {\n    public fun f(): vector<u64> { vector::empty() }\n}\n";
+
+        let collected = engine.lint_source(src).expect("lint failed");
+        let mut streamed = Vec::new();
+        engine
+            .lint_source_with(src, |d| streamed.push(d))
+            .expect("lint failed");
+
+        assert!(!collected.is_empty());
+        assert_eq!(streamed, collected);
+    }
+
+    #[test]
+    fn test_lint_file_with_sets_file() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let path = tmp.path().join("m.move");
+        std::fs::write(
+            &path,
+            "module test::m {\n    public fun f(): vector<u64> { vector::empty() }\n}\n",
+        )
+        .expect("write");
+
+        let engine = create_default_engine();
+        let mut files = Vec::new();
+        engine
+            .lint_file_with(&path, |d| files.push(d.file))
+            .expect("lint failed");
+
+        assert!(!files.is_empty());
+        let expected = path.display().to_string();
+        assert!(
+            files
+                .iter()
+                .all(|f| f.as_deref() == Some(expected.as_str()))
+        );
+    }
+
+    #[test]
+    fn test_max_file_size_skips_large_files() {
+        let tmp = tempfile::tempdir().expect("tempdir");
+        let path = tmp.path().join("m.move");
+        let src = "module test::m {\n    public fun f(): vector<u64> { vector::empty() }\n}\n";
+        std::fs::write(&path, src).expect("write");
+
+        let collect = |engine: &LintEngine| {
+            let mut diags = Vec::new();
+            engine
+                .lint_file_with(&path, |d| diags.push(d))
+                .expect("lint failed");
+            diags
+        };
+
+        let engine = LintEngine::builder()
+            .max_file_size(src.len() as u64 - 1)
+            .build()
+            .expect("build");
+        let diags = collect(&engine);
+        assert_eq!(diags.len(), 1, "{diags:?}");
+        assert_eq!(diags[0].lint.name, "file_too_large");
+        assert_eq!(diags[0].level, LintLevel::Note);
+        assert!(
+            diags[0]
+                .message
+                .starts_with("skipped: file exceeds max size")
+        );
+
+        let engine = LintEngine::builder()
+            .max_file_size(src.len() as u64)
+            .build()
+            .expect("build");
+        assert!(
+            collect(&engine)
+                .iter()
+                .all(|d| d.lint.name != "file_too_large")
+        );
+    }
+
     #[test]
     fn test_engine_debug() {
         let engine = create_default_engine();
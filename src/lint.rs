@@ -1,6 +1,7 @@
 use crate::annotations;
 use crate::diagnostics::{Diagnostic, Span, Suggestion};
 use crate::level::LintLevel;
+use crate::suppression::CommentDirective;
 use anyhow::{Result, anyhow};
 use std::collections::{HashMap, HashSet};
 use tree_sitter::Node;
@@ -50,6 +51,17 @@ impl RuleGroup {
         }
     }
 
+    /// Parse a tier name as produced by [`RuleGroup::as_str`].
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "stable" => Some(RuleGroup::Stable),
+            "preview" => Some(RuleGroup::Preview),
+            "experimental" => Some(RuleGroup::Experimental),
+            "deprecated" => Some(RuleGroup::Deprecated),
+            _ => None,
+        }
+    }
+
     /// Returns true if this tier requires explicit opt-in via CLI flag.
     pub fn requires_opt_in(&self) -> bool {
         matches!(self, RuleGroup::Preview | RuleGroup::Experimental)
@@ -113,6 +125,16 @@ impl AnalysisKind {
     }
 }
 
+/// Serializes as [`AnalysisKind::as_str`], so the JSON name is stable across refactors.
+impl serde::Serialize for AnalysisKind {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
 // ============================================================================
 // Fix Safety Classification - Inspired by Ruff
 // ============================================================================
@@ -200,6 +222,16 @@ pub enum LintCategory {
 }
 
 impl LintCategory {
+    /// Every category, in declaration order.
+    pub const ALL: [LintCategory; 6] = [
+        LintCategory::Style,
+        LintCategory::Modernization,
+        LintCategory::Naming,
+        LintCategory::TestQuality,
+        LintCategory::Suspicious,
+        LintCategory::Security,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             LintCategory::Style => "style",
@@ -210,6 +242,64 @@ impl LintCategory {
             LintCategory::Security => "security",
         }
     }
+
+    /// Parse a category name as produced by [`LintCategory::as_str`].
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "style" => Some(LintCategory::Style),
+            "modernization" => Some(LintCategory::Modernization),
+            "naming" => Some(LintCategory::Naming),
+            "test_quality" | "test-quality" => Some(LintCategory::TestQuality),
+            "suspicious" => Some(LintCategory::Suspicious),
+            "security" => Some(LintCategory::Security),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Lint Selection (by category / tier)
+// ============================================================================
+
+/// Category and tier selection layered on top of explicit lint names.
+///
+/// An empty list places no restriction on that axis. Non-empty lists are
+/// intersected with each other and with any `--only` names.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LintSelection {
+    pub categories: Vec<LintCategory>,
+    pub groups: Vec<RuleGroup>,
+}
+
+impl LintSelection {
+    /// Build a selection from `--only-category` / `--only-group` values.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if any category or group name is unknown.
+    pub fn parse(categories: &[String], groups: &[String]) -> Result<Self> {
+        let categories = categories
+            .iter()
+            .map(|c| {
+                LintCategory::from_name(c).ok_or_else(|| anyhow!("unknown lint category: {c}"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let groups = groups
+            .iter()
+            .map(|g| RuleGroup::from_name(g).ok_or_else(|| anyhow!("unknown lint group: {g}")))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { categories, groups })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.categories.is_empty() && self.groups.is_empty()
+    }
+
+    /// Returns true if `descriptor` passes both the category and tier filters.
+    pub fn matches(&self, descriptor: &LintDescriptor) -> bool {
+        (self.categories.is_empty() || self.categories.contains(&descriptor.category))
+            && (self.groups.is_empty() || self.groups.contains(&descriptor.group))
+    }
 }
 
 // ============================================================================
@@ -291,6 +381,16 @@ impl TypeSystemGap {
     }
 }
 
+/// Serializes as [`TypeSystemGap::as_str`], so the JSON name is stable across refactors.
+impl serde::Serialize for TypeSystemGap {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
 /// Static metadata describing a lint rule.
 #[derive(Debug)]
 pub struct LintDescriptor {
@@ -458,6 +558,64 @@ pub trait LintRule: Send + Sync {
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct LintSettings {
     levels: HashMap<String, LintLevel>,
+    category_levels: HashMap<LintCategory, LintLevel>,
+    known_addresses: Vec<String>,
+    thresholds: Thresholds,
+}
+
+/// Framework addresses that are always considered well-known.
+pub const DEFAULT_KNOWN_ADDRESSES: &[&str] = &["0x0", "0x1", "0x2"];
+
+/// Default for `[lints.thresholds] entry_max_params`.
+pub const DEFAULT_ENTRY_MAX_PARAMS: usize = 8;
+
+/// Default for `[lints.thresholds] max_nesting_depth`.
+pub const DEFAULT_MAX_NESTING_DEPTH: usize = 5;
+
+/// Numeric limits for threshold lints, from `[lints.thresholds]`.
+///
+/// Every limit is optional; rules read it through [`LintSettings`], which falls back to the
+/// rule's built-in default when it is unset.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Thresholds {
+    /// Maximum parameters an `entry` function may take before `entry_function_param_count`
+    /// fires (`TxContext` and `Clock` are not counted). Default: [`DEFAULT_ENTRY_MAX_PARAMS`].
+    pub entry_max_params: Option<usize>,
+
+    /// Maximum depth of nested blocks within a function body before `excessive_nesting`
+    /// fires. Default: [`DEFAULT_MAX_NESTING_DEPTH`].
+    pub max_nesting_depth: Option<usize>,
+
+    /// Largest file, in bytes, that is linted; larger files are skipped with a note.
+    /// Default: no limit.
+    pub max_file_size: Option<u64>,
+}
+
+impl Thresholds {
+    /// Overlay `nearer` on `self`: limits set in `nearer` win and unset ones are inherited.
+    #[must_use]
+    pub fn merge(self, nearer: Thresholds) -> Thresholds {
+        Thresholds {
+            entry_max_params: nearer.entry_max_params.or(self.entry_max_params),
+            max_nesting_depth: nearer.max_nesting_depth.or(self.max_nesting_depth),
+            max_file_size: nearer.max_file_size.or(self.max_file_size),
+        }
+    }
+}
+
+/// Normalize an address literal for comparison: `@0x0002` and `0x2` both become `0x2`.
+///
+/// Named addresses (e.g. `@sui`) are lowercased and otherwise kept as-is.
+fn normalize_address(literal: &str) -> String {
+    let lit = literal.trim().trim_start_matches('@').to_ascii_lowercase();
+    match lit.strip_prefix("0x") {
+        Some(hex) => {
+            let digits = hex.trim_start_matches('0');
+            format!("0x{}", if digits.is_empty() { "0" } else { digits })
+        }
+        None => lit,
+    }
 }
 
 impl LintSettings {
@@ -471,6 +629,92 @@ impl LintSettings {
         self
     }
 
+    /// Set default levels per category (from `[lints.category_levels]`).
+    ///
+    /// These apply only to lints without an explicit per-lint level.
+    #[must_use]
+    pub fn with_category_levels(mut self, levels: HashMap<LintCategory, LintLevel>) -> Self {
+        self.category_levels.extend(levels);
+        self
+    }
+
+    /// Override the level of one lint, e.g. to make `droppable_capability` an error in CI.
+    ///
+    /// Aliases are resolved; unknown lint names are an error. The override replaces any
+    /// per-lint level set earlier (including from config), so call it after
+    /// [`Self::with_config_levels`]. See [`effective_level_for_scopes`] for precedence.
+    pub fn with_severity(&mut self, lint: &str, level: LintLevel) -> Result<&mut Self> {
+        let name = LintName::new(lint).ok_or_else(|| anyhow!("unknown lint: {lint}"))?;
+        self.levels.insert(name.as_str().to_string(), level);
+        Ok(self)
+    }
+
+    /// Add well-known addresses (from `[lints.known_addresses]`) on top of the defaults.
+    #[must_use]
+    pub fn with_known_addresses(mut self, addresses: impl IntoIterator<Item = String>) -> Self {
+        for addr in addresses {
+            let normalized = normalize_address(&addr);
+            if !self.known_addresses.contains(&normalized) {
+                self.known_addresses.push(normalized);
+            }
+        }
+        self
+    }
+
+    /// Whether an address literal is a framework default or a configured known address.
+    pub fn is_known_address(&self, literal: &str) -> bool {
+        let normalized = normalize_address(literal);
+        DEFAULT_KNOWN_ADDRESSES.contains(&normalized.as_str())
+            || self.known_addresses.contains(&normalized)
+    }
+
+    /// Apply the limits set in `thresholds` (from `[lints.thresholds]`).
+    ///
+    /// Limits left unset in `thresholds` keep their current value.
+    #[must_use]
+    pub fn with_thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = self.thresholds.merge(thresholds);
+        self
+    }
+
+    /// The configured limits, before defaults are applied.
+    pub fn thresholds(&self) -> Thresholds {
+        self.thresholds
+    }
+
+    /// Set the parameter limit for `entry` functions (from `[lints.thresholds]`).
+    #[must_use]
+    pub fn with_entry_max_params(mut self, max: usize) -> Self {
+        self.thresholds.entry_max_params = Some(max);
+        self
+    }
+
+    /// Maximum parameters an `entry` function may take, excluding `TxContext` and `Clock`.
+    pub fn entry_max_params(&self) -> usize {
+        self.thresholds
+            .entry_max_params
+            .unwrap_or(DEFAULT_ENTRY_MAX_PARAMS)
+    }
+
+    /// Maximum depth of nested blocks within a function body.
+    pub fn max_nesting_depth(&self) -> usize {
+        self.thresholds
+            .max_nesting_depth
+            .unwrap_or(DEFAULT_MAX_NESTING_DEPTH)
+    }
+
+    /// Skip files larger than `bytes` instead of linting them.
+    #[must_use]
+    pub fn with_max_file_size(mut self, bytes: u64) -> Self {
+        self.thresholds.max_file_size = Some(bytes);
+        self
+    }
+
+    /// Largest file, in bytes, that is linted; `None` (the default) means no limit.
+    pub fn max_file_size(&self) -> Option<u64> {
+        self.thresholds.max_file_size
+    }
+
     #[must_use]
     pub fn disable(mut self, disabled: impl IntoIterator<Item = String>) -> Self {
         for name in disabled {
@@ -486,7 +730,11 @@ impl LintSettings {
     /// This is the preferred method when you have a pre-validated `LintName`.
     #[must_use]
     pub fn level_for_name(&self, lint: &LintName) -> LintLevel {
-        self.levels.get(lint.as_str()).copied().unwrap_or_default()
+        self.levels
+            .get(lint.as_str())
+            .copied()
+            .or_else(|| self.category_level_for(lint.as_str()))
+            .unwrap_or_default()
     }
 
     /// Get the lint level for a lint by string name.
@@ -500,10 +748,35 @@ impl LintSettings {
         }
         // Try resolving as alias
         let canonical = resolve_lint_alias(lint_name);
-        self.levels.get(canonical).copied().unwrap_or_default()
+        self.levels
+            .get(canonical)
+            .copied()
+            .or_else(|| self.category_level_for(canonical))
+            .unwrap_or_default()
+    }
+
+    /// Category default for a canonical lint name, if one is configured.
+    fn category_level_for(&self, canonical: &str) -> Option<LintLevel> {
+        if self.category_levels.is_empty() {
+            return None;
+        }
+        let lint = crate::unified::unified_registry().get(canonical)?;
+        self.category_levels.get(&lint.descriptor.category).copied()
     }
 }
 
+/// Resolve a lint's level at a source location.
+///
+/// Precedence, highest first:
+/// 1. Item directives (`#[allow(lint(..))]`, `#[deny(..)]` on a function or struct)
+/// 2. Module directives
+/// 3. The per-lint level from [`LintSettings`] (config `[lints]` or
+///    [`LintSettings::with_severity`], whichever was applied last)
+/// 4. The category default from `[lints.category_levels]`
+/// 5. [`LintLevel::default`]
+///
+/// An `expect` directive keeps an otherwise allowed lint at `warn`. A configured
+/// [`LintLevel::Deny`] is kept as-is; `#[deny(..)]` directives still raise to `error`.
 pub(crate) fn effective_level_for_scopes(
     settings: &LintSettings,
     lint: &'static LintDescriptor,
@@ -551,6 +824,8 @@ pub struct LintContext<'src> {
     module_expected_unfired: HashSet<String>,
     item_scope_cache: HashMap<usize, annotations::SuppressionScope>,
     item_expected_unfired: HashMap<usize, HashSet<String>>,
+    /// Unfired `// move-clippy:expect(..)` comments, keyed by the byte offset of the comment.
+    comment_expected_unfired: HashMap<usize, HashSet<String>>,
 }
 
 impl<'src> LintContext<'src> {
@@ -560,6 +835,11 @@ impl<'src> LintContext<'src> {
             .unfired_expectations()
             .cloned()
             .collect::<HashSet<_>>();
+        let comment_expected_unfired = crate::suppression::comment_expectations(source)
+            .into_iter()
+            .filter(|(_, names)| !names.is_empty())
+            .map(|(offset, names)| (offset, names.into_iter().collect()))
+            .collect();
 
         Self {
             source,
@@ -569,6 +849,7 @@ impl<'src> LintContext<'src> {
             module_expected_unfired,
             item_scope_cache: HashMap::new(),
             item_expected_unfired: HashMap::new(),
+            comment_expected_unfired,
         }
     }
 
@@ -625,6 +906,18 @@ impl<'src> LintContext<'src> {
         effective_level_for_scopes(&self.settings, lint, &self.module_scope, item_scope)
     }
 
+    /// Level for a diagnostic at `node`: item and module directives, then any
+    /// `// move-clippy:` comment directive on or directly above the node's line.
+    fn effective_level_for_node(&mut self, lint: &'static LintDescriptor, node: Node) -> LintLevel {
+        let anchor_start_byte = crate::suppression::anchor_item_start_byte(node);
+        let level = self.effective_level_for_anchor(lint, anchor_start_byte);
+        match crate::suppression::comment_directive_at(self.source, node.start_byte(), lint.name) {
+            Some(CommentDirective::Allow) => LintLevel::Allow,
+            Some(CommentDirective::Expect) if level == LintLevel::Allow => LintLevel::Warn,
+            _ => level,
+        }
+    }
+
     fn mark_expected_fired(&mut self, anchor_start_byte: usize, lint: &'static LintDescriptor) {
         let lint_name = lint.name;
         let category = lint.category.as_str();
@@ -645,6 +938,19 @@ impl<'src> LintContext<'src> {
         }
     }
 
+    /// Mark a `// move-clippy:expect(..)` comment covering `node` as fired for `lint`.
+    fn mark_comment_expected_fired(&mut self, node: Node, lint: &'static LintDescriptor) {
+        if let Some((CommentDirective::Expect, offset)) =
+            crate::suppression::find_comment_directive(self.source, node.start_byte(), lint.name)
+            && let Some(unfired) = self.comment_expected_unfired.get_mut(&offset)
+        {
+            unfired.remove(lint.name);
+            if unfired.is_empty() {
+                self.comment_expected_unfired.remove(&offset);
+            }
+        }
+    }
+
     pub fn report(
         &mut self,
         lint: &'static LintDescriptor,
@@ -673,7 +979,9 @@ impl<'src> LintContext<'src> {
             span,
             message: message.into(),
             help: None,
-            suggestion: None,
+            suggestions: Vec::new(),
+            notes: Vec::new(),
+            labels: Vec::new(),
         });
     }
 
@@ -684,7 +992,7 @@ impl<'src> LintContext<'src> {
         message: impl Into<String>,
     ) {
         let anchor_start_byte = crate::suppression::anchor_item_start_byte(node);
-        let level = self.effective_level_for_anchor(lint, anchor_start_byte);
+        let level = self.effective_level_for_node(lint, node);
         if level == LintLevel::Allow {
             return;
         }
@@ -696,10 +1004,13 @@ impl<'src> LintContext<'src> {
             span: Span::from_range(node.range()),
             message: message.into(),
             help: None,
-            suggestion: None,
+            suggestions: Vec::new(),
+            notes: Vec::new(),
+            labels: Vec::new(),
         });
 
         self.mark_expected_fired(anchor_start_byte, lint);
+        self.mark_comment_expected_fired(node, lint);
     }
 
     /// Report a diagnostic directly.
@@ -718,7 +1029,7 @@ impl<'src> LintContext<'src> {
     /// `#![allow]` directives.
     pub fn report_diagnostic_for_node(&mut self, node: Node, mut diagnostic: Diagnostic) {
         let anchor_start_byte = crate::suppression::anchor_item_start_byte(node);
-        let level = self.effective_level_for_anchor(diagnostic.lint, anchor_start_byte);
+        let level = self.effective_level_for_node(diagnostic.lint, node);
         if level == LintLevel::Allow {
             return;
         }
@@ -727,6 +1038,7 @@ impl<'src> LintContext<'src> {
         diagnostic.level = level;
         self.diagnostics.push(diagnostic);
         self.mark_expected_fired(anchor_start_byte, lint_descriptor);
+        self.mark_comment_expected_fired(node, lint_descriptor);
     }
 
     pub fn report_span_with_anchor(
@@ -748,7 +1060,9 @@ impl<'src> LintContext<'src> {
             span,
             message: message.into(),
             help: None,
-            suggestion: None,
+            suggestions: Vec::new(),
+            notes: Vec::new(),
+            labels: Vec::new(),
         });
 
         self.mark_expected_fired(anchor_start_byte, lint);
@@ -761,7 +1075,7 @@ impl<'src> LintContext<'src> {
         span: Span,
         message: impl Into<String>,
         help: Option<String>,
-        suggestion: Option<Suggestion>,
+        suggestions: Vec<Suggestion>,
     ) {
         let level = self.effective_level_for_anchor(lint, anchor_start_byte);
         if level == LintLevel::Allow {
@@ -775,7 +1089,9 @@ impl<'src> LintContext<'src> {
             span,
             message: message.into(),
             help,
-            suggestion,
+            suggestions,
+            notes: Vec::new(),
+            labels: Vec::new(),
         });
 
         self.mark_expected_fired(anchor_start_byte, lint);
@@ -815,7 +1131,9 @@ impl<'src> LintContext<'src> {
                     "Remove the `#![expect(...)]` directive or adjust the code/lint so it triggers."
                         .to_string(),
                 ),
-                suggestion: None,
+                suggestions: Vec::new(),
+                notes: Vec::new(),
+                labels: Vec::new(),
             });
         }
 
@@ -849,7 +1167,41 @@ impl<'src> LintContext<'src> {
                         "Remove the `#[expect(...)]` directive or adjust the code/lint so it triggers."
                             .to_string(),
                     ),
-                    suggestion: None,
+                    suggestions: Vec::new(),
+                    notes: Vec::new(),
+                    labels: Vec::new(),
+                });
+            }
+        }
+
+        let mut comments: Vec<(usize, HashSet<String>)> =
+            self.comment_expected_unfired.drain().collect();
+        comments.sort_by_key(|(offset, _)| *offset);
+        for (offset, unfired) in comments {
+            let mut lint_names: Vec<String> = unfired.into_iter().collect();
+            lint_names.sort();
+
+            let pos = position_from_byte_offset(self.source, offset);
+            for lint_name in lint_names {
+                self.diagnostics.push(Diagnostic {
+                    lint: &UNFULFILLED_EXPECTATION,
+                    level: LintLevel::Error,
+                    file: None,
+                    span: Span {
+                        start: pos,
+                        end: pos,
+                    },
+                    message: format!(
+                        "Expected `lint::{}` to produce a diagnostic at this comment, but it did not",
+                        lint_name
+                    ),
+                    help: Some(
+                        "Remove the `// move-clippy:expect(...)` comment or adjust the code/lint so it triggers."
+                            .to_string(),
+                    ),
+                    suggestions: Vec::new(),
+                    notes: Vec::new(),
+                    labels: Vec::new(),
                 });
             }
         }
@@ -888,6 +1240,12 @@ pub fn resolve_lint_alias(name: &str) -> &str {
     name
 }
 
+/// Resolve a lint name through user-defined `aliases` (from a config `[aliases]` table),
+/// then through the built-in [`LINT_ALIASES`].
+pub fn resolve_lint_alias_with<'a>(name: &'a str, aliases: &'a HashMap<String, String>) -> &'a str {
+    resolve_lint_alias(aliases.get(name).map_or(name, String::as_str))
+}
+
 /// Check if a name is a known alias (not the canonical name).
 pub fn is_lint_alias(name: &str) -> bool {
     LINT_ALIASES.iter().any(|(alias, _)| *alias == name)
@@ -902,6 +1260,78 @@ pub fn all_known_lints_with_aliases() -> HashSet<&'static str> {
     known
 }
 
+// ============================================================================
+// Deprecated Lint Names
+// ============================================================================
+
+/// Deprecated lints, with the lint that replaces each one (if any).
+///
+/// Lists both lints still registered in the `Deprecated` tier and lints that were removed.
+/// Removed names are no longer registered; referencing one gets a migration hint instead of
+/// a bare `unknown lint` (see [`deprecation_hint`]).
+///
+/// Format: (deprecated_name, replacement)
+pub const DEPRECATED_LINTS: &[(&str, Option<&str>)] = &[
+    // Registered in the `Deprecated` tier
+    ("capability_antipatterns", Some("copyable_capability")),
+    (
+        "droppable_hot_potato_v2",
+        Some("droppable_flash_loan_receipt"),
+    ),
+    (
+        "receipt_missing_phantom_type",
+        Some("droppable_flash_loan_receipt"),
+    ),
+    ("share_owned_authority", None),
+    ("shared_capability_object", None),
+    ("stale_oracle_price_v2", Some("stale_oracle_price")),
+    // Removed
+    ("invalid_otw", None), // duplicates the Sui verifier
+    ("price_manipulation_window", None),
+    ("stale_oracle_price_v3", Some("stale_oracle_price")),
+    ("tainted_transfer_recipient", None),
+];
+
+/// Migration hint for a deprecated lint, e.g. `deprecated: use stale_oracle_price`.
+///
+/// The replacement comes from [`DEPRECATED_LINTS`]. Returns `None` for names that are not
+/// deprecated.
+pub fn deprecation_hint(name: &str) -> Option<String> {
+    let registered = crate::unified::unified_registry()
+        .get(name)
+        .map(|lint| lint.descriptor);
+    if registered.is_some_and(|lint| lint.group != RuleGroup::Deprecated) {
+        return None;
+    }
+
+    let replacement = DEPRECATED_LINTS
+        .iter()
+        .find(|(old, _)| *old == name)
+        .map(|(_, replacement)| *replacement);
+    Some(match (replacement, registered) {
+        (Some(Some(replacement)), _) => format!("deprecated: use {replacement}"),
+        (_, Some(_)) => "deprecated without a replacement".to_string(),
+        (Some(None), None) => "deprecated: removed without a replacement".to_string(),
+        (None, None) => return None,
+    })
+}
+
+/// Check that `name` is a registered lint or a built-in alias for one.
+///
+/// # Errors
+///
+/// Returns the `unknown lint: <name>` error used for `--only`, `--skip`, and `disabled`, or
+/// the [`deprecation_hint`] when `name` is a removed lint.
+pub fn check_lint_name(name: &str) -> Result<()> {
+    if all_known_lints_with_aliases().contains(name) {
+        Ok(())
+    } else if let Some(hint) = deprecation_hint(name) {
+        Err(anyhow!("lint {name} is {hint}"))
+    } else {
+        Err(anyhow!("unknown lint: {name}"))
+    }
+}
+
 pub fn all_known_lints() -> HashSet<&'static str> {
     crate::unified::unified_registry()
         .descriptors()
@@ -1051,6 +1481,20 @@ impl LintRegistry {
         self.descriptors().find(|d| d.name == name)
     }
 
+    /// Keep only the rules matching a category/tier selection.
+    #[must_use]
+    pub fn retain_selected(mut self, selection: &LintSelection) -> Self {
+        self.rules.retain(|r| selection.matches(r.descriptor()));
+        self
+    }
+
+    /// Keep only the rules that provide an auto-fix.
+    #[must_use]
+    pub fn retain_fixable(mut self) -> Self {
+        self.rules.retain(|r| r.descriptor().fix.available);
+        self
+    }
+
     #[must_use = "registry should be used to create an engine"]
     pub fn default_rules() -> Self {
         crate::unified::build_syntactic_registry()
@@ -1084,13 +1528,8 @@ impl LintRegistry {
     ) -> Result<Self> {
         // Note: experimental flag implies preview
         let effective_preview = preview || experimental;
-        // Use the extended set that includes aliases for validation
-        let known = all_known_lints_with_aliases();
-
         for n in only.iter().chain(skip.iter()).chain(disabled.iter()) {
-            if !known.contains(n.as_str()) {
-                return Err(anyhow!("unknown lint: {n}"));
-            }
+            check_lint_name(n)?;
         }
 
         // Resolve aliases to canonical names for filtering
@@ -1150,6 +1589,40 @@ pub(crate) static UNFULFILLED_EXPECTATION: LintDescriptor = LintDescriptor {
     gap: None,
 };
 
+/// Descriptor for the note reported in place of a file larger than the configured maximum.
+pub(crate) static FILE_TOO_LARGE: LintDescriptor = LintDescriptor {
+    name: "file_too_large",
+    category: LintCategory::Style,
+    description: "File was not linted because it exceeds the configured maximum size",
+    group: RuleGroup::Stable,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::Syntactic,
+    gap: None,
+};
+
+/// Descriptor for the error reported in place of lint findings for a file that does not parse.
+pub(crate) static PARSE_ERROR: LintDescriptor = LintDescriptor {
+    name: "parse_error",
+    category: LintCategory::Style,
+    description: "File was not linted because it has a syntax error the parser could not recover from",
+    group: RuleGroup::Stable,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::Syntactic,
+    gap: None,
+};
+
+/// Descriptor for the note reported in place of semantic findings for a package that failed
+/// to compile under `--continue-on-error`.
+pub(crate) static PACKAGE_COMPILE_FAILED: LintDescriptor = LintDescriptor {
+    name: "package_compile_failed",
+    category: LintCategory::Style,
+    description: "Package was not semantically linted because it failed to compile",
+    group: RuleGroup::Stable,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: None,
+};
+
 pub(crate) fn is_directive_item_kind(kind: &str) -> bool {
     if kind == "module_definition" || kind == "use_declaration" {
         return true;
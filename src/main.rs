@@ -1,25 +1,35 @@
+use anyhow::Context;
 use clap::Parser;
-use move_clippy::LintEngine;
 use move_clippy::cli::{
-    Args, Command, LintArgs, LintMode, OutputFormat, TriageAction, TriageCommand,
+    Args, Command, LintArgs, LintMode, MinLevel, OutputFormat, TriageAction, TriageCommand,
+    WatchArgs,
 };
 use move_clippy::config;
+use move_clippy::diagnostics::Applicability;
+use move_clippy::diagnostics::report::{self, ReportOptions};
 use move_clippy::fixer;
 use move_clippy::level::LintLevel;
-use move_clippy::lint::{LintRegistry, LintSettings, resolve_lint_alias};
+use move_clippy::lint::{
+    LintCategory, LintDescriptor, LintRegistry, LintSelection, LintSettings, RuleGroup,
+    all_known_lints, check_lint_name, deprecation_hint, resolve_lint_alias,
+    resolve_lint_alias_with,
+};
 use move_clippy::semantic;
+use move_clippy::telemetry;
 use move_clippy::triage::{
-    Finding, FindingFilter, ReportFormat, Severity, TriageDatabase, TriageStatus,
-    generate_json_report, generate_markdown_report, generate_text_report,
+    Finding, FindingFilter, ReportFormat, RunDiff, Severity, TriageDatabase, TriageStatus,
+    generate_csv_export, generate_json_report, generate_markdown_report, generate_run_diff_report,
+    generate_text_report, load_lint_run,
 };
 use move_clippy::unified::{self, LintPhase};
+use move_clippy::{Diagnostic, LintEngine};
 use serde::Serialize;
-use std::io::Read;
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 
 fn main() -> ExitCode {
-    move_clippy::telemetry::init_tracing();
+    telemetry::init_tracing();
     match run() {
         Ok(code) => code,
         Err(err) => {
@@ -37,14 +47,43 @@ fn run() -> anyhow::Result<ExitCode> {
             list_rules();
             Ok(ExitCode::SUCCESS)
         }
-        Some(Command::Explain { rule }) => {
+        Some(Command::Explain {
+            rule: Some(rule), ..
+        }) => {
             explain_rule(&rule)?;
             Ok(ExitCode::SUCCESS)
         }
-        Some(Command::Lint(lint)) => lint_command(lint),
+        Some(Command::Explain { category, .. }) => {
+            let category = category.expect("clap requires RULE or --category");
+            print!("{}", explain_category(&category)?);
+            Ok(ExitCode::SUCCESS)
+        }
+        Some(Command::InitConfig { force }) => {
+            let path = init_config(&std::env::current_dir()?, force)?;
+            println!("wrote {}", path.display());
+            Ok(ExitCode::SUCCESS)
+        }
+        Some(Command::ConfigCheck { config }) => {
+            config_check(config.as_deref(), &std::env::current_dir()?)
+        }
+        Some(Command::Lint(lint)) => lint_with_timings(lint),
         Some(Command::Triage(triage)) => triage_command(triage),
-        None => lint_command(args.lint),
+        Some(Command::Watch(watch)) => watch_command(watch),
+        None => lint_with_timings(args.lint),
+    }
+}
+
+/// Run [`lint_command`], printing the per-lint timing table to stderr under `--timings`.
+fn lint_with_timings(args: LintArgs) -> anyhow::Result<ExitCode> {
+    let timings = args.timings;
+    if timings {
+        telemetry::enable_timings();
+    }
+    let result = lint_command(args);
+    if timings {
+        eprint!("{}", telemetry::render_timings(&telemetry::timings()));
     }
+    result
 }
 
 fn list_rules() {
@@ -74,43 +113,145 @@ fn explain_rule(rule: &str) -> anyhow::Result<()> {
     let Some(lint) = unified::unified_registry().get(canonical) else {
         anyhow::bail!("unknown lint: {rule}");
     };
-    let d = lint.descriptor;
+    print!("{}", render_explanation(lint.descriptor));
+    Ok(())
+}
 
-    println!("name: {}", d.name);
-    println!("category: {}", d.category.as_str());
-    println!("group: {}", d.group.as_str());
-    println!("description: {}", d.description);
+fn render_explanation(d: &LintDescriptor) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("name: {}\n", d.name));
+    out.push_str(&format!("category: {}\n", d.category.as_str()));
+    out.push_str(&format!("group: {}\n", d.group.as_str()));
+    out.push_str(&format!("description: {}\n", d.description));
+    if let Some(gap) = d.gap {
+        out.push_str(&format!("gap: {}\n", gap.as_str()));
+    }
     if d.fix.available {
-        println!("fix: available ({})", d.fix.safety.as_str());
+        out.push_str(&format!("fix: available ({})\n", d.fix.safety.as_str()));
         if !d.fix.description.is_empty() {
-            println!("fix description: {}", d.fix.description);
+            out.push_str(&format!("fix description: {}\n", d.fix.description));
         }
     } else {
-        println!("fix: not available");
+        out.push_str("fix: not available\n");
     }
-    Ok(())
+    out
+}
+
+/// Explanations for every lint in `category`, sorted by name and separated by blank lines.
+fn explain_category(category: &str) -> anyhow::Result<String> {
+    let Some(category) = LintCategory::from_name(category) else {
+        anyhow::bail!("unknown lint category: {category}");
+    };
+    let mut lints: Vec<_> = unified::unified_registry()
+        .descriptors()
+        .filter(|d| d.category == category)
+        .collect();
+    lints.sort_by_key(|d| d.name);
+
+    Ok(lints
+        .into_iter()
+        .map(render_explanation)
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+/// Write the starter config into `dir` as `move-clippy.toml`, the name discovery prefers.
+///
+/// Refuses to run when `dir` already has a config under either name unless `force`; with
+/// `force`, a leftover `.move-clippy.toml` is reported because the new file shadows it.
+fn init_config(dir: &Path, force: bool) -> anyhow::Result<PathBuf> {
+    let path = dir.join(config::DEFAULT_CONFIG_FILE_NAME);
+    let hidden = dir.join(config::HIDDEN_CONFIG_FILE_NAME);
+    if !force && let Some(existing) = [&path, &hidden].into_iter().find(|p| p.exists()) {
+        anyhow::bail!(
+            "{} already exists; pass --force to overwrite it",
+            existing.display()
+        );
+    }
+    std::fs::write(&path, config::config_template())
+        .with_context(|| format!("failed to write {}", path.display()))?;
+    if hidden.exists() {
+        eprintln!(
+            "warning: {} is ignored while {} exists",
+            hidden.display(),
+            path.display()
+        );
+    }
+    Ok(path)
+}
+
+/// Report problems in `explicit` or in every config file applying to `start_dir`.
+fn config_check(explicit: Option<&Path>, start_dir: &Path) -> anyhow::Result<ExitCode> {
+    let paths = match explicit {
+        Some(path) => vec![path.to_path_buf()],
+        None => config::find_config_files(start_dir),
+    };
+    if paths.is_empty() {
+        println!("no config file found from {}", start_dir.display());
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut failed = false;
+    for path in &paths {
+        // Keep going so one unparsable file doesn't hide problems in the others.
+        let problems = match config::check_config_file(path) {
+            Ok(problems) => problems,
+            Err(err) => {
+                println!("{}: error: {}", path.display(), err.root_cause());
+                failed = true;
+                continue;
+            }
+        };
+        for problem in &problems {
+            let location = match problem.line {
+                Some(line) => format!("{}:{line}", path.display()),
+                None => path.display().to_string(),
+            };
+            let kind = if problem.is_error { "error" } else { "warning" };
+            println!("{location}: {kind}: {}", problem.message);
+            failed |= problem.is_error;
+        }
+        if problems.is_empty() {
+            println!("{}: ok", path.display());
+        }
+    }
+
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
 }
 
-fn lint_command(args: LintArgs) -> anyhow::Result<ExitCode> {
+fn lint_command(mut args: LintArgs) -> anyhow::Result<ExitCode> {
+    if let Some(manifest) = args.files_from.take() {
+        let listed = read_files_from(&manifest)?;
+        // An empty list means nothing changed; don't fall back to linting stdin.
+        if listed.is_empty() && args.paths.is_empty() {
+            return Ok(ExitCode::SUCCESS);
+        }
+        args.paths.extend(listed);
+    }
+
     // Handle --fix mode
     if args.fix {
         return fix_command(args);
     }
 
     let start_dir = infer_start_dir(&args)?;
-    let loaded_cfg = config::load_config(args.config.as_deref(), &start_dir)?;
+    let mut loaded_cfg = config::load_config(args.config.as_deref(), &start_dir)?;
+    let rulelist = apply_rulelist(&mut args, loaded_cfg.as_mut().map(|(_path, cfg)| cfg))?;
+    apply_config_aliases(&mut args, loaded_cfg.as_ref().map(|(_path, cfg)| cfg));
+    warn_deprecated_lints(&args.only, loaded_cfg.as_ref().map(|(_path, cfg)| cfg));
 
-    let (disabled, settings, preview) = match loaded_cfg.as_ref() {
-        Some((_path, cfg)) => (
-            cfg.lints.disabled.clone(),
-            LintSettings::default()
-                .with_config_levels(cfg.lints.levels.clone())
-                .disable(cfg.lints.disabled.clone()),
-            // CLI flag takes precedence over config
-            args.preview || cfg.lints.preview,
-        ),
-        None => (Vec::new(), LintSettings::default(), args.preview),
-    };
+    let (disabled, settings, preview) =
+        settings_from_config(loaded_cfg.as_ref().map(|(_path, cfg)| cfg), args.preview)?;
+
+    let selection = LintSelection::parse(&args.only_category, &args.only_group)?;
+    let fail_categories = parse_fail_on_categories(&args.fail_on_category)?;
+    let preview = preview || selection.groups.contains(&RuleGroup::Preview);
+    let experimental = args.experimental || selection.groups.contains(&RuleGroup::Experimental);
+    let min_level = args.min_level.map(MinLevel::as_lint_level);
 
     let only_requires_full = args.only.iter().any(|n| {
         unified::lint_phase(resolve_lint_alias(n.as_str()))
@@ -135,54 +276,86 @@ fn lint_command(args: LintArgs) -> anyhow::Result<ExitCode> {
                 anyhow::bail!("--mode full requires either --package or at least one PATH");
             };
 
-            let mut diags =
-                semantic::lint_package(pkg_hint, &settings, preview, args.experimental)?;
-
-            if !args.only.is_empty() {
-                let only_set: std::collections::HashSet<&str> =
-                    args.only.iter().map(|s| s.as_str()).collect();
-                diags.retain(|d| only_set.contains(d.lint.name));
-            }
-
-            if !args.skip.is_empty() {
-                let skip_set: std::collections::HashSet<&str> =
-                    args.skip.iter().map(|s| s.as_str()).collect();
-                diags.retain(|d| !skip_set.contains(d.lint.name));
+            // One package-wide pass: nested per-directory configs do not apply here.
+            match semantic::lint_package(pkg_hint, &settings, preview, experimental) {
+                Ok(mut diags) => {
+                    filter_semantic_diags(&args, &selection, min_level, &mut diags);
+                    diags
+                }
+                Err(err) if args.continue_on_error => {
+                    let mut diags = vec![semantic::compile_failure_note(pkg_hint, &err)];
+                    retain_min_level(&mut diags, min_level);
+                    diags
+                }
+                Err(err) => return Err(err.into()),
             }
-
-            diags
         }
     } else {
         Vec::new()
     };
 
-    let registry = LintRegistry::default_rules_filtered_with_experimental(
-        &args.only,
-        &args.skip,
+    let engine = build_engine(
+        &args,
+        &selection,
         &disabled,
-        matches!(args.mode, LintMode::Full),
+        settings.clone(),
         preview,
-        args.experimental,
+        experimental,
     )?;
-    let engine = LintEngine::new_with_settings(registry, settings.clone());
+    let mut engines = EngineCache::new(&args, &selection, &rulelist, experimental, engine);
+
+    if args.output_per_file.is_some() && args.paths.is_empty() {
+        anyhow::bail!("--output-per-file requires at least one PATH");
+    }
+    if args.dedup
+        && !matches!(
+            args.format,
+            OutputFormat::Pretty | OutputFormat::Rich | OutputFormat::Json
+        )
+    {
+        anyhow::bail!("--dedup is only supported with pretty, rich, and json output");
+    }
 
-    let mut total_diags = 0usize;
     let mut has_error = false;
+    let mut files_linted = 0usize;
+    let mut per_file_records: Vec<JsonDiagnostic> = Vec::new();
+
+    let stdin_name = args
+        .stdin_filename
+        .as_ref()
+        .map(|path| path.display().to_string());
 
     match args.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Checkstyle | OutputFormat::Junit => {
             let mut out: Vec<JsonDiagnostic> = Vec::new();
+            // Every linted file, so JUnit can report clean files as passing.
+            let mut linted: Vec<String> = Vec::new();
 
             if args.paths.is_empty() {
-                let (count, file_has_error, mut diags) = lint_stdin_json(&engine)?;
-                total_diags += count;
+                let (_, file_has_error, mut diags) = lint_stdin_json(
+                    &engines.default,
+                    stdin_name.as_deref(),
+                    min_level,
+                    args.json_full_span,
+                    args.include_suggestions,
+                )?;
+                linted.push(stdin_name.clone().unwrap_or_else(|| "stdin".to_string()));
+                files_linted += 1;
                 has_error |= file_has_error;
                 out.append(&mut diags);
             } else {
-                let files = collect_move_files(&args.paths, args.skip_tests)?;
+                let files = collect_move_files(&args.paths, args.skip_tests, args.no_ignore)?;
                 for path in files {
-                    let (count, file_has_error, mut diags) = lint_file_json(&engine, &path)?;
-                    total_diags += count;
+                    let engine = engines.engine_for(&path)?;
+                    let (_, file_has_error, mut diags) = lint_file_json(
+                        engine,
+                        &path,
+                        min_level,
+                        args.json_full_span,
+                        args.include_suggestions,
+                    )?;
+                    linted.push(path.display().to_string());
+                    files_linted += 1;
                     has_error |= file_has_error;
                     out.append(&mut diags);
                 }
@@ -191,16 +364,13 @@ fn lint_command(args: LintArgs) -> anyhow::Result<ExitCode> {
             if !semantic_diags.is_empty() {
                 for d in &semantic_diags {
                     let file = d.file.clone().unwrap_or_else(|| "<unknown>".to_string());
-                    has_error |= d.level == LintLevel::Error;
-                    total_diags += 1;
-                    out.push(JsonDiagnostic {
+                    has_error |= d.level.fails_run();
+                    out.push(JsonDiagnostic::from_diagnostic(
+                        d,
                         file,
-                        row: d.span.start.row,
-                        column: d.span.start.column,
-                        level: d.level.as_str().to_string(),
-                        lint: d.lint.name.to_string(),
-                        message: d.message.clone(),
-                    });
+                        args.json_full_span,
+                        args.include_suggestions,
+                    ));
                 }
             }
 
@@ -221,243 +391,1273 @@ fn lint_command(args: LintArgs) -> anyhow::Result<ExitCode> {
                     ))
             });
 
-            println!("{}", serde_json::to_string_pretty(&out)?);
+            // `--dedup` only changes what is displayed; `out` keeps every occurrence.
+            let deduped;
+            let records: &[JsonDiagnostic] = if args.dedup {
+                deduped = dedup_records(&out);
+                &deduped
+            } else {
+                &out
+            };
+
+            let mut budget = DiagnosticBudget::new(args.max_diagnostics);
+            let shown = records.iter().filter(|_| budget.take()).count();
+            let displayed = &records[..shown];
+
+            if matches!(args.format, OutputFormat::Checkstyle) {
+                println!("{}", render_checkstyle(displayed));
+            } else if matches!(args.format, OutputFormat::Junit) {
+                println!(
+                    "{}",
+                    render_junit(
+                        linted.iter().map(String::as_str),
+                        displayed,
+                        args.deny_warnings
+                    )
+                );
+            } else if args.summary {
+                let report = JsonReport {
+                    summary: LevelSummary::from_records(&out, files_linted),
+                    diagnostics: displayed,
+                };
+                report::write_json(&mut std::io::stdout().lock(), &report)?;
+            } else {
+                report::write_json(&mut std::io::stdout().lock(), displayed)?;
+            }
+            std::io::stdout().flush()?;
+            // Keep stdout machine-readable; the notice goes to stderr.
+            if let Some(notice) = budget.notice() {
+                eprintln!("{notice}");
+            }
+            per_file_records = out;
         }
-        OutputFormat::Pretty | OutputFormat::Github => {
+        OutputFormat::Pretty | OutputFormat::Rich | OutputFormat::Github => {
+            let mut budget = DiagnosticBudget::new(args.max_diagnostics);
+            // With `--dedup`, findings are collected here and printed once all files are linted.
+            let mut deferred: Option<Vec<(String, Diagnostic)>> = args.dedup.then(Vec::new);
             if args.paths.is_empty() {
-                let (count, file_has_error) =
-                    lint_stdin_text(&engine, args.format, args.deny_warnings, args.show_tier)?;
-                total_diags += count;
+                let (_, file_has_error, mut records) = lint_stdin_text(
+                    &engines.default,
+                    stdin_name.as_deref(),
+                    args.format,
+                    args.deny_warnings,
+                    args.show_tier,
+                    args.quiet,
+                    min_level,
+                    &mut budget,
+                )?;
+                files_linted += 1;
                 has_error |= file_has_error;
+                per_file_records.append(&mut records);
             } else {
-                let files = collect_move_files(&args.paths, args.skip_tests)?;
+                let files = collect_move_files(&args.paths, args.skip_tests, args.no_ignore)?;
                 for path in files {
-                    let (count, file_has_error) = lint_file_text(
-                        &engine,
+                    let engine = engines.engine_for(&path)?;
+                    let (_, file_has_error, mut records) = lint_file_text(
+                        engine,
                         &path,
                         args.format,
                         args.deny_warnings,
                         args.show_tier,
+                        args.quiet,
+                        min_level,
+                        &mut budget,
+                        deferred.as_mut(),
                     )?;
-                    total_diags += count;
+                    files_linted += 1;
                     has_error |= file_has_error;
+                    per_file_records.append(&mut records);
                 }
             }
 
             if !semantic_diags.is_empty() {
                 for diag in &semantic_diags {
                     let file = diag.file.clone().unwrap_or_else(|| "<unknown>".to_string());
+                    has_error |= diag.level.fails_run();
+                    per_file_records.push(JsonDiagnostic::from_diagnostic(
+                        diag,
+                        file.clone(),
+                        false,
+                        false,
+                    ));
+                    // Hide quiet-mode diagnostics before they use up the --max-diagnostics budget.
+                    if args.quiet
+                        && matches!(args.format, OutputFormat::Pretty | OutputFormat::Rich)
+                        && !fails_with(diag, args.deny_warnings)
+                    {
+                        continue;
+                    }
+                    if let Some(deferred) = deferred.as_mut() {
+                        deferred.push((file, diag.clone()));
+                        continue;
+                    }
+                    if !budget.take() {
+                        continue;
+                    }
                     match args.format {
-                        OutputFormat::Pretty => {
-                            let tier_prefix = if args.show_tier {
-                                format!("[{}] ", diag.lint.group.as_str())
-                            } else {
-                                String::new()
-                            };
-                            println!(
-                                "{}:{}:{}: {}: {}{}: {}",
-                                file,
-                                diag.span.start.row,
-                                diag.span.start.column,
-                                diag.level.as_str(),
-                                tier_prefix,
-                                diag.lint.name,
-                                diag.message
-                            );
+                        OutputFormat::Pretty | OutputFormat::Rich => {
+                            print_semantic_text(diag, &file, args.format, args.show_tier)?;
                         }
                         OutputFormat::Github => {
-                            let msg = github_escape(&diag.message);
-                            let kind = if diag.level == LintLevel::Error
-                                || (args.deny_warnings && diag.level == LintLevel::Warn)
-                            {
-                                "error"
-                            } else {
-                                "warning"
+                            let opts = ReportOptions {
+                                show_tier: args.show_tier,
+                                deny_warnings: args.deny_warnings,
                             };
-                            let lint_name = if args.show_tier {
-                                format!("[{}]{}", diag.lint.group.as_str(), diag.lint.name)
-                            } else {
-                                diag.lint.name.to_string()
-                            };
-                            println!(
-                                "::{} file={},line={},col={},title={}::{}",
-                                kind,
-                                github_escape(&file),
-                                diag.span.start.row,
-                                diag.span.start.column,
-                                lint_name,
-                                msg
-                            );
+                            report::write_github_diagnostic(
+                                &mut std::io::stdout().lock(),
+                                diag,
+                                &file,
+                                &opts,
+                            )?;
+                        }
+                        OutputFormat::Json | OutputFormat::Checkstyle | OutputFormat::Junit => {
+                            unreachable!()
                         }
-                        OutputFormat::Json => unreachable!(),
                     }
+                }
+            }
 
-                    has_error |= diag.level == LintLevel::Error;
-                    total_diags += 1;
+            if let Some(deferred) = deferred {
+                let groups = dedup_groups(deferred, |(file, diag)| {
+                    (diag.lint.name, diag.message.as_str(), file.as_str())
+                });
+                for group in groups {
+                    if !budget.take() {
+                        continue;
+                    }
+                    let (file, diag) = &group[0];
+                    print_semantic_text(diag, file, args.format, args.show_tier)?;
+                    if group.len() > 1 {
+                        println!("  ... and {} more locations", group.len() - 1);
+                    }
                 }
             }
+
+            if let Some(notice) = budget.notice() {
+                println!("{notice}");
+            }
+
+            if args.summary && matches!(args.format, OutputFormat::Pretty | OutputFormat::Rich) {
+                println!(
+                    "{}",
+                    LevelSummary::from_records(&per_file_records, files_linted).render()
+                );
+            }
+            std::io::stdout().flush()?;
         }
     }
 
-    if has_error || (args.deny_warnings && total_diags > 0) {
+    if let Some(dir) = &args.output_per_file {
+        let files = collect_move_files(&args.paths, args.skip_tests, args.no_ignore)?;
+        write_per_file_reports(
+            dir,
+            &files,
+            &per_file_records,
+            args.format,
+            args.skip_clean_reports,
+            args.deny_warnings,
+        )?;
+    }
+
+    let category_failure = !fail_categories.is_empty()
+        && per_file_records.iter().any(|record| {
+            unified::unified_registry()
+                .get(&record.lint)
+                .is_some_and(|lint| fail_categories.contains(&lint.descriptor.category))
+        });
+
+    // Notes such as `file_too_large` never fail the run, even with `--deny-warnings`.
+    let denied_warning = args.deny_warnings
+        && per_file_records
+            .iter()
+            .any(|record| record.level == LintLevel::Warn.as_str());
+
+    if has_error || category_failure || denied_warning {
         Ok(ExitCode::from(1))
     } else {
         Ok(ExitCode::SUCCESS)
     }
 }
 
-#[derive(Debug, Serialize, serde::Deserialize)]
-struct JsonDiagnostic {
-    file: String,
-    row: usize,
-    column: usize,
-    level: String,
-    lint: String,
-    message: String,
-}
+/// Apply `--only`, `--skip`, category/tier selection, `--fixable-only`, and `--min-level`
+/// to semantic diagnostics; fast-mode lints are filtered when the registry is built.
+fn filter_semantic_diags(
+    args: &LintArgs,
+    selection: &LintSelection,
+    min_level: Option<LintLevel>,
+    diags: &mut Vec<Diagnostic>,
+) {
+    if !args.only.is_empty() {
+        let only_set: std::collections::HashSet<&str> =
+            args.only.iter().map(|s| s.as_str()).collect();
+        diags.retain(|d| only_set.contains(d.lint.name));
+    }
 
-/// Handle --fix mode: apply auto-fixes to files.
-fn fix_command(args: LintArgs) -> anyhow::Result<ExitCode> {
-    if args.paths.is_empty() {
-        anyhow::bail!("--fix requires file paths (stdin not supported)");
+    if !args.skip.is_empty() {
+        let skip_set: std::collections::HashSet<&str> =
+            args.skip.iter().map(|s| s.as_str()).collect();
+        diags.retain(|d| !skip_set.contains(d.lint.name));
     }
 
-    let start_dir = infer_start_dir(&args)?;
-    let loaded_cfg = config::load_config(args.config.as_deref(), &start_dir)?;
+    if !selection.is_empty() {
+        diags.retain(|d| selection.matches(d.lint));
+    }
 
-    let (disabled, settings, preview) = match loaded_cfg.as_ref() {
-        Some((_path, cfg)) => (
-            cfg.lints.disabled.clone(),
-            LintSettings::default()
-                .with_config_levels(cfg.lints.levels.clone())
-                .disable(cfg.lints.disabled.clone()),
-            args.preview || cfg.lints.preview,
-        ),
-        None => (Vec::new(), LintSettings::default(), args.preview),
-    };
+    if args.fixable_only {
+        diags.retain(|d| d.lint.fix.available);
+    }
 
-    let registry = LintRegistry::default_rules_filtered_with_experimental(
-        &args.only,
-        &args.skip,
-        &disabled,
-        matches!(args.mode, LintMode::Full),
-        preview,
-        args.experimental,
-    )?;
-    let engine = LintEngine::new_with_settings(registry, settings);
+    retain_min_level(diags, min_level);
+}
 
-    let files = collect_move_files(&args.paths, args.skip_tests)?;
-    let mut total_fixed = 0usize;
-    let mut total_skipped = 0usize;
-    let mut files_modified = 0usize;
+/// Whether `diag` fails the run, counting warnings when `--deny-warnings` is set.
+fn fails_with(diag: &Diagnostic, deny_warnings: bool) -> bool {
+    diag.level.fails_run() || (deny_warnings && diag.level == LintLevel::Warn)
+}
 
-    const MAX_ITERATIONS: usize = 10; // Prevent infinite loops
+/// Print one semantic diagnostic in `pretty` or `rich` format.
+fn print_semantic_text(
+    diag: &Diagnostic,
+    file: &str,
+    format: OutputFormat,
+    show_tier: bool,
+) -> std::io::Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    if matches!(format, OutputFormat::Rich) {
+        // Semantic findings may point into any package file; without its
+        // text the excerpt is skipped and the header and help still print.
+        let source = std::fs::read_to_string(file).unwrap_or_default();
+        return write!(
+            stdout,
+            "{}",
+            diag.render_rich(file, &source, rich_color_enabled())
+        );
+    }
+    let opts = ReportOptions {
+        show_tier,
+        ..ReportOptions::default()
+    };
+    report::write_pretty_diagnostic(&mut stdout, diag, file, &opts)
+}
 
-    for path in &files {
-        let original_source = std::fs::read_to_string(path)?;
-        let mut current_source = original_source.clone();
-        let mut file_fixes = 0usize;
-        let mut iterations = 0usize;
+/// Parse `--fail-on-category` values; names are those of [`LintCategory::as_str`].
+fn parse_fail_on_categories(names: &[String]) -> anyhow::Result<Vec<LintCategory>> {
+    names
+        .iter()
+        .map(|name| {
+            LintCategory::from_name(name).ok_or_else(|| {
+                let known = LintCategory::ALL
+                    .iter()
+                    .map(LintCategory::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                anyhow::anyhow!(
+                    "unknown lint category for --fail-on-category: {name} (expected one of: {known})"
+                )
+            })
+        })
+        .collect()
+}
 
-        // Iterate until no more fixes are applied (or max iterations reached)
-        loop {
-            iterations += 1;
-            if iterations > MAX_ITERATIONS {
-                eprintln!(
-                    "Warning: Max fix iterations ({}) reached for {}",
-                    MAX_ITERATIONS,
-                    path.display()
-                );
-                break;
-            }
+/// Rewrite `--only` and `--skip` names to canonical lint names using the config's `[aliases]`.
+///
+/// Aliases come from the config found for the start directory (or `--config`), so later
+/// lookups can stay on the built-in [`resolve_lint_alias`].
+fn apply_config_aliases(args: &mut LintArgs, cfg: Option<&config::MoveClippyConfig>) {
+    let Some(cfg) = cfg.filter(|cfg| !cfg.aliases.is_empty()) else {
+        return;
+    };
+    for name in args.only.iter_mut().chain(args.skip.iter_mut()) {
+        *name = resolve_lint_alias_with(name, &cfg.aliases).to_string();
+    }
+}
 
-            let diagnostics = engine.lint_source(&current_source)?;
+/// Lints selected by a `--rulelist` file.
+#[derive(Debug, Default, PartialEq)]
+struct Rulelist {
+    /// Canonical names to run.
+    only: Vec<String>,
+    /// Canonical names listed as `-name`.
+    skip: Vec<String>,
+}
 
-            // Filter to diagnostics with fix suggestions
-            let fixable: Vec<_> = diagnostics
-                .iter()
-                .filter(|d| d.suggestion.is_some())
-                .cloned()
-                .collect();
+/// Parse a `--rulelist` file: one lint name per line, `-name` to exclude it.
+///
+/// Blank lines and `#` comments are ignored. Names resolve through `aliases` and the built-in
+/// aliases; an unknown name fails with `label:line`.
+fn parse_rulelist(
+    contents: &str,
+    label: &str,
+    aliases: &std::collections::HashMap<String, String>,
+) -> anyhow::Result<Rulelist> {
+    let mut list = Rulelist::default();
+    for (idx, line) in contents.lines().enumerate() {
+        let entry = line.split('#').next().unwrap_or_default().trim();
+        if entry.is_empty() {
+            continue;
+        }
+        let (target, name) = match entry.strip_prefix('-') {
+            Some(name) => (&mut list.skip, name.trim()),
+            None => (&mut list.only, entry),
+        };
+        let name = resolve_lint_alias_with(name, aliases);
+        check_lint_name(name).map_err(|err| anyhow::anyhow!("{label}:{}: {err}", idx + 1))?;
+        target.push(name.to_string());
+    }
+    Ok(list)
+}
 
-            if fixable.is_empty() {
-                break;
-            }
+/// Add the lints of `--rulelist` to `--only` and `--skip`.
+///
+/// Listed lints are dropped from the config's `disabled`, so the rulelist takes precedence.
+/// Returns the listed lints so configs loaded later can drop them too.
+fn apply_rulelist(
+    args: &mut LintArgs,
+    cfg: Option<&mut config::MoveClippyConfig>,
+) -> anyhow::Result<Vec<String>> {
+    let Some(path) = args.rulelist.as_deref() else {
+        return Ok(Vec::new());
+    };
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read --rulelist {}", path.display()))?;
+    let no_aliases = std::collections::HashMap::new();
+    let aliases = cfg.as_deref().map_or(&no_aliases, |cfg| &cfg.aliases);
+    let list = parse_rulelist(&contents, &path.display().to_string(), aliases)?;
+
+    if let Some(cfg) = cfg {
+        enable_rulelist_lints(cfg, &list.only);
+    }
+    args.only.extend(list.only.iter().cloned());
+    args.skip.extend(list.skip);
+    Ok(list.only)
+}
 
-            let result = fixer::apply_fixes(&current_source, &fixable, args.unsafe_fixes)?;
+/// Drop lints named by a `--rulelist` from the config's `disabled`.
+fn enable_rulelist_lints(cfg: &mut config::MoveClippyConfig, listed: &[String]) {
+    let aliases = &cfg.aliases;
+    cfg.lints.disabled.retain(|name| {
+        !listed
+            .iter()
+            .any(|n| n == resolve_lint_alias_with(name, aliases))
+    });
+}
 
-            if result.fixes_applied == 0 {
-                total_skipped += result.fixes_skipped;
-                break;
-            }
+/// Warn about deprecated lints that are still registered but enabled by `--only` or a
+/// config level.
+///
+/// Removed lints are not warned about here; validating their names fails with the same hint.
+fn warn_deprecated_lints(only: &[String], cfg: Option<&config::MoveClippyConfig>) {
+    let mut enabled: Vec<&str> = only.iter().map(String::as_str).collect();
+    if let Some(cfg) = cfg {
+        let mut levels: Vec<&str> = cfg
+            .lints
+            .levels
+            .iter()
+            .filter(|(_, level)| **level != LintLevel::Allow)
+            .map(|(name, _)| name.as_str())
+            .collect();
+        levels.sort_unstable();
+        enabled.extend(levels);
+    }
 
-            file_fixes += result.fixes_applied;
-            current_source = result.fixed_source;
+    let known = all_known_lints();
+    for name in enabled {
+        let name = resolve_lint_alias(name);
+        if known.contains(name)
+            && let Some(hint) = deprecation_hint(name)
+        {
+            eprintln!("warning: lint {name} is {hint}");
+        }
+    }
+}
 
-            // In dry-run mode, only do one iteration
-            if args.fix_dry_run {
-                total_skipped += result.fixes_skipped;
-                break;
-            }
+/// Lint settings from a loaded config: `(disabled, settings, preview)`.
+///
+/// The `--preview` CLI flag is OR-ed with the config's `preview`.
+fn settings_from_config(
+    cfg: Option<&config::MoveClippyConfig>,
+    cli_preview: bool,
+) -> anyhow::Result<(Vec<String>, LintSettings, bool)> {
+    Ok(match cfg {
+        Some(cfg) => {
+            let settings = LintSettings::default()
+                .with_config_levels(cfg.lints.levels.clone())
+                .with_category_levels(cfg.lints.parsed_category_levels()?)
+                .with_known_addresses(cfg.lints.known_addresses.values().cloned())
+                .with_thresholds(cfg.lints.thresholds)
+                .disable(cfg.lints.disabled.clone());
+            (
+                cfg.lints.disabled.clone(),
+                settings,
+                cli_preview || cfg.lints.preview,
+            )
         }
+        None => (Vec::new(), LintSettings::default(), cli_preview),
+    })
+}
 
-        if file_fixes > 0 {
-            if args.fix_dry_run {
-                // Print diff
-                let diff = fixer::format_diff(&original_source, &current_source, path);
-                if !diff.is_empty() {
-                    println!("{}", diff);
-                }
-            } else {
-                // Create backup unless --no-backup is set
-                if !args.no_backup {
-                    let backup_path = path.with_extension(format!(
-                        "{}.bak",
-                        path.extension().unwrap_or_default().to_string_lossy()
-                    ));
-                    std::fs::write(&backup_path, &original_source)?;
-                }
+fn build_engine(
+    args: &LintArgs,
+    selection: &LintSelection,
+    disabled: &[String],
+    settings: LintSettings,
+    preview: bool,
+    experimental: bool,
+) -> anyhow::Result<LintEngine> {
+    let preview = preview || selection.groups.contains(&RuleGroup::Preview);
+    let registry = LintRegistry::default_rules_filtered_with_experimental(
+        &args.only,
+        &args.skip,
+        disabled,
+        matches!(args.mode, LintMode::Full),
+        preview,
+        experimental,
+    )?
+    .retain_selected(selection);
+    let registry = if args.fixable_only {
+        registry.retain_fixable()
+    } else {
+        registry
+    };
+    let settings = match args.max_file_size {
+        Some(bytes) => settings.with_max_file_size(bytes),
+        None => settings,
+    };
+    Ok(LintEngine::new_with_settings(registry, settings))
+}
 
-                // Write fixed source
-                std::fs::write(path, &current_source)?;
-                files_modified += 1;
-            }
-            total_fixed += file_fixes;
+/// Fast-mode engines keyed by the chain of config files that applies to each linted file.
+///
+/// With `--config`, every file uses `default`. Otherwise each file's directory is searched
+/// upwards, and files governed by the same config files share one engine.
+struct EngineCache<'a> {
+    args: &'a LintArgs,
+    selection: &'a LintSelection,
+    /// Lints named by `--rulelist`, enabled even where a discovered config disables them.
+    rulelist: &'a [String],
+    experimental: bool,
+    default: LintEngine,
+    by_configs: std::collections::HashMap<Vec<PathBuf>, LintEngine>,
+}
+
+impl<'a> EngineCache<'a> {
+    fn new(
+        args: &'a LintArgs,
+        selection: &'a LintSelection,
+        rulelist: &'a [String],
+        experimental: bool,
+        default: LintEngine,
+    ) -> Self {
+        Self {
+            args,
+            selection,
+            rulelist,
+            experimental,
+            default,
+            by_configs: std::collections::HashMap::new(),
         }
     }
 
-    // Print summary
-    if args.fix_dry_run {
-        println!(
-            "\n{} fix(es) would be applied to {} file(s)",
-            total_fixed,
-            files.len()
-        );
-        if total_skipped > 0 {
-            println!(
-                "{} fix(es) skipped (use --unsafe-fixes to apply)",
-                total_skipped
-            );
+    fn engine_for(&mut self, path: &Path) -> anyhow::Result<&LintEngine> {
+        if self.args.config.is_some() {
+            return Ok(&self.default);
         }
-    } else {
-        println!(
-            "Applied {} fix(es) to {} file(s)",
-            total_fixed, files_modified
-        );
-        if total_skipped > 0 {
-            println!(
-                "{} fix(es) skipped (use --unsafe-fixes to apply)",
-                total_skipped
-            );
+
+        let dir = path
+            .parent()
+            .filter(|d| !d.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let configs = config::find_config_files(&std::path::absolute(dir)?);
+        if !self.by_configs.contains_key(&configs) {
+            let mut cfg = config::load_merged_config(&configs)?;
+            enable_rulelist_lints(&mut cfg, self.rulelist);
+            let (disabled, settings, preview) =
+                settings_from_config(Some(&cfg), self.args.preview)?;
+            let engine = build_engine(
+                self.args,
+                self.selection,
+                &disabled,
+                settings,
+                preview,
+                self.experimental,
+            )?;
+            self.by_configs.insert(configs.clone(), engine);
         }
+        Ok(&self.by_configs[&configs])
     }
+}
 
-    Ok(ExitCode::SUCCESS)
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+struct JsonDiagnostic {
+    file: String,
+    row: usize,
+    column: usize,
+    /// End of the span; only emitted with `--json-full-span`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    end_row: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    end_column: Option<usize>,
+    level: String,
+    lint: String,
+    message: String,
+    /// How to address the finding; only emitted with `--json-full-span`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    help: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    notes: Vec<String>,
+    /// Secondary labeled locations in the same file.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    labels: Vec<JsonLabel>,
+    /// Fix suggestions, most preferred first; only emitted with `--include-suggestions`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    suggestions: Vec<JsonSuggestion>,
+    /// Every location of a finding collapsed by `--dedup`, this one first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    occurrences: Vec<JsonLocation>,
 }
 
-// ============================================================================
-// Triage Command
-// ============================================================================
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+struct JsonLocation {
+    file: String,
+    row: usize,
+    column: usize,
+}
+
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+struct JsonLabel {
+    row: usize,
+    column: usize,
+    message: String,
+}
+
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+struct JsonSuggestion {
+    message: String,
+    replacement: String,
+    applicability: String,
+    /// Range the replacement is written over, when it differs from the diagnostic's span.
+    /// Equal start and end positions mean a pure insertion.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    span: Option<JsonSpan>,
+}
+
+#[derive(Debug, Clone, Serialize, serde::Deserialize)]
+struct JsonSpan {
+    row: usize,
+    column: usize,
+    end_row: usize,
+    end_column: usize,
+}
+
+impl JsonDiagnostic {
+    fn from_diagnostic(
+        d: &Diagnostic,
+        file: String,
+        full_span: bool,
+        include_suggestions: bool,
+    ) -> Self {
+        JsonDiagnostic {
+            file,
+            row: d.span.start.row,
+            column: d.span.start.column,
+            end_row: full_span.then_some(d.span.end.row),
+            end_column: full_span.then_some(d.span.end.column),
+            level: d.level.as_str().to_string(),
+            lint: d.lint.name.to_string(),
+            message: d.message.clone(),
+            help: if full_span { d.help.clone() } else { None },
+            notes: d.notes.clone(),
+            labels: d
+                .labels
+                .iter()
+                .map(|(span, message)| JsonLabel {
+                    row: span.start.row,
+                    column: span.start.column,
+                    message: message.clone(),
+                })
+                .collect(),
+            suggestions: if include_suggestions {
+                d.suggestions
+                    .iter()
+                    .map(|s| JsonSuggestion {
+                        message: s.message.clone(),
+                        replacement: s.replacement.clone(),
+                        applicability: s.applicability.as_str().to_string(),
+                        span: s.span.map(|span| JsonSpan {
+                            row: span.start.row,
+                            column: span.start.column,
+                            end_row: span.end.row,
+                            end_column: span.end.column,
+                        }),
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            },
+            occurrences: Vec::new(),
+        }
+    }
+}
+
+/// Group items reported with the same lint and message in more than one file, for `--dedup`.
+///
+/// `key` yields `(lint, message, file)`. Groups keep the position of their first item;
+/// findings confined to a single file stay separate one-item groups.
+fn dedup_groups<T>(items: Vec<T>, key: impl Fn(&T) -> (&str, &str, &str)) -> Vec<Vec<T>> {
+    let keys: Vec<((String, String), String)> = items
+        .iter()
+        .map(|item| {
+            let (lint, message, file) = key(item);
+            ((lint.to_string(), message.to_string()), file.to_string())
+        })
+        .collect();
+    let mut files: std::collections::HashMap<&(String, String), std::collections::HashSet<&str>> =
+        std::collections::HashMap::new();
+    for (finding, file) in &keys {
+        files.entry(finding).or_default().insert(file.as_str());
+    }
+
+    let mut groups: Vec<Vec<T>> = Vec::new();
+    let mut group_of: std::collections::HashMap<&(String, String), usize> =
+        std::collections::HashMap::new();
+    for (item, (finding, _file)) in items.into_iter().zip(&keys) {
+        if files[finding].len() < 2 {
+            groups.push(vec![item]);
+            continue;
+        }
+        match group_of.get(finding) {
+            Some(&i) => groups[i].push(item),
+            None => {
+                group_of.insert(finding, groups.len());
+                groups.push(vec![item]);
+            }
+        }
+    }
+    groups
+}
+
+/// Collapse JSON records for `--dedup`: one record per group, with `occurrences` set when
+/// the group spans several files.
+fn dedup_records(records: &[JsonDiagnostic]) -> Vec<JsonDiagnostic> {
+    dedup_groups(records.iter().collect(), |r| {
+        (r.lint.as_str(), r.message.as_str(), r.file.as_str())
+    })
+    .into_iter()
+    .map(|group| {
+        let mut first = group[0].clone();
+        if group.len() > 1 {
+            first.occurrences = group
+                .iter()
+                .map(|r| JsonLocation {
+                    file: r.file.clone(),
+                    row: r.row,
+                    column: r.column,
+                })
+                .collect();
+        }
+        first
+    })
+    .collect()
+}
+
+/// Running cap on displayed diagnostics for `--max-diagnostics`, shared across all files.
+#[derive(Debug, Default)]
+struct DiagnosticBudget {
+    max: Option<usize>,
+    shown: usize,
+    suppressed: usize,
+}
+
+impl DiagnosticBudget {
+    fn new(max: Option<usize>) -> Self {
+        DiagnosticBudget {
+            max,
+            ..DiagnosticBudget::default()
+        }
+    }
+
+    /// Whether the next diagnostic should be displayed; counts it either way.
+    fn take(&mut self) -> bool {
+        if self.max.is_some_and(|max| self.shown >= max) {
+            self.suppressed += 1;
+            false
+        } else {
+            self.shown += 1;
+            true
+        }
+    }
+
+    /// The truncation notice, if anything was suppressed.
+    fn notice(&self) -> Option<String> {
+        let max = self.max?;
+        (self.suppressed > 0).then(|| {
+            format!(
+                "... {} more diagnostics suppressed (--max-diagnostics {max})",
+                self.suppressed
+            )
+        })
+    }
+}
+
+/// Aggregate diagnostic counts by level, printed with `--summary`.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, serde::Deserialize)]
+struct LevelSummary {
+    error: usize,
+    warn: usize,
+    note: usize,
+    files: usize,
+}
+
+impl LevelSummary {
+    fn from_records(records: &[JsonDiagnostic], files: usize) -> Self {
+        let mut summary = LevelSummary {
+            files,
+            ..LevelSummary::default()
+        };
+        for record in records {
+            if record.level == LintLevel::Error.as_str() {
+                summary.error += 1;
+            } else if record.level == LintLevel::Warn.as_str()
+                || record.level == LintLevel::Deny.as_str()
+            {
+                summary.warn += 1;
+            } else {
+                summary.note += 1;
+            }
+        }
+        summary
+    }
+
+    fn render(&self) -> String {
+        format!(
+            "summary: {} error, {} warning, {} note across {} files",
+            self.error, self.warn, self.note, self.files
+        )
+    }
+}
+
+/// JSON output shape used with `--summary`; the default is a bare diagnostics array.
+#[derive(Debug, Serialize)]
+struct JsonReport<'a> {
+    diagnostics: &'a [JsonDiagnostic],
+    summary: LevelSummary,
+}
+
+/// `path` as it should appear in a patch header: relative to the root of the git work tree
+/// containing `cwd`, which is how `git apply` resolves patch paths from any subdirectory.
+/// Outside a work tree, paths are relative to `cwd`.
+///
+/// Fails for a file outside that root, since `git apply` could not find it.
+fn patch_path(path: &Path, cwd: &Path) -> anyhow::Result<PathBuf> {
+    let cwd = cwd
+        .canonicalize()
+        .with_context(|| format!("failed to resolve {}", cwd.display()))?;
+    let root = cwd
+        .ancestors()
+        .find(|dir| dir.join(".git").exists())
+        .unwrap_or(&cwd);
+    let file = path
+        .canonicalize()
+        .with_context(|| format!("failed to resolve {}", path.display()))?;
+    match file.strip_prefix(root) {
+        Ok(relative) => Ok(relative.to_path_buf()),
+        Err(_) => anyhow::bail!(
+            "--print-patch: {} is outside {}; run from inside the repository that contains it",
+            path.display(),
+            root.display()
+        ),
+    }
+}
+
+/// Handle --fix mode: apply auto-fixes to files.
+fn fix_command(mut args: LintArgs) -> anyhow::Result<ExitCode> {
+    if args.paths.is_empty() {
+        anyhow::bail!("--fix requires file paths (stdin not supported)");
+    }
+
+    let start_dir = infer_start_dir(&args)?;
+    let mut loaded_cfg = config::load_config(args.config.as_deref(), &start_dir)?;
+    let rulelist = apply_rulelist(&mut args, loaded_cfg.as_mut().map(|(_path, cfg)| cfg))?;
+    apply_config_aliases(&mut args, loaded_cfg.as_ref().map(|(_path, cfg)| cfg));
+
+    let (disabled, settings, preview) =
+        settings_from_config(loaded_cfg.as_ref().map(|(_path, cfg)| cfg), args.preview)?;
+
+    let selection = LintSelection::parse(&args.only_category, &args.only_group)?;
+    let preview = preview || selection.groups.contains(&RuleGroup::Preview);
+    let experimental = args.experimental || selection.groups.contains(&RuleGroup::Experimental);
+
+    let engine = build_engine(
+        &args,
+        &selection,
+        &disabled,
+        settings,
+        preview,
+        experimental,
+    )?;
+    let mut engines = EngineCache::new(&args, &selection, &rulelist, experimental, engine);
+
+    let files = collect_move_files(&args.paths, args.skip_tests, args.no_ignore)?;
+    let mut total_fixed = 0usize;
+    let mut skipped = SkippedFixes::default();
+    let mut files_modified = 0usize;
+    let mut patch = String::new();
+
+    const MAX_ITERATIONS: usize = 10; // Prevent infinite loops
+
+    for path in &files {
+        let engine = engines.engine_for(path)?;
+        if let Some(note) = engine.oversized_file_note(path)? {
+            eprintln!("{}: note: {}", path.display(), note.message);
+            continue;
+        }
+        let original_source = std::fs::read_to_string(path)?;
+        let mut current_source = original_source.clone();
+        let mut file_fixes = 0usize;
+        let mut file_placeholders = Vec::new();
+        let mut iterations = 0usize;
+        let mut tree = None;
+        let mut tree_edits = Vec::new();
+
+        // Iterate until no more fixes are applied (or max iterations reached)
+        loop {
+            iterations += 1;
+            if iterations > MAX_ITERATIONS {
+                eprintln!(
+                    "Warning: Max fix iterations ({}) reached for {}",
+                    MAX_ITERATIONS,
+                    path.display()
+                );
+                break;
+            }
+
+            let (diagnostics, new_tree) =
+                match engine.lint_source_incremental(&current_source, tree.as_ref(), &tree_edits) {
+                    Ok(linted) => linted,
+                    Err(err) if tree.is_none() => {
+                        let Some(diag) = LintEngine::parse_error_diagnostic(path, &err) else {
+                            return Err(err);
+                        };
+                        eprintln!("{}: error: {}", path.display(), diag.message);
+                        break;
+                    }
+                    Err(err) => return Err(err),
+                };
+            tree = Some(new_tree);
+
+            // Filter to diagnostics with fix suggestions
+            let fixable: Vec<_> = diagnostics
+                .iter()
+                .filter(|d| !d.suggestions.is_empty())
+                .cloned()
+                .collect();
+
+            if fixable.is_empty() {
+                file_placeholders.clear();
+                break;
+            }
+
+            let result = fixer::apply_fixes(&current_source, &fixable, args.unsafe_fixes)?;
+            let result_skipped = SkippedFixes::of(&result);
+            file_placeholders = result.placeholders;
+
+            if result.fixes_applied == 0 {
+                skipped.merge(result_skipped);
+                break;
+            }
+
+            file_fixes += result.fixes_applied;
+            current_source = result.fixed_source;
+            tree_edits = result.edits;
+
+            // In dry-run mode, only do one iteration
+            if args.fix_dry_run {
+                skipped.merge(result_skipped);
+                break;
+            }
+        }
+
+        // Placeholder suggestions need manual edits; print them instead of applying.
+        for diag in &file_placeholders {
+            for suggestion in diag
+                .suggestions
+                .iter()
+                .filter(|s| s.applicability == Applicability::HasPlaceholders)
+            {
+                let span = suggestion.edit_span(diag.span);
+                let line = format!(
+                    "{}:{}:{}: suggestion: {}: `{}`",
+                    path.display(),
+                    span.start.row,
+                    span.start.column,
+                    suggestion.message,
+                    suggestion.replacement
+                );
+                // Keep stdout a clean patch
+                if args.print_patch {
+                    eprintln!("{line}");
+                } else {
+                    println!("{line}");
+                }
+            }
+        }
+
+        if file_fixes > 0 {
+            if args.print_patch {
+                patch.push_str(&fixer::format_diff(
+                    &original_source,
+                    &current_source,
+                    &patch_path(path, &std::env::current_dir()?)?,
+                ));
+            } else if args.fix_dry_run {
+                // Print diff
+                let diff = fixer::format_diff(&original_source, &current_source, path);
+                if !diff.is_empty() {
+                    println!("{}", diff);
+                }
+            } else {
+                // Create backup unless --no-backup is set
+                if !args.no_backup {
+                    let backup_path = path.with_extension(format!(
+                        "{}.bak",
+                        path.extension().unwrap_or_default().to_string_lossy()
+                    ));
+                    std::fs::write(&backup_path, &original_source)?;
+                }
+
+                // Write fixed source
+                std::fs::write(path, &current_source)?;
+                files_modified += 1;
+            }
+            total_fixed += file_fixes;
+        }
+    }
+
+    // Print summary
+    if args.print_patch {
+        print!("{}", patch);
+        eprintln!(
+            "{} fix(es) in patch for {} file(s)",
+            total_fixed,
+            files.len()
+        );
+        for line in skipped.summary() {
+            eprintln!("{line}");
+        }
+    } else if args.fix_dry_run {
+        println!(
+            "\n{} fix(es) would be applied to {} file(s)",
+            total_fixed,
+            files.len()
+        );
+        for line in skipped.summary() {
+            println!("{line}");
+        }
+    } else {
+        println!(
+            "Applied {} fix(es) to {} file(s)",
+            total_fixed, files_modified
+        );
+        for line in skipped.summary() {
+            println!("{line}");
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Fixes that `--fix` left unapplied, by reason.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct SkippedFixes {
+    /// Applied only with `--unsafe-fixes`.
+    unsafe_only: usize,
+    /// Suggestions with placeholders, printed for manual edits.
+    placeholders: usize,
+    /// Overlapping another fix in the same pass.
+    overlapping: usize,
+    /// Suggestions that are never applied automatically.
+    other: usize,
+}
+
+impl SkippedFixes {
+    fn of(result: &fixer::FixResult) -> Self {
+        let placeholders = result.placeholders.len();
+        Self {
+            unsafe_only: result.unsafe_skipped,
+            placeholders,
+            overlapping: result.overlaps_skipped,
+            other: result.fixes_skipped
+                - result.unsafe_skipped
+                - result.overlaps_skipped
+                - placeholders,
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.unsafe_only += other.unsafe_only;
+        self.placeholders += other.placeholders;
+        self.overlapping += other.overlapping;
+        self.other += other.other;
+    }
+
+    /// One summary line per reason with at least one skipped fix.
+    fn summary(&self) -> Vec<String> {
+        [
+            (self.unsafe_only, "use --unsafe-fixes to apply"),
+            (self.placeholders, "placeholders need manual edits"),
+            (self.overlapping, "overlap another fix; run --fix again"),
+            (self.other, "no automatic fix"),
+        ]
+        .into_iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, reason)| format!("{n} fix(es) skipped ({reason})"))
+        .collect()
+    }
+}
+
+// ============================================================================
+// Watch Command
+// ============================================================================
+
+/// Lint everything under the watched paths once, then re-lint changed `.move` files.
+///
+/// Rapid saves are debounced into one run. With `--mode full`, the semantic pass is
+/// re-run only when a file inside the package changes.
+fn watch_command(watch: WatchArgs) -> anyhow::Result<ExitCode> {
+    use notify::{RecursiveMode, Watcher};
+
+    let WatchArgs {
+        lint: mut args,
+        debounce_ms,
+    } = watch;
+    if args.fix {
+        anyhow::bail!("watch does not support --fix");
+    }
+    if !matches!(args.format, OutputFormat::Pretty | OutputFormat::Rich) {
+        anyhow::bail!("watch only supports --format pretty or rich");
+    }
+    if args.paths.is_empty() {
+        args.paths.push(PathBuf::from("."));
+    }
+
+    let start_dir = infer_start_dir(&args)?;
+    let mut loaded_cfg = config::load_config(args.config.as_deref(), &start_dir)?;
+    apply_rulelist(&mut args, loaded_cfg.as_mut().map(|(_path, cfg)| cfg))?;
+    apply_config_aliases(&mut args, loaded_cfg.as_ref().map(|(_path, cfg)| cfg));
+    let (disabled, settings, preview) =
+        settings_from_config(loaded_cfg.as_ref().map(|(_path, cfg)| cfg), args.preview)?;
+    let selection = LintSelection::parse(&args.only_category, &args.only_group)?;
+    let preview = preview || selection.groups.contains(&RuleGroup::Preview);
+    let experimental = args.experimental || selection.groups.contains(&RuleGroup::Experimental);
+    let min_level = args.min_level.map(MinLevel::as_lint_level);
+
+    let package_root = match args.mode {
+        LintMode::Full => {
+            let hint = args.package.as_deref().unwrap_or(args.paths[0].as_path());
+            Some(std::fs::canonicalize(hint)?)
+        }
+        LintMode::Fast => None,
+    };
+
+    // One engine for the whole session; config changes need a restart.
+    let engine = build_engine(
+        &args,
+        &selection,
+        &disabled,
+        settings.clone(),
+        preview,
+        experimental,
+    )?;
+
+    let run = |files: &[PathBuf], run_semantic: bool| -> anyhow::Result<()> {
+        // Clear the screen and move the cursor home.
+        print!("\x1b[2J\x1b[H");
+        let mut budget = DiagnosticBudget::new(args.max_diagnostics);
+        for path in files {
+            if let Err(err) = lint_file_text(
+                &engine,
+                path,
+                args.format,
+                args.deny_warnings,
+                args.show_tier,
+                args.quiet,
+                min_level,
+                &mut budget,
+                None,
+            ) {
+                println!("{}: {err:#}", path.display());
+            }
+        }
+
+        if let Some(root) = package_root.as_deref().filter(|_| run_semantic) {
+            match semantic::lint_package(root, &settings, preview, experimental) {
+                Ok(mut diags) => {
+                    filter_semantic_diags(&args, &selection, min_level, &mut diags);
+                    for diag in &diags {
+                        if args.quiet && !fails_with(diag, args.deny_warnings) {
+                            continue;
+                        }
+                        if !budget.take() {
+                            continue;
+                        }
+                        let file = diag.file.clone().unwrap_or_else(|| "<unknown>".to_string());
+                        print_semantic_text(diag, &file, args.format, args.show_tier)?;
+                    }
+                    if !args.quiet {
+                        println!(
+                            "{} semantic diagnostics for {}",
+                            diags.len(),
+                            root.display()
+                        );
+                    }
+                }
+                Err(err) => println!("{}: {err:#}", root.display()),
+            }
+        }
+
+        if let Some(notice) = budget.notice() {
+            println!("{notice}");
+        }
+        println!("\nWatching for changes (Ctrl-C to stop)...");
+        std::io::stdout().flush()?;
+        Ok(())
+    };
+
+    let files = collect_move_files(&args.paths, args.skip_tests, args.no_ignore)?;
+    run(&files, true)?;
+
+    // Watch canonical paths so event paths can be compared against the package root.
+    let roots = args
+        .paths
+        .iter()
+        .map(std::fs::canonicalize)
+        .collect::<std::io::Result<Vec<_>>>()?;
+    let (tx, rx) = std::sync::mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    for root in &roots {
+        watcher.watch(root, RecursiveMode::Recursive)?;
+    }
+
+    let debounce = std::time::Duration::from_millis(debounce_ms);
+    let cwd = std::env::current_dir()?;
+    while let Ok(event) = rx.recv() {
+        let mut changed = std::collections::BTreeSet::new();
+        collect_watch_event(event, &roots, args.skip_tests, &mut changed);
+        // Editors often write a file several times per save; fold the burst into one run.
+        while let Ok(event) = rx.recv_timeout(debounce) {
+            collect_watch_event(event, &roots, args.skip_tests, &mut changed);
+        }
+
+        let package_changed = package_root
+            .as_deref()
+            .is_some_and(|root| changed.iter().any(|p| p.starts_with(root)));
+        let files: Vec<PathBuf> = changed
+            .iter()
+            .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("move") && p.is_file())
+            .map(|p| p.strip_prefix(&cwd).unwrap_or(p).to_path_buf())
+            .collect();
+        if files.is_empty() && !package_changed {
+            continue;
+        }
+        run(&files, package_changed)?;
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Add the `.move` files and manifests touched by a watcher event to `changed`.
+fn collect_watch_event(
+    event: notify::Result<notify::Event>,
+    roots: &[PathBuf],
+    skip_tests: bool,
+    changed: &mut std::collections::BTreeSet<PathBuf>,
+) {
+    match event {
+        Ok(event) if !matches!(event.kind, notify::EventKind::Access(_)) => {
+            changed.extend(
+                event
+                    .paths
+                    .into_iter()
+                    .filter(|p| is_watched_path(p, roots, skip_tests)),
+            );
+        }
+        Ok(_) => {}
+        Err(err) => eprintln!("watch error: {err}"),
+    }
+}
+
+/// Whether a change to `path` should trigger a re-lint.
+///
+/// Directory skips and `--skip-tests` only look at the part of `path` below the watched
+/// root it belongs to, mirroring how the initial file list is collected.
+fn is_watched_path(path: &Path, roots: &[PathBuf], skip_tests: bool) -> bool {
+    let is_move = path.extension().and_then(|e| e.to_str()) == Some("move");
+    let is_manifest = path.file_name().and_then(|n| n.to_str()) == Some("Move.toml");
+    if !is_move && !is_manifest {
+        return false;
+    }
+    let relative = roots
+        .iter()
+        .find_map(|root| path.strip_prefix(root).ok())
+        .unwrap_or(path);
+    if relative.ancestors().skip(1).any(should_skip_dir) {
+        return false;
+    }
+    !(skip_tests && is_test_file(relative))
+}
+
+// ============================================================================
+// Triage Command
+// ============================================================================
+
+/// Build a [`FindingFilter`] from the `triage list`/`triage export` filter flags.
+fn finding_filter(
+    status: Option<String>,
+    lint: Option<String>,
+    repo: Option<String>,
+    severity: Option<String>,
+    category: Option<String>,
+) -> anyhow::Result<FindingFilter> {
+    let mut filter = FindingFilter::new();
+
+    if let Some(s) = status {
+        filter = filter.with_status(TriageStatus::from_str(&s)?);
+    }
+    if let Some(l) = lint {
+        filter = filter.with_lint(l);
+    }
+    if let Some(r) = repo {
+        filter = filter.with_repo(r);
+    }
+    if let Some(sev) = severity
+        && let Some(s) = Severity::from_str(&sev)
+    {
+        filter = filter.with_severity(s);
+    }
+    if let Some(c) = category {
+        filter = filter.with_category(c);
+    }
+
+    Ok(filter)
+}
+
+/// Parse a `YYYY-MM-DD` date given to `flag` as the first instant of that day in UTC, or the
+/// last one when `end_of_day` is set, so `--since` and `--until` both include the whole day.
+fn parse_triage_date(
+    flag: &str,
+    value: &str,
+    end_of_day: bool,
+) -> anyhow::Result<chrono::DateTime<chrono::Utc>> {
+    let date = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .with_context(|| format!("invalid {flag} date {value} (expected YYYY-MM-DD)"))?;
+    let time = if end_of_day {
+        chrono::NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999)
+    } else {
+        chrono::NaiveTime::from_hms_opt(0, 0, 0)
+    }
+    .expect("valid time of day");
+    Ok(date.and_time(time).and_utc())
+}
 
 /// Handle triage subcommand for tracking and categorizing findings.
 fn triage_command(cmd: TriageCommand) -> anyhow::Result<ExitCode> {
@@ -470,29 +1670,17 @@ fn triage_command(cmd: TriageCommand) -> anyhow::Result<ExitCode> {
             repo,
             severity,
             category,
+            since,
+            until,
             limit,
         } => {
             let db = TriageDatabase::load(db_path)?;
-
-            // Build filter
-            let mut filter = FindingFilter::new();
-
-            if let Some(s) = status {
-                filter = filter.with_status(TriageStatus::from_str(&s)?);
+            let mut filter = finding_filter(status, lint, repo, severity, category)?;
+            if let Some(since) = since {
+                filter = filter.with_detected_after(parse_triage_date("--since", &since, false)?);
             }
-            if let Some(l) = lint {
-                filter = filter.with_lint(l);
-            }
-            if let Some(r) = repo {
-                filter = filter.with_repo(r);
-            }
-            if let Some(sev) = severity
-                && let Some(s) = Severity::from_str(&sev)
-            {
-                filter = filter.with_severity(s);
-            }
-            if let Some(c) = category {
-                filter = filter.with_category(c);
+            if let Some(until) = until {
+                filter = filter.with_detected_before(parse_triage_date("--until", &until, true)?);
             }
 
             let mut findings: Vec<_> = db.filter(&filter);
@@ -614,6 +1802,41 @@ fn triage_command(cmd: TriageCommand) -> anyhow::Result<ExitCode> {
             Ok(ExitCode::SUCCESS)
         }
 
+        TriageAction::Export {
+            format,
+            status,
+            lint,
+            repo,
+            severity,
+            category,
+            output,
+        } => {
+            if !format.eq_ignore_ascii_case("csv") {
+                eprintln!("Unknown export format: {}. Use csv.", format);
+                return Ok(ExitCode::from(1));
+            }
+
+            let db = TriageDatabase::load(db_path)?;
+            let filter = finding_filter(status, lint, repo, severity, category)?;
+
+            let mut findings: Vec<_> = db.filter(&filter);
+            findings.sort_by(|a, b| (&a.repo, &a.file, a.line).cmp(&(&b.repo, &b.file, b.line)));
+            let csv = generate_csv_export(&findings);
+
+            if let Some(output_path) = output {
+                std::fs::write(&output_path, &csv)?;
+                println!(
+                    "Exported {} findings to: {}",
+                    findings.len(),
+                    output_path.display()
+                );
+            } else {
+                print!("{}", csv);
+            }
+
+            Ok(ExitCode::SUCCESS)
+        }
+
         TriageAction::Import {
             input,
             repo,
@@ -886,6 +2109,141 @@ fn triage_command(cmd: TriageCommand) -> anyhow::Result<ExitCode> {
             Ok(ExitCode::SUCCESS)
         }
 
+        TriageAction::Diff {
+            before: Some(before),
+            after: Some(after),
+            format,
+            fail_on_new,
+            exclude_defaults,
+            exclude_patterns,
+            ..
+        } => {
+            let mut patterns: Vec<String> = exclude_patterns;
+            if exclude_defaults {
+                patterns.extend(
+                    move_clippy::triage::DEFAULT_EXCLUDE_PATTERNS
+                        .iter()
+                        .map(|s| s.to_string()),
+                );
+            }
+
+            let Some(report_format) = ReportFormat::from_str(&format) else {
+                eprintln!("Unknown format: {}. Use md, json, or text.", format);
+                return Ok(ExitCode::from(2));
+            };
+
+            let diff = RunDiff::compute(load_lint_run(&before)?, load_lint_run(&after)?, &patterns);
+            print!("{}", generate_run_diff_report(&diff, report_format)?);
+
+            if fail_on_new && diff.has_new() {
+                Ok(ExitCode::from(1))
+            } else {
+                Ok(ExitCode::SUCCESS)
+            }
+        }
+
+        TriageAction::Diff { other, .. } => {
+            let Some(other) = other else {
+                anyhow::bail!("triage diff requires OTHER or --before/--after");
+            };
+            let db = TriageDatabase::load(db_path)?;
+            let other_db = TriageDatabase::load(&other)?;
+            let diff = db.diff(&other_db);
+
+            println!("TRIAGE DIFF");
+            println!("===========");
+            println!("A: {}", db_path.display());
+            println!("B: {}", other.display());
+            println!();
+            println!("Matching:          {}", diff.matching);
+            println!("Only in A:         {}", diff.only_in_self.len());
+            println!("Only in B:         {}", diff.only_in_other.len());
+            println!("Status conflicts:  {}", diff.conflicts.len());
+
+            if !diff.conflicts.is_empty() {
+                println!("\nConflicting statuses (A vs B):");
+                for (ours, theirs) in &diff.conflicts {
+                    println!(
+                        "  {} {} {}:{}:{} - {} vs {}",
+                        ours.id,
+                        ours.lint,
+                        ours.repo,
+                        ours.file,
+                        ours.line,
+                        ours.status,
+                        theirs.status
+                    );
+                }
+            }
+
+            if !diff.only_in_self.is_empty() {
+                println!("\nOnly in A:");
+                for f in &diff.only_in_self {
+                    println!("  {} {}", f.id, f.short_display());
+                }
+            }
+
+            if !diff.only_in_other.is_empty() {
+                println!("\nOnly in B:");
+                for f in &diff.only_in_other {
+                    println!("  {} {}", f.id, f.short_display());
+                }
+            }
+
+            Ok(ExitCode::SUCCESS)
+        }
+
+        TriageAction::Merge { into, dbs, prefer } => {
+            if let Some(prefer) = &prefer
+                && !dbs.contains(prefer)
+            {
+                anyhow::bail!(
+                    "--prefer {} must be one of the databases being merged",
+                    prefer.display()
+                );
+            }
+
+            if let Some(missing) = dbs.iter().find(|path| !path.exists()) {
+                anyhow::bail!("triage database not found: {}", missing.display());
+            }
+
+            let mut master = TriageDatabase::load(&into)?;
+            let mut conflicts = 0;
+            for path in &dbs {
+                let other = TriageDatabase::load(path)?;
+                let prefer_other = prefer.as_ref() == Some(path);
+                let merge = master.merge(&other, prefer_other);
+                println!(
+                    "{}: {} added, {} updated, {} conflict(s)",
+                    path.display(),
+                    merge.added,
+                    merge.updated,
+                    merge.conflicts.len()
+                );
+                for conflict in &merge.conflicts {
+                    eprintln!(
+                        "conflict: {} {} vs {} in {} (kept {})",
+                        conflict.id,
+                        conflict.ours,
+                        conflict.theirs,
+                        path.display(),
+                        conflict.resolved
+                    );
+                }
+                conflicts += merge.conflicts.len();
+            }
+
+            master.save(&into)?;
+            println!(
+                "Merged {} database(s) into {} ({} findings, {} conflict(s))",
+                dbs.len(),
+                into.display(),
+                master.findings.len(),
+                conflicts
+            );
+            Ok(ExitCode::SUCCESS)
+        }
+
         TriageAction::BulkUpdate {
             lint,
             path_contains,
@@ -1028,43 +2386,101 @@ fn infer_category(lint: &str) -> String {
     "style".to_string()
 }
 
+/// Read and lint one file, returning its source and diagnostics.
+///
+/// A file over the engine's maximum size is not read: the source is empty and the only
+/// diagnostic is the skip note.
+fn lint_path(engine: &LintEngine, path: &Path) -> anyhow::Result<(String, Vec<Diagnostic>)> {
+    if let Some(note) = engine.oversized_file_note(path)? {
+        return Ok((String::new(), vec![note]));
+    }
+    let source = std::fs::read_to_string(path)?;
+    match engine.lint_source(&source) {
+        Ok(diagnostics) => Ok((source, diagnostics)),
+        // One unparsable file is reported in place and does not abort the run.
+        Err(err) => match LintEngine::parse_error_diagnostic(path, &err) {
+            Some(diag) => Ok((source, vec![diag])),
+            None => Err(err),
+        },
+    }
+}
+
+/// Read stdin and lint it, labelling diagnostics with `stdin_name` when given.
+///
+/// Like [`lint_path`], unparsable input becomes a `parse_error` diagnostic.
+fn lint_stdin_source(
+    engine: &LintEngine,
+    stdin_name: Option<&str>,
+) -> anyhow::Result<(String, Vec<Diagnostic>)> {
+    let mut source = String::new();
+    std::io::stdin().read_to_string(&mut source)?;
+    let mut diagnostics = match engine.lint_source(&source) {
+        Ok(diagnostics) => diagnostics,
+        Err(err) => {
+            let label = Path::new(stdin_name.unwrap_or("stdin"));
+            match LintEngine::parse_error_diagnostic(label, &err) {
+                Some(diag) => vec![diag],
+                None => return Err(err),
+            }
+        }
+    };
+    if let Some(name) = stdin_name {
+        for diag in &mut diagnostics {
+            diag.file = Some(name.to_string());
+        }
+    }
+    Ok((source, diagnostics))
+}
+
+#[allow(clippy::too_many_arguments)]
 fn lint_file_text(
     engine: &LintEngine,
     path: &Path,
     format: OutputFormat,
     deny_warnings: bool,
     show_tier: bool,
-) -> anyhow::Result<(usize, bool)> {
-    let source = std::fs::read_to_string(path)?;
-    let diagnostics = engine.lint_source(&source)?;
+    quiet: bool,
+    min_level: Option<LintLevel>,
+    budget: &mut DiagnosticBudget,
+    mut deferred: Option<&mut Vec<(String, Diagnostic)>>,
+) -> anyhow::Result<(usize, bool, Vec<JsonDiagnostic>)> {
+    let (source, mut diagnostics) = lint_path(engine, path)?;
+    retain_min_level(&mut diagnostics, min_level);
 
     let mut has_error = false;
+    let color = rich_color_enabled();
+    let opts = ReportOptions {
+        show_tier,
+        deny_warnings,
+    };
 
     match format {
-        OutputFormat::Pretty => {
+        OutputFormat::Pretty | OutputFormat::Rich => {
             for diag in &diagnostics {
+                has_error |= diag.level.fails_run();
+                if quiet && !fails_with(diag, deny_warnings) {
+                    continue;
+                }
                 let file = diag
                     .file
                     .clone()
                     .unwrap_or_else(|| path.display().to_string());
-                let tier_prefix = if show_tier {
-                    format!("[{}] ", diag.lint.group.as_str())
-                } else {
-                    String::new()
-                };
-                println!(
-                    "{}:{}:{}: {}: {}{}: {}",
-                    file,
-                    diag.span.start.row,
-                    diag.span.start.column,
-                    diag.level.as_str(),
-                    tier_prefix,
-                    diag.lint.name,
-                    diag.message
-                );
-                has_error |= diag.level == LintLevel::Error;
+                if let Some(deferred) = deferred.as_mut() {
+                    deferred.push((file, diag.clone()));
+                    continue;
+                }
+                if !budget.take() {
+                    continue;
+                }
+                if matches!(format, OutputFormat::Rich) {
+                    print!("{}", diag.render_rich(&file, &source, color));
+                    continue;
+                }
+                report::write_pretty_diagnostic(&mut std::io::stdout().lock(), diag, &file, &opts)?;
+            }
+            if !quiet {
+                println!("{} diagnostics for {}", diagnostics.len(), path.display());
             }
-            println!("{} diagnostics for {}", diagnostics.len(), path.display());
         }
         OutputFormat::Github => {
             for diag in &diagnostics {
@@ -1072,118 +2488,123 @@ fn lint_file_text(
                     .file
                     .clone()
                     .unwrap_or_else(|| path.display().to_string());
-                let msg = github_escape(&diag.message);
-
-                let kind = if diag.level == LintLevel::Error
-                    || (deny_warnings && diag.level == LintLevel::Warn)
-                {
-                    "error"
-                } else {
-                    "warning"
-                };
-
-                let lint_name = if show_tier {
-                    format!("[{}]{}", diag.lint.group.as_str(), diag.lint.name)
-                } else {
-                    diag.lint.name.to_string()
-                };
-
-                println!(
-                    "::{} file={},line={},col={},title={}::{}",
-                    kind,
-                    github_escape(&file),
-                    diag.span.start.row,
-                    diag.span.start.column,
-                    lint_name,
-                    msg
-                );
-                has_error |= kind == "error";
+                has_error |= report::github_kind(diag, deny_warnings) == "error";
+                if !budget.take() {
+                    continue;
+                }
+                report::write_github_diagnostic(&mut std::io::stdout().lock(), diag, &file, &opts)?;
             }
         }
-        OutputFormat::Json => unreachable!("json handled elsewhere"),
+        OutputFormat::Json | OutputFormat::Checkstyle | OutputFormat::Junit => {
+            unreachable!("json and xml formats handled elsewhere")
+        }
     }
 
-    Ok((diagnostics.len(), has_error))
+    // Push each file's diagnostics out as soon as it is linted, so CI logs stream.
+    std::io::stdout().flush()?;
+
+    let records = diagnostics
+        .iter()
+        .map(|d| {
+            JsonDiagnostic::from_diagnostic(
+                d,
+                d.file.clone().unwrap_or_else(|| path.display().to_string()),
+                false,
+                false,
+            )
+        })
+        .collect();
+
+    Ok((diagnostics.len(), has_error, records))
 }
 
 fn lint_stdin_text(
     engine: &LintEngine,
+    stdin_name: Option<&str>,
     format: OutputFormat,
     deny_warnings: bool,
     show_tier: bool,
-) -> anyhow::Result<(usize, bool)> {
-    let mut source = String::new();
-    std::io::stdin().read_to_string(&mut source)?;
-    let diagnostics = engine.lint_source(&source)?;
+    quiet: bool,
+    min_level: Option<LintLevel>,
+    budget: &mut DiagnosticBudget,
+) -> anyhow::Result<(usize, bool, Vec<JsonDiagnostic>)> {
+    let (source, mut diagnostics) = lint_stdin_source(engine, stdin_name)?;
+    retain_min_level(&mut diagnostics, min_level);
 
     let mut has_error = false;
+    let color = rich_color_enabled();
+    let opts = ReportOptions {
+        show_tier,
+        deny_warnings,
+    };
 
     match format {
-        OutputFormat::Pretty => {
+        OutputFormat::Pretty | OutputFormat::Rich => {
             for diag in &diagnostics {
+                has_error |= diag.level.fails_run();
+                if quiet && !fails_with(diag, deny_warnings) {
+                    continue;
+                }
+                if !budget.take() {
+                    continue;
+                }
                 let file = diag.file.clone().unwrap_or_else(|| "stdin".to_string());
-                let tier_prefix = if show_tier {
-                    format!("[{}] ", diag.lint.group.as_str())
-                } else {
-                    String::new()
-                };
+                if matches!(format, OutputFormat::Rich) {
+                    print!("{}", diag.render_rich(&file, &source, color));
+                    continue;
+                }
+                report::write_pretty_diagnostic(&mut std::io::stdout().lock(), diag, &file, &opts)?;
+            }
+            if !quiet {
                 println!(
-                    "{}:{}:{}: {}: {}{}: {}",
-                    file,
-                    diag.span.start.row,
-                    diag.span.start.column,
-                    diag.level.as_str(),
-                    tier_prefix,
-                    diag.lint.name,
-                    diag.message
+                    "{} diagnostics for {}",
+                    diagnostics.len(),
+                    stdin_name.unwrap_or("stdin")
                 );
-                has_error |= diag.level == LintLevel::Error;
             }
-            println!("{} diagnostics for stdin", diagnostics.len());
         }
         OutputFormat::Github => {
             for diag in &diagnostics {
                 let file = diag.file.clone().unwrap_or_else(|| "stdin".to_string());
-                let msg = github_escape(&diag.message);
-
-                let kind = if diag.level == LintLevel::Error
-                    || (deny_warnings && diag.level == LintLevel::Warn)
-                {
-                    "error"
-                } else {
-                    "warning"
-                };
-
-                let lint_name = if show_tier {
-                    format!("[{}]{}", diag.lint.group.as_str(), diag.lint.name)
-                } else {
-                    diag.lint.name.to_string()
-                };
-
-                println!(
-                    "::{} file={},line={},col={},title={}::{}",
-                    kind,
-                    github_escape(&file),
-                    diag.span.start.row,
-                    diag.span.start.column,
-                    lint_name,
-                    msg
-                );
-                has_error |= kind == "error";
+                has_error |= report::github_kind(diag, deny_warnings) == "error";
+                if !budget.take() {
+                    continue;
+                }
+                report::write_github_diagnostic(&mut std::io::stdout().lock(), diag, &file, &opts)?;
             }
         }
-        OutputFormat::Json => unreachable!("json handled elsewhere"),
+        OutputFormat::Json | OutputFormat::Checkstyle | OutputFormat::Junit => {
+            unreachable!("json and xml formats handled elsewhere")
+        }
     }
 
-    Ok((diagnostics.len(), has_error))
+    // Push each file's diagnostics out as soon as it is linted, so CI logs stream.
+    std::io::stdout().flush()?;
+
+    let records = diagnostics
+        .iter()
+        .map(|d| {
+            JsonDiagnostic::from_diagnostic(
+                d,
+                d.file.clone().unwrap_or_else(|| "stdin".to_string()),
+                false,
+                false,
+            )
+        })
+        .collect();
+
+    Ok((diagnostics.len(), has_error, records))
 }
 
 fn lint_file_json(
     engine: &LintEngine,
     path: &Path,
+    min_level: Option<LintLevel>,
+    full_span: bool,
+    include_suggestions: bool,
 ) -> anyhow::Result<(usize, bool, Vec<JsonDiagnostic>)> {
-    let source = std::fs::read_to_string(path)?;
-    let diagnostics = engine.lint_source(&source)?;
+    let (_source, mut diagnostics) = lint_path(engine, path)?;
+    retain_min_level(&mut diagnostics, min_level);
 
     let mut has_error = false;
 
@@ -1191,25 +2612,23 @@ fn lint_file_json(
         .iter()
         .map(|d| {
             let file = d.file.clone().unwrap_or_else(|| path.display().to_string());
-            has_error |= d.level == LintLevel::Error;
-            JsonDiagnostic {
-                file,
-                row: d.span.start.row,
-                column: d.span.start.column,
-                level: d.level.as_str().to_string(),
-                lint: d.lint.name.to_string(),
-                message: d.message.clone(),
-            }
+            has_error |= d.level.fails_run();
+            JsonDiagnostic::from_diagnostic(d, file, full_span, include_suggestions)
         })
         .collect::<Vec<_>>();
 
     Ok((diagnostics.len(), has_error, out))
 }
 
-fn lint_stdin_json(engine: &LintEngine) -> anyhow::Result<(usize, bool, Vec<JsonDiagnostic>)> {
-    let mut source = String::new();
-    std::io::stdin().read_to_string(&mut source)?;
-    let diagnostics = engine.lint_source(&source)?;
+fn lint_stdin_json(
+    engine: &LintEngine,
+    stdin_name: Option<&str>,
+    min_level: Option<LintLevel>,
+    full_span: bool,
+    include_suggestions: bool,
+) -> anyhow::Result<(usize, bool, Vec<JsonDiagnostic>)> {
+    let (_source, mut diagnostics) = lint_stdin_source(engine, stdin_name)?;
+    retain_min_level(&mut diagnostics, min_level);
 
     let mut has_error = false;
 
@@ -1217,31 +2636,336 @@ fn lint_stdin_json(engine: &LintEngine) -> anyhow::Result<(usize, bool, Vec<Json
         .iter()
         .map(|d| {
             let file = d.file.clone().unwrap_or_else(|| "stdin".to_string());
-            has_error |= d.level == LintLevel::Error;
-            JsonDiagnostic {
-                file,
-                row: d.span.start.row,
-                column: d.span.start.column,
-                level: d.level.as_str().to_string(),
-                lint: d.lint.name.to_string(),
-                message: d.message.clone(),
-            }
+            has_error |= d.level.fails_run();
+            JsonDiagnostic::from_diagnostic(d, file, full_span, include_suggestions)
         })
         .collect::<Vec<_>>();
 
     Ok((diagnostics.len(), has_error, out))
 }
 
-fn github_escape(s: &str) -> String {
-    s.replace('%', "%25")
-        .replace('\r', "%0D")
-        .replace('\n', "%0A")
+/// Map a source file to its report path under `dir`, mirroring the source layout.
+///
+/// Absolute paths are made relative to the current directory when possible. Components that
+/// would leave `dir` are spelled out instead of dropped (`..` becomes `__up__`, the root
+/// `__root__`), so `../a/x.move` and `a/x.move` get different reports.
+fn per_file_report_path(dir: &Path, source: &Path, format: OutputFormat) -> PathBuf {
+    use std::path::Component;
+
+    let relative = std::env::current_dir()
+        .ok()
+        .and_then(|cwd| source.strip_prefix(cwd).ok().map(Path::to_path_buf))
+        .unwrap_or_else(|| source.to_path_buf());
+
+    let mut out = dir.to_path_buf();
+    for component in relative.components() {
+        match component {
+            Component::Normal(part) => out.push(part),
+            Component::ParentDir => out.push("__up__"),
+            Component::RootDir => out.push("__root__"),
+            Component::Prefix(prefix) => out.push(
+                prefix
+                    .as_os_str()
+                    .to_string_lossy()
+                    .replace(|c: char| !c.is_ascii_alphanumeric(), "_"),
+            ),
+            Component::CurDir => {}
+        }
+    }
+
+    let ext = match format {
+        OutputFormat::Json => "json",
+        OutputFormat::Checkstyle | OutputFormat::Junit => "xml",
+        OutputFormat::Pretty | OutputFormat::Rich | OutputFormat::Github => "txt",
+    };
+    let mut name = out
+        .file_name()
+        .map(|n| n.to_os_string())
+        .unwrap_or_default();
+    name.push(".");
+    name.push(ext);
+    out.set_file_name(name);
+    out
+}
+
+/// Render the diagnostics for a single file in the selected output format.
+fn render_file_report(
+    file: &str,
+    diags: &[&JsonDiagnostic],
+    format: OutputFormat,
+    deny_warnings: bool,
+) -> anyhow::Result<String> {
+    let mut buf: Vec<u8> = Vec::new();
+    match format {
+        OutputFormat::Json => report::write_json(&mut buf, diags)?,
+        OutputFormat::Checkstyle => writeln!(buf, "{}", render_checkstyle(diags.iter().copied()))?,
+        OutputFormat::Junit => writeln!(
+            buf,
+            "{}",
+            render_junit([file], diags.iter().copied(), deny_warnings)
+        )?,
+        // Report files carry no source text, so rich reports use the pretty layout.
+        OutputFormat::Pretty | OutputFormat::Rich => {
+            for d in diags {
+                report::write_pretty_line(
+                    &mut buf, &d.file, d.row, d.column, &d.level, &d.lint, &d.message,
+                )?;
+            }
+        }
+        OutputFormat::Github => {
+            for d in diags {
+                let level = LintLevel::from_output_str(&d.level).unwrap_or_default();
+                report::write_github_line(
+                    &mut buf,
+                    report::github_kind_for_level(level, deny_warnings),
+                    &d.file,
+                    d.row,
+                    d.column,
+                    &d.lint,
+                    &d.message,
+                )?;
+            }
+        }
+    }
+    Ok(String::from_utf8(buf)?)
+}
+
+/// Write one report per source file under `dir`.
+///
+/// Every linted file gets a report (an empty one when clean) unless `skip_clean` is set.
+/// Diagnostics for files outside `files` (e.g. semantic findings) get their own reports too.
+/// Returns the number of reports written.
+fn write_per_file_reports(
+    dir: &Path,
+    files: &[PathBuf],
+    diags: &[JsonDiagnostic],
+    format: OutputFormat,
+    skip_clean: bool,
+    deny_warnings: bool,
+) -> anyhow::Result<usize> {
+    let mut by_file: std::collections::BTreeMap<String, Vec<&JsonDiagnostic>> = files
+        .iter()
+        .map(|p| (p.display().to_string(), Vec::new()))
+        .collect();
+    for d in diags {
+        by_file.entry(d.file.clone()).or_default().push(d);
+    }
+
+    let mut written = 0usize;
+    let mut sources: std::collections::HashMap<PathBuf, &str> = std::collections::HashMap::new();
+    for (file, file_diags) in &by_file {
+        if skip_clean && file_diags.is_empty() {
+            continue;
+        }
+        let report_path = per_file_report_path(dir, Path::new(file), format);
+        if let Some(other) = sources.insert(report_path.clone(), file.as_str()) {
+            anyhow::bail!(
+                "--output-per-file: {other} and {file} would both be written to {}",
+                report_path.display()
+            );
+        }
+        if let Some(parent) = report_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(
+            &report_path,
+            render_file_report(file, file_diags, format, deny_warnings)?,
+        )?;
+        written += 1;
+    }
+
+    Ok(written)
+}
+
+/// Drop diagnostics below `--min-level` so they are never emitted.
+///
+/// Diagnostics that fail the run (e.g. `deny`, which displays as a warning) are always
+/// kept, so filtering output never turns a failing run into a passing one.
+fn retain_min_level(diagnostics: &mut Vec<Diagnostic>, min_level: Option<LintLevel>) {
+    if let Some(min_level) = min_level {
+        diagnostics.retain(|d| d.level >= min_level || d.level.fails_run());
+    }
+}
+
+/// Render diagnostics as a Checkstyle XML report, one `<file>` element per source file.
+fn render_checkstyle<'a>(records: impl IntoIterator<Item = &'a JsonDiagnostic>) -> String {
+    let mut by_file: std::collections::BTreeMap<&str, Vec<&JsonDiagnostic>> =
+        std::collections::BTreeMap::new();
+    for record in records {
+        by_file
+            .entry(record.file.as_str())
+            .or_default()
+            .push(record);
+    }
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<checkstyle version=\"4.3\">\n");
+    for (file, diags) in &by_file {
+        xml.push_str(&format!("  <file name=\"{}\">\n", xml_escape(file)));
+        for d in diags {
+            let severity = if d.level == LintLevel::Error.as_str() {
+                "error"
+            } else if d.level == LintLevel::Warn.as_str() || d.level == LintLevel::Deny.as_str() {
+                "warning"
+            } else {
+                "info"
+            };
+            xml.push_str(&format!(
+                "    <error line=\"{}\" column=\"{}\" severity=\"{}\" message=\"{}\" source=\"move-clippy.{}\"/>\n",
+                d.row,
+                d.column,
+                severity,
+                xml_escape(&d.message),
+                xml_escape(&d.lint)
+            ));
+        }
+        xml.push_str("  </file>\n");
+    }
+    xml.push_str("</checkstyle>");
+    xml
+}
+
+/// Render diagnostics as a JUnit XML report, one `<testsuite>` per file.
+///
+/// `files` are the linted files; files that only appear in `records` (e.g. semantic
+/// findings) get a suite too. Each diagnostic is a `<testcase>`, and a clean file gets one
+/// passing testcase. Diagnostics that fail the run are `<failure>`s; warnings are `<error>`s,
+/// or `<failure>`s with `--deny-warnings`.
+fn render_junit<'a>(
+    files: impl IntoIterator<Item = &'a str>,
+    records: impl IntoIterator<Item = &'a JsonDiagnostic>,
+    deny_warnings: bool,
+) -> String {
+    let mut by_file: std::collections::BTreeMap<&str, Vec<&JsonDiagnostic>> =
+        files.into_iter().map(|file| (file, Vec::new())).collect();
+    for record in records {
+        by_file
+            .entry(record.file.as_str())
+            .or_default()
+            .push(record);
+    }
+
+    let is_failure = |d: &JsonDiagnostic| {
+        d.level == LintLevel::Error.as_str() || d.level == LintLevel::Deny.as_str() || deny_warnings
+    };
+    let count = |diags: &[&JsonDiagnostic]| {
+        let failures = diags.iter().filter(|d| is_failure(d)).count();
+        (diags.len().max(1), failures, diags.len() - failures)
+    };
+
+    let (mut tests, mut failures, mut errors) = (0, 0, 0);
+    for diags in by_file.values() {
+        let (t, f, e) = count(diags);
+        tests += t;
+        failures += f;
+        errors += e;
+    }
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites name=\"move-clippy\" tests=\"{tests}\" failures=\"{failures}\" errors=\"{errors}\">\n"
+    ));
+    for (file, diags) in &by_file {
+        let file = xml_escape(file);
+        let (t, f, e) = count(diags);
+        xml.push_str(&format!(
+            "  <testsuite name=\"{file}\" tests=\"{t}\" failures=\"{f}\" errors=\"{e}\">\n"
+        ));
+        if diags.is_empty() {
+            xml.push_str(&format!(
+                "    <testcase name=\"move-clippy\" classname=\"{file}\"/>\n"
+            ));
+        }
+        for d in diags {
+            let lint = xml_escape(&d.lint);
+            let message = xml_escape(&d.message);
+            let tag = if is_failure(d) { "failure" } else { "error" };
+            xml.push_str(&format!(
+                "    <testcase name=\"{lint} ({}:{})\" classname=\"{file}\">\n",
+                d.row, d.column
+            ));
+            xml.push_str(&format!(
+                "      <{tag} message=\"{message}\" type=\"{lint}\">{file}:{}:{}: {}: {lint}: {message}</{tag}>\n",
+                d.row,
+                d.column,
+                xml_escape(&d.level)
+            ));
+            xml.push_str("    </testcase>\n");
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>");
+    xml
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\n' => out.push_str("&#10;"),
+            '\r' => out.push_str("&#13;"),
+            '\t' => out.push_str("&#9;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// ANSI styling for `--format rich`: off when `NO_COLOR` is set (and non-empty) or stdout is
+/// not a terminal.
+fn rich_color_enabled() -> bool {
+    use std::io::IsTerminal;
+
+    std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty()) && std::io::stdout().is_terminal()
+}
+
+/// Paths listed in a `--files-from` manifest (`-` reads stdin).
+///
+/// One path per line; blank lines and `#` comments are ignored, as are non-`.move` paths,
+/// so `git diff --name-only` output can be piped in directly.
+fn read_files_from(manifest: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let contents = if manifest == Path::new("-") {
+        let mut buf = String::new();
+        std::io::stdin().read_to_string(&mut buf)?;
+        buf
+    } else {
+        std::fs::read_to_string(manifest)
+            .with_context(|| format!("failed to read --files-from {}", manifest.display()))?
+    };
+
+    let paths = parse_file_list(&contents);
+    if let Some(missing) = paths.iter().find(|p| !p.exists()) {
+        anyhow::bail!(
+            "file listed in --files-from does not exist: {}",
+            missing.display()
+        );
+    }
+    Ok(paths)
+}
+
+fn parse_file_list(contents: &str) -> Vec<PathBuf> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(PathBuf::from)
+        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("move"))
+        .collect()
 }
 
-fn collect_move_files(paths: &[PathBuf], skip_tests: bool) -> anyhow::Result<Vec<PathBuf>> {
+fn collect_move_files(
+    paths: &[PathBuf],
+    skip_tests: bool,
+    no_ignore: bool,
+) -> anyhow::Result<Vec<PathBuf>> {
     let mut out = Vec::new();
     for path in paths {
-        collect_from_path(path, &mut out, skip_tests)?;
+        collect_from_path(path, &mut out, skip_tests, no_ignore)?;
     }
 
     out.sort();
@@ -1249,12 +2973,17 @@ fn collect_move_files(paths: &[PathBuf], skip_tests: bool) -> anyhow::Result<Vec
     Ok(out)
 }
 
-fn collect_from_path(path: &Path, out: &mut Vec<PathBuf>, skip_tests: bool) -> anyhow::Result<()> {
+fn collect_from_path(
+    path: &Path,
+    out: &mut Vec<PathBuf>,
+    skip_tests: bool,
+    no_ignore: bool,
+) -> anyhow::Result<()> {
     let meta = std::fs::metadata(path)?;
     if meta.is_dir() {
-        collect_from_dir(path, out, skip_tests)
+        collect_from_dir(path, out, skip_tests, no_ignore)
     } else {
-        // Skip test files if requested
+        // Explicit file paths bypass ignore rules; only --skip-tests applies.
         if skip_tests && is_test_file(path) {
             return Ok(());
         }
@@ -1263,38 +2992,68 @@ fn collect_from_path(path: &Path, out: &mut Vec<PathBuf>, skip_tests: bool) -> a
     }
 }
 
-fn collect_from_dir(dir: &Path, out: &mut Vec<PathBuf>, skip_tests: bool) -> anyhow::Result<()> {
-    for entry in std::fs::read_dir(dir)? {
-        let entry = entry?;
-        let path = entry.path();
-
-        if path.is_dir() {
-            if should_skip_dir(&path) {
-                continue;
+/// Recursively collect `.move` files under `dir`.
+///
+/// Honors `.gitignore`/`.ignore` unless `no_ignore` is set; the `.git`/`target`/`build` skip
+/// set applies either way. Symlinks are followed, and symlink loops are skipped.
+fn collect_from_dir(
+    dir: &Path,
+    out: &mut Vec<PathBuf>,
+    skip_tests: bool,
+    no_ignore: bool,
+) -> anyhow::Result<()> {
+    let walker = ignore::WalkBuilder::new(dir)
+        .standard_filters(!no_ignore)
+        // Only ignore files decide what is skipped; hidden directories were always walked.
+        .hidden(false)
+        .follow_links(true)
+        .filter_entry(move |entry| {
+            // Never filter the directory the user asked for.
+            if entry.depth() == 0 || !entry.file_type().is_some_and(|t| t.is_dir()) {
+                return true;
             }
-            // Skip tests/ directories if requested
-            if skip_tests
-                && let Some(name) = path.file_name().and_then(|s| s.to_str())
-                && name == "tests"
-            {
-                continue;
+            let path = entry.path();
+            if should_skip_dir(path) {
+                return false;
             }
-            collect_from_dir(&path, out, skip_tests)?;
+            // Skip tests/ directories if requested
+            !(skip_tests && path.file_name().and_then(|s| s.to_str()) == Some("tests"))
+        })
+        .build();
+
+    for entry in walker {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err) if is_symlink_loop(&err) => continue,
+            Err(err) => return Err(err.into()),
+        };
+        if !entry.file_type().is_some_and(|t| t.is_file()) {
             continue;
         }
 
+        let path = entry.path();
         if path.extension().and_then(|e| e.to_str()) == Some("move") {
             // Skip test files if requested
-            if skip_tests && is_test_file(&path) {
+            if skip_tests && is_test_file(path) {
                 continue;
             }
-            out.push(path);
+            out.push(path.to_path_buf());
         }
     }
 
     Ok(())
 }
 
+fn is_symlink_loop(err: &ignore::Error) -> bool {
+    match err {
+        ignore::Error::Loop { .. } => true,
+        ignore::Error::WithPath { err, .. }
+        | ignore::Error::WithDepth { err, .. }
+        | ignore::Error::WithLineNumber { err, .. } => is_symlink_loop(err),
+        _ => false,
+    }
+}
+
 fn should_skip_dir(path: &Path) -> bool {
     let Some(name) = path.file_name().and_then(|s| s.to_str()) else {
         return false;
@@ -1354,6 +3113,208 @@ fn infer_start_dir(args: &LintArgs) -> anyhow::Result<PathBuf> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_diagnostic_budget_counts_suppressed() {
+        let mut budget = DiagnosticBudget::new(Some(2));
+        let shown: Vec<bool> = (0..5).map(|_| budget.take()).collect();
+        assert_eq!(shown, [true, true, false, false, false]);
+        assert_eq!(
+            budget.notice().as_deref(),
+            Some("... 3 more diagnostics suppressed (--max-diagnostics 2)")
+        );
+
+        let mut unlimited = DiagnosticBudget::new(None);
+        assert!((0..5).all(|_| unlimited.take()));
+        assert_eq!(unlimited.notice(), None);
+    }
+
+    #[test]
+    fn test_skipped_fixes_summary_names_each_reason() {
+        let mut skipped = SkippedFixes {
+            unsafe_only: 2,
+            ..SkippedFixes::default()
+        };
+        skipped.merge(SkippedFixes {
+            placeholders: 1,
+            overlapping: 1,
+            ..SkippedFixes::default()
+        });
+        assert_eq!(
+            skipped.summary(),
+            vec![
+                "2 fix(es) skipped (use --unsafe-fixes to apply)",
+                "1 fix(es) skipped (placeholders need manual edits)",
+                "1 fix(es) skipped (overlap another fix; run --fix again)",
+            ]
+        );
+        assert!(SkippedFixes::default().summary().is_empty());
+    }
+
+    #[test]
+    fn test_is_watched_path() {
+        let roots = [PathBuf::from("/work/pkg")];
+        let watched = |p: &str, skip_tests| is_watched_path(Path::new(p), &roots, skip_tests);
+
+        assert!(watched("/work/pkg/sources/a.move", false));
+        assert!(watched("/work/pkg/Move.toml", false));
+        assert!(!watched("/work/pkg/README.md", false));
+        assert!(!watched("/work/pkg/build/pkg/sources/a.move", false));
+        assert!(!watched("/work/pkg/sources/.a.move.swp", false));
+
+        assert!(watched("/work/pkg/tests/a_tests.move", false));
+        assert!(!watched("/work/pkg/tests/a_tests.move", true));
+
+        // Skip rules only apply below the watched root
+        let roots = [PathBuf::from("/build/pkg")];
+        assert!(is_watched_path(
+            Path::new("/build/pkg/sources/a.move"),
+            &roots,
+            false
+        ));
+    }
+
+    #[test]
+    fn test_init_config_refuses_to_overwrite_without_force() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = init_config(dir.path(), false).unwrap();
+        std::fs::write(&path, "[lints]\npreview = true\n").unwrap();
+
+        assert!(init_config(dir.path(), false).is_err());
+        assert!(
+            std::fs::read_to_string(&path)
+                .unwrap()
+                .contains("preview = true")
+        );
+
+        init_config(dir.path(), true).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            config::config_template()
+        );
+        assert_eq!(config::find_config_file(dir.path()), Some(path));
+
+        // A hidden config also blocks a plain run.
+        let other = tempfile::tempdir().unwrap();
+        std::fs::write(other.path().join(config::HIDDEN_CONFIG_FILE_NAME), "").unwrap();
+        assert!(init_config(other.path(), false).is_err());
+    }
+
+    #[test]
+    fn test_config_check_continues_past_unparsable_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let sub = dir.path().join("sub");
+        std::fs::create_dir(&sub).unwrap();
+        std::fs::write(
+            dir.path().join(config::DEFAULT_CONFIG_FILE_NAME),
+            "[lints\n",
+        )
+        .unwrap();
+        std::fs::write(
+            sub.join(config::DEFAULT_CONFIG_FILE_NAME),
+            "preview = = true\n",
+        )
+        .unwrap();
+
+        // Both files are reported instead of bailing on the nearest one.
+        let code = config_check(None, &sub).unwrap();
+        assert_eq!(format!("{code:?}"), format!("{:?}", ExitCode::FAILURE));
+    }
+
+    #[test]
+    fn test_explain_category_lists_every_lint_in_category() {
+        let out = explain_category("security").unwrap();
+        let security: Vec<_> = unified::unified_registry()
+            .descriptors()
+            .filter(|d| d.category == LintCategory::Security)
+            .collect();
+        assert!(!security.is_empty());
+        for d in &security {
+            assert!(
+                out.contains(&format!("name: {}\n", d.name)),
+                "missing {}",
+                d.name
+            );
+        }
+        assert_eq!(out.matches("name: ").count(), security.len());
+        assert!(!out.contains("category: style"));
+        assert!(explain_category("securty").is_err());
+    }
+
+    #[test]
+    fn test_parse_file_list() {
+        let list = "sources/a.move\n\n# changed files\n  sources/b.move  \nREADME.md\nMove.toml\n";
+        assert_eq!(
+            parse_file_list(list),
+            vec![
+                PathBuf::from("sources/a.move"),
+                PathBuf::from("sources/b.move")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_files_from_rejects_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let manifest = dir.path().join("files.txt");
+        std::fs::write(&manifest, "does/not/exist.move\n").unwrap();
+        let err = read_files_from(&manifest).unwrap_err();
+        assert!(err.to_string().contains("does/not/exist.move"));
+    }
+
+    #[test]
+    fn test_parse_triage_date_covers_whole_day() {
+        let since = parse_triage_date("--since", "2025-03-07", false).unwrap();
+        let until = parse_triage_date("--until", "2025-03-07", true).unwrap();
+        assert_eq!(since.to_rfc3339(), "2025-03-07T00:00:00+00:00");
+        assert_eq!(until.to_rfc3339(), "2025-03-07T23:59:59.999999999+00:00");
+
+        let err = parse_triage_date("--since", "03/07/2025", false).unwrap_err();
+        assert!(err.to_string().contains("invalid --since date 03/07/2025"));
+    }
+
+    #[test]
+    fn test_parse_rulelist() {
+        let aliases = std::collections::HashMap::from([(
+            "abilities".to_string(),
+            "abilities_order".to_string(),
+        )]);
+        let list = parse_rulelist(
+            "# curated for CI
+abilities
+
+  empty_vector_literal  # keep
+-modern_module_syntax
+",
+            "lints.txt",
+            &aliases,
+        )
+        .unwrap();
+        assert_eq!(
+            list,
+            Rulelist {
+                only: vec![
+                    "abilities_order".to_string(),
+                    "empty_vector_literal".to_string()
+                ],
+                skip: vec!["modern_module_syntax".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_rulelist_reports_line_of_unknown_lint() {
+        let err = parse_rulelist(
+            "abilities_order
+# comment
+no_such_lint
+",
+            "lints.txt",
+            &std::collections::HashMap::new(),
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "lints.txt:3: unknown lint: no_such_lint");
+    }
+
     #[test]
     fn test_is_test_file_tests_directory() {
         assert!(is_test_file(Path::new("/project/tests/my_test.move")));
@@ -1379,6 +3340,508 @@ mod tests {
         assert!(!is_test_file(Path::new("/project/src/contest.move")));
     }
 
+    #[test]
+    fn test_patch_path_is_relative_to_repo_root() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path().join("repo");
+        std::fs::create_dir_all(repo.join(".git")).unwrap();
+        std::fs::create_dir_all(repo.join("pkg/sources")).unwrap();
+        let file = repo.join("pkg/sources/m.move");
+        std::fs::write(&file, "module a::m;\n").unwrap();
+
+        // From a subdirectory, the path is still relative to the repository root.
+        let cwd = repo.join("pkg");
+        assert_eq!(
+            patch_path(&file, &cwd).unwrap(),
+            PathBuf::from("pkg/sources/m.move")
+        );
+        assert_eq!(
+            patch_path(&cwd.join("sources/../sources/m.move"), &cwd).unwrap(),
+            PathBuf::from("pkg/sources/m.move")
+        );
+
+        let outside = dir.path().join("other.move");
+        std::fs::write(&outside, "module a::o;\n").unwrap();
+        assert!(patch_path(&outside, &cwd).is_err());
+    }
+
+    #[test]
+    fn test_collect_move_files_honors_gitignore() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        // An empty `.git` directory is enough for `.gitignore` to apply.
+        std::fs::create_dir_all(root.join(".git")).unwrap();
+        std::fs::write(root.join(".gitignore"), "vendor/\n").unwrap();
+        for sub in ["sources", "vendor", "build"] {
+            std::fs::create_dir_all(root.join(sub)).unwrap();
+            std::fs::write(root.join(sub).join("m.move"), "module a::m;\n").unwrap();
+        }
+        let roots = [root.to_path_buf()];
+
+        let files = collect_move_files(&roots, false, false).unwrap();
+        assert_eq!(files, vec![root.join("sources/m.move")]);
+
+        let files = collect_move_files(&roots, false, true).unwrap();
+        assert_eq!(
+            files,
+            vec![root.join("sources/m.move"), root.join("vendor/m.move")]
+        );
+
+        // Explicit file paths are never filtered by ignore rules.
+        let explicit = [root.join("vendor/m.move")];
+        let files = collect_move_files(&explicit, false, false).unwrap();
+        assert_eq!(files, vec![root.join("vendor/m.move")]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_collect_move_files_survives_symlink_loop() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("sources")).unwrap();
+        std::fs::write(root.join("sources/m.move"), "module a::m;\n").unwrap();
+        std::os::unix::fs::symlink(root, root.join("sources/loop")).unwrap();
+
+        let files = collect_move_files(&[root.to_path_buf()], false, false).unwrap();
+        assert_eq!(files, vec![root.join("sources/m.move")]);
+    }
+
+    #[test]
+    fn test_per_file_report_path_mirrors_source_layout() {
+        let dir = Path::new("reports");
+        assert_eq!(
+            per_file_report_path(dir, Path::new("sources/foo.move"), OutputFormat::Json),
+            PathBuf::from("reports/sources/foo.move.json")
+        );
+        assert_eq!(
+            per_file_report_path(dir, Path::new("./sources/foo.move"), OutputFormat::Pretty),
+            PathBuf::from("reports/sources/foo.move.txt")
+        );
+        assert_eq!(
+            per_file_report_path(dir, Path::new("../pkg/foo.move"), OutputFormat::Github),
+            PathBuf::from("reports/__up__/pkg/foo.move.txt")
+        );
+        assert_ne!(
+            per_file_report_path(dir, Path::new("../a/x.move"), OutputFormat::Json),
+            per_file_report_path(dir, Path::new("a/x.move"), OutputFormat::Json)
+        );
+    }
+
+    #[test]
+    fn test_write_per_file_reports_rejects_colliding_paths() {
+        let tmp = tempfile::tempdir().unwrap();
+        let files = vec![
+            PathBuf::from("../a/x.move"),
+            PathBuf::from("__up__/a/x.move"),
+        ];
+        let err = write_per_file_reports(tmp.path(), &files, &[], OutputFormat::Json, false, false)
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("would both be written to"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_write_per_file_reports() {
+        let tmp = tempfile::tempdir().unwrap();
+        let out_dir = tmp.path().join("reports");
+        let files = vec![
+            PathBuf::from("sources/a.move"),
+            PathBuf::from("sources/nested/b.move"),
+        ];
+        let diags = vec![JsonDiagnostic {
+            file: "sources/a.move".to_string(),
+            row: 3,
+            column: 5,
+            end_row: None,
+            end_column: None,
+            help: None,
+            level: "warning".to_string(),
+            lint: "abilities_order".to_string(),
+            message: "msg".to_string(),
+            notes: Vec::new(),
+            labels: Vec::new(),
+            suggestions: Vec::new(),
+            occurrences: Vec::new(),
+        }];
+
+        let written =
+            write_per_file_reports(&out_dir, &files, &diags, OutputFormat::Json, false, false)
+                .unwrap();
+        assert_eq!(written, 2);
+
+        let a = std::fs::read_to_string(out_dir.join("sources/a.move.json")).unwrap();
+        let parsed: Vec<JsonDiagnostic> = serde_json::from_str(&a).unwrap();
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].lint, "abilities_order");
+        assert_eq!(parsed[0].row, 3);
+
+        let b = std::fs::read_to_string(out_dir.join("sources/nested/b.move.json")).unwrap();
+        let parsed: Vec<JsonDiagnostic> = serde_json::from_str(&b).unwrap();
+        assert!(parsed.is_empty());
+
+        let skip_dir = tmp.path().join("skip");
+        let written =
+            write_per_file_reports(&skip_dir, &files, &diags, OutputFormat::Pretty, true, false)
+                .unwrap();
+        assert_eq!(written, 1);
+        let a = std::fs::read_to_string(skip_dir.join("sources/a.move.txt")).unwrap();
+        assert_eq!(a, "sources/a.move:3:5: warning: abilities_order: msg\n");
+        assert!(!skip_dir.join("sources/nested/b.move.txt").exists());
+    }
+
+    #[test]
+    fn test_min_level_error_drops_warnings() {
+        let registry = LintRegistry::default_rules_filtered(
+            &[
+                "prefer_to_string".to_string(),
+                "abilities_order".to_string(),
+            ],
+            &[],
+            &[],
+            false,
+            false,
+        )
+        .unwrap();
+        let settings = LintSettings::default().with_config_levels(
+            [("prefer_to_string".to_string(), LintLevel::Error)]
+                .into_iter()
+                .collect(),
+        );
+        let engine = LintEngine::new_with_settings(registry, settings);
+
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("m.move");
+        std::fs::write(
+            &path,
+            "module 0x1::m {\n    use std::string::utf8;\n    public struct S has drop, copy {}\n}\n",
+        )
+        .unwrap();
+
+        let (count, _, all) = lint_file_json(&engine, &path, None, false, false).unwrap();
+        assert_eq!(count, 2, "{all:?}");
+
+        let (count, has_error, errors_only) =
+            lint_file_json(&engine, &path, Some(LintLevel::Error), false, false).unwrap();
+        assert_eq!(count, 1);
+        assert!(has_error);
+        assert!(errors_only.iter().all(|d| d.level == "error"));
+        assert_eq!(errors_only[0].lint, "prefer_to_string");
+    }
+
+    #[test]
+    fn test_min_level_error_keeps_denied_lints() {
+        let registry = LintRegistry::default_rules_filtered(
+            &[
+                "prefer_to_string".to_string(),
+                "abilities_order".to_string(),
+            ],
+            &[],
+            &[],
+            false,
+            false,
+        )
+        .unwrap();
+        let settings = LintSettings::default().with_config_levels(
+            [("abilities_order".to_string(), LintLevel::Deny)]
+                .into_iter()
+                .collect(),
+        );
+        let engine = LintEngine::new_with_settings(registry, settings);
+
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("m.move");
+        std::fs::write(
+            &path,
+            "module 0x1::m {\n    use std::string::utf8;\n    public struct S has drop, copy {}\n}\n",
+        )
+        .unwrap();
+
+        let (count, has_error, kept) =
+            lint_file_json(&engine, &path, Some(LintLevel::Error), false, false).unwrap();
+        assert_eq!(count, 1, "{kept:?}");
+        assert!(has_error, "a denied lint must still fail the run");
+        assert_eq!(kept[0].lint, "abilities_order");
+    }
+
+    #[test]
+    fn test_unparsable_file_is_reported_and_run_continues() {
+        let engine =
+            LintEngine::new_with_settings(LintRegistry::default_rules(), LintSettings::default());
+        let tmp = tempfile::tempdir().unwrap();
+        let broken = tmp.path().join("broken.move");
+        std::fs::write(&broken, "@@@ ??? ;;;\n").unwrap();
+        let fine = tmp.path().join("fine.move");
+        std::fs::write(&fine, "module 0x1::m {\n    public fun f(): u64 { 1 }\n}\n").unwrap();
+
+        let (count, has_error, diags) =
+            lint_file_json(&engine, &broken, None, false, false).unwrap();
+        assert_eq!(count, 1, "{diags:?}");
+        assert!(has_error, "a file that does not parse fails the run");
+        assert_eq!(diags[0].lint, "parse_error");
+
+        assert!(lint_file_json(&engine, &fine, None, false, false).is_ok());
+    }
+
+    #[test]
+    fn test_json_full_span_emits_span_end() {
+        let registry = LintRegistry::default_rules_filtered(
+            &["abilities_order".to_string()],
+            &[],
+            &[],
+            false,
+            false,
+        )
+        .unwrap();
+        let engine = LintEngine::new_with_settings(registry, LintSettings::default());
+        let source = "module 0x1::m {\n    public struct S has drop, copy {}\n}\n";
+        let diags = engine.lint_source(source).unwrap();
+        let diag = diags
+            .iter()
+            .find(|d| d.lint.name == "abilities_order")
+            .expect("abilities_order should fire");
+        assert_ne!(
+            diag.span.start, diag.span.end,
+            "span should cover several tokens"
+        );
+
+        let full = JsonDiagnostic::from_diagnostic(diag, "m.move".to_string(), true, false);
+        assert_eq!(full.row, diag.span.start.row);
+        assert_eq!(full.column, diag.span.start.column);
+        assert_eq!(full.end_row, Some(diag.span.end.row));
+        assert_eq!(full.end_column, Some(diag.span.end.column));
+        assert_eq!(full.help, diag.help);
+        let value = serde_json::to_value(&full).unwrap();
+        assert_eq!(value["end_column"], diag.span.end.column);
+
+        // Without the flag the schema is unchanged.
+        let start_only = JsonDiagnostic::from_diagnostic(diag, "m.move".to_string(), false, false);
+        let value = serde_json::to_value(&start_only).unwrap();
+        assert!(value.get("end_row").is_none());
+        assert!(value.get("end_column").is_none());
+        assert!(value.get("help").is_none());
+    }
+
+    #[test]
+    fn test_json_diagnostics_import_with_and_without_spans() {
+        let json = r#"[
+            {"file": "a.move", "row": 3, "column": 5, "level": "warning",
+             "lint": "abilities_order", "message": "reorder"},
+            {"file": "b.move", "row": 7, "column": 1, "end_row": 9, "end_column": 2,
+             "level": "error", "lint": "prefer_to_string", "message": "use to_string",
+             "help": "Call `to_string()`"}
+        ]"#;
+        let diags: Vec<JsonDiagnostic> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(diags[0].row, 3);
+        assert_eq!(diags[0].end_row, None);
+        assert_eq!(diags[0].help, None);
+
+        assert_eq!(diags[1].row, 7);
+        assert_eq!((diags[1].end_row, diags[1].end_column), (Some(9), Some(2)));
+        assert_eq!(diags[1].help.as_deref(), Some("Call `to_string()`"));
+    }
+
+    #[test]
+    fn test_json_include_suggestions() {
+        let registry = LintRegistry::default_rules_filtered(
+            &["empty_vector_literal".to_string()],
+            &[],
+            &[],
+            false,
+            false,
+        )
+        .unwrap();
+        let engine = LintEngine::new_with_settings(registry, LintSettings::default());
+        let source = "module 0x1::m {\n    fun f(): vector<u64> { vector::empty() }\n}\n";
+        let diags = engine.lint_source(source).unwrap();
+        let diag = diags
+            .iter()
+            .find(|d| d.lint.name == "empty_vector_literal")
+            .expect("empty_vector_literal should fire");
+
+        let with = JsonDiagnostic::from_diagnostic(diag, "m.move".to_string(), false, true);
+        let value = serde_json::to_value(&with).unwrap();
+        assert_eq!(value["suggestions"][0]["replacement"], "vector[]");
+        assert_eq!(
+            value["suggestions"][0]["applicability"],
+            "machine-applicable"
+        );
+
+        // Without the flag the schema is unchanged.
+        let without = JsonDiagnostic::from_diagnostic(diag, "m.move".to_string(), false, false);
+        let value = serde_json::to_value(&without).unwrap();
+        assert!(value.get("suggestions").is_none());
+    }
+
+    #[test]
+    fn test_dedup_groups_only_collapses_across_files() {
+        let items = vec![
+            ("a.move", "lint_a", "same"),
+            ("a.move", "lint_b", "local"),
+            ("b.move", "lint_a", "same"),
+            ("a.move", "lint_b", "local"),
+            ("c.move", "lint_a", "same"),
+        ];
+        let groups = dedup_groups(items, |&(file, lint, message)| (lint, message, file));
+        let files: Vec<Vec<&str>> = groups
+            .iter()
+            .map(|group| group.iter().map(|item| item.0).collect())
+            .collect();
+        assert_eq!(
+            files,
+            vec![
+                vec!["a.move", "b.move", "c.move"],
+                vec!["a.move"],
+                vec!["a.move"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_level_summary_counts_by_level() {
+        let record = |level: &str| JsonDiagnostic {
+            file: "a.move".to_string(),
+            row: 1,
+            column: 1,
+            end_row: None,
+            end_column: None,
+            help: None,
+            level: level.to_string(),
+            lint: "abilities_order".to_string(),
+            message: String::new(),
+            notes: Vec::new(),
+            labels: Vec::new(),
+            suggestions: Vec::new(),
+            occurrences: Vec::new(),
+        };
+        let records = vec![record("error"), record("warning"), record("warning")];
+
+        let summary = LevelSummary::from_records(&records, 4);
+        assert_eq!(
+            summary,
+            LevelSummary {
+                error: 1,
+                warn: 2,
+                note: 0,
+                files: 4,
+            }
+        );
+        assert_eq!(
+            summary.render(),
+            "summary: 1 error, 2 warning, 0 note across 4 files"
+        );
+
+        let report = JsonReport {
+            diagnostics: &records,
+            summary,
+        };
+        let value = serde_json::to_value(&report).unwrap();
+        assert_eq!(value["diagnostics"].as_array().unwrap().len(), 3);
+        assert_eq!(value["summary"]["warn"], 2);
+        assert_eq!(value["summary"]["files"], 4);
+    }
+
+    #[test]
+    fn test_render_checkstyle_matches_golden() {
+        let record = |file: &str, row, level: &str, lint: &str, message: &str| JsonDiagnostic {
+            file: file.to_string(),
+            row,
+            column: 5,
+            end_row: None,
+            end_column: None,
+            help: None,
+            level: level.to_string(),
+            lint: lint.to_string(),
+            message: message.to_string(),
+            notes: Vec::new(),
+            labels: Vec::new(),
+            suggestions: Vec::new(),
+            occurrences: Vec::new(),
+        };
+        let records = vec![
+            record(
+                "sources/a&b.move",
+                3,
+                "warning",
+                "abilities_order",
+                "Abilities should be ordered as `key, copy, drop, store`",
+            ),
+            record(
+                "sources/a&b.move",
+                9,
+                "error",
+                "prefer_vector_methods",
+                "Prefer `v.push_back(x)` over \"vector::push_back(&mut v, x)\" for <T>",
+            ),
+            record(
+                "sources/c.move",
+                1,
+                "warning",
+                "while_true_to_loop",
+                "Prefer `loop`",
+            ),
+        ];
+
+        assert_eq!(
+            render_checkstyle(&records),
+            include_str!("../tests/fixtures/checkstyle/expected.xml").trim_end()
+        );
+        assert_eq!(
+            render_checkstyle(std::iter::empty()),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<checkstyle version=\"4.3\">\n</checkstyle>"
+        );
+    }
+
+    #[test]
+    fn test_render_junit_matches_golden() {
+        let record = |file: &str, row, level: &str, lint: &str, message: &str| JsonDiagnostic {
+            file: file.to_string(),
+            row,
+            column: 5,
+            end_row: None,
+            end_column: None,
+            help: None,
+            level: level.to_string(),
+            lint: lint.to_string(),
+            message: message.to_string(),
+            notes: Vec::new(),
+            labels: Vec::new(),
+            suggestions: Vec::new(),
+            occurrences: Vec::new(),
+        };
+        let records = vec![
+            record(
+                "sources/a&b.move",
+                3,
+                "warning",
+                "abilities_order",
+                "Abilities should be ordered as `key, copy, drop, store`",
+            ),
+            record(
+                "sources/a&b.move",
+                9,
+                "error",
+                "prefer_vector_methods",
+                "Prefer `v.push_back(x)` over \"vector::push_back(&mut v, x)\" for <T>",
+            ),
+        ];
+        let files = ["sources/a&b.move", "sources/clean.move"];
+
+        assert_eq!(
+            render_junit(files, &records, false),
+            include_str!("../tests/fixtures/junit/expected.xml").trim_end()
+        );
+        let denied = render_junit(files, &records, true);
+        assert!(denied.contains("tests=\"3\" failures=\"2\" errors=\"0\""));
+        assert!(!denied.contains("<error"));
+        assert_eq!(
+            render_junit(std::iter::empty(), std::iter::empty(), false),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"move-clippy\" tests=\"0\" failures=\"0\" errors=\"0\">\n</testsuites>"
+        );
+    }
+
     #[test]
     fn test_should_skip_dir() {
         assert!(should_skip_dir(Path::new(".git")));
@@ -1,6 +1,11 @@
+use crate::diagnostics::Position;
+use crate::error::Error;
 use anyhow::{Context, Result};
 use std::borrow::Cow;
-use tree_sitter::{Language, Parser, Tree};
+use tree_sitter::{InputEdit, Language, Node, Parser, Point, Tree};
+
+/// Longest excerpt, in characters, carried by [`Error::ParseError`].
+const MAX_EXCERPT_CHARS: usize = 40;
 
 fn move_language() -> Language {
     tree_sitter_move::language()
@@ -87,6 +92,19 @@ fn mask_lint_directive_lines(source: &str) -> Cow<'_, str> {
 }
 
 pub fn parse_source(source: &str) -> Result<Tree> {
+    parse_source_incremental(source, None, &[])
+}
+
+/// Parse `source`, reusing `old_tree` for the regions `edits` did not touch.
+///
+/// `old_tree` must be the tree for the source before `edits` were applied, and `edits` must be
+/// in the order they were applied to that source. Directive masking preserves byte offsets, so
+/// the same edits line up with the masked text tree-sitter actually sees.
+pub fn parse_source_incremental(
+    source: &str,
+    old_tree: Option<&Tree>,
+    edits: &[InputEdit],
+) -> Result<Tree> {
     let mut parser = Parser::new();
     parser
         .set_language(move_language())
@@ -94,9 +112,107 @@ pub fn parse_source(source: &str) -> Result<Tree> {
 
     let masked = mask_lint_directive_lines(source);
 
-    parser
-        .parse(masked.as_ref(), None)
-        .context("tree-sitter failed to parse source")
+    let old_tree = old_tree.map(|tree| {
+        let mut tree = tree.clone();
+        for edit in edits {
+            tree.edit(edit);
+        }
+        tree
+    });
+
+    let tree = parser
+        .parse(masked.as_ref(), old_tree.as_ref())
+        .context("tree-sitter failed to parse source")?;
+
+    // A tree with some valid items is still linted; only give up when nothing parsed.
+    let root = tree.root_node();
+    let mut cursor = root.walk();
+    let unrecoverable = root.is_error()
+        || (root.has_error()
+            && root
+                .named_children(&mut cursor)
+                .all(|child| child.is_error()));
+    if unrecoverable && let Some(err) = first_syntax_error(&tree, source) {
+        return Err(err.into());
+    }
+
+    Ok(tree)
+}
+
+/// The first `ERROR` or `MISSING` node in `tree`, as an [`Error::ParseError`].
+///
+/// Returns `None` when the tree parsed cleanly.
+pub fn first_syntax_error(tree: &Tree, source: &str) -> Option<Error> {
+    let node = first_error_node(tree.root_node())?;
+    let start = node.start_position();
+    let position = Position {
+        row: start.row + 1,
+        column: start.column + 1,
+    };
+
+    let excerpt = if node.is_missing() {
+        format!("missing {}", node.kind())
+    } else {
+        let text = source.get(node.start_byte()..).unwrap_or_default();
+        let line = text.lines().next().unwrap_or_default().trim_end();
+        line.chars().take(MAX_EXCERPT_CHARS).collect()
+    };
+
+    Some(Error::parse_error(position, excerpt))
+}
+
+fn first_error_node(node: Node<'_>) -> Option<Node<'_>> {
+    if node.is_error() || node.is_missing() {
+        return Some(node);
+    }
+    if !node.has_error() {
+        return None;
+    }
+    let mut cursor = node.walk();
+    let children: Vec<_> = node.children(&mut cursor).collect();
+    children.into_iter().find_map(first_error_node)
+}
+
+/// Byte offset to tree-sitter `Point` (0-based row, byte column).
+fn point_at(source: &str, byte: usize) -> Point {
+    let before = &source.as_bytes()[..byte];
+    let row = before.iter().filter(|b| **b == b'\n').count();
+    let line_start = before
+        .iter()
+        .rposition(|b| *b == b'\n')
+        .map_or(0, |idx| idx + 1);
+    Point::new(row, byte - line_start)
+}
+
+/// Describe replacing `source[start_byte..end_byte]` with `replacement` as an [`InputEdit`].
+pub fn input_edit(
+    source: &str,
+    start_byte: usize,
+    end_byte: usize,
+    replacement: &str,
+) -> InputEdit {
+    let start_position = point_at(source, start_byte);
+    let old_end_position = point_at(source, end_byte);
+
+    let new_end_position = match replacement.rfind('\n') {
+        Some(idx) => Point::new(
+            start_position.row + replacement.matches('\n').count(),
+            replacement.len() - idx - 1,
+        ),
+        None => Point::new(
+            start_position.row,
+            start_position.column + replacement.len(),
+        ),
+    };
+
+    InputEdit {
+        start_byte,
+        old_end_byte: end_byte,
+        new_end_byte: start_byte + replacement.len(),
+        start_position,
+        old_end_position,
+        new_end_position,
+    }
 }
 
 #[cfg(test)]
@@ -271,4 +387,61 @@ public fun demo() {
             "expected masking to prevent ERROR nodes for whitespace-heavy directives"
         );
     }
+
+    #[test]
+    fn first_syntax_error_points_at_the_typo() {
+        let src =
+            "module my_pkg::m {\n    fun f(): u64 {\n        let x = ;\n        1\n    }\n}\n";
+
+        // The rest of the module still parses, so linting carries on.
+        let tree = parse_source(src).expect("partial tree should still be returned");
+        let err = first_syntax_error(&tree, src).expect("expected a syntax error");
+        let Error::ParseError { position, .. } = err else {
+            panic!("expected ParseError, got {err:?}");
+        };
+        assert_eq!(position.row, 3);
+
+        let clean = "module my_pkg::m {\n    fun f(): u64 { 1 }\n}\n";
+        let tree = parse_source(clean).expect("parse should succeed");
+        assert!(first_syntax_error(&tree, clean).is_none());
+    }
+
+    #[test]
+    fn unparseable_source_reports_position() {
+        let err = parse_source("@@@ ??? ;;;\n").expect_err("nothing here is Move");
+        let Some(Error::ParseError { position, excerpt }) = err.downcast_ref::<Error>() else {
+            panic!("expected ParseError, got {err:?}");
+        };
+        assert_eq!(position.row, 1);
+        assert!(!excerpt.is_empty());
+    }
+
+    #[test]
+    fn incremental_parse_matches_full_parse() {
+        let src =
+            "module my_pkg::m {\n    public fun a(): u64 { 1 }\n    public fun b(): u64 { 2 }\n}\n";
+        let old_tree = parse_source(src).expect("parse should succeed");
+
+        // Apply edits back to front, as the fixer does, so each edit's offsets stay valid.
+        let mut edited = src.to_string();
+        let mut edits = Vec::new();
+        for (from, to) in [("{ 2 }", "{\n        40 + 2\n    }"), ("a()", "alpha()")] {
+            let start = edited.find(from).expect("edit target");
+            let end = start + from.len();
+            edits.push(input_edit(&edited, start, end, to));
+            edited.replace_range(start..end, to);
+        }
+
+        let incremental =
+            parse_source_incremental(&edited, Some(&old_tree), &edits).expect("reparse");
+        let full = parse_source(&edited).expect("parse should succeed");
+        assert_eq!(
+            incremental.root_node().to_sexp(),
+            full.root_node().to_sexp()
+        );
+        assert_eq!(
+            incremental.root_node().end_position(),
+            full.root_node().end_position()
+        );
+    }
 }
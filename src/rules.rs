@@ -13,8 +13,9 @@ pub use conventions::AdminCapPositionLint;
 
 // Modernization lints
 pub use modernization::{
-    EqualityInAssertLint, ManualLoopIterationLint, ManualOptionCheckLint, ModernMethodSyntaxLint,
-    ModernModuleSyntaxLint, PreferVectorMethodsLint,
+    BoolComparisonLint, EqualityInAssertLint, ManualLoopIterationLint, ManualOptionCheckLint,
+    ManualVectorBuildLint, ModernMethodSyntaxLint, ModernModuleSyntaxLint, NeedlessBoolLint,
+    PreferVectorMethodsLint,
 };
 // REMOVED from modernization:
 // - WhileTrueToLoopLint, UnnecessaryPublicEntryLint, PublicMutTxContextLint (compiler-redundant)
@@ -22,8 +23,9 @@ pub use modernization::{
 
 // Security lints (audit-backed)
 pub use security::{
-    FreshAddressReuseLint, SuggestBalancedReceiptLint, SuggestCapabilityPatternLint,
-    SuggestCountedCapabilityLint, SuggestSequencedWitnessLint, SuspiciousOverflowCheckLint,
+    FreshAddressReuseLint, HardcodedAddressLint, ShadowedFrameworkAliasLint,
+    SuggestBalancedReceiptLint, SuggestCapabilityPatternLint, SuggestCountedCapabilityLint,
+    SuggestSequencedWitnessLint, SuspiciousOverflowCheckLint,
 };
 // REMOVED deprecated/superseded/obvious lints:
 // - StaleOraclePriceLint, SingleStepOwnershipTransferLint, UncheckedCoinSplitLint
@@ -34,9 +36,11 @@ pub use security::{
 
 // Style lints
 pub use style::{
-    AbilitiesOrderLint, ConstantNamingLint, DocCommentStyleLint, EmptyVectorLiteralLint,
-    ErrorConstNamingLint, ExplicitSelfAssignmentsLint, PreferToStringLint, RedundantSelfImportLint,
-    TypedAbortCodeLint, UnneededReturnLint,
+    AbilitiesOrderLint, ConstantAssertConditionLint, ConstantNamingLint, DocCommentStyleLint,
+    EmptyVectorLiteralLint, ErrorConstNamingLint, ExcessiveNestingLint,
+    ExplicitSelfAssignmentsLint, GlobalStorageOperatorLint, LoopWithLeadingBreakToWhileLint,
+    PreferToStringLint, RedundantBindingLint, RedundantSelfImportLint, TypedAbortCodeLint,
+    UnneededReturnLint,
 };
 // REMOVED: EventSuffixLint (not backed by Move Book)
 
@@ -9,8 +9,9 @@ use super::patterns::{
     parse_length_comparison,
 };
 use super::util::{
-    compact_ws, generate_method_call_fix, is_simple_ident, is_simple_receiver, parse_ref_ident,
-    parse_ref_mut_ident, slice, split_args, split_call, walk,
+    compact_ws, generate_method_call_fix, is_atomic_operand, is_simple_ident, is_simple_receiver,
+    negate_condition, parse_ref_ident, parse_ref_mut_ident, slice, split_args, split_call,
+    use_bindings, walk,
 };
 use crate::diagnostics::{Applicability, Suggestion};
 
@@ -66,6 +67,7 @@ fn generate_assert_eq_fix(assert_text: &str, condition: &str) -> Option<Suggesti
         message: "Replace with assert_eq!".to_string(),
         replacement,
         applicability: Applicability::MachineApplicable,
+        span: None,
     })
 }
 
@@ -119,7 +121,9 @@ impl LintRule for EqualityInAssertLint {
                         message: "Prefer `assert_eq!(a, b)` for clearer failure messages"
                             .to_string(),
                         help: Some("Use assert_eq! for better error messages".to_string()),
-                        suggestion,
+                        suggestions: suggestion.into_iter().collect(),
+                        notes: Vec::new(),
+                        labels: Vec::new(),
                     };
                     ctx.report_diagnostic_for_node(node, diagnostic);
                 }
@@ -199,6 +203,7 @@ fn generate_manual_option_fix(
         message: format!("Replace with {}.do! macro", var_name),
         replacement,
         applicability: Applicability::MaybeIncorrect,
+        span: None,
     })
 }
 
@@ -274,7 +279,9 @@ impl LintRule for ManualOptionCheckLint {
                             var_name
                         ),
                         help: Some("Use do! macro for cleaner option handling".to_string()),
-                        suggestion,
+                        suggestions: suggestion.into_iter().collect(),
+                        notes: Vec::new(),
+                        labels: Vec::new(),
                     };
                     ctx.report_diagnostic_for_node(node, diagnostic);
                 }
@@ -368,6 +375,7 @@ fn generate_manual_loop_fix(body_text: &str, iter_var: &str, vec_var: &str) -> O
         ),
         replacement,
         applicability: Applicability::MaybeIncorrect,
+        span: None,
     })
 }
 
@@ -443,7 +451,9 @@ impl LintRule for ManualLoopIterationLint {
                             "Use do_ref! macro for cleaner iteration. Note: You must manually remove `let mut {} = 0;` above this loop.",
                             iter_var
                         )),
-                        suggestion,
+                        suggestions: suggestion.into_iter().collect(),
+                        notes: Vec::new(),
+                        labels: Vec::new(),
                     };
                     ctx.report_diagnostic_for_node(node, diagnostic);
                 }
@@ -518,14 +528,17 @@ impl LintRule for ModernModuleSyntaxLint {
                         message: "Use Move 2024 module label syntax: `module pkg::mod;`"
                             .to_string(),
                         help: Some("Convert to label syntax".to_string()),
-                        suggestion: Some(Suggestion {
+                        suggestions: vec![Suggestion {
                             message: format!(
                                 "Convert `module {} {{ ... }}` to `{}`",
                                 module_path, replacement
                             ),
                             replacement,
                             applicability: Applicability::MachineApplicable,
-                        }),
+                            span: None,
+                        }],
+                        notes: Vec::new(),
+                        labels: Vec::new(),
                     };
 
                     // Check for suppression
@@ -600,6 +613,7 @@ impl LintRule for PreferVectorMethodsLint {
                         message: format!("Use method syntax: {}", replacement),
                         replacement,
                         applicability: Applicability::MachineApplicable,
+                        span: None,
                     })
                 } else {
                     None
@@ -612,7 +626,9 @@ impl LintRule for PreferVectorMethodsLint {
                     span: Span::from_range(node.range()),
                     message: format!("Prefer method syntax: `{receiver}.push_back(...)`"),
                     help: Some("Use method call syntax for cleaner code".to_string()),
-                    suggestion,
+                    suggestions: suggestion.into_iter().collect(),
+                    notes: Vec::new(),
+                    labels: Vec::new(),
                 };
                 ctx.report_diagnostic_for_node(node, diagnostic);
             } else if callee == "vector::length" {
@@ -633,6 +649,7 @@ impl LintRule for PreferVectorMethodsLint {
                         message: format!("Use method syntax: {}", replacement),
                         replacement,
                         applicability: Applicability::MachineApplicable,
+                        span: None,
                     })
                 } else {
                     None
@@ -645,7 +662,9 @@ impl LintRule for PreferVectorMethodsLint {
                     span: Span::from_range(node.range()),
                     message: format!("Prefer method syntax: `{receiver}.length()`"),
                     help: Some("Use method call syntax for cleaner code".to_string()),
-                    suggestion,
+                    suggestions: suggestion.into_iter().collect(),
+                    notes: Vec::new(),
+                    labels: Vec::new(),
                 };
                 ctx.report_diagnostic_for_node(node, diagnostic);
             }
@@ -753,6 +772,8 @@ impl LintRule for ModernMethodSyntaxLint {
     }
 
     fn check(&self, root: Node, source: &str, ctx: &mut LintContext<'_>) {
+        let module_bindings = framework_module_bindings(root, source);
+
         walk(root, &mut |node| {
             if node.kind() != "call_expression" {
                 return;
@@ -764,11 +785,20 @@ impl LintRule for ModernMethodSyntaxLint {
             };
 
             let callee = compact_ws(callee);
+            let Some((local_module, callee_fn)) = callee.split_once("::") else {
+                return;
+            };
+            // Resolve `use` aliases; a module name bound to a non-framework module is left
+            // alone, since method syntax would dispatch to a different function.
+            let callee_module = match module_bindings.get(local_module) {
+                Some(Some(module)) => module.as_str(),
+                Some(None) => return,
+                None => local_module,
+            };
 
             // Try to match against the extended allowlist
             for (module, func, method, expected_args) in KNOWN_METHOD_TRANSFORMS {
-                let pattern = format!("{}::{}", module, func);
-                if callee != pattern {
+                if callee_module != *module || callee_fn != *func {
                     continue;
                 }
 
@@ -804,6 +834,7 @@ impl LintRule for ModernMethodSyntaxLint {
                         message: format!("Use method syntax: {}", replacement),
                         replacement,
                         applicability: Applicability::MachineApplicable,
+                        span: None,
                     })
                 } else {
                     None
@@ -816,7 +847,9 @@ impl LintRule for ModernMethodSyntaxLint {
                     span: Span::from_range(node.range()),
                     message: format!("Prefer method syntax: `{}.{}(...)`", clean_receiver, method),
                     help: Some("Use method call syntax for cleaner code".to_string()),
-                    suggestion,
+                    suggestions: suggestion.into_iter().collect(),
+                    notes: Vec::new(),
+                    labels: Vec::new(),
                 };
                 ctx.report_diagnostic_for_node(node, diagnostic);
                 return;
@@ -825,6 +858,516 @@ impl LintRule for ModernMethodSyntaxLint {
     }
 }
 
+/// Local names bound by `use` declarations, mapped to the framework module they resolve
+/// to (`Some("coin")` for `use sui::coin as c;`) or `None` when the binding is not a
+/// `sui`/`std` module.
+fn framework_module_bindings(
+    root: Node,
+    source: &str,
+) -> std::collections::HashMap<String, Option<String>> {
+    let mut bindings = std::collections::HashMap::new();
+    walk(root, &mut |node| {
+        if node.kind() != "use_declaration" {
+            return;
+        }
+        for binding in use_bindings(slice(source, node)) {
+            let module = match binding.path.split("::").collect::<Vec<_>>().as_slice() {
+                ["sui" | "std" | "0x1" | "0x2", module] => Some((*module).to_string()),
+                _ => None,
+            };
+            bindings.insert(binding.name, module);
+        }
+    });
+    bindings
+}
+
+// ============================================================================
+// NeedlessBoolLint - Stable (Zero FP)
+// ============================================================================
+
+/// Detects `if` expressions whose branches are just the boolean literals.
+///
+/// # Example
+///
+/// ```move
+/// // bad!
+/// let ok = if (amount > 0) true else false;
+/// if (paused) { return false } else { return true }
+///
+/// // good!
+/// let ok = amount > 0;
+/// return !paused
+/// ```
+pub struct NeedlessBoolLint;
+
+static NEEDLESS_BOOL: LintDescriptor = LintDescriptor {
+    name: "needless_bool",
+    category: LintCategory::Modernization,
+    description: "Prefer `cond` over `if (cond) true else false` (and `!cond` for the inverse)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::safe("Replace the `if` with the condition or its negation"),
+    analysis: AnalysisKind::Syntactic,
+    gap: None,
+};
+
+impl LintRule for NeedlessBoolLint {
+    fn descriptor(&self) -> &'static LintDescriptor {
+        &NEEDLESS_BOOL
+    }
+
+    fn check(&self, root: Node, source: &str, ctx: &mut LintContext<'_>) {
+        walk(root, &mut |node| {
+            if node.kind() != "if_expression" {
+                return;
+            }
+            let (Some(condition), Some(then_branch), Some(else_branch)) = (
+                node.child_by_field_name("eb"),
+                node.child_by_field_name("et"),
+                node.child_by_field_name("ef"),
+            ) else {
+                return;
+            };
+
+            let Some((then_value, then_returns)) = bool_branch(slice(source, then_branch)) else {
+                return;
+            };
+            let Some((else_value, else_returns)) = bool_branch(slice(source, else_branch)) else {
+                return;
+            };
+            if then_value == else_value || then_returns != else_returns {
+                return;
+            }
+
+            let condition = slice(source, condition).trim();
+            if condition.is_empty() {
+                return;
+            }
+            let value = if then_value {
+                let in_operand = node.parent().is_some_and(|parent| {
+                    matches!(
+                        parent.kind(),
+                        "binary_expression" | "unary_expression" | "dot_expression"
+                    )
+                });
+                if in_operand && !is_atomic_operand(condition) {
+                    format!("({condition})")
+                } else {
+                    condition.to_string()
+                }
+            } else {
+                negate_condition(condition)
+            };
+            let replacement = if then_returns {
+                format!("return {value}")
+            } else {
+                value
+            };
+
+            let diagnostic = crate::diagnostics::Diagnostic {
+                lint: self.descriptor(),
+                level: ctx.settings().level_for(self.descriptor().name),
+                file: None,
+                span: Span::from_range(node.range()),
+                message: format!(
+                    "`if` returning boolean literals can be written as `{replacement}`"
+                ),
+                help: Some("Use the condition directly".to_string()),
+                suggestions: vec![Suggestion {
+                    message: format!("Replace with `{replacement}`"),
+                    replacement,
+                    applicability: Applicability::MachineApplicable,
+                    span: None,
+                }],
+                notes: Vec::new(),
+                labels: Vec::new(),
+            };
+            ctx.report_diagnostic_for_node(node, diagnostic);
+        });
+    }
+}
+
+/// The literal of a branch that is exactly `true`/`false`, optionally braced or `return`ed.
+///
+/// Returns `(value, returns)`. Anything else in the branch (calls, comments, a trailing `;`
+/// that would make the block unit) means the branch is not a plain literal.
+fn bool_branch(text: &str) -> Option<(bool, bool)> {
+    let text = text.trim();
+    let inner = text
+        .strip_prefix('{')
+        .and_then(|t| t.strip_suffix('}'))
+        .map_or(text, str::trim);
+    let (value, returns) = match inner.strip_prefix("return") {
+        Some(rest) if rest.starts_with(char::is_whitespace) => {
+            let rest = rest.trim();
+            (rest.strip_suffix(';').unwrap_or(rest).trim_end(), true)
+        }
+        _ => (inner, false),
+    };
+    match value {
+        "true" => Some((true, returns)),
+        "false" => Some((false, returns)),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// ManualVectorBuildLint - Preview
+// ============================================================================
+
+/// Detects a vector built by `vector::empty()` followed only by `push_back` calls.
+///
+/// # Example
+///
+/// ```move
+/// // bad!
+/// let mut fees = vector::empty();
+/// vector::push_back(&mut fees, protocol_fee);
+/// fees.push_back(referral_fee);
+///
+/// // good!
+/// let fees = vector[protocol_fee, referral_fee];
+/// ```
+pub struct ManualVectorBuildLint;
+
+static MANUAL_VECTOR_BUILD: LintDescriptor = LintDescriptor {
+    name: "manual_vector_build",
+    category: LintCategory::Modernization,
+    description: "Prefer a `vector[...]` literal over `vector::empty()` followed by `push_back` calls",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::safe("Replace the pushes with a `vector[...]` literal"),
+    analysis: AnalysisKind::Syntactic,
+    gap: None,
+};
+
+impl LintRule for ManualVectorBuildLint {
+    fn descriptor(&self) -> &'static LintDescriptor {
+        &MANUAL_VECTOR_BUILD
+    }
+
+    fn check(&self, root: Node, source: &str, ctx: &mut LintContext<'_>) {
+        walk(root, &mut |node| {
+            if node.kind() != "block" {
+                return;
+            }
+
+            let mut cursor = node.walk();
+            let items: Vec<Node> = node.named_children(&mut cursor).collect();
+            let mut i = 0;
+            while i < items.len() {
+                let Some(build) = manual_vector_build(source, &items[i..]) else {
+                    i += 1;
+                    continue;
+                };
+                let first = items[i];
+                let last = items[i + build.elements.len()];
+                i += build.elements.len() + 1;
+
+                let replacement = format!(
+                    "{} = vector{}[{}];",
+                    build.binding,
+                    build.type_args,
+                    build.elements.join(", ")
+                );
+                let diagnostic = crate::diagnostics::Diagnostic {
+                    lint: self.descriptor(),
+                    level: ctx.settings().level_for(self.descriptor().name),
+                    file: None,
+                    span: Span {
+                        start: Span::from_range(first.range()).start,
+                        end: Span::from_range(last.range()).end,
+                    },
+                    message: format!(
+                        "`{}` is built by pushing {} element(s) onto `vector::empty()`",
+                        build.name,
+                        build.elements.len()
+                    ),
+                    help: Some("Construct the vector with a `vector[...]` literal".to_string()),
+                    suggestions: vec![Suggestion {
+                        message: format!("Replace with `{replacement}`"),
+                        replacement,
+                        applicability: Applicability::MachineApplicable,
+                        span: None,
+                    }],
+                    notes: Vec::new(),
+                    labels: Vec::new(),
+                };
+                ctx.report_diagnostic_for_node(first, diagnostic);
+            }
+        });
+    }
+}
+
+/// An empty-vector binding and the `push_back` statements that directly follow it.
+struct VectorBuild<'a> {
+    /// Everything before the `=` (`let mut v`, `let mut v: vector<u64>`), without `mut`
+    /// unless the vector may still be mutated after the pushes
+    binding: String,
+    name: &'a str,
+    /// Type arguments of `vector::empty<T>()`, or empty
+    type_args: String,
+    elements: Vec<&'a str>,
+}
+
+/// Match `items` starting with `let v = vector::empty()` and one or more pushes onto `v`.
+///
+/// The pushes must be consecutive statements whose elements do not mention `v`. A later
+/// `push_back` statement mentioning `v` (e.g. under a condition) means the vector is still
+/// being built piecemeal, so nothing is reported.
+fn manual_vector_build<'a>(source: &'a str, items: &[Node]) -> Option<VectorBuild<'a>> {
+    let (first, rest) = items.split_first()?;
+    let (binding, name, type_args) = empty_vector_binding(block_statement(source, *first)?)?;
+
+    let elements: Vec<&str> = rest
+        .iter()
+        .map_while(|item| {
+            pushed_element(block_statement(source, *item)?, name)
+                .filter(|element| !mentions_ident(element, name))
+        })
+        .collect();
+    if elements.is_empty() {
+        return None;
+    }
+
+    let pushes_later = rest[elements.len()..].iter().any(|item| {
+        let text = slice(source, *item);
+        text.contains("push_back") && mentions_ident(text, name)
+    });
+    if pushes_later {
+        return None;
+    }
+
+    let mutated_later = rest[elements.len()..]
+        .iter()
+        .any(|item| may_mutate(slice(source, *item), name));
+    let binding = if mutated_later {
+        binding.to_string()
+    } else {
+        drop_mut(binding)
+    };
+
+    Some(VectorBuild {
+        binding,
+        name,
+        type_args,
+        elements,
+    })
+}
+
+/// Text of a `;`-terminated block statement, without the `;`.
+fn block_statement<'a>(source: &'a str, item: Node) -> Option<&'a str> {
+    if item.kind() != "block_item" {
+        return None;
+    }
+    slice(source, item)
+        .trim()
+        .strip_suffix(';')
+        .map(str::trim_end)
+}
+
+/// Split `let v = vector::empty<T>()` into the binding, the variable, and `<T>`.
+fn empty_vector_binding(stmt: &str) -> Option<(&str, &str, String)> {
+    let (lhs, rhs) = stmt.split_once('=')?;
+    let decl = lhs.strip_prefix("let")?;
+    if !decl.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let decl = decl.trim();
+    let decl = match decl.strip_prefix("mut") {
+        Some(rest) if rest.starts_with(char::is_whitespace) => rest,
+        _ => decl,
+    };
+    let name = decl.split(':').next()?.trim();
+    if !is_simple_ident(name) {
+        return None;
+    }
+
+    let rhs = compact_ws(rhs);
+    let type_args = rhs.strip_prefix("vector::empty")?.strip_suffix("()")?;
+    if !type_args.is_empty() && !(type_args.starts_with('<') && type_args.ends_with('>')) {
+        return None;
+    }
+    Some((lhs.trim(), name, type_args.to_string()))
+}
+
+/// The element pushed by `vector::push_back(&mut v, e)` or `v.push_back(e)`.
+fn pushed_element<'a>(stmt: &'a str, name: &str) -> Option<&'a str> {
+    let (callee, args) = split_call(stmt)?;
+    let args = split_args(args)?;
+    match (compact_ws(callee).as_str(), args.as_slice()) {
+        ("vector::push_back", [target, element]) if parse_ref_mut_ident(target) == Some(name) => {
+            Some(*element)
+        }
+        (callee, [element]) if callee.strip_suffix(".push_back") == Some(name) => Some(*element),
+        _ => None,
+    }
+}
+
+/// `let mut v` as `let v`; other bindings are returned unchanged.
+fn drop_mut(binding: &str) -> String {
+    let decl = binding.strip_prefix("let").map(str::trim_start);
+    match decl.and_then(|decl| decl.strip_prefix("mut")) {
+        Some(rest) if rest.starts_with(char::is_whitespace) => format!("let {}", rest.trim_start()),
+        _ => binding.to_string(),
+    }
+}
+
+/// `text` uses `name` where it may be mutated: under `&mut`, as a method receiver or
+/// indexed, or on the left of an assignment.
+fn may_mutate(text: &str, name: &str) -> bool {
+    let is_ident_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    text.match_indices(name).any(|(at, _)| {
+        let before = &text[..at];
+        let after = &text[at + name.len()..];
+        if before.ends_with(is_ident_char) || after.starts_with(is_ident_char) {
+            return false;
+        }
+        let after = after.trim_start();
+        before.trim_end().ends_with("&mut")
+            || after.starts_with('.')
+            || after.starts_with('[')
+            || (after.starts_with('=') && !after.starts_with("=="))
+    })
+}
+
+/// `text` contains `name` as a whole identifier.
+fn mentions_ident(text: &str, name: &str) -> bool {
+    text.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .any(|token| token == name)
+}
+
+// ============================================================================
+// BoolComparisonLint - Preview
+// ============================================================================
+
+/// Detects `==`/`!=` comparisons against a boolean literal.
+///
+/// # Example
+///
+/// ```move
+/// // bad!
+/// if (paused == true) abort E_PAUSED;
+/// assert!(a && b == false, E_INVALID);
+///
+/// // good!
+/// if (paused) abort E_PAUSED;
+/// assert!(a && !b, E_INVALID);
+/// ```
+pub struct BoolComparisonLint;
+
+static BOOL_COMPARISON: LintDescriptor = LintDescriptor {
+    name: "bool_comparison",
+    category: LintCategory::Modernization,
+    description: "Prefer `x` over `x == true` and `!x` over `x == false`",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::safe("Replace the comparison with the operand or its negation"),
+    analysis: AnalysisKind::Syntactic,
+    gap: None,
+};
+
+impl LintRule for BoolComparisonLint {
+    fn descriptor(&self) -> &'static LintDescriptor {
+        &BOOL_COMPARISON
+    }
+
+    fn check(&self, root: Node, source: &str, ctx: &mut LintContext<'_>) {
+        walk(root, &mut |node| {
+            if node.kind() != "binary_expression" {
+                return;
+            }
+            let Some((operand, keeps_value)) = bool_comparison(node, source) else {
+                return;
+            };
+
+            let parent_kind = node.parent().map(|parent| parent.kind());
+            // Atomic and already-parenthesized operands never need extra parentheses.
+            let grouped = is_atomic_operand(operand)
+                || split_call(operand).is_some_and(|(callee, _)| callee.is_empty());
+            let replacement = if keeps_value {
+                let in_operand = matches!(
+                    parent_kind,
+                    Some("binary_expression" | "unary_expression" | "dot_expression")
+                );
+                if in_operand && !grouped {
+                    format!("({operand})")
+                } else {
+                    operand.to_string()
+                }
+            } else {
+                // `!` binds tighter than any binary operator, so only a non-atomic operand
+                // needs its own parentheses; a field access or method call on the result
+                // needs the whole negation wrapped.
+                let negated = if grouped {
+                    format!("!{operand}")
+                } else {
+                    format!("!({operand})")
+                };
+                if parent_kind == Some("dot_expression") {
+                    format!("({negated})")
+                } else {
+                    negated
+                }
+            };
+
+            let comparison = compact_ws(slice(source, node));
+            let diagnostic = crate::diagnostics::Diagnostic {
+                lint: self.descriptor(),
+                level: ctx.settings().level_for(self.descriptor().name),
+                file: None,
+                span: Span::from_range(node.range()),
+                message: format!("`{comparison}` can be written as `{replacement}`"),
+                help: Some(
+                    "Use the boolean directly instead of comparing it to a literal".to_string(),
+                ),
+                suggestions: vec![Suggestion {
+                    message: format!("Replace with `{replacement}`"),
+                    replacement,
+                    applicability: Applicability::MachineApplicable,
+                    span: None,
+                }],
+                notes: Vec::new(),
+                labels: Vec::new(),
+            };
+            ctx.report_diagnostic_for_node(node, diagnostic);
+        });
+    }
+}
+
+/// The non-literal operand of `x == true`-style comparisons, with whether the comparison
+/// evaluates to `x` itself (`== true`, `!= false`) rather than `!x`.
+///
+/// Comparisons with a literal on either side are recognized; a literal compared to a
+/// literal is left to `constant_assert_condition`.
+fn bool_comparison<'a>(node: Node, source: &'a str) -> Option<(&'a str, bool)> {
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node
+        .children(&mut cursor)
+        .filter(|child| !child.kind().contains("comment"))
+        .collect();
+    let [lhs, op, rhs] = children.as_slice() else {
+        return None;
+    };
+    let equal = match slice(source, *op).trim() {
+        "==" => true,
+        "!=" => false,
+        _ => return None,
+    };
+    let literal = |text: &str| match text.trim() {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    };
+    let (lhs, rhs) = (slice(source, *lhs).trim(), slice(source, *rhs).trim());
+    let (operand, value) = match (literal(lhs), literal(rhs)) {
+        (None, Some(value)) => (lhs, value),
+        (Some(value), None) => (rhs, value),
+        _ => return None,
+    };
+    if operand.is_empty() {
+        return None;
+    }
+    Some((operand, value == equal))
+}
+
 // ============================================================================
 // REMOVED LINTS:
 // - UnnecessaryPublicEntryLint - duplicates Sui compiler's built-in lint
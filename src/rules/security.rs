@@ -13,7 +13,7 @@ use crate::lint::{
     AnalysisKind, FixDescriptor, LintCategory, LintContext, LintDescriptor, LintRule, RuleGroup,
     TypeSystemGap,
 };
-use crate::rules::util::is_test_only_module;
+use crate::rules::util::{has_test_attribute, is_test_only_module, slice, walk};
 use tree_sitter::Node;
 
 // ============================================================================
@@ -144,7 +144,7 @@ fn check_suspicious_overflow(node: Node, source: &str, ctx: &mut LintContext<'_>
 
 // ============================================================================
 // REMOVED LINTS:
-// - StaleOraclePriceLint - superseded by stale_oracle_price_v3 (CFG-aware, --mode full)
+// - StaleOraclePriceLint - superseded by stale_oracle_price (CFG-aware, --mode full)
 // - SingleStepOwnershipTransferLint - ~50% FP rate, syntactic matching insufficient
 // - UncheckedCoinSplitLint - Sui runtime already enforces balance checks
 // - MissingWitnessDropLint - superseded by missing_witness_drop_v2 (type-based)
@@ -805,6 +805,173 @@ fn check_bookend_invariants(node: Node, source: &str, ctx: &mut LintContext<'_>)
     }
 }
 
+// ============================================================================
+// shadowed_framework_alias - Detects `use` aliases that shadow framework modules
+// ============================================================================
+
+/// Detects `use` declarations that alias a non-framework module to the name of a
+/// well-known Sui framework module.
+///
+/// # Why This Matters
+///
+/// Readers (and name-based tooling) assume `transfer::share_object(..)` refers to
+/// `sui::transfer`. An alias such as `use my_pkg::evil as transfer;` silently
+/// redirects every such call to a different module, which can hide custom
+/// transfer/share logic during review.
+///
+/// # Example
+///
+/// ```move
+/// // FLAGGED
+/// use my_pkg::evil as transfer;
+///
+/// // OK
+/// use sui::transfer;
+/// use my_pkg::evil as evil_transfer;
+/// ```
+pub static SHADOWED_FRAMEWORK_ALIAS: LintDescriptor = LintDescriptor {
+    name: "shadowed_framework_alias",
+    category: LintCategory::Security,
+    description: "`use` alias shadows a Sui framework module name (e.g. `use my_pkg::evil as transfer`)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::Syntactic,
+    gap: Some(TypeSystemGap::ApiMisuse),
+};
+
+/// Sui framework modules whose names semantic lints and reviewers rely on.
+const SHADOWABLE_FRAMEWORK_MODULES: &[&str] = &[
+    "transfer",
+    "event",
+    "coin",
+    "balance",
+    "object",
+    "tx_context",
+];
+
+pub struct ShadowedFrameworkAliasLint;
+
+impl LintRule for ShadowedFrameworkAliasLint {
+    fn descriptor(&self) -> &'static LintDescriptor {
+        &SHADOWED_FRAMEWORK_ALIAS
+    }
+
+    fn check(&self, root: Node, source: &str, ctx: &mut LintContext<'_>) {
+        walk(root, &mut |node| {
+            if node.kind() != "use_declaration" {
+                return;
+            }
+
+            for (path, alias) in aliased_use_paths(slice(source, node)) {
+                if !SHADOWABLE_FRAMEWORK_MODULES.contains(&alias.as_str()) {
+                    continue;
+                }
+                if path == format!("sui::{alias}") || path == format!("0x2::{alias}") {
+                    continue;
+                }
+
+                ctx.report_node(
+                    &SHADOWED_FRAMEWORK_ALIAS,
+                    node,
+                    format!(
+                        "`{path}` is imported as `{alias}`, shadowing the Sui framework module \
+                         `sui::{alias}`. Calls like `{alias}::...` will not reach the framework; \
+                         use a distinct alias."
+                    ),
+                );
+            }
+        });
+    }
+}
+
+/// Extract `(path, alias)` pairs from a `use` declaration.
+///
+/// Only members imported with an explicit `as` are returned; see [`use_bindings`].
+fn aliased_use_paths(text: &str) -> Vec<(String, String)> {
+    use_bindings(text)
+        .into_iter()
+        .filter(|binding| binding.aliased)
+        .map(|binding| (binding.path, binding.name))
+        .collect()
+}
+
+// ============================================================================
+// hardcoded_address - Detects inline numeric address literals in function bodies
+// ============================================================================
+
+/// Detects numeric address literals (`@0x...`) written inline in function bodies.
+///
+/// Hardcoded addresses in business logic are often test leftovers or deployment-specific
+/// values that were never parameterized. Framework addresses (`@0x1`, `@0x2`), addresses
+/// configured under `[lints.known_addresses]`, `const` declarations, and test code are
+/// not flagged.
+///
+/// # Example
+///
+/// ```move
+/// // FLAGGED
+/// transfer::public_transfer(coin, @0xABC);
+///
+/// // OK
+/// const TREASURY: address = @0xABC;
+/// transfer::public_transfer(coin, TREASURY);
+/// ```
+pub static HARDCODED_ADDRESS: LintDescriptor = LintDescriptor {
+    name: "hardcoded_address",
+    category: LintCategory::Security,
+    description: "Inline hardcoded address literal in a function body - use a named constant or parameter",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::Syntactic,
+    gap: None,
+};
+
+pub struct HardcodedAddressLint;
+
+impl LintRule for HardcodedAddressLint {
+    fn descriptor(&self) -> &'static LintDescriptor {
+        &HARDCODED_ADDRESS
+    }
+
+    fn check(&self, root: Node, source: &str, ctx: &mut LintContext<'_>) {
+        if is_test_only_module(root, source) {
+            return;
+        }
+
+        walk(root, &mut |node| {
+            if node.kind() != "function_definition" || has_test_attribute(node, source) {
+                return;
+            }
+            let fn_name = node
+                .child_by_field_name("name")
+                .map(|n| slice(source, n))
+                .unwrap_or("<function>");
+
+            walk(node, &mut |lit| {
+                if lit.kind() != "address_literal" {
+                    return;
+                }
+                let text = slice(source, lit).trim();
+                let is_numeric = text
+                    .strip_prefix('@')
+                    .is_some_and(|addr| addr.starts_with(|c: char| c.is_ascii_digit()));
+                if !is_numeric || ctx.settings().is_known_address(text) {
+                    return;
+                }
+
+                ctx.report_node(
+                    &HARDCODED_ADDRESS,
+                    lit,
+                    format!(
+                        "Hardcoded address `{text}` in `{fn_name}`. Move it into a named `const` \
+                         or take it as a parameter so it is visible and reviewable at deploy time."
+                    ),
+                );
+            });
+        });
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -833,6 +1000,69 @@ mod tests {
             .collect()
     }
 
+    fn lint_shadowed_alias(source: &str) -> Vec<String> {
+        let tree = parse_source(source).unwrap();
+        let mut ctx = LintContext::new(source, LintSettings::default());
+        ShadowedFrameworkAliasLint.check(tree.root_node(), source, &mut ctx);
+        ctx.into_diagnostics()
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_aliased_use_paths() {
+        assert_eq!(
+            aliased_use_paths("use my_pkg::evil as transfer;"),
+            vec![("my_pkg::evil".to_string(), "transfer".to_string())]
+        );
+        assert_eq!(
+            aliased_use_paths("use my_pkg::{evil as event, other};"),
+            vec![("my_pkg::evil".to_string(), "event".to_string())]
+        );
+        assert_eq!(
+            aliased_use_paths("use my_pkg::evil::{Self as coin, Thing};"),
+            vec![("my_pkg::evil".to_string(), "coin".to_string())]
+        );
+        assert!(aliased_use_paths("use sui::transfer;").is_empty());
+    }
+
+    #[test]
+    fn test_aliased_use_paths_nested_groups() {
+        assert_eq!(
+            aliased_use_paths("use sui::{transfer::{Self as t}};"),
+            vec![("sui::transfer".to_string(), "t".to_string())]
+        );
+        assert_eq!(
+            aliased_use_paths(
+                "use my_pkg::{evil::{Self as transfer, Thing}, fake::{emit as e}, other as event};"
+            ),
+            vec![
+                ("my_pkg::evil".to_string(), "transfer".to_string()),
+                ("my_pkg::fake::emit".to_string(), "e".to_string()),
+                ("my_pkg::other".to_string(), "event".to_string()),
+            ]
+        );
+        assert!(aliased_use_paths("use fun my_pkg::m::f as Thing.transfer;").is_empty());
+    }
+
+    #[test]
+    fn test_shadowed_framework_alias() {
+        let source = r#"
+module example::m {
+    use example::evil as transfer;
+    use example::{fake_event as event};
+    use sui::transfer as transfer;
+    use example::evil as evil_transfer;
+}
+        "#;
+
+        let messages = lint_shadowed_alias(source);
+        assert_eq!(messages.len(), 2, "{messages:?}");
+        assert!(messages[0].contains("`example::evil` is imported as `transfer`"));
+        assert!(messages[1].contains("`example::fake_event` is imported as `event`"));
+    }
+
     #[test]
     fn test_hot_potato_no_abilities_ok() {
         let source = r#"
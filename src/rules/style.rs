@@ -1,10 +1,11 @@
-use crate::diagnostics::{Applicability, Span, Suggestion};
+use crate::diagnostics::{Applicability, Position, Span, Suggestion};
 use crate::lint::{
     AnalysisKind, FixDescriptor, LintCategory, LintContext, LintDescriptor, LintRule, RuleGroup,
 };
 use tree_sitter::Node;
 
-use super::util::{compact_ws, slice, walk};
+use super::patterns::extract_assert_condition;
+use super::util::{compact_ws, negate_condition, slice, split_call, walk};
 
 // ============================================================================
 // AbilitiesOrderLint - P0 (Zero FP)
@@ -109,11 +110,14 @@ fn check_abilities_order(
             span: Span::from_range(node.range()),
             message,
             help: Some(format!("Reorder to `{}`", replacement)),
-            suggestion: Some(Suggestion {
+            suggestions: vec![Suggestion {
                 message: format!("Reorder abilities to `{}`", replacement),
                 replacement,
                 applicability: Applicability::MachineApplicable,
-            }),
+                span: None,
+            }],
+            notes: Vec::new(),
+            labels: Vec::new(),
         };
 
         ctx.report_diagnostic_for_node(node, diagnostic);
@@ -350,11 +354,14 @@ impl LintRule for EmptyVectorLiteralLint {
                     span: Span::from_range(node.range()),
                     message: format!("Prefer `{}` over `{}`", replacement, text.trim()),
                     help: Some(format!("Replace with `{}`", replacement)),
-                    suggestion: Some(Suggestion {
+                    suggestions: vec![Suggestion {
                         message: format!("Replace `{}` with `{}`", text.trim(), replacement),
                         replacement: replacement.clone(),
                         applicability: Applicability::MachineApplicable,
-                    }),
+                        span: None,
+                    }],
+                    notes: Vec::new(),
+                    labels: Vec::new(),
                 };
 
                 ctx.report_diagnostic_for_node(node, diagnostic);
@@ -596,11 +603,14 @@ impl LintRule for RedundantSelfImportLint {
                             replacement
                         ),
                         help: Some(format!("Simplify to `{}`", replacement)),
-                        suggestion: Some(Suggestion {
+                        suggestions: vec![Suggestion {
                             message: "Remove redundant `{Self}`".to_string(),
                             replacement,
                             applicability: Applicability::MachineApplicable,
-                        }),
+                            span: None,
+                        }],
+                        notes: Vec::new(),
+                        labels: Vec::new(),
                     };
 
                     ctx.report_diagnostic_for_node(node, diagnostic);
@@ -670,6 +680,14 @@ impl LintRule for ConstantNamingLint {
     }
 
     fn check(&self, root: Node, source: &str, ctx: &mut LintContext<'_>) {
+        let abort_codes = abort_code_operands(root, source);
+        let mut accesses: Vec<(String, std::ops::Range<usize>)> = Vec::new();
+        walk(root, &mut |node| {
+            if node.kind() == "module_access" {
+                accesses.push((slice(source, node).trim().to_string(), node.byte_range()));
+            }
+        });
+
         walk(root, &mut |node| {
             if node.kind() != "constant" {
                 return;
@@ -684,64 +702,42 @@ impl LintRule for ConstantNamingLint {
 
             match classify_constant(name) {
                 ConstantKind::Error if !is_valid_error_constant(name) => {
-                    let suggested = to_e_pascal_case(name);
-                    let message = format!(
-                        "Error constants should use EPascalCase (e.g. `ENotAuthorized`), found `{name}`"
-                    );
-
-                    // Check for suppression
-                    if crate::suppression::is_suppressed_at(
+                    self.report_rename(
+                        ctx,
                         source,
-                        name_node.start_byte(),
-                        self.descriptor().name,
-                    ) {
-                        return;
-                    }
-
-                    let diagnostic = crate::diagnostics::Diagnostic {
-                        lint: self.descriptor(),
-                        level: ctx.settings().level_for(self.descriptor().name),
-                        file: None,
-                        span: Span::from_range(name_node.range()),
-                        message: message.clone(),
-                        help: Some(format!("Consider renaming to `{}`", suggested)),
-                        suggestion: Some(Suggestion {
-                            message: format!("Rename to `{}`", suggested),
-                            replacement: suggested,
-                            applicability: Applicability::MaybeIncorrect, // Renaming affects all usages
-                        }),
-                    };
-                    ctx.report_diagnostic_for_node(name_node, diagnostic);
+                        name_node,
+                        format!(
+                            "Error constants should use EPascalCase (e.g. `ENotAuthorized`), found `{name}`"
+                        ),
+                        to_e_pascal_case(name),
+                    );
                 }
                 ConstantKind::Regular if !is_valid_regular_constant(name) => {
-                    let suggested = to_screaming_snake_case(name);
-                    let message = format!(
-                        "Regular constants should be SCREAMING_SNAKE_CASE (e.g. `MAX_SUPPLY`), found `{name}`"
+                    self.report_rename(
+                        ctx,
+                        source,
+                        name_node,
+                        format!(
+                            "Regular constants should be SCREAMING_SNAKE_CASE (e.g. `MAX_SUPPLY`), found `{name}`"
+                        ),
+                        to_screaming_snake_case(name),
                     );
-
-                    // Check for suppression
-                    if crate::suppression::is_suppressed_at(
+                }
+                // Conservative: legacy `E_` names are left alone; the rest get an `E` prefix.
+                ConstantKind::Regular
+                    if !name.starts_with("E_")
+                        && is_only_abort_code(name, &abort_codes, &accesses) =>
+                {
+                    let suggested = to_e_pascal_case(&format!("E_{name}"));
+                    self.report_rename(
+                        ctx,
                         source,
-                        name_node.start_byte(),
-                        self.descriptor().name,
-                    ) {
-                        return;
-                    }
-
-                    let diagnostic = crate::diagnostics::Diagnostic {
-                        lint: self.descriptor(),
-                        level: ctx.settings().level_for(self.descriptor().name),
-                        file: None,
-                        span: Span::from_range(name_node.range()),
-                        message: message.clone(),
-                        help: Some(format!("Consider renaming to `{}`", suggested)),
-                        suggestion: Some(Suggestion {
-                            message: format!("Rename to `{}`", suggested),
-                            replacement: suggested,
-                            applicability: Applicability::MaybeIncorrect, // Renaming affects all usages
-                        }),
-                    };
-                    ctx.report_diagnostic_for_node(name_node, diagnostic);
+                        name_node,
+                        format!(
+                            "Constant `{name}` is only used as an abort code; name error codes in EPascalCase (e.g. `{suggested}`)"
+                        ),
+                        suggested,
+                    );
                 }
                 _ => {}
             }
@@ -749,6 +745,99 @@ impl LintRule for ConstantNamingLint {
     }
 }
 
+impl ConstantNamingLint {
+    fn report_rename(
+        &self,
+        ctx: &mut LintContext<'_>,
+        source: &str,
+        name_node: Node,
+        message: String,
+        suggested: String,
+    ) {
+        if crate::suppression::is_suppressed_at(
+            source,
+            name_node.start_byte(),
+            self.descriptor().name,
+        ) {
+            return;
+        }
+
+        let diagnostic = crate::diagnostics::Diagnostic {
+            lint: self.descriptor(),
+            level: ctx.settings().level_for(self.descriptor().name),
+            file: None,
+            span: Span::from_range(name_node.range()),
+            message,
+            help: Some(format!("Consider renaming to `{}`", suggested)),
+            suggestions: vec![Suggestion {
+                message: format!("Rename to `{}`", suggested),
+                replacement: suggested,
+                applicability: Applicability::MaybeIncorrect, // Renaming affects all usages
+                span: None,
+            }],
+            notes: Vec::new(),
+            labels: Vec::new(),
+        };
+        ctx.report_diagnostic_for_node(name_node, diagnostic);
+    }
+}
+
+/// Whether `name` is used as an abort code and every other reference to it sits inside one.
+fn is_only_abort_code(
+    name: &str,
+    abort_codes: &[(String, std::ops::Range<usize>)],
+    accesses: &[(String, std::ops::Range<usize>)],
+) -> bool {
+    let code_ranges: Vec<_> = abort_codes
+        .iter()
+        .filter(|(text, _)| text == name)
+        .map(|(_, range)| range)
+        .collect();
+    if code_ranges.is_empty() {
+        return false;
+    }
+    accesses
+        .iter()
+        .filter(|(text, _)| text == name)
+        .all(|(_, range)| {
+            code_ranges
+                .iter()
+                .any(|code| code.start <= range.start && range.end <= code.end)
+        })
+}
+
+/// Abort-code operands in `root`: the operand of each `abort` and the error argument
+/// of each `assert!`, as `(text, byte range)`.
+fn abort_code_operands(root: Node, source: &str) -> Vec<(String, std::ops::Range<usize>)> {
+    let mut codes = Vec::new();
+    walk(root, &mut |node| {
+        // abort ERROR_CODE
+        if node.kind() == "abort_expression"
+            && let Some(arg) = node.named_child(0)
+            && (arg.kind() == "name_expression" || arg.kind() == "module_access")
+        {
+            codes.push((slice(source, arg).trim().to_string(), arg.byte_range()));
+        }
+
+        // assert!(condition, ERROR_CODE)
+        if node.kind() == "macro_call"
+            && slice(source, node).starts_with("assert!")
+            && let Some(args) = node.child_by_field_name("arguments")
+        {
+            let mut cursor = args.walk();
+            let children: Vec<_> = args.children(&mut cursor).collect();
+            for (i, child) in children.iter().enumerate() {
+                if slice(source, *child) == ","
+                    && let Some(next) = children.get(i + 1)
+                {
+                    codes.push((slice(source, *next).trim().to_string(), next.byte_range()));
+                }
+            }
+        }
+    });
+    codes
+}
+
 pub struct UnneededReturnLint;
 
 static UNNEEDED_RETURN: LintDescriptor = LintDescriptor {
@@ -824,11 +913,14 @@ impl LintRule for UnneededReturnLint {
                         "Remove `return`; the last expression in a block already returns implicitly"
                             .to_string(),
                     help: Some(format!("Replace with `{}`", replacement)),
-                    suggestion: Some(Suggestion {
+                    suggestions: vec![Suggestion {
                         message: "Remove `return` keyword".to_string(),
                         replacement,
                         applicability: Applicability::MachineApplicable,
-                    }),
+                        span: None,
+                    }],
+                    notes: Vec::new(),
+                    labels: Vec::new(),
                 };
 
                 ctx.report_diagnostic_for_node(ret, diagnostic);
@@ -992,40 +1084,10 @@ impl LintRule for ErrorConstNamingLint {
 
     fn check(&self, root: Node, source: &str, ctx: &mut LintContext<'_>) {
         // First pass: collect constants used in abort/assert
-        let mut error_consts: std::collections::HashSet<String> = std::collections::HashSet::new();
-
-        walk(root, &mut |node| {
-            // Look for abort expressions: abort ERROR_CODE
-            if node.kind() == "abort_expression"
-                && let Some(arg) = node.named_child(0)
-            {
-                let text = slice(source, arg).trim();
-                // If it's a simple identifier (constant reference)
-                if arg.kind() == "name_expression" || arg.kind() == "module_access" {
-                    error_consts.insert(text.to_string());
-                }
-            }
-
-            // Look for assert! macro: assert!(condition, ERROR_CODE)
-            if node.kind() == "macro_call" {
-                let text = slice(source, node);
-                if text.starts_with("assert!")
-                    && let Some(args) = node.child_by_field_name("arguments")
-                {
-                    let mut cursor = args.walk();
-                    let children: Vec<_> = args.children(&mut cursor).collect();
-                    // Find the comma and get the expression after it
-                    for (i, child) in children.iter().enumerate() {
-                        if slice(source, *child) == ","
-                            && let Some(next) = children.get(i + 1)
-                        {
-                            let err_text = slice(source, *next).trim();
-                            error_consts.insert(err_text.to_string());
-                        }
-                    }
-                }
-            }
-        });
+        let error_consts: std::collections::HashSet<String> = abort_code_operands(root, source)
+            .into_iter()
+            .map(|(text, _)| text)
+            .collect();
 
         // Second pass: check constant definitions
         walk(root, &mut |node| {
@@ -1094,3 +1156,768 @@ fn is_valid_error_const_name(name: &str) -> bool {
     // Should be PascalCase (no underscores, not all caps)
     !rest.contains('_') && !rest.chars().all(|c| c.is_uppercase() || c.is_numeric())
 }
+
+// ============================================================================
+// LoopWithLeadingBreakToWhileLint - Preview
+// ============================================================================
+
+/// Detects `loop` bodies whose first statement is an unconditional `if (cond) break`.
+///
+/// # Example
+///
+/// ```move
+/// // before
+/// loop {
+///     if (i >= n) break;
+///     i = i + 1;
+/// };
+///
+/// // after
+/// while (i < n) {
+///     i = i + 1;
+/// };
+/// ```
+pub struct LoopWithLeadingBreakToWhileLint;
+
+static LOOP_WITH_LEADING_BREAK_TO_WHILE: LintDescriptor = LintDescriptor {
+    name: "loop_with_leading_break_to_while",
+    category: LintCategory::Style,
+    description: "A `loop` that starts with `if (cond) break` is clearer as `while (!cond)`",
+    group: RuleGroup::Preview,
+    // Negating arbitrary conditions by hand is easy to get subtly wrong.
+    fix: FixDescriptor::unsafe_fix("Rewrite as `while` with the negated condition"),
+    analysis: AnalysisKind::Syntactic,
+    gap: None,
+};
+
+impl LintRule for LoopWithLeadingBreakToWhileLint {
+    fn descriptor(&self) -> &'static LintDescriptor {
+        &LOOP_WITH_LEADING_BREAK_TO_WHILE
+    }
+
+    fn check(&self, root: Node, source: &str, ctx: &mut LintContext<'_>) {
+        walk(root, &mut |node| {
+            if node.kind() != "loop_expression" {
+                return;
+            }
+
+            let body = node
+                .child_by_field_name("body")
+                .or_else(|| node.named_child(node.named_child_count().saturating_sub(1)));
+            let Some(body) = body else { return };
+            if body.kind() != "block" {
+                return;
+            }
+
+            let Some(first_item) = body.named_child(0) else {
+                return;
+            };
+            let first_expr = if first_item.kind() == "block_item" {
+                match first_item.named_child(0) {
+                    Some(expr) => expr,
+                    None => return,
+                }
+            } else {
+                first_item
+            };
+
+            let Some(condition) = leading_break_condition(first_expr, source) else {
+                return;
+            };
+
+            // Any other break targeting this loop means the `while` form would not be equivalent.
+            let rest_start = first_item.end_byte();
+            let mut cursor = body.walk();
+            let has_other_exit = body.named_children(&mut cursor).any(|child| {
+                child.start_byte() >= rest_start && breaks_enclosing_loop(child, source)
+            });
+            if has_other_exit {
+                return;
+            }
+
+            let negated = negate_condition(condition);
+            let suggestion = build_while_suggestion(source, body, rest_start, &negated);
+
+            let diagnostic = crate::diagnostics::Diagnostic {
+                lint: self.descriptor(),
+                level: ctx.settings().level_for(self.descriptor().name),
+                file: None,
+                span: Span::from_range(node.range()),
+                message: format!(
+                    "`loop` starting with `if ({condition}) break` can be written as `while ({negated})`"
+                ),
+                help: Some("Use a `while` loop with the negated condition".to_string()),
+                suggestions: suggestion.into_iter().collect(),
+                notes: Vec::new(),
+                labels: Vec::new(),
+            };
+            ctx.report_diagnostic_for_node(node, diagnostic);
+        });
+    }
+}
+
+/// Rebuild the loop as `while (negated) { <rest of body> }`.
+fn build_while_suggestion(
+    source: &str,
+    body: Node,
+    rest_start: usize,
+    negated: &str,
+) -> Option<Suggestion> {
+    if !slice(source, body).ends_with('}') {
+        return None;
+    }
+    let rest = source.get(rest_start..body.end_byte() - 1)?;
+    let rest = rest.strip_prefix(';').unwrap_or(rest);
+    Some(Suggestion {
+        message: format!("Rewrite as `while ({negated})`"),
+        replacement: format!("while ({negated}) {{{rest}}}"),
+        applicability: Applicability::MaybeIncorrect,
+        span: None,
+    })
+}
+
+/// If `expr` is `if (cond) break` or `if (cond) { break }` with no `else`, returns `cond`.
+fn leading_break_condition<'a>(expr: Node, source: &'a str) -> Option<&'a str> {
+    if expr.kind() != "if_expression" {
+        return None;
+    }
+    if expr.child_by_field_name("ef").is_some() {
+        return None;
+    }
+    let condition = expr.child_by_field_name("eb")?;
+    let then_branch = expr.child_by_field_name("et")?;
+
+    let then_text = compact_ws(slice(source, then_branch));
+    let then_text = then_text
+        .strip_prefix('{')
+        .and_then(|t| t.strip_suffix('}'))
+        .unwrap_or(&then_text);
+    let then_text = then_text.strip_suffix(';').unwrap_or(then_text);
+    if then_text != "break" {
+        return None;
+    }
+
+    let text = slice(source, condition).trim();
+    (!text.is_empty()).then_some(text)
+}
+
+/// Returns true if `node` contains a `break` that exits the enclosing loop.
+///
+/// Breaks inside nested loops belong to those loops and are ignored, unless they are
+/// labeled (a labeled break may target an outer loop, so we stay conservative).
+fn breaks_enclosing_loop(node: Node, source: &str) -> bool {
+    match node.kind() {
+        "break_expression" => return true,
+        "loop_expression" | "while_expression" => {
+            let mut found = false;
+            walk(node, &mut |inner| {
+                if inner.kind() == "break_expression" && slice(source, inner).contains('\'') {
+                    found = true;
+                }
+            });
+            return found;
+        }
+        _ => {}
+    }
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor)
+        .any(|child| breaks_enclosing_loop(child, source))
+}
+
+// ============================================================================
+// RedundantBindingLint - Stable (Zero FP)
+// ============================================================================
+
+/// Detects statements that bind or assign a value to itself.
+///
+/// # Example
+///
+/// ```move
+/// // bad! leftovers from a refactor
+/// let amount = amount;
+/// let coin = move coin;
+/// self.value = self.value;
+///
+/// // good! a type annotation or `mut` changes the binding
+/// let amount: u128 = amount;
+/// let mut coin = coin;
+/// ```
+pub struct RedundantBindingLint;
+
+static REDUNDANT_BINDING: LintDescriptor = LintDescriptor {
+    name: "redundant_binding",
+    category: LintCategory::Style,
+    description: "Statement binds or assigns a value to itself (`let x = x;`, `x = x;`)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::safe("Remove the redundant statement"),
+    analysis: AnalysisKind::Syntactic,
+    gap: None,
+};
+
+impl LintRule for RedundantBindingLint {
+    fn descriptor(&self) -> &'static LintDescriptor {
+        &REDUNDANT_BINDING
+    }
+
+    fn check(&self, root: Node, source: &str, ctx: &mut LintContext<'_>) {
+        walk(root, &mut |node| {
+            // Only `;`-terminated statements: a trailing block expression is the block's value.
+            if node.kind() != "block_item" {
+                return;
+            }
+
+            let text = slice(source, node).trim();
+            let Some(stmt) = text.strip_suffix(';') else {
+                return;
+            };
+            let Some(binding) = redundant_binding(stmt.trim()) else {
+                return;
+            };
+
+            if crate::suppression::is_suppressed_at(
+                source,
+                node.start_byte(),
+                self.descriptor().name,
+            ) {
+                return;
+            }
+
+            let message = match binding {
+                RedundantBinding::Let(name) => format!("`{text}` rebinds `{name}` to itself"),
+                RedundantBinding::Assign(name) => format!("`{text}` assigns `{name}` to itself"),
+                RedundantBinding::Field(path) => {
+                    format!("Field assignment `{text}` has no effect on `{path}`")
+                }
+            };
+
+            let diagnostic = crate::diagnostics::Diagnostic {
+                lint: self.descriptor(),
+                level: ctx.settings().level_for(self.descriptor().name),
+                file: None,
+                span: statement_deletion_span(source, node),
+                message,
+                help: Some("Remove the statement".to_string()),
+                suggestions: vec![Suggestion {
+                    message: format!("Remove `{text}`"),
+                    replacement: String::new(),
+                    applicability: Applicability::MachineApplicable,
+                    span: None,
+                }],
+                notes: Vec::new(),
+                labels: Vec::new(),
+            };
+
+            ctx.report_diagnostic_for_node(node, diagnostic);
+        });
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum RedundantBinding<'a> {
+    /// `let x = x` or `let x = move x`.
+    Let(&'a str),
+    /// `x = x` or `x = move x`.
+    Assign(&'a str),
+    /// `a.b = a.b`; kept separate because the fix is the same but the cause usually differs.
+    Field(String),
+}
+
+/// Classify a statement (without its trailing `;`) as a self-binding or self-assignment.
+fn redundant_binding(stmt: &str) -> Option<RedundantBinding<'_>> {
+    if let Some(rest) = stmt.strip_prefix("let")
+        && rest.starts_with(char::is_whitespace)
+    {
+        // `let x: T = x` may change the type and `let mut x = x` changes mutability.
+        let (lhs, rhs) = rest.split_once('=')?;
+        let name = lhs.trim();
+        return (is_identifier(name) && is_same_value(name, rhs.trim()))
+            .then_some(RedundantBinding::Let(name));
+    }
+
+    let eq = assignment_operator(stmt)?;
+    let lhs = stmt[..eq].trim();
+    let rhs = stmt[eq + 1..].trim();
+    if is_identifier(lhs) {
+        return is_same_value(lhs, rhs).then_some(RedundantBinding::Assign(lhs));
+    }
+
+    // Field paths: compare with whitespace removed so `self . x` matches `self.x`.
+    let path = compact_ws(lhs);
+    let is_field_path = path.contains('.') && path.split('.').all(is_identifier);
+    (is_field_path && compact_ws(rhs) == path).then_some(RedundantBinding::Field(path))
+}
+
+/// Byte offset of the first plain `=` (not part of `==`, `!=`, `<=`, `>=`).
+fn assignment_operator(stmt: &str) -> Option<usize> {
+    let bytes = stmt.as_bytes();
+    (0..bytes.len()).find(|&i| {
+        bytes[i] == b'='
+            && (i == 0 || !matches!(bytes[i - 1], b'=' | b'!' | b'<' | b'>'))
+            && bytes.get(i + 1) != Some(&b'=')
+    })
+}
+
+/// `rhs` is exactly `name`, optionally preceded by `move`.
+fn is_same_value(name: &str, rhs: &str) -> bool {
+    let rhs = match rhs.strip_prefix("move") {
+        Some(rest) if rest.starts_with(char::is_whitespace) => rest.trim(),
+        _ => rhs,
+    };
+    rhs == name
+}
+
+fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !matches!(text, "mut" | "move" | "copy")
+}
+
+/// Span that deletes `node`, widened to whole lines when the statement is alone on them so the
+/// fix does not leave a blank indented line behind.
+fn statement_deletion_span(source: &str, node: Node) -> Span {
+    let span = Span::from_range(node.range());
+    let line_start = source[..node.start_byte()].rfind('\n').map_or(0, |i| i + 1);
+    let rest = &source[node.end_byte()..];
+    let line_end = rest.find('\n');
+
+    let alone_on_line = source[line_start..node.start_byte()].trim().is_empty()
+        && rest[..line_end.unwrap_or(rest.len())].trim().is_empty();
+    if !alone_on_line || line_end.is_none() {
+        return span;
+    }
+
+    Span {
+        start: Position {
+            row: span.start.row,
+            column: 1,
+        },
+        end: Position {
+            row: span.end.row + 1,
+            column: 1,
+        },
+    }
+}
+
+// ============================================================================
+// ConstantAssertConditionLint - Preview
+// ============================================================================
+
+/// Detects `assert!` and `if` conditions built only from literals.
+///
+/// An always-true `assert!` never aborts, while an always-false one is an unconditional
+/// abort; both are usually refactor leftovers.
+///
+/// # Example
+///
+/// ```move
+/// // bad!
+/// assert!(true, E_UNREACHABLE);
+/// assert!(1 == 2, E_PAUSED);
+/// if (false) { reset(pool) };
+///
+/// // good!
+/// assert!(amount > 0, E_ZERO_AMOUNT);
+/// abort E_PAUSED
+/// ```
+pub struct ConstantAssertConditionLint;
+
+static CONSTANT_ASSERT_CONDITION: LintDescriptor = LintDescriptor {
+    name: "constant_assert_condition",
+    category: LintCategory::Suspicious,
+    description: "`assert!` or `if` condition is built only from literals, so it is always true or always false",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::Syntactic,
+    gap: None,
+};
+
+impl LintRule for ConstantAssertConditionLint {
+    fn descriptor(&self) -> &'static LintDescriptor {
+        &CONSTANT_ASSERT_CONDITION
+    }
+
+    fn check(&self, root: Node, source: &str, ctx: &mut LintContext<'_>) {
+        walk(root, &mut |node| {
+            let message = match node.kind() {
+                "macro_call_expression" => {
+                    let text = slice(source, node).trim();
+                    if !text.starts_with("assert!") {
+                        return;
+                    }
+                    let Some(condition) = extract_assert_condition(text) else {
+                        return;
+                    };
+                    match constant_condition(condition) {
+                        Some(true) => format!(
+                            "`assert!` condition `{condition}` is always true, so the assertion never aborts. \
+                             Remove it or assert the value it was meant to check."
+                        ),
+                        Some(false) => format!(
+                            "`assert!` condition `{condition}` is always false, so this always aborts. \
+                             Use `abort` to make the unconditional abort explicit."
+                        ),
+                        None => return,
+                    }
+                }
+                "if_expression" => {
+                    let Some(condition) = node.child_by_field_name("eb") else {
+                        return;
+                    };
+                    let condition = slice(source, condition).trim();
+                    let has_else = node.child_by_field_name("ef").is_some();
+                    match constant_condition(condition) {
+                        Some(true) if has_else => format!(
+                            "`if` condition `{condition}` is always true, so the `else` branch never runs"
+                        ),
+                        Some(true) => format!(
+                            "`if` condition `{condition}` is always true, so the check is redundant"
+                        ),
+                        Some(false) => format!(
+                            "`if` condition `{condition}` is always false, so its branch never runs"
+                        ),
+                        None => return,
+                    }
+                }
+                _ => return,
+            };
+            ctx.report_node(&CONSTANT_ASSERT_CONDITION, node, message);
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConstToken {
+    Bool(bool),
+    Int(u128),
+    Op(&'static str),
+    Open,
+    Close,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConstValue {
+    Bool(bool),
+    Int(u128),
+}
+
+/// Value of a condition made only of boolean/integer literals, parentheses, `!`, `&&`, `||`,
+/// and comparisons. `None` if it reads anything else (locals, constants, calls).
+fn constant_condition(text: &str) -> Option<bool> {
+    let tokens = const_tokens(text)?;
+    let mut pos = 0;
+    let value = const_or(&tokens, &mut pos)?;
+    match (value, pos == tokens.len()) {
+        (ConstValue::Bool(b), true) => Some(b),
+        _ => None,
+    }
+}
+
+fn const_tokens(text: &str) -> Option<Vec<ConstToken>> {
+    const OPS: [&str; 9] = ["&&", "||", "==", "!=", "<=", ">=", "<", ">", "!"];
+    let mut tokens = Vec::new();
+    let mut rest = text.trim_start();
+    while !rest.is_empty() {
+        let len = if let Some(op) = OPS.iter().copied().find(|op| rest.starts_with(op)) {
+            tokens.push(ConstToken::Op(op));
+            op.len()
+        } else if rest.starts_with('(') || rest.starts_with(')') {
+            tokens.push(if rest.starts_with('(') {
+                ConstToken::Open
+            } else {
+                ConstToken::Close
+            });
+            1
+        } else {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            tokens.push(const_literal(&rest[..len])?);
+            len
+        };
+        rest = rest[len..].trim_start();
+    }
+    Some(tokens)
+}
+
+/// `true`, `false`, or an integer literal such as `10`, `0xff`, or `1_000u64`.
+fn const_literal(word: &str) -> Option<ConstToken> {
+    match word {
+        "true" => return Some(ConstToken::Bool(true)),
+        "false" => return Some(ConstToken::Bool(false)),
+        _ => {}
+    }
+    if !word.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    let digits = ["u8", "u16", "u32", "u64", "u128", "u256"]
+        .iter()
+        .find_map(|suffix| word.strip_suffix(suffix))
+        .unwrap_or(word)
+        .replace('_', "");
+    let value = match digits.strip_prefix("0x") {
+        Some(hex) => u128::from_str_radix(hex, 16).ok()?,
+        None => digits.parse().ok()?,
+    };
+    Some(ConstToken::Int(value))
+}
+
+fn const_or(tokens: &[ConstToken], pos: &mut usize) -> Option<ConstValue> {
+    let mut value = const_and(tokens, pos)?;
+    while tokens.get(*pos) == Some(&ConstToken::Op("||")) {
+        *pos += 1;
+        let (ConstValue::Bool(lhs), ConstValue::Bool(rhs)) = (value, const_and(tokens, pos)?)
+        else {
+            return None;
+        };
+        value = ConstValue::Bool(lhs || rhs);
+    }
+    Some(value)
+}
+
+fn const_and(tokens: &[ConstToken], pos: &mut usize) -> Option<ConstValue> {
+    let mut value = const_cmp(tokens, pos)?;
+    while tokens.get(*pos) == Some(&ConstToken::Op("&&")) {
+        *pos += 1;
+        let (ConstValue::Bool(lhs), ConstValue::Bool(rhs)) = (value, const_cmp(tokens, pos)?)
+        else {
+            return None;
+        };
+        value = ConstValue::Bool(lhs && rhs);
+    }
+    Some(value)
+}
+
+fn const_cmp(tokens: &[ConstToken], pos: &mut usize) -> Option<ConstValue> {
+    let lhs = const_unary(tokens, pos)?;
+    let Some(ConstToken::Op(op @ ("==" | "!=" | "<" | "<=" | ">" | ">="))) = tokens.get(*pos)
+    else {
+        return Some(lhs);
+    };
+    *pos += 1;
+    let rhs = const_unary(tokens, pos)?;
+    let result = match (lhs, rhs) {
+        (ConstValue::Bool(lhs), ConstValue::Bool(rhs)) => match *op {
+            "==" => lhs == rhs,
+            "!=" => lhs != rhs,
+            _ => return None,
+        },
+        (ConstValue::Int(lhs), ConstValue::Int(rhs)) => match *op {
+            "==" => lhs == rhs,
+            "!=" => lhs != rhs,
+            "<" => lhs < rhs,
+            "<=" => lhs <= rhs,
+            ">" => lhs > rhs,
+            _ => lhs >= rhs,
+        },
+        _ => return None,
+    };
+    Some(ConstValue::Bool(result))
+}
+
+fn const_unary(tokens: &[ConstToken], pos: &mut usize) -> Option<ConstValue> {
+    let token = *tokens.get(*pos)?;
+    *pos += 1;
+    match token {
+        ConstToken::Op("!") => match const_unary(tokens, pos)? {
+            ConstValue::Bool(b) => Some(ConstValue::Bool(!b)),
+            ConstValue::Int(_) => None,
+        },
+        ConstToken::Bool(b) => Some(ConstValue::Bool(b)),
+        ConstToken::Int(n) => Some(ConstValue::Int(n)),
+        ConstToken::Open => {
+            let value = const_or(tokens, pos)?;
+            (tokens.get(*pos) == Some(&ConstToken::Close)).then(|| {
+                *pos += 1;
+                value
+            })
+        }
+        _ => None,
+    }
+}
+
+// ============================================================================
+// ExcessiveNestingLint - Preview
+// ============================================================================
+
+/// Detects functions whose blocks nest deeper than `[lints.thresholds] max_nesting_depth`.
+///
+/// Each `{ ... }` block inside the function body is one level; the body itself is not
+/// counted. A `match` adds no level of its own, so a braced arm counts once.
+///
+/// # Example
+///
+/// ```move
+/// // bad!
+/// if (a) { if (b) { while (c) { if (d) { if (e) { if (f) { work() } } } } } }
+///
+/// // good!
+/// if (!a || !b) return;
+/// while (c) { step(d, e, f) }
+/// ```
+pub struct ExcessiveNestingLint;
+
+static EXCESSIVE_NESTING: LintDescriptor = LintDescriptor {
+    name: "excessive_nesting",
+    category: LintCategory::Style,
+    description: "Function nests blocks deeper than `[lints.thresholds] max_nesting_depth` (default 5)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::Syntactic,
+    gap: None,
+};
+
+impl LintRule for ExcessiveNestingLint {
+    fn descriptor(&self) -> &'static LintDescriptor {
+        &EXCESSIVE_NESTING
+    }
+
+    fn check(&self, root: Node, source: &str, ctx: &mut LintContext<'_>) {
+        let max_depth = ctx.settings().max_nesting_depth();
+        walk(root, &mut |node| {
+            if node.kind() != "function_definition" {
+                return;
+            }
+            let Some(body) = node.child_by_field_name("body") else {
+                return;
+            };
+            let Some((depth, deepest)) = deepest_block(body, 0) else {
+                return;
+            };
+            if depth <= max_depth {
+                return;
+            }
+
+            let name = node
+                .child_by_field_name("name")
+                .map_or("function", |name| slice(source, name));
+            let diagnostic = crate::diagnostics::Diagnostic {
+                lint: self.descriptor(),
+                level: ctx.settings().level_for(self.descriptor().name),
+                file: None,
+                span: Span::from_range(deepest.range()),
+                message: format!(
+                    "`{name}` nests blocks {depth} levels deep (limit {max_depth})"
+                ),
+                help: Some(
+                    "Return early for guard conditions or extract the inner logic into a helper function"
+                        .to_string(),
+                ),
+                suggestions: Vec::new(),
+                notes: Vec::new(),
+                labels: Vec::new(),
+            };
+            ctx.report_diagnostic_for_node(node, diagnostic);
+        });
+    }
+}
+
+/// The deepest block below `node` and its depth, with `node` itself at `depth`.
+///
+/// The first block found wins ties, so the report points at the earliest offender.
+fn deepest_block(node: Node, depth: usize) -> Option<(usize, Node)> {
+    let mut deepest: Option<(usize, Node)> = None;
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        // Grouping the arms of a `match` is not a level of its own.
+        let counts = child.kind() == "block" && node.kind() != "match_expression";
+        let child_depth = if counts { depth + 1 } else { depth };
+        let found =
+            deepest_block(child, child_depth).or_else(|| counts.then_some((child_depth, child)));
+        if let Some(found) = found
+            && deepest.is_none_or(|(max, _)| found.0 > max)
+        {
+            deepest = Some(found);
+        }
+    }
+    deepest
+}
+
+// ============================================================================
+// GlobalStorageOperatorLint - Preview
+// ============================================================================
+
+/// Detects core Move global storage operators, which Sui does not support.
+///
+/// Sui keeps state in objects rather than under account addresses, so `borrow_global`,
+/// `move_to` and friends usually mean code was ported from core Move. The compiler rejects
+/// them under the Sui flavor; this lint names the Sui replacement in fast mode.
+///
+/// # Example
+///
+/// ```move
+/// // bad!
+/// let counter = borrow_global_mut<Counter>(addr);
+///
+/// // good!
+/// public fun increment(counter: &mut Counter) { counter.value = counter.value + 1 }
+/// ```
+pub struct GlobalStorageOperatorLint;
+
+static GLOBAL_STORAGE_OPERATOR: LintDescriptor = LintDescriptor {
+    name: "global_storage_operator",
+    category: LintCategory::Suspicious,
+    description: "Core Move global storage operator (`borrow_global`, `move_to`, ...) - Sui uses objects and dynamic fields",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::Syntactic,
+    gap: None,
+};
+
+/// Global storage operators paired with the Sui construct that replaces them.
+const GLOBAL_STORAGE_OPERATORS: &[(&str, &str)] = &[
+    (
+        "borrow_global",
+        "take the object as a `&` parameter, or use `dynamic_field::borrow`",
+    ),
+    (
+        "borrow_global_mut",
+        "take the object as a `&mut` parameter, or use `dynamic_field::borrow_mut`",
+    ),
+    (
+        "move_from",
+        "take the object by value and unpack it, or use `dynamic_field::remove`",
+    ),
+    (
+        "move_to",
+        "create an object and `transfer`/`share_object` it, or use `dynamic_field::add`",
+    ),
+    (
+        "exists",
+        "use `dynamic_field::exists_` on the parent object",
+    ),
+];
+
+impl LintRule for GlobalStorageOperatorLint {
+    fn descriptor(&self) -> &'static LintDescriptor {
+        &GLOBAL_STORAGE_OPERATOR
+    }
+
+    fn check(&self, root: Node, source: &str, ctx: &mut LintContext<'_>) {
+        walk(root, &mut |node| {
+            if node.kind() != "call_expression" {
+                return;
+            }
+            let Some((callee, _args)) = split_call(slice(source, node).trim()) else {
+                return;
+            };
+            // `borrow_global<T>` and `borrow_global` both name the builtin; `m::exists` does not.
+            let name = callee.split('<').next().unwrap_or_default().trim();
+            let Some((operator, alternative)) = GLOBAL_STORAGE_OPERATORS
+                .iter()
+                .find(|(operator, _)| *operator == name)
+            else {
+                return;
+            };
+            ctx.report_node(
+                &GLOBAL_STORAGE_OPERATOR,
+                node,
+                format!(
+                    "`{operator}` is core Move global storage, which Sui does not have; {alternative}"
+                ),
+            );
+        });
+    }
+}
@@ -6,7 +6,7 @@ use crate::suppression;
 use tree_sitter::Node;
 
 use super::util::{
-    is_exact_test_attr, is_expected_failure_attr, is_only_whitespace_between,
+    has_test_attribute, is_exact_test_attr, is_expected_failure_attr, is_only_whitespace_between,
     position_from_byte_offset, slice, walk,
 };
 
@@ -276,26 +276,6 @@ fn get_enclosing_module_name(node: Node, source: &str) -> String {
     String::new()
 }
 
-/// Check if a function has a #[test] attribute
-fn has_test_attribute(node: Node, source: &str) -> bool {
-    // Check siblings before the function for annotations
-    let mut sibling = node.prev_sibling();
-    while let Some(sib) = sibling {
-        if sib.kind() == "annotation" {
-            let text = slice(source, sib);
-            if text.contains("#[test") {
-                return true;
-            }
-        }
-        // Stop at non-annotation, non-newline siblings
-        if sib.kind() != "annotation" && sib.kind() != "newline" {
-            break;
-        }
-        sibling = sib.prev_sibling();
-    }
-    false
-}
-
 // ============================================================================
 // MergeTestAttributesLint (existing)
 // ============================================================================
@@ -365,11 +345,12 @@ impl LintRule for MergeTestAttributesLint {
                 span,
                 "Merge `#[test]` and `#[expected_failure]` into a single attribute list",
                 Some("Combine into `#[test, expected_failure]`".to_string()),
-                Some(crate::diagnostics::Suggestion {
+                vec![crate::diagnostics::Suggestion {
                     message: "Merge attributes".to_string(),
                     replacement,
                     applicability: crate::diagnostics::Applicability::MachineApplicable,
-                }),
+                    span: None,
+                }],
             );
         }
     }
@@ -30,6 +30,150 @@ pub(crate) fn compact_ws(text: &str) -> String {
     text.chars().filter(|c| !c.is_whitespace()).collect()
 }
 
+/// A name brought into scope by a `use` declaration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct UseBinding {
+    /// Fully written path, e.g. `sui::transfer` for `use sui::{transfer::{Self as t}}`.
+    pub path: String,
+    /// Local name, e.g. `t`.
+    pub name: String,
+    /// Whether the local name comes from an explicit `as`.
+    pub aliased: bool,
+}
+
+/// Extract the bindings of a `use` declaration.
+///
+/// Handles `use a::b;`, `use a::b as c;`, `use a::{b as c, d};`, `use a::b::{Self as c};`
+/// and nested groups such as `use a::{b::{Self as c}, d::{e as f}};`. Method aliases
+/// (`use fun f as T.m;`) bind no module-level names and yield nothing.
+pub(crate) fn use_bindings(text: &str) -> Vec<UseBinding> {
+    let normalized = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let body = normalized
+        .trim_start_matches("use ")
+        .trim_end_matches(';')
+        .trim();
+
+    let mut out = Vec::new();
+    if !body.starts_with("fun ") {
+        collect_use_bindings("", body, &mut out);
+    }
+    out
+}
+
+/// Collect the members of one `use` item under `prefix`, recursing into groups.
+fn collect_use_bindings(prefix: &str, item: &str, out: &mut Vec<UseBinding>) {
+    let join = |head: &str| match (prefix.is_empty(), head.is_empty()) {
+        (true, _) => head.to_string(),
+        (false, true) => prefix.to_string(),
+        (false, false) => format!("{prefix}::{head}"),
+    };
+
+    if let Some(open) = item.find('{') {
+        let head = item[..open].trim().trim_end_matches("::").replace(' ', "");
+        let rest = item[open + 1..].trim_end();
+        let group = rest.strip_suffix('}').unwrap_or(rest);
+        let prefix = join(&head);
+        for member in split_top_level_commas(group) {
+            collect_use_bindings(&prefix, member.trim(), out);
+        }
+        return;
+    }
+
+    let (target, alias) = match item.split_once(" as ") {
+        Some((target, alias)) => (target, Some(alias.trim())),
+        None => (item, None),
+    };
+    let target = target.trim().replace(' ', "");
+    if target.is_empty() {
+        return;
+    }
+    let path = if target == "Self" {
+        prefix.to_string()
+    } else {
+        join(&target)
+    };
+    let name = match alias {
+        Some(alias) => alias.to_string(),
+        None => path.rsplit("::").next().unwrap_or_default().to_string(),
+    };
+    out.push(UseBinding {
+        path,
+        name,
+        aliased: alias.is_some(),
+    });
+}
+
+/// Split `a, b::{c, d}, e` on the commas that are not inside braces.
+fn split_top_level_commas(text: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (idx, ch) in text.char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&text[start..idx]);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+/// Negate a condition, simplifying the common shapes instead of wrapping in `!(...)`.
+pub(crate) fn negate_condition(condition: &str) -> String {
+    let trimmed = condition.trim();
+
+    if let Some(inner) = trimmed.strip_prefix('!')
+        && is_atomic_operand(inner)
+    {
+        return inner.trim().to_string();
+    }
+
+    if !trimmed.contains("&&") && !trimmed.contains("||") {
+        const INVERSES: &[(&str, &str)] = &[
+            ("==", "!="),
+            ("!=", "=="),
+            ("<=", ">"),
+            (">=", "<"),
+            ("<", ">="),
+            (">", "<="),
+        ];
+        for (op, inverse) in INVERSES {
+            let pattern = format!(" {op} ");
+            if trimmed.matches(&pattern).count() == 1 && count_comparisons(trimmed) == 1 {
+                return trimmed.replacen(&pattern, &format!(" {inverse} "), 1);
+            }
+        }
+    }
+
+    if is_atomic_operand(trimmed) {
+        format!("!{trimmed}")
+    } else {
+        format!("!({trimmed})")
+    }
+}
+
+/// Counts whitespace-delimited comparison operators in `text`.
+fn count_comparisons(text: &str) -> usize {
+    text.split_whitespace()
+        .filter(|tok| matches!(*tok, "==" | "!=" | "<=" | ">=" | "<" | ">"))
+        .count()
+}
+
+/// A simple identifier, field access, or call that can be negated without parentheses.
+pub(crate) fn is_atomic_operand(text: &str) -> bool {
+    let text = text.trim();
+    !text.is_empty()
+        && !text.chars().any(char::is_whitespace)
+        && text
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | ':' | '(' | ')'))
+}
+
 pub(crate) fn split_call(text: &str) -> Option<(&str, &str)> {
     let open = text.find('(')?;
     let close = find_matching_paren(text, open)?;
@@ -140,6 +284,26 @@ pub(crate) fn is_simple_ident(text: &str) -> bool {
     chars.all(|c| c == '_' || c.is_ascii_alphanumeric())
 }
 
+/// Check if a function has a `#[test]` (or `#[test_only]`) annotation.
+pub(crate) fn has_test_attribute(node: Node, source: &str) -> bool {
+    // Check siblings before the function for annotations
+    let mut sibling = node.prev_sibling();
+    while let Some(sib) = sibling {
+        if sib.kind() == "annotation" {
+            let text = slice(source, sib);
+            if text.contains("#[test") {
+                return true;
+            }
+        }
+        // Stop at non-annotation, non-newline siblings
+        if sib.kind() != "annotation" && sib.kind() != "newline" {
+            break;
+        }
+        sibling = sib.prev_sibling();
+    }
+    false
+}
+
 pub(crate) fn is_exact_test_attr(text: &str) -> bool {
     text.trim() == "#[test]"
 }
@@ -172,6 +172,18 @@ pub static ENTRY_FUNCTION_RETURNS_VALUE: LintDescriptor = LintDescriptor {
     gap: Some(TypeSystemGap::ValueFlow),
 };
 
+/// Detects entry functions with more parameters than `[lints.thresholds] entry_max_params`
+/// (default 8), not counting `TxContext` or `Clock`.
+pub static ENTRY_FUNCTION_PARAM_COUNT: LintDescriptor = LintDescriptor {
+    name: "entry_function_param_count",
+    category: LintCategory::Style,
+    description: "Entry function has too many parameters - group related arguments into a struct (type-based)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: None,
+};
+
 /// Detects entry functions that are private (unreachable from transactions).
 pub static PRIVATE_ENTRY_FUNCTION: LintDescriptor = LintDescriptor {
     name: "private_entry_function",
@@ -210,6 +222,48 @@ pub static EVENT_PAST_TENSE: LintDescriptor = LintDescriptor {
     gap: None,
 };
 
+/// Detects event-like structs declared without `copy + drop`, or with `key`.
+///
+/// Complements `event_emit_type_sanity` by reporting at the struct declaration. A struct is
+/// event-like when it is passed to `event::emit` or its name ends in `Event`.
+pub static EVENT_STRUCT_MISSING_ABILITIES: LintDescriptor = LintDescriptor {
+    name: "event_struct_missing_abilities",
+    category: LintCategory::Security,
+    description: "Event struct declared without copy + drop or with key (type-based, requires --mode full --preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::ApiMisuse),
+};
+
+/// Detects one abort code guarding unrelated `assert!`/`abort` conditions in a function.
+///
+/// Distinct failure modes sharing an abort code cannot be told apart from the on-chain
+/// abort alone.
+pub static AMBIGUOUS_ABORT_CODE: LintDescriptor = LintDescriptor {
+    name: "ambiguous_abort_code",
+    category: LintCategory::Suspicious,
+    description: "Same abort code guards unrelated conditions - use one code per failure mode (type-based, preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::StyleConvention),
+};
+
+/// Detects `transfer::public_*` of a `key + store` type declared in the calling module.
+///
+/// The defining module can call the private `transfer`, `share_object`, or `freeze_object`,
+/// which do not depend on `store` and keep the operation module-controlled.
+pub static WRONG_TRANSFER_VARIANT: LintDescriptor = LintDescriptor {
+    name: "wrong_transfer_variant",
+    category: LintCategory::Style,
+    description: "`public_*` transfer of a type defined in the same module - use the private variant (type-based, preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::StyleConvention),
+};
+
 /// DEPRECATED: This lint cannot be implemented with principled detection.
 ///
 /// The ability pattern `key + store + !copy + !drop` describes ALL valuable Sui objects,
@@ -300,6 +354,288 @@ pub static UNBOUNDED_ITERATION_OVER_PARAM_VECTOR: LintDescriptor = LintDescripto
     gap: Some(TypeSystemGap::ResourceExhaustion),
 };
 
+/// Detects `assert!(vector::length(&v) == N, E)` checks.
+///
+/// Exact-length asserts reject longer inputs; when only a minimum is needed, `>=` is safer.
+pub static EXACT_LENGTH_ASSERT: LintDescriptor = LintDescriptor {
+    name: "exact_length_assert",
+    category: LintCategory::Suspicious,
+    description: "Assertion requires an exact vector length - consider `>=` if only a minimum is needed (type-based, preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::ApiMisuse),
+};
+
+/// Detects a second `dynamic_field::add` with the same literal key on the same object.
+///
+/// Both adds must run on every path through the function with no intervening call that could
+/// remove the field, so the second one always aborts. Key abilities are left to the compiler.
+pub static DUPLICATE_DYNAMIC_FIELD_ADD: LintDescriptor = LintDescriptor {
+    name: "duplicate_dynamic_field_add",
+    category: LintCategory::Suspicious,
+    description: "The same literal key is added twice to one object, so the second add always aborts (type-based, preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::ApiMisuse),
+};
+
+/// Detects function type parameters that appear in no type position at all.
+///
+/// A type parameter that is absent from the parameters, return type, and every type
+/// in the body (including call type arguments) has no effect and can be removed, or
+/// signals a missing witness argument. Parameters used only as body type arguments
+/// (e.g. `type_name::get<T>()`) are intentional type-level dispatch and are not flagged.
+pub static UNUSED_FUNCTION_TYPE_PARAMETER: LintDescriptor = LintDescriptor {
+    name: "unused_function_type_parameter",
+    category: LintCategory::Suspicious,
+    description: "Function type parameter is never used in the signature or body (type-based, preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::TypeConfusion),
+};
+
+/// Detects function type parameters constrained with `store` that the function never needs.
+///
+/// `store` is needed when the parameter flows into a callee or struct type parameter that
+/// declares it, or into a type that must have `key`. Otherwise the constraint only narrows
+/// which types callers may pass.
+pub static REDUNDANT_ABILITY_CONSTRAINT: LintDescriptor = LintDescriptor {
+    name: "redundant_ability_constraint",
+    category: LintCategory::Style,
+    description: "Type parameter is constrained with store but nothing in the function needs it (type-based, preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::AbilityMismatch),
+};
+
+/// Detects `transfer::transfer`/`share_object`/`freeze_object` on a type without `key`.
+///
+/// The framework rejects these at compile time; the lint names the offending type so the
+/// fix is obvious.
+pub static TRANSFER_OF_NON_OBJECT: LintDescriptor = LintDescriptor {
+    name: "transfer_of_non_object",
+    category: LintCategory::Suspicious,
+    description: "Transfer, share, or freeze of a value whose type lacks `key` (type-based, preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::AbilityMismatch),
+};
+
+/// Detects public functions that take a `&mut` object but only read it to build the return value.
+///
+/// Preview because tightening a published `public` signature is not upgrade-compatible; the
+/// finding is still worth fixing before the first publish.
+pub static MUT_PARAM_ONLY_READ_FOR_RETURN: LintDescriptor = LintDescriptor {
+    name: "mut_param_only_read_for_return",
+    category: LintCategory::Style,
+    description: "Public function takes `&mut` object that is only read to compute the return value - use `&` (type-based, preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: None,
+};
+
+/// Detects `destroy`/`delete` functions that do not consume the object they are named after.
+///
+/// Preview because it keys off the function name.
+pub static DESTROY_FUNCTION_DOES_NOT_CONSUME: LintDescriptor = LintDescriptor {
+    name: "destroy_function_does_not_consume",
+    category: LintCategory::Suspicious,
+    description: "Function named destroy/delete takes its object by reference or never unpacks it (type-based, preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::ApiMisuse),
+};
+
+/// Detects public functions that return `&mut` references into the module's own state.
+///
+/// Any package can then mutate those fields directly, bypassing the module's invariants.
+pub static PUBLIC_RETURNS_MUT_REF: LintDescriptor = LintDescriptor {
+    name: "public_returns_mut_ref",
+    category: LintCategory::Security,
+    description: "Public function returns `&mut` into module-internal state (type-based, preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::ApiMisuse),
+};
+
+/// Detects `assert!` calls inside loops whose condition does not change between iterations.
+///
+/// The check runs (and costs gas) on every iteration; as a precondition before the loop it runs
+/// once and states the intent more clearly. Hoisting is only equivalent when the loop body runs
+/// at least once: before a zero-iteration loop the hoisted assert could abort where the
+/// original did not.
+pub static LOOP_INVARIANT_ASSERT: LintDescriptor = LintDescriptor {
+    name: "loop_invariant_assert",
+    category: LintCategory::Style,
+    description: "Loop-invariant `assert!` inside a loop - consider hoisting it if the loop always runs (type-based, preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: None,
+};
+
+/// Detects `while` loops whose body never modifies the locals their condition reads.
+///
+/// Without a `break`, `return`, or `abort` in the body, such a loop runs until it exhausts gas.
+pub static LOOP_NEVER_PROGRESSES: LintDescriptor = LintDescriptor {
+    name: "loop_never_progresses",
+    category: LintCategory::Suspicious,
+    description: "`while` loop never updates its condition variables - infinite loop (type-based, preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::ResourceExhaustion),
+};
+
+/// Detects entry functions whose `TxContext` parameter is not the last parameter.
+///
+/// Sui convention (and some client tooling) expects `ctx: &mut TxContext` to come last.
+pub static TXCONTEXT_NOT_LAST_PARAM: LintDescriptor = LintDescriptor {
+    name: "txcontext_not_last_param",
+    category: LintCategory::Style,
+    description: "Entry function takes `TxContext` before other parameters - make it the last parameter (type-based, preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: None,
+};
+
+/// Detects non-public entry functions that take `&mut TxContext` but never need mutation.
+///
+/// `&TxContext` documents that the function creates no objects and lets readers skip it when
+/// looking for where IDs are minted.
+pub static UNNECESSARY_MUT_TX_CONTEXT: LintDescriptor = LintDescriptor {
+    name: "unnecessary_mut_tx_context",
+    category: LintCategory::Style,
+    description: "Entry function takes `&mut TxContext` but only reads it - use `&TxContext` (type-based, preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: None,
+};
+
+/// Detects `&mut` parameters that are never mutated.
+///
+/// A `&mut` parameter demands exclusive access from every caller; `&` states that the function
+/// only reads the value.
+pub static UNNECESSARY_MUT_PARAM: LintDescriptor = LintDescriptor {
+    name: "unnecessary_mut_param",
+    category: LintCategory::Style,
+    description: "`&mut` parameter is never mutated - use `&` (type-based, preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: None,
+};
+
+/// Detects public functions that return the `UID` of one of the module's own objects.
+///
+/// With an object's `UID`, other packages can read (or, by value, re-wrap) state the module
+/// meant to keep behind its own API.
+pub static EXPOSES_UID: LintDescriptor = LintDescriptor {
+    name: "exposes_uid",
+    category: LintCategory::Security,
+    description: "Public function returns an object's own `UID` - expose an `ID` or specific accessors instead (type-based, preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::CapabilityEscape),
+};
+
+/// Detects non-test functions that take a `signer` or `&signer` parameter.
+///
+/// Sui transactions never supply a `signer`; code ported from core Move should take
+/// `&TxContext` and use `tx_context::sender` instead.
+pub static SUI_SIGNER_PARAM: LintDescriptor = LintDescriptor {
+    name: "sui_signer_param",
+    category: LintCategory::Suspicious,
+    description: "Function takes a `signer` parameter, which Sui never provides - take `&TxContext` and use `tx_context::sender` (type-based, preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::ApiMisuse),
+};
+
+/// Detects non-test functions that take `&Clock` but never read it.
+///
+/// An unused clock suggests time-dependence that isn't there, and every caller still has to
+/// add the shared `Clock` object to their transaction.
+pub static UNUSED_CLOCK_PARAM: LintDescriptor = LintDescriptor {
+    name: "unused_clock_param",
+    category: LintCategory::Style,
+    description: "Function takes `&Clock` but never reads it - remove the parameter (type-based, preview)",
+    group: RuleGroup::Preview,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: None,
+};
+
+/// Detects public functions that take a capability by value and return it unchanged.
+///
+/// Such pass-throughs do no work with the capability but move it through another call frame,
+/// which makes custody harder to audit.
+pub static CAPABILITY_PASSTHROUGH: LintDescriptor = LintDescriptor {
+    name: "capability_passthrough",
+    category: LintCategory::Security,
+    description: "Public function takes a capability by value and returns it unchanged (type-based, requires --mode full --experimental)",
+    group: RuleGroup::Experimental,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::CapabilityEscape),
+};
+
+/// Detects `vector::borrow`/`borrow_mut` at a loop index whose bound is unrelated to the
+/// borrowed vector's length.
+///
+/// In `while (i < count) { vector::borrow(&items, i); ... }` nothing ties `count` to
+/// `vector::length(&items)`, so a shorter `items` aborts the transaction mid-loop.
+pub static INDEX_MAY_EXCEED_LENGTH: LintDescriptor = LintDescriptor {
+    name: "index_may_exceed_length",
+    category: LintCategory::Suspicious,
+    description: "Loop borrows a vector at its index but is bounded by something other than that vector's length (type-based, requires --mode full --experimental)",
+    group: RuleGroup::Experimental,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::ApiMisuse),
+};
+
+/// Detects counting loops bounded by an integer parameter whose increment can overflow.
+///
+/// Covers inclusive bounds (`while (i <= n)`) and non-unit steps (`i = i + step`) where `n`
+/// comes straight from a parameter and no `assert!` caps it.
+pub static LOOP_COUNTER_OVERFLOW_RISK: LintDescriptor = LintDescriptor {
+    name: "loop_counter_overflow_risk",
+    category: LintCategory::Suspicious,
+    description: "Loop counter bounded by an unchecked integer parameter can overflow on increment (type-based, requires --mode full --experimental)",
+    group: RuleGroup::Experimental,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::ArithmeticSafety),
+};
+
+/// Detects `copy` structs with a field whose type is, or mentions, a `key` object.
+///
+/// The compiler rejects a `key` value stored directly in a `copy` struct, but a phantom type
+/// argument (`Marker<Pool>`) or a type parameter constrained `key + copy` gets through. Either
+/// makes copies of the struct look like they carry an object they cannot hold.
+pub static COPY_STRUCT_WITH_OBJECT_FIELD: LintDescriptor = LintDescriptor {
+    name: "copy_struct_with_object_field",
+    category: LintCategory::Suspicious,
+    description: "`copy` struct has a field whose type involves a `key` object (type-based, requires --mode full --experimental)",
+    group: RuleGroup::Experimental,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::AbilityMismatch),
+};
+
 /// Detects generic functions that accept a `type_name::TypeName` witness but never use it.
 ///
 /// If a witness parameter is unused, the function may be missing a type validation check.
@@ -419,6 +755,49 @@ pub static COPYABLE_FUNGIBLE_TYPE: LintDescriptor = LintDescriptor {
     gap: Some(TypeSystemGap::AbilityMismatch),
 };
 
+/// Detects `Coin<T>` struct fields that the declaring module only uses for their value.
+///
+/// A more targeted companion to `coin_field`: it stays quiet when the module splits the field
+/// into new coins or hands it to other code, and suggests the `Balance` API otherwise.
+pub static PREFER_BALANCE_FIELD: LintDescriptor = LintDescriptor {
+    name: "prefer_balance_field",
+    category: LintCategory::Suspicious,
+    description: "Coin struct field only used for its value - store a Balance instead (type-based, experimental)",
+    group: RuleGroup::Experimental,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::ApiMisuse),
+};
+
+/// Detects `coin::split` calls whose amount is a parameter with no preceding guard.
+///
+/// Splitting more than the coin's value aborts and splitting zero is usually a logic bug.
+/// Experimental because validation is tracked across statements: only a top-level
+/// `assert!` comparing the parameter earlier in the same function counts as a guard.
+pub static SUSPICIOUS_COIN_SPLIT: LintDescriptor = LintDescriptor {
+    name: "suspicious_coin_split",
+    category: LintCategory::Suspicious,
+    description: "coin::split with an unvalidated amount parameter (type-based, experimental)",
+    group: RuleGroup::Experimental,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::ValueFlow),
+};
+
+/// Detects `transfer::freeze_object` on an object that holds a `Coin`, `Balance`, or `key` object.
+///
+/// Frozen objects are immutable, so value stored in them is locked for good. Fields are
+/// walked recursively through nested structs.
+pub static FREEZE_LOCKS_VALUE: LintDescriptor = LintDescriptor {
+    name: "freeze_locks_value",
+    category: LintCategory::Security,
+    description: "Freezing an object that holds Coin, Balance, or another object locks that value forever (type-based, experimental)",
+    group: RuleGroup::Experimental,
+    fix: FixDescriptor::none(),
+    analysis: AnalysisKind::TypeBased,
+    gap: Some(TypeSystemGap::OwnershipViolation),
+};
+
 /// Detects structs that are transferable (`key + store`) but also copyable.
 ///
 /// A `key + store + copy` type is almost always a severe bug:
@@ -610,12 +989,12 @@ pub static CAPABILITY_ANTIPATTERNS: LintDescriptor = LintDescriptor {
 ///
 /// Uses type-based detection to verify the call is to a known oracle module.
 ///
-/// DEPRECATED: Superseded by stale_oracle_price_v3 which uses CFG-aware dataflow
+/// DEPRECATED: Superseded by stale_oracle_price which uses CFG-aware dataflow
 /// analysis to track whether prices are validated before use.
 pub static STALE_ORACLE_PRICE_V2: LintDescriptor = LintDescriptor {
     name: "stale_oracle_price_v2",
     category: LintCategory::Security,
-    description: "Using get_price_unsafe from known oracle may return stale prices (deprecated: use v3 with --preview)",
+    description: "Using get_price_unsafe from known oracle may return stale prices (deprecated: use stale_oracle_price with --preview)",
     group: RuleGroup::Deprecated,
     fix: FixDescriptor::none(),
     analysis: AnalysisKind::TypeBased,
@@ -660,6 +1039,7 @@ static DESCRIPTORS: &[&LintDescriptor] = &[
     &EVENT_PAST_TENSE,
     &ENTRY_FUNCTION_RETURNS_VALUE,
     &PRIVATE_ENTRY_FUNCTION,
+    &ENTRY_FUNCTION_PARAM_COUNT,
     &COPYABLE_CAPABILITY,
     &DROPPABLE_CAPABILITY,
     &CAPABILITY_ANTIPATTERNS,
@@ -674,6 +1054,25 @@ static DESCRIPTORS: &[&LintDescriptor] = &[
     &CAPABILITY_TRANSFER_LITERAL_ADDRESS,
     &MUT_KEY_PARAM_MISSING_AUTHORITY,
     &UNBOUNDED_ITERATION_OVER_PARAM_VECTOR,
+    &EXACT_LENGTH_ASSERT,
+    &DUPLICATE_DYNAMIC_FIELD_ADD,
+    &UNUSED_FUNCTION_TYPE_PARAMETER,
+    &REDUNDANT_ABILITY_CONSTRAINT,
+    &TRANSFER_OF_NON_OBJECT,
+    &MUT_PARAM_ONLY_READ_FOR_RETURN,
+    &DESTROY_FUNCTION_DOES_NOT_CONSUME,
+    &PUBLIC_RETURNS_MUT_REF,
+    &LOOP_INVARIANT_ASSERT,
+    &LOOP_NEVER_PROGRESSES,
+    &TXCONTEXT_NOT_LAST_PARAM,
+    &UNNECESSARY_MUT_TX_CONTEXT,
+    &UNNECESSARY_MUT_PARAM,
+    &EXPOSES_UID,
+    &SUI_SIGNER_PARAM,
+    &UNUSED_CLOCK_PARAM,
+    &EVENT_STRUCT_MISSING_ABILITIES,
+    &WRONG_TRANSFER_VARIANT,
+    &AMBIGUOUS_ABORT_CODE,
     // Security (experimental, type-based)
     &UNCHECKED_DIVISION,
     &UNUSED_RETURN_VALUE,
@@ -682,8 +1081,15 @@ static DESCRIPTORS: &[&LintDescriptor] = &[
     &DROPPABLE_FLASH_LOAN_RECEIPT,
     // NOTE: RECEIPT_MISSING_PHANTOM_TYPE deprecated - flags legitimate non-receipt returns (pools, positions)
     &COPYABLE_FUNGIBLE_TYPE,
+    &PREFER_BALANCE_FIELD,
+    &SUSPICIOUS_COIN_SPLIT,
+    &FREEZE_LOCKS_VALUE,
     &CAPABILITY_TRANSFER_V2,
     &GENERIC_TYPE_WITNESS_UNUSED,
+    &CAPABILITY_PASSTHROUGH,
+    &INDEX_MAY_EXCEED_LENGTH,
+    &LOOP_COUNTER_OVERFLOW_RISK,
+    &COPY_STRUCT_WITH_OBJECT_FIELD,
     // NOTE: phantom_capability is in absint_lints.rs (CFG-aware)
     // NOTE: unused_hot_potato requires dataflow analysis (future work)
 ];
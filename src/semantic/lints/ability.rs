@@ -2,12 +2,15 @@ use crate::diagnostics::Diagnostic;
 use crate::error::Result as ClippyResult;
 use crate::lint::LintSettings;
 
+use crate::type_classifier::{abilities_of_type, has_copy_ability, has_key_ability};
+use move_compiler::naming::ast as N;
 use move_compiler::parser::ast::TargetKind;
 use move_compiler::shared::Identifier;
 use move_compiler::shared::{files::MappedFiles, program_info::TypingProgramInfo};
 
 use super::super::util::{diag_from_loc, push_diag};
-use super::super::{COPYABLE_CAPABILITY, DROPPABLE_CAPABILITY};
+use super::super::{COPY_STRUCT_WITH_OBJECT_FIELD, COPYABLE_CAPABILITY, DROPPABLE_CAPABILITY};
+use super::shared::format_type;
 
 type Result<T> = ClippyResult<T>;
 
@@ -50,7 +53,7 @@ pub(crate) fn lint_copyable_capability(
     file_map: &MappedFiles,
     info: &TypingProgramInfo,
 ) -> Result<()> {
-    use crate::type_classifier::{has_copy_ability, has_key_ability, has_store_ability};
+    use crate::type_classifier::has_store_ability;
 
     for (_mident, minfo) in info.modules.key_cloned_iter() {
         match minfo.target_kind {
@@ -102,9 +105,7 @@ pub(crate) fn lint_droppable_capability(
     file_map: &MappedFiles,
     info: &TypingProgramInfo,
 ) -> Result<()> {
-    use crate::type_classifier::{
-        has_copy_ability, has_drop_ability, has_key_ability, has_store_ability,
-    };
+    use crate::type_classifier::{has_drop_ability, has_store_ability};
 
     for (_mident, minfo) in info.modules.key_cloned_iter() {
         match minfo.target_kind {
@@ -150,3 +151,76 @@ pub(crate) fn lint_droppable_capability(
 
     Ok(())
 }
+
+/// `ty` itself or a type argument of it (at any depth) that has `key`.
+///
+/// Phantom arguments are searched too: they are how a `key` type ends up inside a `copy`
+/// struct without the compiler objecting.
+fn key_type_in(ty: &N::Type_) -> Option<&N::Type_> {
+    if abilities_of_type(ty).is_some_and(|a| has_key_ability(&a)) {
+        return Some(ty);
+    }
+    match ty {
+        N::Type_::Apply(_, _, type_args) => type_args.iter().find_map(|t| key_type_in(&t.value)),
+        _ => None,
+    }
+}
+
+/// Detects `copy` structs with a field whose type is, or has a type argument that is, a `key`
+/// object or a type parameter constrained by `key`.
+///
+/// `key` structs are skipped; `copyable_capability` already covers `key + copy`.
+pub(crate) fn lint_copy_struct_with_object_field(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    info: &TypingProgramInfo,
+) -> Result<()> {
+    for (_mident, minfo) in info.modules.key_cloned_iter() {
+        match minfo.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (sname, sdef) in minfo.structs.key_cloned_iter() {
+            if !has_copy_ability(&sdef.abilities) || has_key_ability(&sdef.abilities) {
+                continue;
+            }
+            let N::StructFields::Defined(_, fields) = &sdef.fields else {
+                continue;
+            };
+            let sym = sname.value();
+            let name_str = sym.as_str();
+
+            for (field_loc, field, (_, (_, field_ty))) in fields.iter() {
+                let Some(object_ty) = key_type_in(&field_ty.value) else {
+                    continue;
+                };
+                let Some((file, span, contents)) = diag_from_loc(file_map, &field_loc) else {
+                    continue;
+                };
+                let anchor = field_loc.start() as usize;
+                let field_ty_str = format_type(&field_ty.value);
+                let object = format_type(object_ty);
+
+                push_diag(
+                    out,
+                    settings,
+                    &COPY_STRUCT_WITH_OBJECT_FIELD,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    anchor,
+                    format!(
+                        "Field `{field}` of `copy` struct `{name_str}` has type `{field_ty_str}`, which involves the `key` type `{object}`. \
+                         Copies of `{name_str}` cannot carry an object; store an `ID` instead, or drop `copy`."
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+use crate::diagnostics::Diagnostic;
+use crate::error::Result as ClippyResult;
+use crate::lint::LintSettings;
+use move_compiler::expansion::ast::Value_;
+use move_compiler::parser::ast::TargetKind;
+use move_compiler::shared::Identifier;
+use move_compiler::shared::files::MappedFiles;
+use move_compiler::typing::ast as T;
+use move_ir_types::location::Loc;
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::super::AMBIGUOUS_ABORT_CODE;
+use super::super::util::{diag_from_loc, push_diag};
+use super::shared::{exp_list_nth_single, for_each_exp, for_each_exp_in_seq};
+
+type Result<T> = ClippyResult<T>;
+
+/// One `assert!` or `if (..) abort` in a function body.
+struct AbortCheck<'a> {
+    /// Display name of the abort code (`E_INVALID`, `7`)
+    code: String,
+    cond: &'a T::Exp,
+    loc: Loc,
+}
+
+/// Display name for a literal or constant abort code, if the code is one.
+///
+/// Numeric literals are shown as written without a suffix, so `7` and `7u64` are one code.
+fn abort_code_name(exp: &T::Exp) -> Option<String> {
+    match &exp.exp.value {
+        T::UnannotatedExp_::Value(val) => match &val.value {
+            Value_::U8(n) => Some(n.to_string()),
+            Value_::U16(n) => Some(n.to_string()),
+            Value_::U32(n) => Some(n.to_string()),
+            Value_::U64(n) => Some(n.to_string()),
+            Value_::U128(n) => Some(n.to_string()),
+            Value_::U256(n) | Value_::InferredNum(n) => Some(n.to_string()),
+            _ => None,
+        },
+        T::UnannotatedExp_::Constant(_mident, name) => Some(name.value().to_string()),
+        T::UnannotatedExp_::Annotate(inner, _) => abort_code_name(inner),
+        _ => None,
+    }
+}
+
+/// The code of `abort CODE`, or of a block consisting only of `abort CODE`.
+fn abort_code_of(exp: &T::Exp) -> Option<&T::Exp> {
+    match &exp.exp.value {
+        T::UnannotatedExp_::Abort(code) => Some(code),
+        T::UnannotatedExp_::Block((_, seq_items)) if seq_items.len() == 1 => {
+            match &seq_items.front()?.value {
+                T::SequenceItem_::Seq(inner) => abort_code_of(inner),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn abort_check(exp: &T::Exp) -> Option<AbortCheck<'_>> {
+    let (cond, code) = match &exp.exp.value {
+        T::UnannotatedExp_::Builtin(builtin, args) => {
+            if !format!("{:?}", builtin).contains("Assert") {
+                return None;
+            }
+            (exp_list_nth_single(args, 0)?, exp_list_nth_single(args, 1)?)
+        }
+        T::UnannotatedExp_::IfElse(cond, if_body, else_body) => {
+            let code =
+                abort_code_of(if_body).or_else(|| else_body.as_deref().and_then(abort_code_of))?;
+            (cond.as_ref(), code)
+        }
+        _ => return None,
+    };
+    Some(AbortCheck {
+        code: abort_code_name(code)?,
+        cond,
+        loc: exp.exp.loc,
+    })
+}
+
+/// Locals read by a condition.
+fn condition_locals(cond: &T::Exp) -> BTreeSet<u16> {
+    let mut locals = BTreeSet::new();
+    for_each_exp(cond, &mut |exp| match &exp.exp.value {
+        T::UnannotatedExp_::Use(var)
+        | T::UnannotatedExp_::Copy { var, .. }
+        | T::UnannotatedExp_::Move { var, .. }
+        | T::UnannotatedExp_::BorrowLocal(_, var) => {
+            locals.insert(var.value.id);
+        }
+        _ => {}
+    });
+    locals
+}
+
+/// Detects one abort code guarding unrelated conditions within a function.
+///
+/// Two checks are treated as different failure modes when their conditions read disjoint
+/// sets of locals (e.g. `amount > 0` and `vault.owner == sender`). Checks that share a
+/// local, such as the two halves of a range check, are left alone.
+pub(crate) fn lint_ambiguous_abort_code(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+            let fn_name_sym = fname.value();
+            let fn_name = fn_name_sym.as_str();
+
+            let mut by_code: BTreeMap<String, Vec<(Loc, BTreeSet<u16>)>> = BTreeMap::new();
+            for_each_exp_in_seq(seq_items.iter(), &mut |exp| {
+                if let Some(check) = abort_check(exp) {
+                    let locals = condition_locals(check.cond);
+                    if !locals.is_empty() {
+                        by_code
+                            .entry(check.code)
+                            .or_default()
+                            .push((check.loc, locals));
+                    }
+                }
+            });
+
+            for (code, checks) in by_code {
+                let Some((first_loc, later_loc)) = checks.iter().enumerate().find_map(|(i, b)| {
+                    checks[..i]
+                        .iter()
+                        .find(|a| a.1.is_disjoint(&b.1))
+                        .map(|a| (a.0, b.0))
+                }) else {
+                    continue;
+                };
+                let Some((_, first_span, _)) = diag_from_loc(file_map, &first_loc) else {
+                    continue;
+                };
+                let Some((file, span, contents)) = diag_from_loc(file_map, &later_loc) else {
+                    continue;
+                };
+                push_diag(
+                    out,
+                    settings,
+                    &AMBIGUOUS_ABORT_CODE,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    later_loc.start() as usize,
+                    format!(
+                        "`{code}` in `{fn_name}` also guards an unrelated check on line {}. \
+                         Use a distinct abort code for each failure mode so aborts can be told apart.",
+                        first_span.start.row
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,6 +1,11 @@
 use crate::diagnostics::Diagnostic;
 use crate::error::Result as ClippyResult;
 use crate::lint::LintSettings;
+use crate::type_classifier::{
+    capability_call_type_arg, is_capability_type_from_ty, is_sui_framework_call,
+};
+use move_compiler::expansion::ast::Visibility;
+use move_compiler::naming::ast as N;
 use move_compiler::parser::ast::TargetKind;
 use move_compiler::shared::Identifier;
 use move_compiler::shared::files::MappedFiles;
@@ -8,8 +13,10 @@ use move_compiler::shared::program_info::TypingProgramInfo;
 use move_compiler::typing::ast as T;
 
 use super::super::util::{diag_from_loc, push_diag};
-use super::super::{CAPABILITY_TRANSFER_LITERAL_ADDRESS, CAPABILITY_TRANSFER_V2};
-use super::shared::{format_type, is_coin_type};
+use super::super::{
+    CAPABILITY_PASSTHROUGH, CAPABILITY_TRANSFER_LITERAL_ADDRESS, CAPABILITY_TRANSFER_V2,
+};
+use super::shared::{for_each_exp_in_seq, format_type, is_local_var};
 
 type Result<T> = ClippyResult<T>;
 
@@ -160,22 +167,13 @@ fn check_capability_transfer_literal_in_exp(
     file_map: &MappedFiles,
     func_name: &str,
 ) {
-    use crate::type_classifier::is_capability_type_from_ty;
-
     if let T::UnannotatedExp_::ModuleCall(call) = &exp.exp.value {
-        let module_sym = call.module.value.module.value();
-        let module_name = module_sym.as_str();
-        let call_sym = call.name.value();
-        let call_name = call_sym.as_str();
-
         let is_transfer_call = transfer_fns
             .iter()
-            .any(|(mod_pat, fn_pat)| module_name == *mod_pat && call_name == *fn_pat);
+            .any(|(mod_pat, fn_pat)| is_sui_framework_call(call, mod_pat, fn_pat));
 
         if is_transfer_call
-            && let Some(type_arg) = call.type_arguments.first()
-            && !is_coin_type(&type_arg.value)
-            && is_capability_type_from_ty(&type_arg.value)
+            && let Some(type_arg) = capability_call_type_arg(call, 0)
             && let Some(recipient) = exp_list_nth_single(&call.arguments, 1)
             && looks_like_address_literal(recipient)
         {
@@ -359,23 +357,12 @@ fn check_capability_transfer_in_exp(
     file_map: &MappedFiles,
     func_name: &str,
 ) {
-    use crate::type_classifier::is_capability_type_from_ty;
-
     if let T::UnannotatedExp_::ModuleCall(call) = &exp.exp.value {
-        let module_sym = call.module.value.module.value();
-        let module_name = module_sym.as_str();
-        let call_sym = call.name.value();
-        let call_name = call_sym.as_str();
-
         let is_transfer_call = transfer_fns
             .iter()
-            .any(|(mod_pat, fn_pat)| module_name == *mod_pat && call_name == *fn_pat);
+            .any(|(mod_pat, fn_pat)| is_sui_framework_call(call, mod_pat, fn_pat));
 
-        if is_transfer_call
-            && let Some(type_arg) = call.type_arguments.first()
-            && !is_coin_type(&type_arg.value)
-            && is_capability_type_from_ty(&type_arg.value)
-        {
+        if is_transfer_call && let Some(type_arg) = capability_call_type_arg(call, 0) {
             let loc = exp.exp.loc;
             let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
                 return;
@@ -481,3 +468,104 @@ fn check_capability_transfer_in_exp(
         _ => {}
     }
 }
+
+// =========================================================================
+// Capability Passthrough Lint (type-based, experimental)
+// =========================================================================
+
+/// Whether the tail expression returns local `target` directly, alone or as a tuple member.
+fn returns_var(tail: &T::Exp, target: u16) -> bool {
+    match &tail.exp.value {
+        T::UnannotatedExp_::ExpList(items) => items
+            .iter()
+            .any(|item| matches!(item, T::ExpListItem::Single(e, _) if is_local_var(e, target))),
+        T::UnannotatedExp_::Annotate(inner, _) => returns_var(inner, target),
+        _ => is_local_var(tail, target),
+    }
+}
+
+/// Detects public functions that take a capability by value and hand the same value back
+/// without otherwise touching it. Reference parameters are skipped.
+///
+/// The capability must be mentioned exactly once in the body: in the returned expression.
+/// Any other use (borrowing it for a check, passing it on) means the function does real work
+/// with the capability and is left alone.
+pub(crate) fn lint_capability_passthrough(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            if !matches!(fdef.visibility, Visibility::Public(_)) {
+                continue;
+            }
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+            let Some(T::SequenceItem_::Seq(tail)) = seq_items.back().map(|item| &item.value) else {
+                continue;
+            };
+
+            for (_mut, var, ty) in &fdef.signature.parameters {
+                // A borrowed capability handed back as a reference is a plain accessor.
+                if matches!(ty.value, N::Type_::Ref(..)) || !is_capability_type_from_ty(&ty.value) {
+                    continue;
+                }
+                let id = var.value.id;
+                if !returns_var(tail, id) {
+                    continue;
+                }
+
+                let mut mentions = 0;
+                for_each_exp_in_seq(seq_items.iter(), &mut |e| {
+                    let mentioned = match &e.exp.value {
+                        T::UnannotatedExp_::BorrowLocal(_, v) => v.value.id == id,
+                        _ => is_local_var(e, id),
+                    };
+                    if mentioned {
+                        mentions += 1;
+                    }
+                });
+                if mentions != 1 {
+                    continue;
+                }
+
+                let loc = var.loc;
+                let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                    continue;
+                };
+                let anchor = loc.start() as usize;
+                let fn_name_sym = fname.value();
+                let fn_name = fn_name_sym.as_str();
+                let param_sym = var.value.name;
+                let param = param_sym.as_str();
+                let cap_ty = format_type(&ty.value);
+
+                push_diag(
+                    out,
+                    settings,
+                    &CAPABILITY_PASSTHROUGH,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    anchor,
+                    format!(
+                        "`{fn_name}` takes `{param}: {cap_ty}` by value and returns it unchanged. \
+                         The pass-through makes custody harder to follow; take `&{cap_ty}` if the capability only gates access, or drop the parameter."
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
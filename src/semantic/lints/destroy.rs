@@ -0,0 +1,167 @@
+use crate::diagnostics::Diagnostic;
+use crate::error::Result as ClippyResult;
+use crate::lint::LintSettings;
+use crate::type_classifier::{abilities_of_type, has_drop_ability};
+use move_compiler::naming::ast as N;
+use move_compiler::parser::ast::TargetKind;
+use move_compiler::shared::Identifier;
+use move_compiler::shared::files::MappedFiles;
+use move_compiler::typing::ast as T;
+use std::collections::VecDeque;
+
+use super::super::DESTROY_FUNCTION_DOES_NOT_CONSUME;
+use super::super::util::{diag_from_loc, push_diag};
+use super::shared::{
+    for_each_exp, for_each_exp_in_seq, format_type, is_local_var, is_module_local_datatype,
+    strip_refs,
+};
+
+type Result<T> = ClippyResult<T>;
+
+/// `destroy`, `delete`, and `destroy_*` (e.g. `destroy_empty`).
+///
+/// `delete_*` is left out: it usually removes an entry from a collection argument.
+fn is_destroy_name(name: &str) -> bool {
+    name == "destroy" || name == "delete" || name.starts_with("destroy_")
+}
+
+fn unpacks(lvalues: &T::LValueList) -> bool {
+    lvalues.value.iter().any(|lv| {
+        matches!(
+            lv.value,
+            T::LValue_::Unpack(..) | T::LValue_::UnpackVariant(..)
+        )
+    })
+}
+
+fn binds_unpack_of(item: &T::SequenceItem, target: u16) -> bool {
+    matches!(
+        &item.value,
+        T::SequenceItem_::Bind(lvalues, _, rhs) if unpacks(lvalues) && is_local_var(rhs, target)
+    )
+}
+
+/// Whether the by-value local `target` is destructured, matched on, or handed to another call.
+fn is_consumed(seq_items: &VecDeque<T::SequenceItem>, target: u16) -> bool {
+    if seq_items.iter().any(|item| binds_unpack_of(item, target)) {
+        return true;
+    }
+
+    let mut consumed = false;
+    for_each_exp_in_seq(seq_items.iter(), &mut |exp| {
+        for_each_exp(exp, &mut |e| match &e.exp.value {
+            T::UnannotatedExp_::Assign(lvalues, _, rhs) => {
+                consumed |= unpacks(lvalues) && is_local_var(rhs, target);
+            }
+            T::UnannotatedExp_::Block((_, inner))
+            | T::UnannotatedExp_::NamedBlock(_, (_, inner)) => {
+                consumed |= inner.iter().any(|item| binds_unpack_of(item, target));
+            }
+            T::UnannotatedExp_::Match(scrut, _) | T::UnannotatedExp_::VariantMatch(scrut, _, _) => {
+                consumed |= is_local_var(scrut, target);
+            }
+            T::UnannotatedExp_::ModuleCall(call) => {
+                let args_consume = match &call.arguments.exp.value {
+                    T::UnannotatedExp_::ExpList(items) => items.iter().any(|item| {
+                        matches!(item, T::ExpListItem::Single(arg, _) if is_local_var(arg, target))
+                    }),
+                    _ => is_local_var(&call.arguments, target),
+                };
+                consumed |= args_consume;
+            }
+            _ => {}
+        });
+    });
+    consumed
+}
+
+/// Detects `destroy`/`delete` functions that take their object by reference, or take it by
+/// value but never unpack it or pass it on.
+pub(crate) fn lint_destroy_function_does_not_consume(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            let fn_name_sym = fname.value();
+            let fn_name = fn_name_sym.as_str();
+            if !is_destroy_name(fn_name) {
+                continue;
+            }
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+
+            let local_params: Vec<_> = fdef
+                .signature
+                .parameters
+                .iter()
+                .filter(|(_, _, ty)| is_module_local_datatype(&ty.value, &mident))
+                .collect();
+            let by_value = local_params
+                .iter()
+                .find(|(_, _, ty)| !matches!(ty.value, N::Type_::Ref(..)));
+
+            let (var, msg) = match (by_value, local_params.first()) {
+                (Some((_, var, ty)), _) => {
+                    // Values with `drop` are destroyed just by going out of scope.
+                    if abilities_of_type(&ty.value).is_some_and(|a| has_drop_ability(&a))
+                        || is_consumed(seq_items, var.value.id)
+                    {
+                        continue;
+                    }
+                    let ty_name = format_type(&ty.value);
+                    (
+                        var,
+                        format!(
+                            "`{fn_name}` takes `{}: {ty_name}` by value but never unpacks it or passes it on. \
+                             Destructure it (and delete its `UID`) so the function actually destroys it.",
+                            var.value.name
+                        ),
+                    )
+                }
+                (None, Some((_, var, ty))) => {
+                    let ty_name = format_type(&ty.value);
+                    let inner = format_type(strip_refs(&ty.value));
+                    (
+                        var,
+                        format!(
+                            "`{fn_name}` takes `{}: {ty_name}` by reference, so it cannot destroy it. \
+                             Take `{inner}` by value and unpack it, or rename the function.",
+                            var.value.name
+                        ),
+                    )
+                }
+                (None, None) => continue,
+            };
+
+            let loc = var.loc;
+            let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                continue;
+            };
+            let anchor = loc.start() as usize;
+
+            push_diag(
+                out,
+                settings,
+                &DESTROY_FUNCTION_DOES_NOT_CONSUME,
+                file,
+                span,
+                contents.as_ref(),
+                anchor,
+                msg,
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use crate::diagnostics::Diagnostic;
+use crate::error::Result as ClippyResult;
+use crate::lint::LintSettings;
+use crate::type_classifier::is_sui_framework_call;
+use move_compiler::parser::ast::TargetKind;
+use move_compiler::shared::Identifier;
+use move_compiler::shared::files::MappedFiles;
+use move_compiler::typing::ast as T;
+
+use super::super::DUPLICATE_DYNAMIC_FIELD_ADD;
+use super::super::util::{diag_from_loc, push_diag};
+use super::shared::{exp_list_nth_single, extract_local_var_id, for_each_exp_in_seq};
+
+type Result<T> = ClippyResult<T>;
+
+/// Framework modules whose `add` aborts when the field already exists.
+const DYNAMIC_FIELD_MODULES: &[&str] = &["dynamic_field", "dynamic_object_field"];
+
+fn is_dynamic_field_add(call: &T::ModuleCall) -> Option<&'static str> {
+    DYNAMIC_FIELD_MODULES
+        .iter()
+        .find(|module| is_sui_framework_call(call, module, "add"))
+        .copied()
+}
+
+/// Stable textual identity for a literal or constant key, if the key is one.
+fn literal_key(exp: &T::Exp) -> Option<String> {
+    match &exp.exp.value {
+        T::UnannotatedExp_::Value(val) => Some(format!("{:?}", val.value)),
+        T::UnannotatedExp_::Constant(mident, name) => {
+            Some(format!("{}::{}", mident.value.module.value(), name.value()))
+        }
+        T::UnannotatedExp_::Annotate(inner, _) => literal_key(inner),
+        _ => None,
+    }
+}
+
+/// Top-level `add` call of a sequence item, if the item is exactly one.
+fn top_level_add(item: &T::SequenceItem) -> Option<(&T::ModuleCall, &'static str)> {
+    let T::SequenceItem_::Seq(exp) = &item.value else {
+        return None;
+    };
+    let mut exp = exp.as_ref();
+    while let T::UnannotatedExp_::Annotate(inner, _) = &exp.exp.value {
+        exp = inner;
+    }
+    match &exp.exp.value {
+        T::UnannotatedExp_::ModuleCall(call) => {
+            is_dynamic_field_add(call).map(|module| (call.as_ref(), module))
+        }
+        _ => None,
+    }
+}
+
+/// Locals passed (by value or by reference) to `call`.
+fn call_arg_locals(call: &T::ModuleCall) -> Vec<u16> {
+    match &call.arguments.exp.value {
+        T::UnannotatedExp_::ExpList(items) => items
+            .iter()
+            .filter_map(|item| match item {
+                T::ExpListItem::Single(e, _) => extract_local_var_id(e),
+                _ => None,
+            })
+            .collect(),
+        _ => extract_local_var_id(&call.arguments).into_iter().collect(),
+    }
+}
+
+/// Detects a second `dynamic_field::add` with the same literal key on the same object.
+///
+/// Only `add` calls that are top-level statements of the function body are tracked, so
+/// both calls run on every path. Any other call that receives the object (a `remove`, or a
+/// helper that might remove the field) forgets the keys recorded for it.
+pub(crate) fn lint_duplicate_dynamic_field_add(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+            let fn_name_sym = fname.value();
+            let fn_name = fn_name_sym.as_str();
+
+            // (object local, key) pairs added on every path so far
+            let mut seen_keys: Vec<(u16, String)> = Vec::new();
+            for item in seq_items.iter() {
+                let add = top_level_add(item);
+
+                // Calls elsewhere in this statement may remove fields from the object.
+                let mut touched = Vec::new();
+                for_each_exp_in_seq(std::iter::once(item), &mut |exp| {
+                    if let T::UnannotatedExp_::ModuleCall(call) = &exp.exp.value
+                        && !add.is_some_and(|(add_call, _)| std::ptr::eq(add_call, call.as_ref()))
+                    {
+                        touched.extend(call_arg_locals(call));
+                    }
+                });
+                seen_keys.retain(|(id, _)| !touched.contains(id));
+
+                let Some((call, module)) = add else {
+                    continue;
+                };
+                let Some(object_id) =
+                    exp_list_nth_single(&call.arguments, 0).and_then(extract_local_var_id)
+                else {
+                    continue;
+                };
+                let Some(key) = exp_list_nth_single(&call.arguments, 1).and_then(literal_key)
+                else {
+                    continue;
+                };
+
+                if !seen_keys
+                    .iter()
+                    .any(|(id, k)| *id == object_id && *k == key)
+                {
+                    seen_keys.push((object_id, key));
+                    continue;
+                }
+
+                let T::SequenceItem_::Seq(exp) = &item.value else {
+                    continue;
+                };
+                let loc = exp.exp.loc;
+                let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                    continue;
+                };
+                push_diag(
+                    out,
+                    settings,
+                    &DUPLICATE_DYNAMIC_FIELD_ADD,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    loc.start() as usize,
+                    format!(
+                        "`{module}::add` in `{fn_name}` reuses a literal key already added to the same object. \
+                         The second `add` always aborts because the field already exists."
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
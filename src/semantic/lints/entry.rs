@@ -1,6 +1,7 @@
 use crate::diagnostics::Diagnostic;
 use crate::error::Result as ClippyResult;
 use crate::lint::LintSettings;
+use crate::type_classifier::is_clock_type;
 use move_compiler::naming::ast as N;
 use move_compiler::parser::ast::TargetKind;
 use move_compiler::shared::Identifier;
@@ -8,8 +9,11 @@ use move_compiler::shared::files::MappedFiles;
 use move_compiler::typing::ast as T;
 
 use super::super::util::{diag_from_loc, push_diag};
-use super::super::{ENTRY_FUNCTION_RETURNS_VALUE, PRIVATE_ENTRY_FUNCTION};
-use super::shared::format_type;
+use super::super::{
+    ENTRY_FUNCTION_PARAM_COUNT, ENTRY_FUNCTION_RETURNS_VALUE, PRIVATE_ENTRY_FUNCTION,
+    SUI_SIGNER_PARAM, TXCONTEXT_NOT_LAST_PARAM, UNUSED_CLOCK_PARAM,
+};
+use super::shared::{exp_uses_var, format_type, is_signer_type, is_tx_context_type};
 
 type Result<T> = ClippyResult<T>;
 
@@ -118,3 +122,269 @@ pub(crate) fn lint_private_entry_function(
 
     Ok(())
 }
+
+/// Detects entry functions that take a `TxContext` anywhere but the last parameter position.
+pub(crate) fn lint_txcontext_not_last_param(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            if fdef.entry.is_none() {
+                continue;
+            }
+
+            let params = &fdef.signature.parameters;
+            let Some(ctx_idx) = params
+                .iter()
+                .position(|(_, _, ty)| is_tx_context_type(&ty.value))
+            else {
+                continue;
+            };
+            if ctx_idx + 1 == params.len() {
+                continue;
+            }
+
+            let (_, var, ty) = &params[ctx_idx];
+            let loc = var.loc;
+            let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                continue;
+            };
+            let anchor = loc.start() as usize;
+
+            let fn_name_sym = fname.value();
+            let fn_name = fn_name_sym.as_str();
+            let param_sym = var.value.name;
+            let param = param_sym.as_str();
+            let ctx_ty = format_type(&ty.value);
+
+            push_diag(
+                out,
+                settings,
+                &TXCONTEXT_NOT_LAST_PARAM,
+                file,
+                span,
+                contents.as_ref(),
+                anchor,
+                format!(
+                    "Entry function `{fn_name}` takes `{param}: {ctx_ty}` as parameter {} of {}. \
+                     By Sui convention the `TxContext` parameter comes last.",
+                    ctx_idx + 1,
+                    params.len()
+                ),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Detects non-test functions with a `signer` or `&signer` parameter.
+///
+/// Sui never passes a `signer` to a transaction, so such a function can only be reached from
+/// other functions that already have one. Each offending parameter is reported.
+pub(crate) fn lint_sui_signer_param(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+        if mdef.attributes.is_test_or_test_only() {
+            continue;
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            if fdef.attributes.is_test_or_test_only() {
+                continue;
+            }
+
+            for (_, var, ty) in &fdef.signature.parameters {
+                if !is_signer_type(&ty.value) {
+                    continue;
+                }
+                let loc = var.loc;
+                let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                    continue;
+                };
+                let anchor = loc.start() as usize;
+
+                let fn_name_sym = fname.value();
+                let fn_name = fn_name_sym.as_str();
+                let param_sym = var.value.name;
+                let param = param_sym.as_str();
+                let signer_ty = match &ty.value {
+                    N::Type_::Ref(true, _) => "&mut signer",
+                    N::Type_::Ref(false, _) => "&signer",
+                    _ => "signer",
+                };
+
+                push_diag(
+                    out,
+                    settings,
+                    &SUI_SIGNER_PARAM,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    anchor,
+                    format!(
+                        "`{fn_name}` takes `{param}: {signer_ty}`, but Sui transactions never provide a `signer`. \
+                         Take `ctx: &TxContext` instead and use `tx_context::sender(ctx)` (or `ctx.sender()`) for the caller's address."
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Detects non-test functions with a `&Clock` parameter that the body never reads.
+///
+/// A clock that is forwarded to another function counts as read, since the callee may use it.
+pub(crate) fn lint_unused_clock_param(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+        if mdef.attributes.is_test_or_test_only() {
+            continue;
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            if fdef.macro_.is_some() || fdef.attributes.is_test_or_test_only() {
+                continue;
+            }
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+
+            for (_, var, ty) in &fdef.signature.parameters {
+                if !matches!(ty.value, N::Type_::Ref(false, _)) || !is_clock_type(&ty.value) {
+                    continue;
+                }
+                let clock_id = var.value.id;
+                let used = seq_items.iter().any(|item| match &item.value {
+                    T::SequenceItem_::Seq(e) => exp_uses_var(e, clock_id),
+                    T::SequenceItem_::Bind(_, _, e) => exp_uses_var(e, clock_id),
+                    _ => false,
+                });
+                if used {
+                    continue;
+                }
+
+                let loc = var.loc;
+                let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                    continue;
+                };
+                let anchor = loc.start() as usize;
+                let fn_name_sym = fname.value();
+                let fn_name = fn_name_sym.as_str();
+                let param_sym = var.value.name;
+                let param = param_sym.as_str();
+
+                push_diag(
+                    out,
+                    settings,
+                    &UNUSED_CLOCK_PARAM,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    anchor,
+                    format!(
+                        "`{fn_name}` takes `{param}: &Clock` but never reads the time, so callers pass the shared `Clock` for nothing. \
+                         Remove the parameter, or keep it only if a published signature requires it."
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Detects entry functions with more parameters than `[lints.thresholds] entry_max_params`.
+///
+/// `TxContext` and `Clock` parameters are supplied by the runtime or are boilerplate in
+/// PTBs, so they are not counted.
+pub(crate) fn lint_entry_function_param_count(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    let max_params = settings.entry_max_params();
+
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            if fdef.entry.is_none() {
+                continue;
+            }
+
+            let count = fdef
+                .signature
+                .parameters
+                .iter()
+                .filter(|(_, _, ty)| !is_tx_context_type(&ty.value) && !is_clock_type(&ty.value))
+                .count();
+            if count <= max_params {
+                continue;
+            }
+
+            let loc = fdef.loc;
+            let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                continue;
+            };
+            let anchor = loc.start() as usize;
+
+            let fn_name_sym = fname.value();
+            let fn_name = fn_name_sym.as_str();
+
+            push_diag(
+                out,
+                settings,
+                &ENTRY_FUNCTION_PARAM_COUNT,
+                file,
+                span,
+                contents.as_ref(),
+                anchor,
+                format!(
+                    "Entry function `{fn_name}` takes {count} parameters (limit {max_params}, not counting `TxContext` or `Clock`). \
+                     Long entry signatures are hard to call from PTBs; consider grouping related arguments into a config struct."
+                ),
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -1,15 +1,18 @@
 use crate::diagnostics::Diagnostic;
 use crate::error::Result as ClippyResult;
 use crate::lint::LintSettings;
+use crate::type_classifier::{abilities_of_call_type_arg, call_type_arg, is_sui_framework_call};
+use move_compiler::expansion::ast as E;
 use move_compiler::naming::ast as N;
 use move_compiler::parser::ast::TargetKind;
 use move_compiler::shared::Identifier;
-use move_compiler::shared::files::MappedFiles;
+use move_compiler::shared::{files::MappedFiles, program_info::TypingProgramInfo};
 use move_compiler::typing::ast as T;
+use std::collections::BTreeSet;
 
 use super::super::util::{diag_from_loc, push_diag};
-use super::super::{EVENT_EMIT_TYPE_SANITY, EVENT_PAST_TENSE};
-use super::shared::format_type;
+use super::super::{EVENT_EMIT_TYPE_SANITY, EVENT_PAST_TENSE, EVENT_STRUCT_MISSING_ABILITIES};
+use super::shared::{for_each_exp_in_seq, format_type};
 
 type Result<T> = ClippyResult<T>;
 
@@ -23,7 +26,7 @@ pub(crate) fn lint_event_emit_type_sanity(
     file_map: &MappedFiles,
     prog: &T::Program,
 ) -> Result<()> {
-    // TODO(infra): Move to crate::framework_catalog and match on fully-qualified IDs.
+    // TODO(infra): Move to crate::framework_catalog.
     // Event emit functions to detect
     const EVENT_EMIT_FUNCTIONS: &[(&str, &str)] = &[("event", "emit")];
 
@@ -226,17 +229,12 @@ fn check_event_past_tense_in_exp(
     file_map: &MappedFiles,
 ) {
     if let T::UnannotatedExp_::ModuleCall(call) = &exp.exp.value {
-        let module_sym = call.module.value.module.value();
-        let module_name = module_sym.as_str();
-        let call_sym = call.name.value();
-        let call_name = call_sym.as_str();
-
         let is_emit_call = emit_fns
             .iter()
-            .any(|(mod_pat, fn_pat)| module_name == *mod_pat && call_name == *fn_pat);
+            .any(|(mod_pat, fn_pat)| is_sui_framework_call(call, mod_pat, fn_pat));
 
         if is_emit_call
-            && let Some(type_arg) = call.type_arguments.first()
+            && let Some(type_arg) = call_type_arg(call, 0)
             && let N::Type_::Apply(_, type_name, _) = &type_arg.value
             && let N::TypeName_::ModuleType(_, struct_name) = &type_name.value
         {
@@ -303,17 +301,12 @@ fn check_event_emit_in_exp(
     func_name: &str,
 ) {
     if let T::UnannotatedExp_::ModuleCall(call) = &exp.exp.value {
-        let module_sym = call.module.value.module.value();
-        let module_name = module_sym.as_str();
-        let call_sym = call.name.value();
-        let call_name = call_sym.as_str();
-
         let is_emit_call = emit_fns
             .iter()
-            .any(|(mod_pat, fn_pat)| module_name == *mod_pat && call_name == *fn_pat);
+            .any(|(mod_pat, fn_pat)| is_sui_framework_call(call, mod_pat, fn_pat));
 
-        if is_emit_call && let Some(type_arg) = call.type_arguments.first() {
-            let abilities = crate::type_classifier::abilities_of_type(&type_arg.value);
+        if is_emit_call && let Some(type_arg) = call_type_arg(call, 0) {
+            let abilities = abilities_of_call_type_arg(call, 0);
             let is_event_like = crate::type_classifier::is_event_like_type(&type_arg.value);
 
             if abilities.is_some() && !is_event_like {
@@ -373,3 +366,113 @@ fn check_event_emit_in_exp(
         _ => {}
     }
 }
+
+// =========================================================================
+// Event Struct Missing Abilities Lint (type-based)
+// =========================================================================
+
+/// Report event-like structs whose declaration has the wrong abilities.
+///
+/// A struct is event-like when it is passed to `event::emit` anywhere in the
+/// root package, or when its name ends in `Event`. Unlike
+/// `event_emit_type_sanity`, the diagnostic points at the struct declaration,
+/// which is where the fix belongs.
+pub(crate) fn lint_event_struct_missing_abilities(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+    info: &TypingProgramInfo,
+) -> Result<()> {
+    use crate::type_classifier::{has_copy_ability, has_drop_ability, has_key_ability};
+
+    let mut emitted: BTreeSet<(E::ModuleIdent_, String)> = BTreeSet::new();
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (_fname, fdef) in mdef.functions.key_cloned_iter() {
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+
+            for_each_exp_in_seq(seq_items.iter(), &mut |exp| {
+                if let T::UnannotatedExp_::ModuleCall(call) = &exp.exp.value
+                    && is_sui_framework_call(call, "event", "emit")
+                    && let Some(type_arg) = call_type_arg(call, 0)
+                    && let N::Type_::Apply(_, type_name, _) = &type_arg.value
+                    && let N::TypeName_::ModuleType(struct_mident, struct_name) = &type_name.value
+                {
+                    emitted.insert((
+                        struct_mident.value,
+                        struct_name.value().as_str().to_string(),
+                    ));
+                }
+            });
+        }
+    }
+
+    for (mident, minfo) in info.modules.key_cloned_iter() {
+        match minfo.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (sname, sdef) in minfo.structs.key_cloned_iter() {
+            let sym = sname.value();
+            let name_str = sym.as_str();
+            let is_emitted = emitted.contains(&(mident.value, name_str.to_string()));
+            if !is_emitted && !name_str.ends_with("Event") {
+                continue;
+            }
+
+            let abilities = &sdef.abilities;
+            let mut problems = Vec::new();
+            if !has_copy_ability(abilities) {
+                problems.push("is missing `copy`");
+            }
+            if !has_drop_ability(abilities) {
+                problems.push("is missing `drop`");
+            }
+            if has_key_ability(abilities) {
+                problems.push("has `key`");
+            }
+            if problems.is_empty() {
+                continue;
+            }
+
+            let loc = sname.loc();
+            let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                continue;
+            };
+            let anchor = loc.start() as usize;
+            let reason = if is_emitted {
+                "is emitted via `event::emit`"
+            } else {
+                "is named like an event"
+            };
+
+            push_diag(
+                out,
+                settings,
+                &EVENT_STRUCT_MISSING_ABILITIES,
+                file,
+                span,
+                contents.as_ref(),
+                anchor,
+                format!(
+                    "Struct `{name_str}` {reason} but {}. Declare event structs with `has copy, drop` and without `key`.",
+                    problems.join(" and ")
+                ),
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -1,15 +1,21 @@
 use crate::diagnostics::Diagnostic;
 use crate::error::Result as ClippyResult;
 use crate::lint::LintSettings;
+use crate::type_classifier::{call_type_arg, is_sui_framework_call};
+use move_compiler::expansion::ast as E;
 use move_compiler::naming::ast as N;
-use move_compiler::parser::ast::TargetKind;
+use move_compiler::parser::ast::{self as P, TargetKind};
 use move_compiler::shared::Identifier;
 use move_compiler::shared::{files::MappedFiles, program_info::TypingProgramInfo};
 use move_compiler::typing::ast as T;
 
 use super::super::util::{diag_from_loc, push_diag};
-use super::super::{COPYABLE_FUNGIBLE_TYPE, NON_TRANSFERABLE_FUNGIBLE_OBJECT};
-use super::shared::strip_refs;
+use super::super::{
+    COPYABLE_FUNGIBLE_TYPE, NON_TRANSFERABLE_FUNGIBLE_OBJECT, PREFER_BALANCE_FIELD,
+};
+use super::shared::{
+    exp_list_nth_single, for_each_exp_in_seq, format_type, is_coin_type, strip_refs,
+};
 
 type Result<T> = ClippyResult<T>;
 
@@ -134,17 +140,18 @@ fn collect_transferred_types_in_exp(
     out: &mut std::collections::BTreeSet<String>,
 ) {
     if let T::UnannotatedExp_::ModuleCall(call) = &exp.exp.value {
+        // Matched by the resolved module name at any address: `sui::transfer` requires
+        // `key`, so `copy + store` values only move through package-defined `transfer`
+        // wrappers. `use` aliases are already resolved here, so `use my_pkg::evil as
+        // transfer` does not match.
         let module_sym = call.module.value.module.value();
-        let module_name = module_sym.as_str();
         let call_sym = call.name.value();
-        let call_name = call_sym.as_str();
-
-        let is_transfer_call = transfer_fns
-            .iter()
-            .any(|(mod_pat, fn_pat)| module_name == *mod_pat && call_name == *fn_pat);
+        let is_transfer_call = transfer_fns.iter().any(|(mod_pat, fn_pat)| {
+            module_sym.as_str() == *mod_pat && call_sym.as_str() == *fn_pat
+        });
 
         if is_transfer_call
-            && let Some(type_arg) = call.type_arguments.first()
+            && let Some(type_arg) = call_type_arg(call, 0)
             && let Some(type_key) = module_type_key(strip_refs(&type_arg.value))
         {
             out.insert(type_key);
@@ -243,3 +250,168 @@ pub(crate) fn lint_copyable_fungible_type(
 
     Ok(())
 }
+
+// =========================================================================
+// Prefer Balance Field Lint (type-based, experimental)
+// =========================================================================
+
+/// `sui::coin` functions that only need the value held by a `Coin`, so each has a direct
+/// `Balance` counterpart (`balance::value`, `balance::join`, or the balance itself).
+const COIN_VALUE_ONLY_FUNCTIONS: &[&str] = &["value", "join", "balance", "balance_mut"];
+
+/// Detects `Coin<T>` struct fields that the declaring module only uses for their value.
+///
+/// Every borrow of the field in the module must be a direct argument to one of
+/// [`COIN_VALUE_ONLY_FUNCTIONS`]. Any other use (e.g. `coin::split` into a new coin, or
+/// handing the reference to another function) needs a `Coin`, as does destructuring the
+/// struct, so those fields are left alone.
+pub(crate) fn lint_prefer_balance_field(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (sname, sdef) in mdef.structs.key_cloned_iter() {
+            let N::StructFields::Defined(_, fields) = &sdef.fields else {
+                continue;
+            };
+            if module_unpacks_struct(mdef, &sname) {
+                continue;
+            }
+
+            for (loc, field, (_, (_, field_ty))) in fields.iter() {
+                let N::Type_::Apply(_, _, coin_args) = &field_ty.value else {
+                    continue;
+                };
+                if !is_coin_type(&field_ty.value) {
+                    continue;
+                }
+
+                let (accesses, value_only) = coin_field_uses(mdef, &mident, &sname, field.as_str());
+                if accesses == 0 || accesses != value_only {
+                    continue;
+                }
+
+                let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                    continue;
+                };
+                let anchor = loc.start() as usize;
+                let struct_sym = sname.value();
+                let struct_name = struct_sym.as_str();
+                let coin_ty = format_type(&field_ty.value);
+                let balance_ty = match coin_args.first() {
+                    Some(arg) => format!("Balance<{}>", format_type(&arg.value)),
+                    None => "Balance".to_string(),
+                };
+
+                push_diag(
+                    out,
+                    settings,
+                    &PREFER_BALANCE_FIELD,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    anchor,
+                    format!(
+                        "`{struct_name}.{field}` is a `{coin_ty}`, but this module only reads or adds to its value. \
+                         Store a `{balance_ty}` instead: use `balance::value`, `balance::join(&mut self.{field}, coin::into_balance(c))`, \
+                         and `coin::take` / `coin::from_balance` when paying out."
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Count borrows of `struct_name.field` in the module, and how many of them are direct
+/// arguments to a value-only `coin` function.
+fn coin_field_uses(
+    mdef: &T::ModuleDefinition,
+    mident: &E::ModuleIdent,
+    struct_name: &P::DatatypeName,
+    field: &str,
+) -> (usize, usize) {
+    let is_field_borrow = |exp: &T::Exp| match &exp.exp.value {
+        T::UnannotatedExp_::Borrow(_, base, borrowed) => {
+            borrowed.value().as_str() == field
+                && is_struct_type(&base.ty.value, mident, struct_name)
+        }
+        _ => false,
+    };
+
+    let mut accesses = 0;
+    let mut value_only = 0;
+    for (_fname, fdef) in mdef.functions.key_cloned_iter() {
+        let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+            continue;
+        };
+        for_each_exp_in_seq(seq_items.iter(), &mut |exp| {
+            if is_field_borrow(exp) {
+                accesses += 1;
+            }
+            if let T::UnannotatedExp_::ModuleCall(call) = &exp.exp.value
+                && COIN_VALUE_ONLY_FUNCTIONS
+                    .iter()
+                    .any(|function| is_sui_framework_call(call, "coin", function))
+            {
+                value_only += (0..)
+                    .map_while(|idx| exp_list_nth_single(&call.arguments, idx))
+                    .filter(|arg| is_field_borrow(arg))
+                    .count();
+            }
+        });
+    }
+    (accesses, value_only)
+}
+
+fn is_struct_type(ty: &N::Type_, mident: &E::ModuleIdent, struct_name: &P::DatatypeName) -> bool {
+    match strip_refs(ty) {
+        N::Type_::Apply(_, type_name, _) => matches!(
+            &type_name.value,
+            N::TypeName_::ModuleType(owner, name)
+                if owner == mident && name.value() == struct_name.value()
+        ),
+        _ => false,
+    }
+}
+
+/// Whether any function in the module destructures `struct_name`, which moves its fields out.
+fn module_unpacks_struct(mdef: &T::ModuleDefinition, struct_name: &P::DatatypeName) -> bool {
+    let unpacks = |lvalues: &T::LValueList| {
+        lvalues.value.iter().any(|lv| {
+            matches!(
+                &lv.value,
+                T::LValue_::Unpack(_, name, ..) | T::LValue_::BorrowUnpack(_, _, name, ..)
+                    if name.value() == struct_name.value()
+            )
+        })
+    };
+    let binds_unpack = |item: &T::SequenceItem| matches!(&item.value, T::SequenceItem_::Bind(lvalues, _, _) if unpacks(lvalues));
+
+    let mut found = false;
+    for (_fname, fdef) in mdef.functions.key_cloned_iter() {
+        let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+            continue;
+        };
+        found |= seq_items.iter().any(binds_unpack);
+        for_each_exp_in_seq(seq_items.iter(), &mut |exp| match &exp.exp.value {
+            T::UnannotatedExp_::Assign(lvalues, _, _) => found |= unpacks(lvalues),
+            T::UnannotatedExp_::Block((_, inner))
+            | T::UnannotatedExp_::NamedBlock(_, (_, inner)) => {
+                found |= inner.iter().any(binds_unpack);
+            }
+            _ => {}
+        });
+    }
+    found
+}
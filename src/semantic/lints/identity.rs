@@ -0,0 +1,153 @@
+use crate::diagnostics::Diagnostic;
+use crate::error::Result as ClippyResult;
+use crate::lint::LintSettings;
+use crate::type_classifier::is_sui_framework_type;
+use move_compiler::expansion::ast::{ModuleIdent, Visibility};
+use move_compiler::naming::ast as N;
+use move_compiler::parser::ast::TargetKind;
+use move_compiler::shared::Identifier;
+use move_compiler::shared::files::MappedFiles;
+use move_compiler::typing::ast as T;
+use std::collections::{BTreeMap, VecDeque};
+
+use super::super::EXPOSES_UID;
+use super::super::util::{diag_from_loc, push_diag};
+use super::shared::{for_each_exp_in_seq, format_type, is_module_local_datatype, strip_refs};
+
+type Result<T> = ClippyResult<T>;
+
+fn is_uid_type(ty: &N::Type_) -> bool {
+    is_sui_framework_type(ty, "object", "UID")
+}
+
+/// Locals bound to the `UID` field when a body destructures one of `mident`'s structs, mapped
+/// to the struct's name.
+fn unpacked_uids(
+    seq_items: &VecDeque<T::SequenceItem>,
+    mident: &ModuleIdent,
+) -> BTreeMap<u16, String> {
+    let mut uids = BTreeMap::new();
+    for item in seq_items.iter() {
+        let T::SequenceItem_::Bind(lvalues, _, _) = &item.value else {
+            continue;
+        };
+        for lvalue in lvalues.value.iter() {
+            let T::LValue_::Unpack(owner, name, _, fields) = &lvalue.value else {
+                continue;
+            };
+            if owner != mident {
+                continue;
+            }
+            for (_, _, (_, (ty, inner))) in fields.iter() {
+                if let T::LValue_::Var { var, .. } = &inner.value
+                    && is_uid_type(&ty.value)
+                {
+                    uids.insert(var.value.id, name.value().to_string());
+                }
+            }
+        }
+    }
+    uids
+}
+
+/// The module struct whose own `UID` `exp` hands out: `&obj.id`, or a local unpacked from it.
+fn leaked_uid_owner(
+    exp: &T::Exp,
+    mident: &ModuleIdent,
+    unpacked: &BTreeMap<u16, String>,
+) -> Option<String> {
+    match &exp.exp.value {
+        T::UnannotatedExp_::Annotate(inner, _) => leaked_uid_owner(inner, mident, unpacked),
+        T::UnannotatedExp_::Borrow(false, base, _)
+            if is_uid_type(&exp.ty.value) && is_module_local_datatype(&base.ty.value, mident) =>
+        {
+            Some(format_type(strip_refs(&base.ty.value)))
+        }
+        T::UnannotatedExp_::Use(var)
+        | T::UnannotatedExp_::Copy { var, .. }
+        | T::UnannotatedExp_::Move { var, .. } => unpacked.get(&var.value.id).cloned(),
+        _ => None,
+    }
+}
+
+/// Detects public functions that return the `UID` of one of the module's own objects.
+///
+/// Both `&UID` borrowed from an object's field and a `UID` unpacked from the object are
+/// flagged. `&mut UID` is left to `public_returns_mut_ref`, and functions returning `ID` are
+/// not flagged: an object's ID is public on chain and cannot be used to act on it.
+pub(crate) fn lint_exposes_uid(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            if !matches!(fdef.visibility, Visibility::Public(_)) {
+                continue;
+            }
+            let return_ty = &fdef.signature.return_type.value;
+            if !is_uid_type(return_ty) || matches!(return_ty, N::Type_::Ref(true, _)) {
+                continue;
+            }
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+            let Some(T::SequenceItem_::Seq(tail)) = seq_items.back().map(|item| &item.value) else {
+                continue;
+            };
+
+            let unpacked = unpacked_uids(seq_items, &mident);
+            let mut owner = leaked_uid_owner(tail, &mident, &unpacked);
+            for_each_exp_in_seq(seq_items.iter(), &mut |exp| {
+                if owner.is_none()
+                    && let T::UnannotatedExp_::Return(inner) = &exp.exp.value
+                {
+                    owner = leaked_uid_owner(inner, &mident, &unpacked);
+                }
+            });
+            let Some(owner) = owner else {
+                continue;
+            };
+
+            let loc = fdef.signature.return_type.loc;
+            let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                continue;
+            };
+            let anchor = loc.start() as usize;
+            let fn_name_sym = fname.value();
+            let fn_name = fn_name_sym.as_str();
+
+            let message = if matches!(return_ty, N::Type_::Ref(..)) {
+                format!(
+                    "Public `{fn_name}` returns a reference to the `UID` of `{owner}`, letting any caller read its dynamic fields. \
+                     Return `object::uid_to_inner` (an `ID`) or the specific fields callers need, or make the function `public(package)`."
+                )
+            } else {
+                format!(
+                    "Public `{fn_name}` returns the `UID` unpacked from `{owner}`; any caller can wrap it in their own object and reuse its identity. \
+                     Delete it with `object::delete`, or make the function `public(package)`."
+                )
+            };
+            push_diag(
+                out,
+                settings,
+                &EXPOSES_UID,
+                file,
+                span,
+                contents.as_ref(),
+                anchor,
+                message,
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -1,29 +1,29 @@
 use crate::diagnostics::Diagnostic;
 use crate::error::Result as ClippyResult;
 use crate::lint::LintSettings;
+use crate::type_classifier::is_package_call;
+use move_compiler::expansion::ast::Value_;
 use move_compiler::naming::ast as N;
-use move_compiler::parser::ast::TargetKind;
+use move_compiler::parser::ast::{BinOp_, TargetKind};
 use move_compiler::shared::Identifier;
 use move_compiler::shared::files::MappedFiles;
 use move_compiler::typing::ast as T;
+use move_ir_types::location::Loc;
 
 use super::super::util::{diag_from_loc, push_diag};
-use super::super::{MUT_KEY_PARAM_MISSING_AUTHORITY, UNBOUNDED_ITERATION_OVER_PARAM_VECTOR};
-use super::shared::{format_type, is_coin_type, strip_refs};
+use super::super::{
+    EXACT_LENGTH_ASSERT, INDEX_MAY_EXCEED_LENGTH, LOOP_COUNTER_OVERFLOW_RISK,
+    LOOP_INVARIANT_ASSERT, LOOP_NEVER_PROGRESSES, MUT_KEY_PARAM_MISSING_AUTHORITY,
+    UNBOUNDED_ITERATION_OVER_PARAM_VECTOR,
+};
+use super::shared::{
+    exp_list_nth_single, extract_local_var_id, for_each_exp, for_each_exp_in_seq, format_type,
+    is_coin_type, is_local_var, is_signer_type, strip_refs,
+};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 
 type Result<T> = ClippyResult<T>;
 
-fn exp_list_nth_single(args: &T::Exp, idx: usize) -> Option<&T::Exp> {
-    match &args.exp.value {
-        T::UnannotatedExp_::ExpList(items) => items.get(idx).and_then(|item| match item {
-            T::ExpListItem::Single(e, _) => Some(e),
-            _ => None,
-        }),
-        _ if idx == 0 => Some(args),
-        _ => None,
-    }
-}
-
 fn is_public_entry_function(fdef: &T::Function) -> bool {
     fdef.entry.is_some()
         && matches!(
@@ -32,16 +32,6 @@ fn is_public_entry_function(fdef: &T::Function) -> bool {
         )
 }
 
-fn is_signer_type(ty: &N::Type_) -> bool {
-    match strip_refs(ty) {
-        N::Type_::Apply(_, tname, _) => matches!(
-            &tname.value,
-            N::TypeName_::Builtin(b) if matches!(b.value, N::BuiltinTypeName_::Signer)
-        ),
-        _ => false,
-    }
-}
-
 fn is_vector_type(ty: &N::Type_) -> bool {
     match strip_refs(ty) {
         N::Type_::Apply(_, tname, _) => matches!(
@@ -148,21 +138,6 @@ pub(crate) fn lint_mut_key_param_missing_authority(
     Ok(())
 }
 
-fn extract_local_var_id(exp: &T::Exp) -> Option<u16> {
-    match &exp.exp.value {
-        T::UnannotatedExp_::Use(v) => Some(v.value.id),
-        T::UnannotatedExp_::Copy { var, .. } => Some(var.value.id),
-        T::UnannotatedExp_::Move { var, .. } => Some(var.value.id),
-        T::UnannotatedExp_::BorrowLocal(_mut_, v) => Some(v.value.id),
-        T::UnannotatedExp_::TempBorrow(_, inner) => extract_local_var_id(inner),
-        T::UnannotatedExp_::Dereference(inner) => extract_local_var_id(inner),
-        T::UnannotatedExp_::Cast(inner, _) => extract_local_var_id(inner),
-        T::UnannotatedExp_::Annotate(inner, _) => extract_local_var_id(inner),
-        T::UnannotatedExp_::Borrow(_, base, _) => extract_local_var_id(base),
-        _ => None,
-    }
-}
-
 fn vector_length_param_id(
     exp: &T::Exp,
     vector_param_ids: &std::collections::BTreeSet<u16>,
@@ -170,12 +145,7 @@ fn vector_length_param_id(
     let T::UnannotatedExp_::ModuleCall(call) = &exp.exp.value else {
         return None;
     };
-    let module_sym = call.module.value.module.value();
-    let module_name = module_sym.as_str();
-    let call_sym = call.name.value();
-    let call_name = call_sym.as_str();
-
-    if module_name != "vector" || call_name != "length" {
+    if !is_package_call(call, "std", "vector", "length") {
         return None;
     }
 
@@ -361,3 +331,684 @@ pub(crate) fn lint_unbounded_iteration_over_param_vector(
 
     Ok(())
 }
+
+fn is_vector_length_call(exp: &T::Exp) -> bool {
+    let T::UnannotatedExp_::ModuleCall(call) = &exp.exp.value else {
+        return false;
+    };
+    is_package_call(call, "std", "vector", "length")
+}
+
+fn is_literal_or_constant(exp: &T::Exp) -> bool {
+    match &exp.exp.value {
+        T::UnannotatedExp_::Value(_) | T::UnannotatedExp_::Constant(_, _) => true,
+        T::UnannotatedExp_::Annotate(inner, _) => is_literal_or_constant(inner),
+        _ => false,
+    }
+}
+
+/// Returns true for `assert!(vector::length(..) == N, ..)` (in either operand order).
+fn is_exact_length_assert(exp: &T::Exp) -> bool {
+    let T::UnannotatedExp_::Builtin(builtin, args) = &exp.exp.value else {
+        return false;
+    };
+    if !format!("{:?}", builtin).contains("Assert") {
+        return false;
+    }
+    let Some(cond) = exp_list_nth_single(args, 0) else {
+        return false;
+    };
+    let T::UnannotatedExp_::BinopExp(left, op, _ty, right) = &cond.exp.value else {
+        return false;
+    };
+    let op_str = format!("{:?}", op);
+    if !op_str.contains("Eq") || op_str.contains("Neq") {
+        return false;
+    }
+
+    (is_vector_length_call(left) && is_literal_or_constant(right))
+        || (is_vector_length_call(right) && is_literal_or_constant(left))
+}
+
+/// Detects `assert!(vector::length(&v) == N, E)` where a minimum-length check may be intended.
+pub(crate) fn lint_exact_length_assert(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+            let fn_name_sym = fname.value();
+            let fn_name = fn_name_sym.as_str();
+
+            let mut asserts = Vec::new();
+            for_each_exp_in_seq(seq_items.iter(), &mut |exp| {
+                if is_exact_length_assert(exp) {
+                    asserts.push(exp.exp.loc);
+                }
+            });
+
+            for loc in asserts {
+                let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                    continue;
+                };
+                push_diag(
+                    out,
+                    settings,
+                    &EXACT_LENGTH_ASSERT,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    loc.start() as usize,
+                    format!(
+                        "Assertion in `{fn_name}` requires an exact `vector::length`. \
+                         If the code only needs a minimum length, consider `>=` instead of `==`."
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn is_assert(exp: &T::Exp) -> Option<&T::Exp> {
+    let T::UnannotatedExp_::Builtin(builtin, args) = &exp.exp.value else {
+        return None;
+    };
+    if !format!("{:?}", builtin).contains("Assert") {
+        return None;
+    }
+    exp_list_nth_single(args, 0)
+}
+
+fn collect_lvalue_vars(lvalue: &T::LValue, vars: &mut BTreeSet<u16>) {
+    match &lvalue.value {
+        T::LValue_::Var { var, .. } => {
+            vars.insert(var.value.id);
+        }
+        T::LValue_::Unpack(.., fields)
+        | T::LValue_::BorrowUnpack(.., fields)
+        | T::LValue_::UnpackVariant(.., fields)
+        | T::LValue_::BorrowUnpackVariant(.., fields) => {
+            for (_, _, (_, (_, inner))) in fields.iter() {
+                collect_lvalue_vars(inner, vars);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Locals that may hold a different value on each iteration of a loop: locals assigned or
+/// declared in it, and locals mutably borrowed (directly or by passing them to a `&mut`
+/// parameter).
+fn vars_modified_in(exp: &T::Exp) -> BTreeSet<u16> {
+    let mut modified = BTreeSet::new();
+    for_each_exp(exp, &mut |e| match &e.exp.value {
+        T::UnannotatedExp_::Assign(lvalues, _, _) => {
+            for lv in lvalues.value.iter() {
+                collect_lvalue_vars(lv, &mut modified);
+            }
+        }
+        T::UnannotatedExp_::Block((_, seq_items))
+        | T::UnannotatedExp_::NamedBlock(_, (_, seq_items)) => {
+            for item in seq_items.iter() {
+                if let T::SequenceItem_::Bind(lvalues, _, _) = &item.value {
+                    for lv in lvalues.value.iter() {
+                        collect_lvalue_vars(lv, &mut modified);
+                    }
+                }
+            }
+        }
+        T::UnannotatedExp_::BorrowLocal(true, var) => {
+            modified.insert(var.value.id);
+        }
+        T::UnannotatedExp_::Borrow(true, base, _) | T::UnannotatedExp_::Mutate(base, _) => {
+            modified.extend(extract_local_var_id(base));
+        }
+        T::UnannotatedExp_::ModuleCall(call) => {
+            for (idx, param_ty) in call.parameter_types.iter().enumerate() {
+                if matches!(param_ty.value, N::Type_::Ref(true, _)) {
+                    modified.extend(
+                        exp_list_nth_single(&call.arguments, idx).and_then(extract_local_var_id),
+                    );
+                }
+            }
+        }
+        _ => {}
+    });
+    modified
+}
+
+/// Locals read by `exp`, or `None` if it contains anything other than reads, literals,
+/// operators, and calls.
+fn condition_vars(exp: &T::Exp) -> Option<BTreeSet<u16>> {
+    let mut vars = BTreeSet::new();
+    let mut pure = true;
+    for_each_exp(exp, &mut |e| match &e.exp.value {
+        T::UnannotatedExp_::Use(var)
+        | T::UnannotatedExp_::Copy { var, .. }
+        | T::UnannotatedExp_::Move { var, .. }
+        | T::UnannotatedExp_::BorrowLocal(false, var) => {
+            vars.insert(var.value.id);
+        }
+        T::UnannotatedExp_::Value(_)
+        | T::UnannotatedExp_::Constant(..)
+        | T::UnannotatedExp_::BinopExp(..)
+        | T::UnannotatedExp_::UnaryExp(..)
+        | T::UnannotatedExp_::Cast(..)
+        | T::UnannotatedExp_::Annotate(..)
+        | T::UnannotatedExp_::Dereference(..)
+        | T::UnannotatedExp_::Borrow(false, ..)
+        | T::UnannotatedExp_::TempBorrow(false, ..)
+        | T::UnannotatedExp_::ModuleCall(..)
+        | T::UnannotatedExp_::ExpList(..) => {}
+        _ => pure = false,
+    });
+    (pure && !vars.is_empty()).then_some(vars)
+}
+
+/// Locations of asserts in `loop_exp` whose condition only reads locals the loop never modifies.
+fn invariant_asserts(loop_exp: &T::Exp, found: &mut BTreeSet<u32>, locs: &mut Vec<Loc>) {
+    let modified = vars_modified_in(loop_exp);
+    for_each_exp(loop_exp, &mut |e| {
+        let Some(cond) = is_assert(e) else {
+            return;
+        };
+        let Some(vars) = condition_vars(cond) else {
+            return;
+        };
+        if vars.is_disjoint(&modified) && found.insert(e.exp.loc.start()) {
+            locs.push(e.exp.loc);
+        }
+    });
+}
+
+/// Detects `assert!` calls inside a loop whose condition cannot change between iterations.
+///
+/// Nested loops are checked from the outermost in, so an assert is reported once, against the
+/// outermost loop it could be hoisted out of.
+pub(crate) fn lint_loop_invariant_assert(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+            let fn_name_sym = fname.value();
+            let fn_name = fn_name_sym.as_str();
+
+            let mut found = BTreeSet::new();
+            let mut asserts = Vec::new();
+            for_each_exp_in_seq(seq_items.iter(), &mut |exp| {
+                if matches!(
+                    exp.exp.value,
+                    T::UnannotatedExp_::While(..) | T::UnannotatedExp_::Loop { .. }
+                ) {
+                    invariant_asserts(exp, &mut found, &mut asserts);
+                }
+            });
+
+            for loc in asserts {
+                let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                    continue;
+                };
+                push_diag(
+                    out,
+                    settings,
+                    &LOOP_INVARIANT_ASSERT,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    loc.start() as usize,
+                    format!(
+                        "Assertion in `{fn_name}` checks values that do not change inside the loop, \
+                         so it is re-evaluated on every iteration. If the loop always runs at least once, \
+                         consider checking it once before the loop; hoisting it changes behavior when the loop body never runs."
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether `body` contains a `break`, `return`, or `abort` that could leave the loop.
+fn has_loop_exit(body: &T::Exp) -> bool {
+    let mut exits = false;
+    for_each_exp(body, &mut |e| {
+        exits = exits
+            || matches!(
+                e.exp.value,
+                T::UnannotatedExp_::Give(..)
+                    | T::UnannotatedExp_::Return(_)
+                    | T::UnannotatedExp_::Abort(_)
+            );
+    });
+    exits
+}
+
+/// Detects `while` loops whose body never modifies a local the condition reads.
+///
+/// Once such a loop is entered its condition stays true, so it only ends by running out of
+/// gas. Loops containing any `break`, `return`, or `abort` are left alone, as are conditions
+/// with side effects.
+pub(crate) fn lint_loop_never_progresses(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+            let fn_name_sym = fname.value();
+            let fn_name = fn_name_sym.as_str();
+
+            let mut stuck = Vec::new();
+            for_each_exp_in_seq(seq_items.iter(), &mut |exp| {
+                let T::UnannotatedExp_::While(_, cond, body) = &exp.exp.value else {
+                    return;
+                };
+                let Some(vars) = condition_vars(cond) else {
+                    return;
+                };
+                if vars.is_disjoint(&vars_modified_in(exp)) && !has_loop_exit(body) {
+                    stuck.push(exp.exp.loc);
+                }
+            });
+
+            for loc in stuck {
+                let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                    continue;
+                };
+                push_diag(
+                    out,
+                    settings,
+                    &LOOP_NEVER_PROGRESSES,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    loc.start() as usize,
+                    format!(
+                        "`while` loop in `{fn_name}` never modifies the locals its condition reads and has no \
+                         `break`, `return`, or `abort`, so once entered it never ends. Update the loop variable in the body."
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The vector local whose length `exp` computes, for `vector::length(&v)`.
+fn length_call_vector(exp: &T::Exp) -> Option<u16> {
+    if !is_vector_length_call(exp) {
+        return None;
+    }
+    let T::UnannotatedExp_::ModuleCall(call) = &exp.exp.value else {
+        return None;
+    };
+    exp_list_nth_single(&call.arguments, 0).and_then(extract_local_var_id)
+}
+
+/// Locals assigned `vector::length(&v)`, mapped to `v`.
+fn length_locals(seq_items: &VecDeque<T::SequenceItem>) -> BTreeMap<u16, u16> {
+    fn record(lvalues: &T::LValueList, rhs: &T::Exp, locals: &mut BTreeMap<u16, u16>) {
+        let [lvalue] = &lvalues.value[..] else {
+            return;
+        };
+        if let (T::LValue_::Var { var, .. }, Some(vector)) =
+            (&lvalue.value, length_call_vector(rhs))
+        {
+            locals.insert(var.value.id, vector);
+        }
+    }
+
+    fn record_binds(items: &VecDeque<T::SequenceItem>, locals: &mut BTreeMap<u16, u16>) {
+        for item in items.iter() {
+            if let T::SequenceItem_::Bind(lvalues, _, rhs) = &item.value {
+                record(lvalues, rhs, locals);
+            }
+        }
+    }
+
+    let mut locals = BTreeMap::new();
+    record_binds(seq_items, &mut locals);
+    for_each_exp_in_seq(seq_items.iter(), &mut |e| match &e.exp.value {
+        T::UnannotatedExp_::Assign(lvalues, _, rhs) => record(lvalues, rhs, &mut locals),
+        T::UnannotatedExp_::Block((_, inner)) | T::UnannotatedExp_::NamedBlock(_, (_, inner)) => {
+            record_binds(inner, &mut locals)
+        }
+        _ => {}
+    });
+    locals
+}
+
+/// For `i < bound` (or `bound > i`, and the non-strict forms), the index local, the bound,
+/// and whether the comparison is inclusive (`<=`/`>=`).
+fn loop_index_and_bound(cond: &T::Exp) -> Option<(u16, &T::Exp, bool)> {
+    let T::UnannotatedExp_::BinopExp(left, op, _ty, right) = &cond.exp.value else {
+        return None;
+    };
+    let (index, bound) = match op.value {
+        BinOp_::Lt | BinOp_::Le => (left, right),
+        BinOp_::Gt | BinOp_::Ge => (right, left),
+        _ => return None,
+    };
+    let inclusive = matches!(op.value, BinOp_::Le | BinOp_::Ge);
+    let (T::UnannotatedExp_::Copy { var, .. } | T::UnannotatedExp_::Move { var, .. }) =
+        &index.exp.value
+    else {
+        return None;
+    };
+    Some((var.value.id, bound, inclusive))
+}
+
+/// Vectors whose length `bound` is derived from, directly or through a length local.
+fn bound_vectors(bound: &T::Exp, length_locals: &BTreeMap<u16, u16>) -> BTreeSet<u16> {
+    let mut vectors = BTreeSet::new();
+    for_each_exp(bound, &mut |e| {
+        vectors.extend(length_call_vector(e));
+        if let T::UnannotatedExp_::Copy { var, .. } | T::UnannotatedExp_::Move { var, .. } =
+            &e.exp.value
+        {
+            vectors.extend(length_locals.get(&var.value.id));
+        }
+    });
+    vectors
+}
+
+/// `vector::borrow(&v, i)` or `vector::borrow_mut(&mut v, i)`: the vector local, when the
+/// index is local `index`.
+fn borrowed_vector_at(exp: &T::Exp, index: u16) -> Option<u16> {
+    let T::UnannotatedExp_::ModuleCall(call) = &exp.exp.value else {
+        return None;
+    };
+    if !is_package_call(call, "std", "vector", "borrow")
+        && !is_package_call(call, "std", "vector", "borrow_mut")
+    {
+        return None;
+    }
+    let idx = exp_list_nth_single(&call.arguments, 1)?;
+    if !is_local_var(idx, index) {
+        return None;
+    }
+    exp_list_nth_single(&call.arguments, 0).and_then(extract_local_var_id)
+}
+
+/// Detects `vector::borrow`/`borrow_mut` inside `while (i < n)` where the borrowed vector's
+/// length is unrelated to `n`.
+///
+/// The bound is related to a vector when it is `vector::length(&v)` or a local assigned from
+/// it. Vectors whose length is checked in an `assert!` anywhere in the function count as
+/// guarded, since that is the usual way to tie parallel vectors to one bound.
+pub(crate) fn lint_index_may_exceed_length(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+            let fn_name_sym = fname.value();
+            let fn_name = fn_name_sym.as_str();
+
+            let length_locals = length_locals(seq_items);
+            let mut asserted = BTreeSet::new();
+            for_each_exp_in_seq(seq_items.iter(), &mut |e| {
+                if let Some(cond) = is_assert(e) {
+                    for_each_exp(cond, &mut |c| asserted.extend(length_call_vector(c)));
+                }
+            });
+
+            let mut found = BTreeSet::new();
+            let mut borrows = Vec::new();
+            for_each_exp_in_seq(seq_items.iter(), &mut |exp| {
+                let T::UnannotatedExp_::While(_, cond, body) = &exp.exp.value else {
+                    return;
+                };
+                let Some((index, bound, _inclusive)) = loop_index_and_bound(cond) else {
+                    return;
+                };
+                let related = bound_vectors(bound, &length_locals);
+                for_each_exp(body, &mut |e| {
+                    let Some(vector) = borrowed_vector_at(e, index) else {
+                        return;
+                    };
+                    if !related.contains(&vector)
+                        && !asserted.contains(&vector)
+                        && found.insert(e.exp.loc.start())
+                    {
+                        borrows.push(e.exp.loc);
+                    }
+                });
+            });
+
+            for loc in borrows {
+                let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                    continue;
+                };
+                push_diag(
+                    out,
+                    settings,
+                    &INDEX_MAY_EXCEED_LENGTH,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    loc.start() as usize,
+                    format!(
+                        "Loop in `{fn_name}` borrows a vector element at its loop index, but the loop bound \
+                         is not that vector's length, so the borrow aborts if the vector is shorter. \
+                         Bound the loop by `vector::length` of the borrowed vector, or assert the lengths match."
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn is_integer_type(ty: &N::Type_) -> bool {
+    let N::Type_::Apply(_, type_name, _) = ty else {
+        return false;
+    };
+    matches!(
+        &type_name.value,
+        N::TypeName_::Builtin(builtin) if matches!(
+            builtin.value,
+            N::BuiltinTypeName_::U8
+                | N::BuiltinTypeName_::U16
+                | N::BuiltinTypeName_::U32
+                | N::BuiltinTypeName_::U64
+                | N::BuiltinTypeName_::U128
+                | N::BuiltinTypeName_::U256
+        )
+    )
+}
+
+fn is_literal_one(exp: &T::Exp) -> bool {
+    match &exp.exp.value {
+        T::UnannotatedExp_::Value(v) => match &v.value {
+            Value_::U8(n) => *n == 1,
+            Value_::U16(n) => *n == 1,
+            Value_::U32(n) => *n == 1,
+            Value_::U64(n) => *n == 1,
+            Value_::U128(n) => *n == 1,
+            Value_::U256(n) | Value_::InferredNum(n) => n.to_string() == "1",
+            _ => false,
+        },
+        T::UnannotatedExp_::Annotate(inner, _) => is_literal_one(inner),
+        _ => false,
+    }
+}
+
+/// The step of every `i = i + step` in `body`: `Some(true)` if all steps are the literal `1`,
+/// `Some(false)` if any is not, and `None` if `i` is never incremented this way.
+fn increments_by_one(body: &T::Exp, index: u16) -> Option<bool> {
+    let mut all_one = None;
+    for_each_exp(body, &mut |e| {
+        let T::UnannotatedExp_::Assign(lvalues, _, rhs) = &e.exp.value else {
+            return;
+        };
+        let [lvalue] = &lvalues.value[..] else {
+            return;
+        };
+        if !matches!(&lvalue.value, T::LValue_::Var { var, .. } if var.value.id == index) {
+            return;
+        }
+        let T::UnannotatedExp_::BinopExp(left, op, _ty, step) = &rhs.exp.value else {
+            return;
+        };
+        if matches!(op.value, BinOp_::Add) && is_local_var(left, index) {
+            *all_one.get_or_insert(true) &= is_literal_one(step);
+        }
+    });
+    all_one
+}
+
+/// Detects counting loops whose increment can overflow because the bound is a raw integer
+/// parameter.
+///
+/// `while (i < n) { i = i + 1 }` cannot overflow: `i < n` implies `i + 1 <= n`. The risky
+/// shapes are an inclusive bound (`i <= n` with `n == MAX` never exits) and a step other
+/// than `1` (`i + step` can jump past `MAX`). Bounds derived from `vector::length` are
+/// small in practice and are skipped, as are loops where an `assert!` mentions the counter
+/// or bound.
+pub(crate) fn lint_loop_counter_overflow_risk(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+            let int_params: BTreeSet<u16> = fdef
+                .signature
+                .parameters
+                .iter()
+                .filter(|(_, _, ty)| is_integer_type(&ty.value))
+                .map(|(_, var, _)| var.value.id)
+                .collect();
+            if int_params.is_empty() {
+                continue;
+            }
+            let fn_name_sym = fname.value();
+            let fn_name = fn_name_sym.as_str();
+
+            let length_locals = length_locals(seq_items);
+            let mut guarded = BTreeSet::new();
+            for_each_exp_in_seq(seq_items.iter(), &mut |e| {
+                if let Some(cond) = is_assert(e) {
+                    guarded.extend(condition_vars(cond).unwrap_or_default());
+                }
+            });
+
+            let mut loops = Vec::new();
+            for_each_exp_in_seq(seq_items.iter(), &mut |exp| {
+                let T::UnannotatedExp_::While(_, cond, body) = &exp.exp.value else {
+                    return;
+                };
+                let Some((index, bound, inclusive)) = loop_index_and_bound(cond) else {
+                    return;
+                };
+                let Some(bound_vars) = condition_vars(bound) else {
+                    return;
+                };
+                if bound_vars.is_disjoint(&int_params)
+                    || !bound_vectors(bound, &length_locals).is_empty()
+                    || guarded.contains(&index)
+                    || !bound_vars.is_disjoint(&guarded)
+                {
+                    return;
+                }
+                let Some(unit_step) = increments_by_one(body, index) else {
+                    return;
+                };
+                if inclusive || !unit_step {
+                    loops.push((exp.exp.loc, inclusive));
+                }
+            });
+
+            for (loc, inclusive) in loops {
+                let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                    continue;
+                };
+                let why = if inclusive {
+                    "uses an inclusive bound, so the increment overflows when the bound is the type's maximum"
+                } else {
+                    "steps by more than one, so the increment can overflow past a bound near the type's maximum"
+                };
+                push_diag(
+                    out,
+                    settings,
+                    &LOOP_COUNTER_OVERFLOW_RISK,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    loc.start() as usize,
+                    format!(
+                        "Loop in `{fn_name}` is bounded by a caller-supplied integer and {why}. \
+                         Assert an upper limit on the bound before the loop, or use `<` with a step of 1."
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,38 +1,71 @@
 mod ability;
+mod abort;
 mod capability;
+mod destroy;
+mod dynamic_field;
 mod entry;
 mod event;
 mod fungible;
+mod identity;
 mod iteration;
+mod mutability;
 mod oracle;
 mod random;
 mod receipt;
-mod shared;
+pub(crate) mod shared;
 mod sui_delegated;
+mod transfer;
 mod value_flow;
 mod witness;
 
 pub(super) use ability::{
-    lint_copyable_capability, lint_droppable_capability, lint_droppable_hot_potato_v2,
+    lint_copy_struct_with_object_field, lint_copyable_capability, lint_droppable_capability,
+    lint_droppable_hot_potato_v2,
 };
+pub(super) use abort::lint_ambiguous_abort_code;
 pub(super) use capability::{
-    lint_capability_transfer_literal_address, lint_capability_transfer_v2,
-    lint_shared_capability_object,
+    lint_capability_passthrough, lint_capability_transfer_literal_address,
+    lint_capability_transfer_v2, lint_shared_capability_object,
 };
 // lint_capability_antipatterns removed - deprecated
-pub(super) use entry::{lint_entry_function_returns_value, lint_private_entry_function};
-pub(super) use event::{lint_event_emit_type_sanity, lint_event_past_tense};
-pub(super) use fungible::{lint_copyable_fungible_type, lint_non_transferable_fungible_object};
+pub(super) use destroy::lint_destroy_function_does_not_consume;
+pub(super) use dynamic_field::lint_duplicate_dynamic_field_add;
+pub(super) use entry::{
+    lint_entry_function_param_count, lint_entry_function_returns_value,
+    lint_private_entry_function, lint_sui_signer_param, lint_txcontext_not_last_param,
+    lint_unused_clock_param,
+};
+pub(super) use event::{
+    lint_event_emit_type_sanity, lint_event_past_tense, lint_event_struct_missing_abilities,
+};
+pub(super) use fungible::{
+    lint_copyable_fungible_type, lint_non_transferable_fungible_object, lint_prefer_balance_field,
+};
+pub(super) use identity::lint_exposes_uid;
 pub(super) use iteration::{
-    lint_mut_key_param_missing_authority, lint_unbounded_iteration_over_param_vector,
+    lint_exact_length_assert, lint_index_may_exceed_length, lint_loop_counter_overflow_risk,
+    lint_loop_invariant_assert, lint_loop_never_progresses, lint_mut_key_param_missing_authority,
+    lint_unbounded_iteration_over_param_vector,
+};
+pub(super) use mutability::{
+    lint_mut_param_only_read_for_return, lint_public_returns_mut_ref, lint_unnecessary_mut_param,
+    lint_unnecessary_mut_tx_context,
 };
 // lint_stale_oracle_price_v2 removed - deprecated
 pub(super) use random::lint_public_random_access_v2;
 pub(super) use receipt::{lint_droppable_flash_loan_receipt, lint_receipt_missing_phantom_type};
 pub(super) use sui_delegated::lint_sui_visitors;
-pub(super) use value_flow::{lint_share_owned_authority, lint_unused_return_value};
+pub(super) use transfer::{
+    lint_freeze_locks_value, lint_transfer_of_non_object, lint_wrong_transfer_variant,
+};
+pub(super) use value_flow::{
+    lint_share_owned_authority, lint_suspicious_coin_split, lint_unused_return_value,
+};
 // lint_unchecked_division removed - obvious lint
 pub(super) use witness::{
-    lint_generic_type_witness_unused, lint_missing_witness_drop_v2, lint_witness_antipatterns,
+    lint_generic_type_witness_unused, lint_missing_witness_drop_v2,
+    lint_redundant_ability_constraint, lint_unused_function_type_parameter,
+    lint_witness_antipatterns,
 };
 // lint_invalid_otw removed - duplicates Sui Verifier
+// inconsistent_uid_field_position not added - Sui compiler requires `id: UID` first in key structs
@@ -0,0 +1,423 @@
+use crate::diagnostics::Diagnostic;
+use crate::error::Result as ClippyResult;
+use crate::lint::LintSettings;
+use crate::type_classifier::{abilities_of_type, has_key_ability};
+use move_compiler::expansion::ast::Visibility;
+use move_compiler::naming::ast as N;
+use move_compiler::parser::ast::TargetKind;
+use move_compiler::shared::Identifier;
+use move_compiler::shared::files::MappedFiles;
+use move_compiler::typing::ast as T;
+use std::collections::VecDeque;
+
+use super::super::util::{diag_from_loc, push_diag};
+use super::super::{
+    MUT_PARAM_ONLY_READ_FOR_RETURN, PUBLIC_RETURNS_MUT_REF, UNNECESSARY_MUT_PARAM,
+    UNNECESSARY_MUT_TX_CONTEXT,
+};
+use super::shared::{
+    exp_uses_var, for_each_exp, format_type, is_local_var, is_module_local_datatype,
+    is_tx_context_type, strip_refs,
+};
+
+type Result<T> = ClippyResult<T>;
+
+/// The key object behind a `&mut` parameter type.
+fn mut_ref_key_object(ty: &N::Type_) -> Option<&N::Type_> {
+    let N::Type_::Ref(true, inner) = ty else {
+        return None;
+    };
+    abilities_of_type(&inner.value)
+        .is_some_and(|a| has_key_ability(&a))
+        .then_some(&inner.value)
+}
+
+/// Count `(uses, reads)` of reference local `target` within `exp`.
+///
+/// A use is any direct mention of the local. A read is a use whose parent only needs shared
+/// access: an immutable field borrow, a dereference, or an argument to a `&` parameter.
+/// Every other use (mutable borrow, `&mut` argument, aliasing, returning the reference) is
+/// treated as a potential mutation.
+fn count_uses_and_reads(exp: &T::Exp, target: u16) -> (usize, usize) {
+    let mut uses = 0;
+    let mut reads = 0;
+    for_each_exp(exp, &mut |e| {
+        if is_local_var(e, target) {
+            uses += 1;
+            return;
+        }
+        match &e.exp.value {
+            T::UnannotatedExp_::Borrow(false, base, _) | T::UnannotatedExp_::Dereference(base)
+                if is_local_var(base, target) =>
+            {
+                reads += 1;
+            }
+            T::UnannotatedExp_::ModuleCall(call) => {
+                let args: Vec<&T::Exp> = match &call.arguments.exp.value {
+                    T::UnannotatedExp_::ExpList(items) => items
+                        .iter()
+                        .filter_map(|item| match item {
+                            T::ExpListItem::Single(arg, _) => Some(arg),
+                            T::ExpListItem::Splat(..) => None,
+                        })
+                        .collect(),
+                    _ => vec![call.arguments.as_ref()],
+                };
+                reads += args
+                    .iter()
+                    .zip(call.parameter_types.iter())
+                    .filter(|(arg, param_ty)| {
+                        is_local_var(arg, target)
+                            && matches!(param_ty.value, N::Type_::Ref(false, _))
+                    })
+                    .count();
+            }
+            _ => {}
+        }
+    });
+    (uses, reads)
+}
+
+/// [`count_uses_and_reads`] summed over a function body.
+fn count_uses_and_reads_in_seq(
+    seq_items: &VecDeque<T::SequenceItem>,
+    target: u16,
+) -> (usize, usize) {
+    seq_items
+        .iter()
+        .filter_map(|item| match &item.value {
+            T::SequenceItem_::Seq(e) | T::SequenceItem_::Bind(_, _, e) => {
+                Some(count_uses_and_reads(e, target))
+            }
+            _ => None,
+        })
+        .fold((0, 0), |(u, r), (du, dr)| (u + du, r + dr))
+}
+
+/// Whether reference local `target` is mentioned only by the trailing expression of `fdef`'s
+/// body, and only where shared access suffices.
+fn only_read_in_tail(fdef: &T::Function, target: u16) -> bool {
+    let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+        return false;
+    };
+    let items: Vec<&T::SequenceItem> = seq_items.iter().collect();
+    let Some((tail, stmts)) = items.split_last() else {
+        return false;
+    };
+    let T::SequenceItem_::Seq(tail_exp) = &tail.value else {
+        return false;
+    };
+    let used_before_tail = stmts.iter().any(|item| match &item.value {
+        T::SequenceItem_::Seq(e) | T::SequenceItem_::Bind(_, _, e) => exp_uses_var(e, target),
+        _ => false,
+    });
+    if used_before_tail || !exp_uses_var(tail_exp, target) {
+        return false;
+    }
+    let (uses, reads) = count_uses_and_reads(tail_exp, target);
+    uses > 0 && uses == reads
+}
+
+/// Whether `lint_mut_param_only_read_for_return` reports parameter `target` of `fdef`.
+fn is_only_read_for_return(fdef: &T::Function, ty: &N::Type_, target: u16) -> bool {
+    matches!(fdef.visibility, Visibility::Public(_))
+        && !matches!(fdef.signature.return_type.value, N::Type_::Unit)
+        && mut_ref_key_object(ty).is_some()
+        && only_read_in_tail(fdef, target)
+}
+
+/// Detects public functions whose `&mut` object parameter is never mutated and is only read to
+/// compute the return value.
+///
+/// Only the trailing expression of the body may mention the parameter, so helpers that also use
+/// the object for side effects (events, assertions, calls) are left alone.
+pub(crate) fn lint_mut_param_only_read_for_return(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            if !matches!(fdef.visibility, Visibility::Public(_)) {
+                continue;
+            }
+            if matches!(fdef.signature.return_type.value, N::Type_::Unit) {
+                continue;
+            }
+            let fn_name_sym = fname.value();
+            let fn_name = fn_name_sym.as_str();
+
+            for (_mut, var, ty) in &fdef.signature.parameters {
+                let Some(object_ty) = mut_ref_key_object(&ty.value) else {
+                    continue;
+                };
+                if !only_read_in_tail(fdef, var.value.id) {
+                    continue;
+                }
+
+                let loc = var.loc;
+                let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                    continue;
+                };
+                let anchor = loc.start() as usize;
+                let param_sym = var.value.name;
+                let param = param_sym.as_str();
+                let object = format_type(object_ty);
+
+                push_diag(
+                    out,
+                    settings,
+                    &MUT_PARAM_ONLY_READ_FOR_RETURN,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    anchor,
+                    format!(
+                        "Parameter `{param}` of `{fn_name}` is `&mut {object}` but is only read to compute the return value. \
+                         Take `&{object}` so callers do not need exclusive access."
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The `&mut` components of a return type (the type itself, or members of a tuple).
+fn mut_ref_returns(ty: &N::Type_) -> Vec<&N::Type_> {
+    match ty {
+        N::Type_::Ref(true, _) => vec![ty],
+        N::Type_::Apply(_, type_name, members)
+            if matches!(type_name.value, N::TypeName_::Multiple(_)) =>
+        {
+            members
+                .iter()
+                .filter(|t| matches!(t.value, N::Type_::Ref(true, _)))
+                .map(|t| &t.value)
+                .collect()
+        }
+        _ => vec![],
+    }
+}
+
+/// Detects public functions that hand out `&mut` references into a module's own datatypes.
+///
+/// A returned `&mut` counts as internal state when it points at a datatype declared in the
+/// module, or when the function takes one of the module's datatypes by `&mut` (so the
+/// reference is likely a field of it, e.g. `&mut self.balance`). `&mut TxContext` is an
+/// input, not state, and is ignored. `public(package)` functions are not flagged.
+pub(crate) fn lint_public_returns_mut_ref(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            if !matches!(fdef.visibility, Visibility::Public(_)) {
+                continue;
+            }
+
+            let mut_local_param = fdef.signature.parameters.iter().any(|(_, _, ty)| {
+                matches!(ty.value, N::Type_::Ref(true, _))
+                    && is_module_local_datatype(&ty.value, &mident)
+            });
+
+            let Some(returned) = mut_ref_returns(&fdef.signature.return_type.value)
+                .into_iter()
+                .filter(|ty| !is_tx_context_type(ty))
+                .find(|ty| mut_local_param || is_module_local_datatype(ty, &mident))
+            else {
+                continue;
+            };
+
+            let loc = fdef.signature.return_type.loc;
+            let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                continue;
+            };
+            let anchor = loc.start() as usize;
+            let fn_name_sym = fname.value();
+            let fn_name = fn_name_sym.as_str();
+            let returned_ty = format_type(returned);
+            let target = format_type(strip_refs(returned));
+
+            push_diag(
+                out,
+                settings,
+                &PUBLIC_RETURNS_MUT_REF,
+                file,
+                span,
+                contents.as_ref(),
+                anchor,
+                format!(
+                    "Public `{fn_name}` returns `{returned_ty}`, letting any caller mutate this module's internal state. \
+                     Expose the operations callers need, return `&{target}`, or make the function `public(package)`."
+                ),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Detects non-`public` entry functions that take `&mut TxContext` but only ever use it where
+/// `&TxContext` suffices.
+///
+/// Whether a use needs `&mut` comes from the callee's parameter type, so helpers that forward
+/// the context to `object::new` (or anything else taking `&mut TxContext`) count as mutation.
+/// `public` entry functions are skipped: Sui's `PreferMutableTxContext` lint asks those to keep
+/// `&mut` for upgrade compatibility. Unused contexts are left alone.
+pub(crate) fn lint_unnecessary_mut_tx_context(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            if fdef.entry.is_none() || matches!(fdef.visibility, Visibility::Public(_)) {
+                continue;
+            }
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+
+            for (_mut, var, ty) in &fdef.signature.parameters {
+                if !matches!(ty.value, N::Type_::Ref(true, _)) || !is_tx_context_type(&ty.value) {
+                    continue;
+                }
+                let id = var.value.id;
+
+                let (uses, reads) = count_uses_and_reads_in_seq(seq_items, id);
+                if uses == 0 || uses != reads {
+                    continue;
+                }
+
+                let loc = var.loc;
+                let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                    continue;
+                };
+                let anchor = loc.start() as usize;
+                let fn_name_sym = fname.value();
+                let fn_name = fn_name_sym.as_str();
+                let param_sym = var.value.name;
+                let param = param_sym.as_str();
+
+                push_diag(
+                    out,
+                    settings,
+                    &UNNECESSARY_MUT_TX_CONTEXT,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    anchor,
+                    format!(
+                        "Entry function `{fn_name}` takes `{param}: &mut TxContext` but never passes it where `&mut` is required. \
+                         Take `&TxContext` instead."
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Detects `&mut` parameters that are only ever used where `&` suffices.
+///
+/// Uses are classified the same way as for `unnecessary_mut_tx_context`: field writes, `&mut`
+/// borrows, `&mut` arguments, and aliasing all count as mutation. `&mut TxContext` is left to
+/// that lint, parameters already reported by `mut_param_only_read_for_return` are skipped, and
+/// unused parameters are left to the compiler's unused-variable warning.
+pub(crate) fn lint_unnecessary_mut_param(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            if fdef.macro_.is_some() {
+                continue;
+            }
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+
+            for (_mut, var, ty) in &fdef.signature.parameters {
+                let N::Type_::Ref(true, inner) = &ty.value else {
+                    continue;
+                };
+                if is_tx_context_type(&ty.value) {
+                    continue;
+                }
+                let id = var.value.id;
+                if is_only_read_for_return(fdef, &ty.value, id) {
+                    continue;
+                }
+
+                let (uses, reads) = count_uses_and_reads_in_seq(seq_items, id);
+                if uses == 0 || uses != reads {
+                    continue;
+                }
+
+                let loc = var.loc;
+                let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                    continue;
+                };
+                let anchor = loc.start() as usize;
+                let fn_name_sym = fname.value();
+                let fn_name = fn_name_sym.as_str();
+                let param_sym = var.value.name;
+                let param = param_sym.as_str();
+                let target = format_type(&inner.value);
+
+                push_diag(
+                    out,
+                    settings,
+                    &UNNECESSARY_MUT_PARAM,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    anchor,
+                    format!(
+                        "Parameter `{param}` of `{fn_name}` is `&mut {target}` but is never mutated. \
+                         Take `&{target}` so callers do not need exclusive access."
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
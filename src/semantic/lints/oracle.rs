@@ -1,6 +1,7 @@
 use crate::diagnostics::Diagnostic;
 use crate::error::Result as ClippyResult;
 use crate::lint::LintSettings;
+use crate::type_classifier::is_package_call;
 use move_compiler::parser::ast::TargetKind;
 use move_compiler::shared::Identifier;
 use move_compiler::shared::files::MappedFiles;
@@ -15,17 +16,18 @@ type Result<T> = ClippyResult<T>;
 // =========================================================================
 // Stale Oracle Price V2 Lint (type-based)
 //
-// DEPRECATED: This lint is superseded by stale_oracle_price_v3 in absint_lints.rs
+// DEPRECATED: This lint is superseded by stale_oracle_price in absint_lints.rs
 // which uses CFG-aware dataflow analysis for rigorous detection.
 // This version is kept for backwards compatibility but will be removed.
 // =========================================================================
 
+/// `(package, module, functions)`, where `package` is the oracle's named address.
 #[allow(dead_code)]
-const ORACLE_MODULES: &[(&str, &[&str])] = &[
-    ("pyth", &["get_price_unsafe", "price_unsafe"]),
-    ("price_info", &["get_price_unsafe"]),
-    ("switchboard", &["get_price_unsafe"]),
-    ("supra", &["get_price_unsafe"]),
+const ORACLE_MODULES: &[(&str, &str, &[&str])] = &[
+    ("pyth", "pyth", &["get_price_unsafe", "price_unsafe"]),
+    ("pyth", "price_info", &["get_price_unsafe"]),
+    ("switchboard", "switchboard", &["get_price_unsafe"]),
+    ("supra", "supra", &["get_price_unsafe"]),
 ];
 
 #[allow(dead_code)]
@@ -93,9 +95,14 @@ fn check_stale_oracle_in_exp(
         let call_sym = call.name.value();
         let call_name = call_sym.as_str();
 
-        let is_unsafe_oracle_call = ORACLE_MODULES.iter().any(|(oracle_mod, unsafe_fns)| {
-            module_name == *oracle_mod && unsafe_fns.iter().any(|f| call_name == *f)
-        });
+        let is_unsafe_oracle_call =
+            ORACLE_MODULES
+                .iter()
+                .any(|(oracle_pkg, oracle_mod, unsafe_fns)| {
+                    unsafe_fns
+                        .iter()
+                        .any(|f| is_package_call(call, oracle_pkg, oracle_mod, f))
+                });
 
         if is_unsafe_oracle_call {
             let loc = exp.exp.loc;
@@ -1,6 +1,7 @@
 use crate::diagnostics::Diagnostic;
 use crate::error::Result as ClippyResult;
 use crate::lint::LintSettings;
+use crate::type_classifier::{is_sui_framework_address, is_sui_framework_type};
 use move_compiler::expansion::ast::Address;
 use move_compiler::naming::ast as N;
 use move_compiler::parser::ast::TargetKind;
@@ -109,37 +110,7 @@ fn is_framework_random_allowlisted(module_name: &str, address: &Address, fn_name
     is_sui_framework_address(address)
 }
 
-fn is_sui_framework_address(addr: &Address) -> bool {
-    match addr {
-        Address::Numerical {
-            value: addr_value, ..
-        } => {
-            let bytes = addr_value.value.into_bytes();
-            bytes.iter().take(31).all(|&b| b == 0) && bytes[31] == 2
-        }
-        Address::NamedUnassigned(name) => {
-            name.value.as_str() == "sui" || name.value.as_str() == "0x2"
-        }
-    }
-}
-
 /// Check if a type is sui::random::Random (including references).
 fn is_random_type(ty: &N::Type_) -> bool {
-    match ty {
-        N::Type_::Apply(_, type_name, _) => {
-            if let N::TypeName_::ModuleType(mident, struct_name) = &type_name.value {
-                let addr = &mident.value.address;
-                let module_sym = mident.value.module.value();
-                let struct_sym = struct_name.value();
-
-                is_sui_framework_address(addr)
-                    && module_sym.as_str() == "random"
-                    && struct_sym.as_str() == "Random"
-            } else {
-                false
-            }
-        }
-        N::Type_::Ref(_, inner) => is_random_type(&inner.value),
-        _ => false,
-    }
+    is_sui_framework_type(ty, "random", "Random")
 }
@@ -1,5 +1,11 @@
+use move_compiler::expansion::ast::ModuleIdent;
 use move_compiler::naming::ast as N;
 use move_compiler::shared::Identifier;
+use move_compiler::typing::ast as T;
+
+use crate::type_classifier::is_sui_framework_type;
+
+pub(super) use crate::type_classifier::is_coin_type;
 
 pub(super) fn strip_refs(ty: &N::Type_) -> &N::Type_ {
     match ty {
@@ -8,41 +14,34 @@ pub(super) fn strip_refs(ty: &N::Type_) -> &N::Type_ {
     }
 }
 
-/// Check if a type is `sui::coin::Coin<T>`.
-///
-/// Coin types have the same ability pattern as capabilities (key+store, no copy/drop)
-/// but they are value tokens, not access control objects. We exclude them from
-/// capability transfer warnings to avoid false positives.
-pub(super) fn is_coin_type(ty: &N::Type_) -> bool {
-    match ty {
-        N::Type_::Apply(_, type_name, _) => {
-            if let N::TypeName_::ModuleType(mident, struct_name) = &type_name.value {
-                let module_sym = mident.value.module.value();
-                let struct_sym = struct_name.value();
-                // Match sui::coin::Coin or any coin module's Coin type
-                module_sym.as_str() == "coin" && struct_sym.as_str() == "Coin"
-            } else {
-                false
-            }
-        }
-        N::Type_::Ref(_, inner) => is_coin_type(&inner.value),
+/// Check if a type is `sui::balance::Balance<T>`.
+pub(super) fn is_balance_type(ty: &N::Type_) -> bool {
+    is_sui_framework_type(ty, "balance", "Balance")
+}
+
+/// Whether `ty` (ignoring references) is a struct or enum declared in `mident`.
+pub(super) fn is_module_local_datatype(ty: &N::Type_, mident: &ModuleIdent) -> bool {
+    match strip_refs(ty) {
+        N::Type_::Apply(_, type_name, _) => matches!(
+            &type_name.value,
+            N::TypeName_::ModuleType(owner, _) if owner == mident
+        ),
         _ => false,
     }
 }
 
-/// Check if a type is `sui::balance::Balance<T>`.
-pub(super) fn is_balance_type(ty: &N::Type_) -> bool {
-    match ty {
-        N::Type_::Apply(_, type_name, _) => {
-            if let N::TypeName_::ModuleType(mident, struct_name) = &type_name.value {
-                let module_sym = mident.value.module.value();
-                let struct_sym = struct_name.value();
-                module_sym.as_str() == "balance" && struct_sym.as_str() == "Balance"
-            } else {
-                false
-            }
-        }
-        N::Type_::Ref(_, inner) => is_balance_type(&inner.value),
+/// Check if a type is `sui::tx_context::TxContext` (through references).
+pub(super) fn is_tx_context_type(ty: &N::Type_) -> bool {
+    is_sui_framework_type(ty, "tx_context", "TxContext")
+}
+
+/// Check if a type is the builtin `signer` (through references).
+pub(super) fn is_signer_type(ty: &N::Type_) -> bool {
+    match strip_refs(ty) {
+        N::Type_::Apply(_, tname, _) => matches!(
+            &tname.value,
+            N::TypeName_::Builtin(b) if matches!(b.value, N::BuiltinTypeName_::Signer)
+        ),
         _ => false,
     }
 }
@@ -95,3 +94,196 @@ pub(super) fn format_apply_type(type_name: &N::TypeName, type_args: &[N::Type])
         format!("{}<{}>", name, args.join(", "))
     }
 }
+
+/// Return the `idx`-th argument of a call's argument list.
+pub(super) fn exp_list_nth_single(args: &T::Exp, idx: usize) -> Option<&T::Exp> {
+    match &args.exp.value {
+        T::UnannotatedExp_::ExpList(items) => items.get(idx).and_then(|item| match item {
+            T::ExpListItem::Single(e, _) => Some(e),
+            _ => None,
+        }),
+        _ if idx == 0 => Some(args),
+        _ => None,
+    }
+}
+
+/// Resolve the local variable an expression reads or borrows, looking through refs and casts.
+pub(super) fn extract_local_var_id(exp: &T::Exp) -> Option<u16> {
+    match &exp.exp.value {
+        T::UnannotatedExp_::Use(v) => Some(v.value.id),
+        T::UnannotatedExp_::Copy { var, .. } => Some(var.value.id),
+        T::UnannotatedExp_::Move { var, .. } => Some(var.value.id),
+        T::UnannotatedExp_::BorrowLocal(_mut_, v) => Some(v.value.id),
+        T::UnannotatedExp_::TempBorrow(_, inner) => extract_local_var_id(inner),
+        T::UnannotatedExp_::Dereference(inner) => extract_local_var_id(inner),
+        T::UnannotatedExp_::Cast(inner, _) => extract_local_var_id(inner),
+        T::UnannotatedExp_::Annotate(inner, _) => extract_local_var_id(inner),
+        T::UnannotatedExp_::Borrow(_, base, _) => extract_local_var_id(base),
+        _ => None,
+    }
+}
+
+/// Whether `exp` is exactly a use (copy or move) of local variable `target`.
+pub(super) fn is_local_var(exp: &T::Exp, target: u16) -> bool {
+    match &exp.exp.value {
+        T::UnannotatedExp_::Use(v) => v.value.id == target,
+        T::UnannotatedExp_::Copy { var, .. } | T::UnannotatedExp_::Move { var, .. } => {
+            var.value.id == target
+        }
+        _ => false,
+    }
+}
+
+/// Whether local variable `target` is read anywhere within `exp`.
+pub(super) fn exp_uses_var(exp: &T::Exp, target: u16) -> bool {
+    match &exp.exp.value {
+        T::UnannotatedExp_::Use(v) => v.value.id == target,
+        T::UnannotatedExp_::Copy { var, .. } => var.value.id == target,
+        T::UnannotatedExp_::Move { var, .. } => var.value.id == target,
+        T::UnannotatedExp_::BorrowLocal(_, v) => v.value.id == target,
+        T::UnannotatedExp_::TempBorrow(_, inner) => exp_uses_var(inner, target),
+        T::UnannotatedExp_::Dereference(inner) => exp_uses_var(inner, target),
+        T::UnannotatedExp_::Borrow(_, base, _) => exp_uses_var(base, target),
+        T::UnannotatedExp_::UnaryExp(_, inner) => exp_uses_var(inner, target),
+        T::UnannotatedExp_::Cast(inner, _) => exp_uses_var(inner, target),
+        T::UnannotatedExp_::Annotate(inner, _) => exp_uses_var(inner, target),
+        T::UnannotatedExp_::Return(inner) => exp_uses_var(inner, target),
+        T::UnannotatedExp_::Abort(inner) => exp_uses_var(inner, target),
+        T::UnannotatedExp_::Give(_, inner) => exp_uses_var(inner, target),
+        T::UnannotatedExp_::BinopExp(left, _op, _ty, right) => {
+            exp_uses_var(left, target) || exp_uses_var(right, target)
+        }
+        T::UnannotatedExp_::Mutate(left, right) => {
+            exp_uses_var(left, target) || exp_uses_var(right, target)
+        }
+        T::UnannotatedExp_::Assign(_lvalues, _expected_types, rhs) => exp_uses_var(rhs, target),
+        T::UnannotatedExp_::ModuleCall(call) => exp_uses_var(&call.arguments, target),
+        T::UnannotatedExp_::Builtin(_, args) => exp_uses_var(args, target),
+        T::UnannotatedExp_::Vector(_loc, _n, _ty, args) => exp_uses_var(args, target),
+        T::UnannotatedExp_::ExpList(items) => items.iter().any(|item| match item {
+            T::ExpListItem::Single(e, _) => exp_uses_var(e, target),
+            T::ExpListItem::Splat(_, e, _) => exp_uses_var(e, target),
+        }),
+        T::UnannotatedExp_::IfElse(cond, if_body, else_body) => {
+            exp_uses_var(cond, target)
+                || exp_uses_var(if_body, target)
+                || else_body
+                    .as_deref()
+                    .is_some_and(|e| exp_uses_var(e, target))
+        }
+        T::UnannotatedExp_::While(_, cond, body) => {
+            exp_uses_var(cond, target) || exp_uses_var(body, target)
+        }
+        T::UnannotatedExp_::Loop { body, .. } => exp_uses_var(body, target),
+        T::UnannotatedExp_::Block((_, seq_items))
+        | T::UnannotatedExp_::NamedBlock(_, (_, seq_items)) => {
+            seq_items.iter().any(|item| match &item.value {
+                T::SequenceItem_::Seq(e) => exp_uses_var(e, target),
+                T::SequenceItem_::Bind(_, _, e) => exp_uses_var(e, target),
+                _ => false,
+            })
+        }
+        T::UnannotatedExp_::Match(scrut, arms) => {
+            exp_uses_var(scrut, target)
+                || arms.value.iter().any(|arm| {
+                    arm.value
+                        .guard
+                        .as_deref()
+                        .is_some_and(|g| exp_uses_var(g, target))
+                        || exp_uses_var(&arm.value.rhs, target)
+                })
+        }
+        T::UnannotatedExp_::VariantMatch(scrut, _t, arms) => {
+            exp_uses_var(scrut, target) || arms.iter().any(|(_vname, e)| exp_uses_var(e, target))
+        }
+        T::UnannotatedExp_::Pack(_, _, _tys, fields) => fields
+            .iter()
+            .any(|(_f, _idx, (_, (_, e)))| exp_uses_var(e, target)),
+        T::UnannotatedExp_::PackVariant(_, _, _, _tys, fields) => fields
+            .iter()
+            .any(|(_f, _idx, (_, (_, e)))| exp_uses_var(e, target)),
+        _ => false,
+    }
+}
+
+/// Visit `exp` and every sub-expression beneath it in pre-order.
+pub(crate) fn for_each_exp<'a>(exp: &'a T::Exp, f: &mut impl FnMut(&'a T::Exp)) {
+    f(exp);
+    match &exp.exp.value {
+        T::UnannotatedExp_::TempBorrow(_, inner)
+        | T::UnannotatedExp_::Dereference(inner)
+        | T::UnannotatedExp_::Borrow(_, inner, _)
+        | T::UnannotatedExp_::UnaryExp(_, inner)
+        | T::UnannotatedExp_::Cast(inner, _)
+        | T::UnannotatedExp_::Annotate(inner, _)
+        | T::UnannotatedExp_::Return(inner)
+        | T::UnannotatedExp_::Abort(inner)
+        | T::UnannotatedExp_::Give(_, inner)
+        | T::UnannotatedExp_::Assign(_, _, inner)
+        | T::UnannotatedExp_::Builtin(_, inner)
+        | T::UnannotatedExp_::Vector(_, _, _, inner)
+        | T::UnannotatedExp_::Loop { body: inner, .. } => for_each_exp(inner, f),
+        T::UnannotatedExp_::ModuleCall(call) => for_each_exp(&call.arguments, f),
+        T::UnannotatedExp_::BinopExp(left, _, _, right)
+        | T::UnannotatedExp_::Mutate(left, right)
+        | T::UnannotatedExp_::While(_, left, right) => {
+            for_each_exp(left, f);
+            for_each_exp(right, f);
+        }
+        T::UnannotatedExp_::ExpList(items) => {
+            for item in items {
+                match item {
+                    T::ExpListItem::Single(e, _) | T::ExpListItem::Splat(_, e, _) => {
+                        for_each_exp(e, f)
+                    }
+                }
+            }
+        }
+        T::UnannotatedExp_::IfElse(cond, if_body, else_body) => {
+            for_each_exp(cond, f);
+            for_each_exp(if_body, f);
+            if let Some(e) = else_body {
+                for_each_exp(e, f);
+            }
+        }
+        T::UnannotatedExp_::Block((_, seq_items))
+        | T::UnannotatedExp_::NamedBlock(_, (_, seq_items)) => {
+            for_each_exp_in_seq(seq_items.iter(), f);
+        }
+        T::UnannotatedExp_::Match(scrut, arms) => {
+            for_each_exp(scrut, f);
+            for arm in arms.value.iter() {
+                if let Some(guard) = arm.value.guard.as_deref() {
+                    for_each_exp(guard, f);
+                }
+                for_each_exp(&arm.value.rhs, f);
+            }
+        }
+        T::UnannotatedExp_::VariantMatch(scrut, _, arms) => {
+            for_each_exp(scrut, f);
+            for (_, e) in arms {
+                for_each_exp(e, f);
+            }
+        }
+        T::UnannotatedExp_::Pack(_, _, _, fields)
+        | T::UnannotatedExp_::PackVariant(_, _, _, _, fields) => {
+            for (_, _, (_, (_, e))) in fields.iter() {
+                for_each_exp(e, f);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Visit every expression in a sequence (function body or block) in pre-order.
+pub(crate) fn for_each_exp_in_seq<'a>(
+    seq_items: impl IntoIterator<Item = &'a T::SequenceItem>,
+    f: &mut impl FnMut(&'a T::Exp),
+) {
+    for item in seq_items {
+        match &item.value {
+            T::SequenceItem_::Seq(e) | T::SequenceItem_::Bind(_, _, e) => for_each_exp(e, f),
+            _ => {}
+        }
+    }
+}
@@ -113,7 +113,9 @@ pub(crate) fn lint_sui_visitors(
                 span,
                 message,
                 help: None,
-                suggestion: None,
+                suggestions: Vec::new(),
+                notes: Vec::new(),
+                labels: Vec::new(),
             });
         }
     }
@@ -0,0 +1,315 @@
+use crate::diagnostics::Diagnostic;
+use crate::error::Result as ClippyResult;
+use crate::lint::LintSettings;
+use crate::type_classifier::{
+    abilities_of_type, call_type_arg, has_key_ability, is_key_store_type, is_sui_framework_call,
+};
+use move_compiler::naming::ast as N;
+use move_compiler::parser::ast::TargetKind;
+use move_compiler::shared::Identifier;
+use move_compiler::shared::files::MappedFiles;
+use move_compiler::shared::program_info::TypingProgramInfo;
+use move_compiler::typing::ast as T;
+
+use super::super::util::{diag_from_loc, push_diag};
+use super::super::{FREEZE_LOCKS_VALUE, TRANSFER_OF_NON_OBJECT, WRONG_TRANSFER_VARIANT};
+use super::shared::{
+    exp_list_nth_single, for_each_exp_in_seq, format_type, is_coin_or_balance_type, strip_refs,
+};
+
+type Result<T> = ClippyResult<T>;
+
+/// `sui::transfer` functions whose object argument must have `key`.
+const OBJECT_TRANSFER_FUNCTIONS: &[&str] = &[
+    "transfer",
+    "public_transfer",
+    "share_object",
+    "public_share_object",
+    "freeze_object",
+    "public_freeze_object",
+];
+
+/// Private `sui::transfer` functions paired with their `public_*` counterparts.
+const PRIVATE_TRANSFER_FUNCTIONS: &[(&str, &str)] = &[
+    ("transfer", "public_transfer"),
+    ("share_object", "public_share_object"),
+    ("freeze_object", "public_freeze_object"),
+];
+
+fn object_transfer_function(call: &T::ModuleCall) -> Option<&'static str> {
+    OBJECT_TRANSFER_FUNCTIONS
+        .iter()
+        .find(|function| is_sui_framework_call(call, "transfer", function))
+        .copied()
+}
+
+/// Detects `transfer::{transfer, share_object, freeze_object, ...}` on a value without `key`.
+///
+/// The real framework rejects these at compile time; this lint gives a targeted message that
+/// names the offending type. The object type is taken from the call's type argument, falling
+/// back to the type of the first argument.
+pub(crate) fn lint_transfer_of_non_object(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+            let fn_name_sym = fname.value();
+            let fn_name = fn_name_sym.as_str();
+
+            for_each_exp_in_seq(seq_items.iter(), &mut |exp| {
+                let T::UnannotatedExp_::ModuleCall(call) = &exp.exp.value else {
+                    return;
+                };
+                let Some(function) = object_transfer_function(call) else {
+                    return;
+                };
+                let Some(object_ty) = call_type_arg(call, 0)
+                    .map(|ty| &ty.value)
+                    .or_else(|| exp_list_nth_single(&call.arguments, 0).map(|arg| &arg.ty.value))
+                else {
+                    return;
+                };
+                let Some(abilities) = abilities_of_type(object_ty) else {
+                    return;
+                };
+                if has_key_ability(&abilities) {
+                    return;
+                }
+
+                let loc = exp.exp.loc;
+                let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                    return;
+                };
+                let anchor = loc.start() as usize;
+                let type_name = format_type(strip_refs(object_ty));
+
+                push_diag(
+                    out,
+                    settings,
+                    &TRANSFER_OF_NON_OBJECT,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    anchor,
+                    format!(
+                        "`transfer::{function}` in `{fn_name}` is called on `{type_name}`, which lacks `key`. \
+                         Only `key` objects can be transferred, shared, or frozen."
+                    ),
+                );
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Detects `transfer::{public_transfer, public_share_object, public_freeze_object}<T>` where
+/// `T` has `key + store` and is declared in the calling module.
+///
+/// Only the defining module may call the private variants, so there they are the narrower
+/// choice: they keep working if `store` is later dropped from `T`, and they keep the call site
+/// from reading like an escape hatch for other packages. The type argument is resolved against
+/// `TypingProgramInfo` so only structs actually declared by the caller's module count as
+/// module-local. Private variants on foreign types are already rejected by the Sui verifier.
+pub(crate) fn lint_wrong_transfer_variant(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+    info: &TypingProgramInfo,
+) -> Result<()> {
+    for (mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+            let fn_name_sym = fname.value();
+            let fn_name = fn_name_sym.as_str();
+
+            for_each_exp_in_seq(seq_items.iter(), &mut |exp| {
+                let T::UnannotatedExp_::ModuleCall(call) = &exp.exp.value else {
+                    return;
+                };
+                let Some((private_function, function)) = PRIVATE_TRANSFER_FUNCTIONS
+                    .iter()
+                    .find(|(_, function)| is_sui_framework_call(call, "transfer", function))
+                else {
+                    return;
+                };
+                let Some(object_ty) = call_type_arg(call, 0).map(|ty| &ty.value) else {
+                    return;
+                };
+                if !is_key_store_type(object_ty) {
+                    return;
+                }
+                let N::Type_::Apply(_, type_name, _) = strip_refs(object_ty) else {
+                    return;
+                };
+                let N::TypeName_::ModuleType(struct_mident, struct_name) = &type_name.value else {
+                    return;
+                };
+                if struct_mident.value != mident.value
+                    || info
+                        .struct_definition_opt(struct_mident, struct_name)
+                        .is_none()
+                {
+                    return;
+                }
+
+                let loc = exp.exp.loc;
+                let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                    return;
+                };
+                let anchor = loc.start() as usize;
+                let struct_sym = struct_name.value();
+                let struct_str = struct_sym.as_str();
+
+                push_diag(
+                    out,
+                    settings,
+                    &WRONG_TRANSFER_VARIANT,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    anchor,
+                    format!(
+                        "`{fn_name}` calls `transfer::{function}` on `{struct_str}`, which is defined in this module. \
+                         Use `transfer::{private_function}` instead; it does not rely on `store` and keeps the operation module-controlled."
+                    ),
+                );
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// A field of a struct (or of a struct nested in its fields) that holds value, as
+/// `(field path, field type)`.
+///
+/// `Coin`, `Balance`, and any `key` object count as value. Type arguments are searched too,
+/// so `Option<Coin<T>>` and `vector<Balance<T>>` fields are found.
+fn value_holding_field(info: &TypingProgramInfo, ty: &N::Type_) -> Option<(String, String)> {
+    let N::Type_::Apply(_, type_name, _) = strip_refs(ty) else {
+        return None;
+    };
+    let N::TypeName_::ModuleType(mident, struct_name) = &type_name.value else {
+        return None;
+    };
+    let sdef = info.struct_definition_opt(mident, struct_name)?;
+    let N::StructFields::Defined(_, fields) = &sdef.fields else {
+        return None;
+    };
+    fields.iter().find_map(|(_, field, (_, (_, field_ty)))| {
+        if let Some(held) = value_type_in(&field_ty.value) {
+            return Some((field.to_string(), format_type(held)));
+        }
+        value_holding_field(info, &field_ty.value)
+            .map(|(path, held)| (format!("{field}.{path}"), held))
+    })
+}
+
+/// `ty` itself or a type argument of it that is a `Coin`, `Balance`, or `key` object.
+fn value_type_in(ty: &N::Type_) -> Option<&N::Type_> {
+    if is_coin_or_balance_type(ty) || abilities_of_type(ty).is_some_and(|a| has_key_ability(&a)) {
+        return Some(ty);
+    }
+    match ty {
+        N::Type_::Apply(_, _, type_args) => type_args.iter().find_map(|t| value_type_in(&t.value)),
+        _ => None,
+    }
+}
+
+/// Detects `transfer::freeze_object<T>` where `T` holds a `Coin`, `Balance`, or `key` object.
+///
+/// Frozen objects are immutable forever, so any value stored inside them can never be moved out
+/// again. The frozen type's fields are resolved through `TypingProgramInfo` and walked through
+/// nested structs, so the struct may be declared in another module of the package.
+pub(crate) fn lint_freeze_locks_value(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+    info: &TypingProgramInfo,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+            let fn_name_sym = fname.value();
+            let fn_name = fn_name_sym.as_str();
+
+            for_each_exp_in_seq(seq_items.iter(), &mut |exp| {
+                let T::UnannotatedExp_::ModuleCall(call) = &exp.exp.value else {
+                    return;
+                };
+                let Some(function) = ["freeze_object", "public_freeze_object"]
+                    .into_iter()
+                    .find(|function| is_sui_framework_call(call, "transfer", function))
+                else {
+                    return;
+                };
+                let Some(object_ty) = call_type_arg(call, 0)
+                    .map(|ty| &ty.value)
+                    .or_else(|| exp_list_nth_single(&call.arguments, 0).map(|arg| &arg.ty.value))
+                else {
+                    return;
+                };
+                let Some((field_path, held_ty)) = value_holding_field(info, object_ty) else {
+                    return;
+                };
+
+                let loc = exp.exp.loc;
+                let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                    return;
+                };
+                let anchor = loc.start() as usize;
+                let type_name = format_type(strip_refs(object_ty));
+
+                push_diag(
+                    out,
+                    settings,
+                    &FREEZE_LOCKS_VALUE,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    anchor,
+                    format!(
+                        "`transfer::{function}` in `{fn_name}` freezes `{type_name}`, whose field `{field_path}` holds `{held_ty}`. \
+                         Frozen objects can never be mutated or unpacked, so that value is locked permanently."
+                    ),
+                );
+            });
+        }
+    }
+
+    Ok(())
+}
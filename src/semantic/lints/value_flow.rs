@@ -1,13 +1,15 @@
 use crate::diagnostics::Diagnostic;
 use crate::error::Result as ClippyResult;
 use crate::lint::LintSettings;
-use move_compiler::parser::ast::TargetKind;
+use crate::type_classifier::{is_package_call, is_sui_framework_call};
+use move_compiler::parser::ast::{BinOp_, TargetKind};
 use move_compiler::shared::Identifier;
 use move_compiler::shared::files::MappedFiles;
 use move_compiler::typing::ast as T;
 
 use super::super::util::{diag_from_loc, push_diag};
-use super::super::{UNCHECKED_DIVISION, UNUSED_RETURN_VALUE};
+use super::super::{SUSPICIOUS_COIN_SPLIT, UNCHECKED_DIVISION, UNUSED_RETURN_VALUE};
+use super::shared::{exp_list_nth_single, for_each_exp};
 
 type Result<T> = ClippyResult<T>;
 
@@ -245,16 +247,16 @@ pub(crate) fn lint_unused_return_value(
 ) -> Result<()> {
     // TODO(infra): Move to crate::framework_catalog and match on fully-qualified IDs.
     // Functions whose return values should not be ignored
-    const IMPORTANT_FUNCTIONS: &[(&str, &str)] = &[
-        ("coin", "split"),
-        ("coin", "take"),
-        ("balance", "split"),
-        ("balance", "withdraw_all"),
-        ("option", "extract"),
-        ("option", "destroy_some"),
-        ("vector", "pop_back"),
-        ("table", "remove"),
-        ("bag", "remove"),
+    const IMPORTANT_FUNCTIONS: &[(&str, &str, &str)] = &[
+        ("sui", "coin", "split"),
+        ("sui", "coin", "take"),
+        ("sui", "balance", "split"),
+        ("sui", "balance", "withdraw_all"),
+        ("std", "option", "extract"),
+        ("std", "option", "destroy_some"),
+        ("std", "vector", "pop_back"),
+        ("sui", "table", "remove"),
+        ("sui", "bag", "remove"),
     ];
 
     for (_mident, mdef) in prog.modules.key_cloned_iter() {
@@ -289,7 +291,7 @@ pub(crate) fn lint_unused_return_value(
 /// Check for unused return values in a sequence item.
 fn check_unused_return_in_seq_item(
     item: &T::SequenceItem,
-    important_fns: &[(&str, &str)],
+    important_fns: &[(&str, &str, &str)],
     out: &mut Vec<Diagnostic>,
     settings: &LintSettings,
     file_map: &MappedFiles,
@@ -304,8 +306,8 @@ fn check_unused_return_in_seq_item(
                 let call_sym = call.name.value();
                 let call_name = call_sym.as_str();
 
-                for (mod_pattern, fn_pattern) in important_fns {
-                    if module_name == *mod_pattern && call_name == *fn_pattern {
+                for (package, mod_pattern, fn_pattern) in important_fns {
+                    if is_package_call(call, package, mod_pattern, fn_pattern) {
                         let loc = exp.exp.loc;
                         let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
                             continue;
@@ -340,7 +342,7 @@ fn check_unused_return_in_seq_item(
 /// Recursively check for unused return values in expressions.
 fn check_unused_return_in_exp(
     exp: &T::Exp,
-    important_fns: &[(&str, &str)],
+    important_fns: &[(&str, &str, &str)],
     out: &mut Vec<Diagnostic>,
     settings: &LintSettings,
     file_map: &MappedFiles,
@@ -543,6 +545,179 @@ fn check_unused_return_in_exp(
     }
 }
 
+// =========================================================================
+// Suspicious Coin Split Lint (type-based, experimental)
+// =========================================================================
+
+/// Lint for `coin::split` calls whose amount is an unchecked function parameter.
+///
+/// Splitting more than the coin holds aborts, and splitting zero is usually a logic bug.
+/// A preceding `assert!` that compares the parameter (`amount > 0`,
+/// `amount <= coin::value(&c)`) counts as validation.
+pub(crate) fn lint_suspicious_coin_split(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+            let fn_name_sym = fname.value();
+            let fn_name = fn_name_sym.as_str();
+
+            let param_ids: std::collections::HashSet<u16> = fdef
+                .signature
+                .parameters
+                .iter()
+                .map(|(_mut_, var, _ty)| var.value.id)
+                .collect();
+
+            // Track parameters that have been compared in an assertion
+            let mut validated_vars: std::collections::HashSet<u16> =
+                std::collections::HashSet::new();
+
+            for item in seq_items.iter() {
+                let exp = match &item.value {
+                    T::SequenceItem_::Seq(exp) => {
+                        check_for_amount_assertion(exp, &param_ids, &mut validated_vars);
+                        exp
+                    }
+                    T::SequenceItem_::Bind(_, _, exp) => exp,
+                    _ => continue,
+                };
+
+                for_each_exp(exp, &mut |e| {
+                    let T::UnannotatedExp_::ModuleCall(call) = &e.exp.value else {
+                        return;
+                    };
+                    if !is_sui_framework_call(call, "coin", "split") {
+                        return;
+                    }
+                    let Some(var_id) =
+                        exp_list_nth_single(&call.arguments, 1).and_then(extract_var_id)
+                    else {
+                        return;
+                    };
+                    if !param_ids.contains(&var_id) || validated_vars.contains(&var_id) {
+                        return;
+                    }
+
+                    let loc = e.exp.loc;
+                    let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                        return;
+                    };
+                    let Some(amount_sym) = fdef
+                        .signature
+                        .parameters
+                        .iter()
+                        .find(|(_mut_, var, _ty)| var.value.id == var_id)
+                        .map(|(_mut_, var, _ty)| var.value.name)
+                    else {
+                        return;
+                    };
+                    let amount = amount_sym.as_str();
+                    // The coin argument for `coin::value`: `&c` for a borrowed value, or the
+                    // reference itself when the coin is already held by reference.
+                    let coin = exp_list_nth_single(&call.arguments, 0)
+                        .and_then(|arg| {
+                            let text = contents
+                                .get(arg.exp.loc.start() as usize..arg.exp.loc.end() as usize)?
+                                .trim();
+                            let text = text
+                                .strip_prefix("&mut ")
+                                .or_else(|| text.strip_prefix('&'))
+                                .unwrap_or(text)
+                                .trim();
+                            if text.is_empty() || text.contains('\n') {
+                                return None;
+                            }
+                            let borrowed = matches!(
+                                arg.exp.value,
+                                T::UnannotatedExp_::BorrowLocal(..)
+                                    | T::UnannotatedExp_::TempBorrow(..)
+                                    | T::UnannotatedExp_::Borrow(..)
+                            );
+                            Some(if borrowed {
+                                format!("&{text}")
+                            } else {
+                                text.to_string()
+                            })
+                        })
+                        .unwrap_or_else(|| "&coin".to_string());
+                    push_diag(
+                        out,
+                        settings,
+                        &SUSPICIOUS_COIN_SPLIT,
+                        file,
+                        span,
+                        contents.as_ref(),
+                        loc.start() as usize,
+                        format!(
+                            "`coin::split` in `{fn_name}` uses the unchecked parameter `{amount}` as the amount. \
+                             Consider `assert!({amount} > 0 && {amount} <= coin::value({coin}), E_INVALID_AMOUNT)` before splitting."
+                        ),
+                    );
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Record parameters compared inside an `assert!` condition, looking through `&&`.
+fn check_for_amount_assertion(
+    exp: &T::Exp,
+    param_ids: &std::collections::HashSet<u16>,
+    validated_vars: &mut std::collections::HashSet<u16>,
+) {
+    let T::UnannotatedExp_::Builtin(builtin, args) = &exp.exp.value else {
+        return;
+    };
+    if !format!("{:?}", builtin).contains("Assert") {
+        return;
+    }
+    if let Some(cond) = exp_list_nth_single(args, 0) {
+        collect_compared_params(cond, param_ids, validated_vars);
+    }
+}
+
+fn collect_compared_params(
+    cond: &T::Exp,
+    param_ids: &std::collections::HashSet<u16>,
+    validated_vars: &mut std::collections::HashSet<u16>,
+) {
+    let T::UnannotatedExp_::BinopExp(left, op, _, right) = &cond.exp.value else {
+        return;
+    };
+    match op.value {
+        BinOp_::And => {
+            collect_compared_params(left, param_ids, validated_vars);
+            collect_compared_params(right, param_ids, validated_vars);
+        }
+        BinOp_::Gt | BinOp_::Ge | BinOp_::Lt | BinOp_::Le | BinOp_::Neq => {
+            for side in [left, right] {
+                if let Some(var_id) = extract_var_id(side)
+                    && param_ids.contains(&var_id)
+                {
+                    validated_vars.insert(var_id);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
 // =========================================================================
 // Share Owned Authority Lint (type-grounded)
 // =========================================================================
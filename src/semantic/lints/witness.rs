@@ -1,6 +1,8 @@
 use crate::diagnostics::Diagnostic;
 use crate::error::Result as ClippyResult;
 use crate::lint::LintSettings;
+use crate::type_classifier::{has_key_ability, has_store_ability, is_package_address};
+use move_compiler::expansion::ast::AbilitySet;
 use move_compiler::naming::ast as N;
 use move_compiler::parser::ast::TargetKind;
 use move_compiler::shared::Identifier;
@@ -8,9 +10,12 @@ use move_compiler::shared::{files::MappedFiles, program_info::TypingProgramInfo}
 use move_compiler::typing::ast as T;
 
 use super::super::util::{diag_from_loc, push_diag};
-use super::super::{GENERIC_TYPE_WITNESS_UNUSED, MISSING_WITNESS_DROP_V2, WITNESS_ANTIPATTERNS};
+use super::super::{
+    GENERIC_TYPE_WITNESS_UNUSED, MISSING_WITNESS_DROP_V2, REDUNDANT_ABILITY_CONSTRAINT,
+    UNUSED_FUNCTION_TYPE_PARAMETER, WITNESS_ANTIPATTERNS,
+};
 // INVALID_OTW removed - duplicates Sui Verifier's one_time_witness_verifier.rs
-use super::shared::{format_type, strip_refs};
+use super::shared::{exp_uses_var, for_each_exp_in_seq, format_type, strip_refs};
 
 type Result<T> = ClippyResult<T>;
 
@@ -20,8 +25,9 @@ fn is_type_name_witness_type(ty: &N::Type_) -> bool {
             if let N::TypeName_::ModuleType(mident, struct_name) = &type_name.value {
                 let module_sym = mident.value.module.value();
                 let struct_sym = struct_name.value();
-                // Match sui::coin::Coin or any coin module's Coin type
-                module_sym.as_str() == "type_name" && struct_sym.as_str() == "TypeName"
+                is_package_address(&mident.value.address, "std")
+                    && module_sym.as_str() == "type_name"
+                    && struct_sym.as_str() == "TypeName"
             } else {
                 false
             }
@@ -30,77 +36,6 @@ fn is_type_name_witness_type(ty: &N::Type_) -> bool {
     }
 }
 
-fn exp_uses_var(exp: &T::Exp, target: u16) -> bool {
-    match &exp.exp.value {
-        T::UnannotatedExp_::Use(v) => v.value.id == target,
-        T::UnannotatedExp_::Copy { var, .. } => var.value.id == target,
-        T::UnannotatedExp_::Move { var, .. } => var.value.id == target,
-        T::UnannotatedExp_::BorrowLocal(_, v) => v.value.id == target,
-        T::UnannotatedExp_::TempBorrow(_, inner) => exp_uses_var(inner, target),
-        T::UnannotatedExp_::Dereference(inner) => exp_uses_var(inner, target),
-        T::UnannotatedExp_::Borrow(_, base, _) => exp_uses_var(base, target),
-        T::UnannotatedExp_::UnaryExp(_, inner) => exp_uses_var(inner, target),
-        T::UnannotatedExp_::Cast(inner, _) => exp_uses_var(inner, target),
-        T::UnannotatedExp_::Annotate(inner, _) => exp_uses_var(inner, target),
-        T::UnannotatedExp_::Return(inner) => exp_uses_var(inner, target),
-        T::UnannotatedExp_::Abort(inner) => exp_uses_var(inner, target),
-        T::UnannotatedExp_::Give(_, inner) => exp_uses_var(inner, target),
-        T::UnannotatedExp_::BinopExp(left, _op, _ty, right) => {
-            exp_uses_var(left, target) || exp_uses_var(right, target)
-        }
-        T::UnannotatedExp_::Mutate(left, right) => {
-            exp_uses_var(left, target) || exp_uses_var(right, target)
-        }
-        T::UnannotatedExp_::Assign(_lvalues, _expected_types, rhs) => exp_uses_var(rhs, target),
-        T::UnannotatedExp_::ModuleCall(call) => exp_uses_var(&call.arguments, target),
-        T::UnannotatedExp_::Builtin(_, args) => exp_uses_var(args, target),
-        T::UnannotatedExp_::Vector(_loc, _n, _ty, args) => exp_uses_var(args, target),
-        T::UnannotatedExp_::ExpList(items) => items.iter().any(|item| match item {
-            T::ExpListItem::Single(e, _) => exp_uses_var(e, target),
-            T::ExpListItem::Splat(_, e, _) => exp_uses_var(e, target),
-        }),
-        T::UnannotatedExp_::IfElse(cond, if_body, else_body) => {
-            exp_uses_var(cond, target)
-                || exp_uses_var(if_body, target)
-                || else_body
-                    .as_deref()
-                    .is_some_and(|e| exp_uses_var(e, target))
-        }
-        T::UnannotatedExp_::While(_, cond, body) => {
-            exp_uses_var(cond, target) || exp_uses_var(body, target)
-        }
-        T::UnannotatedExp_::Loop { body, .. } => exp_uses_var(body, target),
-        T::UnannotatedExp_::Block((_, seq_items))
-        | T::UnannotatedExp_::NamedBlock(_, (_, seq_items)) => {
-            seq_items.iter().any(|item| match &item.value {
-                T::SequenceItem_::Seq(e) => exp_uses_var(e, target),
-                T::SequenceItem_::Bind(_, _, e) => exp_uses_var(e, target),
-                _ => false,
-            })
-        }
-        T::UnannotatedExp_::Match(scrut, arms) => {
-            exp_uses_var(scrut, target)
-                || arms.value.iter().any(|arm| {
-                    arm.value
-                        .guard
-                        .as_deref()
-                        .is_some_and(|g| exp_uses_var(g, target))
-                        || exp_uses_var(&arm.value.rhs, target)
-                })
-        }
-        T::UnannotatedExp_::VariantMatch(scrut, _t, arms) => {
-            exp_uses_var(scrut, target) || arms.iter().any(|(_vname, e)| exp_uses_var(e, target))
-        }
-        T::UnannotatedExp_::Pack(_, _, _tys, fields) => fields
-            .iter()
-            .any(|(_f, _idx, (_, (_, e)))| exp_uses_var(e, target)),
-        T::UnannotatedExp_::PackVariant(_, _, _, _tys, fields) => fields
-            .iter()
-            .any(|(_f, _idx, (_, (_, e)))| exp_uses_var(e, target)),
-        _ => false,
-    }
-}
-
 /// Detects generic functions that accept a `type_name::TypeName` witness but never use it.
 pub(crate) fn lint_generic_type_witness_unused(
     out: &mut Vec<Diagnostic>,
@@ -176,6 +111,302 @@ pub(crate) fn lint_generic_type_witness_unused(
     Ok(())
 }
 
+// =========================================================================
+// Unused Function Type Parameter Lint (type-based)
+// =========================================================================
+
+fn type_mentions_param(ty: &N::Type_, id: N::TParamID) -> bool {
+    match ty {
+        N::Type_::Param(tp) => tp.id == id,
+        N::Type_::Ref(_, inner) => type_mentions_param(&inner.value, id),
+        N::Type_::Apply(_, _, type_args) => {
+            type_args.iter().any(|t| type_mentions_param(&t.value, id))
+        }
+        N::Type_::Fun(args, ret) => {
+            args.iter().any(|t| type_mentions_param(&t.value, id))
+                || type_mentions_param(&ret.value, id)
+        }
+        _ => false,
+    }
+}
+
+/// Whether `exp` mentions the type parameter in its own type or in an explicit type argument.
+fn exp_mentions_param(exp: &T::Exp, id: N::TParamID) -> bool {
+    if type_mentions_param(&exp.ty.value, id) {
+        return true;
+    }
+    match &exp.exp.value {
+        T::UnannotatedExp_::ModuleCall(call) => call
+            .type_arguments
+            .iter()
+            .any(|t| type_mentions_param(&t.value, id)),
+        T::UnannotatedExp_::Pack(_, _, tys, _)
+        | T::UnannotatedExp_::PackVariant(_, _, _, tys, _) => {
+            tys.iter().any(|t| type_mentions_param(&t.value, id))
+        }
+        T::UnannotatedExp_::Vector(_, _, ty, _)
+        | T::UnannotatedExp_::Cast(_, ty)
+        | T::UnannotatedExp_::Annotate(_, ty) => type_mentions_param(&ty.value, id),
+        _ => false,
+    }
+}
+
+/// Detects function type parameters that are used in no type position at all.
+///
+/// A parameter that only appears as a type argument inside the body (e.g.
+/// `type_name::get<T>()` or `balance::zero<T>()`) is a witness/dispatch pattern and is
+/// not flagged; only parameters absent from the signature and every body type are.
+pub(crate) fn lint_unused_function_type_parameter(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            if fdef.signature.type_parameters.is_empty() {
+                continue;
+            }
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+
+            for tparam in &fdef.signature.type_parameters {
+                let id = tparam.id;
+                let in_signature = fdef
+                    .signature
+                    .parameters
+                    .iter()
+                    .any(|(_m, _v, t)| type_mentions_param(&t.value, id))
+                    || type_mentions_param(&fdef.signature.return_type.value, id);
+                if in_signature {
+                    continue;
+                }
+
+                let mut in_body = false;
+                for_each_exp_in_seq(seq_items.iter(), &mut |exp| {
+                    in_body = in_body || exp_mentions_param(exp, id);
+                });
+                if in_body {
+                    continue;
+                }
+
+                let loc = tparam.user_specified_name.loc;
+                let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                    continue;
+                };
+                let anchor = loc.start() as usize;
+                let fn_name_sym = fname.value();
+                let fn_name = fn_name_sym.as_str();
+                let tparam_name = tparam.user_specified_name.value;
+
+                push_diag(
+                    out,
+                    settings,
+                    &UNUSED_FUNCTION_TYPE_PARAMETER,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    anchor,
+                    format!(
+                        "Type parameter `{tparam_name}` of `{fn_name}` is never used in the signature or body. \
+                         Remove it, or take a witness argument of that type if callers must prove ownership of it."
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// =========================================================================
+// Redundant Ability Constraint Lint (type-based)
+// =========================================================================
+
+/// Whether a type argument constrained by `required` forces `store` onto type parameter `id`.
+///
+/// `T` itself needs `store` only if the constraint says so. When `T` is nested inside the
+/// argument (e.g. `Box<T>`), a `key` constraint counts too, since a generic struct only has
+/// `key` when its type arguments have `store`. Unknown constraints are treated as requiring it.
+fn constraint_requires_store(
+    required: Option<&AbilitySet>,
+    arg: &N::Type_,
+    id: N::TParamID,
+) -> bool {
+    if !type_mentions_param(arg, id) {
+        return false;
+    }
+    let Some(required) = required else {
+        return true;
+    };
+    match strip_refs(arg) {
+        N::Type_::Param(tp) if tp.id == id => has_store_ability(required),
+        _ => has_store_ability(required) || has_key_ability(required),
+    }
+}
+
+/// Whether `ty` instantiates a struct whose type parameter constraints need `store` on `id`.
+fn type_requires_store(info: &TypingProgramInfo, ty: &N::Type_, id: N::TParamID) -> bool {
+    match ty {
+        N::Type_::Ref(_, inner) => type_requires_store(info, &inner.value, id),
+        N::Type_::Apply(_, type_name, type_args) => {
+            let sdef = match &type_name.value {
+                N::TypeName_::ModuleType(mident, name) => {
+                    Some(info.struct_definition_opt(mident, name))
+                }
+                _ => None,
+            };
+            type_args.iter().enumerate().any(|(i, arg)| {
+                let declared_requires_store = match sdef {
+                    // Builtin types (`vector`) add no constraints of their own.
+                    None => false,
+                    Some(sdef) => {
+                        let required = sdef
+                            .and_then(|sdef| sdef.type_parameters.get(i))
+                            .map(|tp| &tp.param.abilities);
+                        constraint_requires_store(required, &arg.value, id)
+                    }
+                };
+                declared_requires_store || type_requires_store(info, &arg.value, id)
+            })
+        }
+        N::Type_::Fun(args, ret) => {
+            args.iter().any(|t| type_requires_store(info, &t.value, id))
+                || type_requires_store(info, &ret.value, id)
+        }
+        _ => false,
+    }
+}
+
+/// Whether `exp` needs `store` on type parameter `id`, through its own type, a call's type
+/// arguments (checked against the callee's constraints), or a pack's type arguments.
+fn exp_requires_store(info: &TypingProgramInfo, exp: &T::Exp, id: N::TParamID) -> bool {
+    if type_requires_store(info, &exp.ty.value, id) {
+        return true;
+    }
+    match &exp.exp.value {
+        T::UnannotatedExp_::ModuleCall(call) => {
+            let callee = info
+                .modules
+                .get(&call.module)
+                .and_then(|minfo| minfo.functions.get(&call.name));
+            call.type_arguments.iter().enumerate().any(|(i, arg)| {
+                let required = callee
+                    .and_then(|finfo| finfo.signature.type_parameters.get(i))
+                    .map(|tp| &tp.abilities);
+                constraint_requires_store(required, &arg.value, id)
+                    || type_requires_store(info, &arg.value, id)
+            })
+        }
+        T::UnannotatedExp_::Pack(mident, name, tys, _) => {
+            let sdef = info.struct_definition_opt(mident, name);
+            tys.iter().enumerate().any(|(i, arg)| {
+                let required = sdef
+                    .and_then(|sdef| sdef.type_parameters.get(i))
+                    .map(|tp| &tp.param.abilities);
+                constraint_requires_store(required, &arg.value, id)
+                    || type_requires_store(info, &arg.value, id)
+            })
+        }
+        // Enum constraints are not looked up; any mention keeps `store`.
+        T::UnannotatedExp_::PackVariant(_, _, _, tys, _) => {
+            tys.iter().any(|t| type_mentions_param(&t.value, id))
+        }
+        T::UnannotatedExp_::Vector(_, _, ty, _)
+        | T::UnannotatedExp_::Cast(_, ty)
+        | T::UnannotatedExp_::Annotate(_, ty) => type_requires_store(info, &ty.value, id),
+        _ => false,
+    }
+}
+
+/// Detects function type parameters constrained with `store` that nothing in the function needs.
+///
+/// `store` is needed when the parameter reaches a callee or struct type parameter that declares
+/// `store`, or sits inside a type that must have `key`. An unneeded `store` constraint only
+/// narrows which types callers may pass.
+pub(crate) fn lint_redundant_ability_constraint(
+    out: &mut Vec<Diagnostic>,
+    settings: &LintSettings,
+    file_map: &MappedFiles,
+    prog: &T::Program,
+    info: &TypingProgramInfo,
+) -> Result<()> {
+    for (_mident, mdef) in prog.modules.key_cloned_iter() {
+        match mdef.target_kind {
+            TargetKind::Source {
+                is_root_package: true,
+            } => {}
+            _ => continue,
+        }
+
+        for (fname, fdef) in mdef.functions.key_cloned_iter() {
+            if fdef.macro_.is_some() {
+                continue;
+            }
+            let T::FunctionBody_::Defined((_use_funs, seq_items)) = &fdef.body.value else {
+                continue;
+            };
+
+            for tparam in &fdef.signature.type_parameters {
+                if !has_store_ability(&tparam.abilities) {
+                    continue;
+                }
+                let id = tparam.id;
+                let in_signature = fdef
+                    .signature
+                    .parameters
+                    .iter()
+                    .any(|(_m, _v, t)| type_requires_store(info, &t.value, id))
+                    || type_requires_store(info, &fdef.signature.return_type.value, id);
+                if in_signature {
+                    continue;
+                }
+
+                let mut in_body = false;
+                for_each_exp_in_seq(seq_items.iter(), &mut |exp| {
+                    in_body = in_body || exp_requires_store(info, exp, id);
+                });
+                if in_body {
+                    continue;
+                }
+
+                let loc = tparam.user_specified_name.loc;
+                let Some((file, span, contents)) = diag_from_loc(file_map, &loc) else {
+                    continue;
+                };
+                let anchor = loc.start() as usize;
+                let fn_name_sym = fname.value();
+                let fn_name = fn_name_sym.as_str();
+                let tparam_name = tparam.user_specified_name.value;
+
+                push_diag(
+                    out,
+                    settings,
+                    &REDUNDANT_ABILITY_CONSTRAINT,
+                    file,
+                    span,
+                    contents.as_ref(),
+                    anchor,
+                    format!(
+                        "Type parameter `{tparam_name}` of `{fn_name}` is constrained with `store`, but nothing in the function needs it. \
+                         Remove `store` from the constraint so callers can pass types without it."
+                    ),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
 // =========================================================================
 // Missing Witness Drop V2 Lint (type-based)
 // =========================================================================
@@ -9,8 +9,9 @@
 //! These lints are only available when `move-clippy` is built with the
 //! `full` feature and run in `--mode full` against a Move package.
 
-use crate::diagnostics::Diagnostic;
+use crate::diagnostics::{Diagnostic, Position, Span};
 use crate::error::{Error, Result as ClippyResult};
+use crate::level::LintLevel;
 use crate::lint::LintSettings;
 use std::path::Path;
 
@@ -18,7 +19,7 @@ mod descriptors;
 pub use descriptors::*;
 
 #[cfg(feature = "full")]
-mod lints;
+pub(crate) mod lints;
 #[cfg(feature = "full")]
 mod util;
 
@@ -29,12 +30,12 @@ mod full {
     use super::*;
     use crate::absint_lints;
     use crate::cross_module_lints;
-    use crate::diagnostics::Span;
     use crate::instrument_block;
-    use crate::level::LintLevel;
+    use crate::instrument_lint;
     use crate::lint::{LintDescriptor, RuleGroup};
     type Result<T> = ClippyResult<T>;
     use move_compiler::command_line::compiler::Visitor;
+    use move_compiler::diagnostics::Diagnostic as CompilerDiagnostic;
     use move_compiler::editions::Flavor;
     use move_compiler::parser::ast::TargetKind;
     use move_compiler::shared::{SaveFlag, SaveHook};
@@ -43,6 +44,16 @@ mod full {
     use move_compiler::typing::ast as T;
     use move_package::BuildConfig;
     use move_package::compilation::build_plan::BuildPlan;
+    use move_package::resolution::resolution_graph::ResolvedGraph;
+    use std::path::PathBuf;
+
+    /// Run one semantic lint pass and propagate its error, timed as `semantic::<pass>`
+    /// under `--timings`.
+    macro_rules! run_lint {
+        ($lint:ident($($arg:expr),* $(,)?)) => {
+            instrument_lint!(concat!("semantic::", stringify!($lint)), { $lint($($arg),*) })?;
+        };
+    }
 
     fn descriptor_for_absint_diag(
         info: &move_compiler::diagnostics::codes::DiagnosticInfo,
@@ -60,7 +71,138 @@ mod full {
         crate::absint_lints::descriptor_for_diag_code(info.code())
     }
 
+    /// A package compiled once, so its lint passes can be re-run without recompiling.
+    ///
+    /// Produced by [`compile_package`] or [`compile_package_with_graph`] and consumed by
+    /// [`lint_package_with_graph`]. The typing AST and program info are the ones captured by
+    /// the compiler's `SaveHook`.
+    pub struct CompiledPackage {
+        pub package_root: PathBuf,
+        pub build_plan: BuildPlan,
+        pub typing_ast: T::Program,
+        pub typing_info: std::sync::Arc<TypingProgramInfo>,
+        pub file_map: MappedFiles,
+        /// Warnings from the Phase II (AbsInt) visitors, converted on each lint run.
+        phase2_warnings: Vec<CompilerDiagnostic>,
+        /// Keeps the per-compile build artifacts alive for the Sui-delegated lint pass.
+        _install_dir: tempfile::TempDir,
+    }
+
+    /// Resolve and compile the package rooted at `package_path`.
+    ///
+    /// `preview` and `experimental` select which Phase II visitors run during compilation,
+    /// so lint runs over the result should use the same (or narrower) tiers.
+    pub fn compile_package(
+        package_path: &Path,
+        preview: bool,
+        experimental: bool,
+    ) -> ClippyResult<CompiledPackage> {
+        let package_root = std::fs::canonicalize(package_path)?;
+        let mut writer = Vec::<u8>::new();
+        let install_dir = tempfile::tempdir()?;
+        let resolved_graph = build_config(&install_dir).resolution_graph_for_package(
+            &package_root,
+            None,
+            &mut writer,
+        )?;
+        compile_resolved(
+            &resolved_graph,
+            package_root,
+            install_dir,
+            preview,
+            experimental,
+        )
+    }
+
+    /// Compile an already-resolved package graph, skipping dependency resolution.
+    pub fn compile_package_with_graph(
+        resolved_graph: &ResolvedGraph,
+        package_root: &Path,
+        preview: bool,
+        experimental: bool,
+    ) -> ClippyResult<CompiledPackage> {
+        let package_root = std::fs::canonicalize(package_root)?;
+        compile_resolved(
+            resolved_graph,
+            package_root,
+            tempfile::tempdir()?,
+            preview,
+            experimental,
+        )
+    }
+
+    fn build_config(install_dir: &tempfile::TempDir) -> BuildConfig {
+        let mut build_config = BuildConfig::default();
+        build_config.default_flavor = Some(Flavor::Sui);
+        // Isolate build artifacts per invocation so tests (and parallel runs) don't race by
+        // writing into the fixture/package directory.
+        build_config.install_dir = Some(install_dir.path().to_path_buf());
+        build_config
+    }
+
+    fn compile_resolved(
+        resolved_graph: &ResolvedGraph,
+        package_root: PathBuf,
+        install_dir: tempfile::TempDir,
+        preview: bool,
+        experimental: bool,
+    ) -> ClippyResult<CompiledPackage> {
+        let mut writer = Vec::<u8>::new();
+        let build_plan = BuildPlan::create(resolved_graph)?;
+
+        let hook = SaveHook::new([SaveFlag::Typing, SaveFlag::TypingInfo]);
+
+        // Get Phase II visitors (SimpleAbsInt-based lints)
+        let phase2_visitors: Vec<Visitor> = absint_lints::create_visitors(preview, experimental)
+            .into_iter()
+            .map(Visitor::AbsIntVisitor)
+            .collect();
+
+        // IMPORTANT: avoid `compile_no_exit` here; it prints compiler diagnostics to stdout,
+        // which corrupts `--format json` output for ecosystem validation. Instead, capture
+        // warnings and convert them into JSON diagnostics.
+        let collected_phase2 = std::cell::RefCell::new(Vec::new());
+        let deps = build_plan.compute_dependencies();
+        let compiled = build_plan.compile_with_driver_and_deps(deps, &mut writer, |compiler| {
+            use move_compiler::diagnostics::report_diagnostics_to_buffer_with_env_color;
+
+            let (attr, filters) = linters::known_filters();
+            let compiler = compiler
+                .add_save_hook(&hook)
+                .add_custom_known_filters(attr, filters)
+                .add_visitors(phase2_visitors);
+
+            let (files, res) = compiler.build()?;
+            match res {
+                Ok((units, warnings)) => {
+                    collected_phase2.borrow_mut().extend(warnings.into_vec());
+                    Ok((files, units))
+                }
+                Err(errors) => {
+                    let rendered = report_diagnostics_to_buffer_with_env_color(&files, errors);
+                    Err(Error::semantic(format!(
+                        "Move compilation failed while running Phase II visitors:\n{}",
+                        String::from_utf8_lossy(&rendered)
+                    ))
+                    .into())
+                }
+            }
+        })?;
+
+        Ok(CompiledPackage {
+            package_root,
+            typing_ast: hook.take_typing_ast(),
+            typing_info: hook.take_typing_info(),
+            file_map: compiled.file_map.clone(),
+            build_plan,
+            phase2_warnings: collected_phase2.into_inner(),
+            _install_dir: install_dir,
+        })
+    }
+
     /// Run all semantic lints against the package rooted at `package_path`.
+    ///
+    /// Compiles the package, then runs [`lint_package_with_graph`] over the result.
     pub fn lint_package(
         package_path: &Path,
         settings: &LintSettings,
@@ -68,156 +210,195 @@ mod full {
         experimental: bool,
     ) -> ClippyResult<Vec<Diagnostic>> {
         instrument_block!("semantic::lint_package", {
-            let package_root = std::fs::canonicalize(package_path)?;
-            let mut writer = Vec::<u8>::new();
-            let mut build_config = BuildConfig::default();
-            build_config.default_flavor = Some(Flavor::Sui);
-            // Isolate build artifacts per invocation so tests (and parallel runs) don't race by
-            // writing into the fixture/package directory.
-            let install_dir = tempfile::tempdir()?;
-            build_config.install_dir = Some(install_dir.path().to_path_buf());
-            let resolved_graph =
-                build_config.resolution_graph_for_package(&package_root, None, &mut writer)?;
-            let build_plan = BuildPlan::create(&resolved_graph)?;
-
-            let hook = SaveHook::new([SaveFlag::Typing, SaveFlag::TypingInfo]);
-
-            // Get Phase II visitors (SimpleAbsInt-based lints)
-            let phase2_visitors: Vec<Visitor> =
-                absint_lints::create_visitors(preview, experimental)
-                    .into_iter()
-                    .map(Visitor::AbsIntVisitor)
-                    .collect();
-
-            // IMPORTANT: avoid `compile_no_exit` here; it prints compiler diagnostics to stdout,
-            // which corrupts `--format json` output for ecosystem validation. Instead, capture
-            // warnings and convert them into JSON diagnostics.
-            let collected_phase2 = std::cell::RefCell::new(Vec::new());
-            let deps = build_plan.compute_dependencies();
-            let compiled =
-                build_plan.compile_with_driver_and_deps(deps, &mut writer, |compiler| {
-                    use move_compiler::diagnostics::report_diagnostics_to_buffer_with_env_color;
-
-                    let (attr, filters) = linters::known_filters();
-                    let compiler = compiler
-                        .add_save_hook(&hook)
-                        .add_custom_known_filters(attr, filters)
-                        .add_visitors(phase2_visitors);
-
-                    let (files, res) = compiler.build()?;
-                    match res {
-                        Ok((units, warnings)) => {
-                            collected_phase2
-                                .borrow_mut()
-                                .push((files.clone(), warnings));
-                            Ok((files, units))
-                        }
-                        Err(errors) => {
-                            let rendered =
-                                report_diagnostics_to_buffer_with_env_color(&files, errors);
-                            Err(Error::semantic(format!(
-                                "Move compilation failed while running Phase II visitors:\n{}",
-                                String::from_utf8_lossy(&rendered)
-                            ))
-                            .into())
-                        }
-                    }
-                })?;
-
-            let typing_ast: T::Program = hook.take_typing_ast();
-            let typing_info: std::sync::Arc<TypingProgramInfo> = hook.take_typing_info();
-            let file_map: MappedFiles = compiled.file_map.clone();
-
-            let mut out = Vec::new();
-
-            // Phase II: convert AbsInt visitor diagnostics into our JSON diagnostics.
-            for (_files, warnings) in collected_phase2.into_inner() {
-                for compiler_diag in warnings.into_vec() {
-                    let Some(descriptor) = descriptor_for_absint_diag(compiler_diag.info()) else {
-                        continue;
-                    };
-                    if let Some(diag) =
-                        convert_compiler_diagnostic(&compiler_diag, settings, &file_map, descriptor)
-                    {
-                        out.push(diag);
-                    }
-                }
+            let package = compile_package(package_path, preview, experimental)?;
+            lint_package_with_graph(&package, settings, preview, experimental)
+        })
+    }
+
+    /// Run all semantic lints against an already-compiled package.
+    ///
+    /// Only the lint passes run; the package is not resolved or recompiled, except that the
+    /// Sui-delegated lints re-drive the compiler from the saved build plan.
+    pub fn lint_package_with_graph(
+        package: &CompiledPackage,
+        settings: &LintSettings,
+        preview: bool,
+        experimental: bool,
+    ) -> ClippyResult<Vec<Diagnostic>> {
+        let mut out = Vec::new();
+
+        // Phase II: convert AbsInt visitor diagnostics into our JSON diagnostics.
+        for compiler_diag in &package.phase2_warnings {
+            let Some(descriptor) = descriptor_for_absint_diag(compiler_diag.info()) else {
+                continue;
+            };
+            if let Some(diag) =
+                convert_compiler_diagnostic(compiler_diag, settings, &package.file_map, descriptor)
+            {
+                out.push(diag);
             }
+        }
 
-            // Type-based naming lints
-            // Type-based security lints
-            lint_entry_function_returns_value(&mut out, settings, &file_map, &typing_ast)?;
-            lint_private_entry_function(&mut out, settings, &file_map, &typing_ast)?;
-            lint_event_emit_type_sanity(&mut out, settings, &file_map, &typing_ast)?;
-            lint_event_past_tense(&mut out, settings, &file_map, &typing_ast)?;
-            lint_copyable_capability(&mut out, settings, &file_map, &typing_info)?;
-            lint_droppable_capability(&mut out, settings, &file_map, &typing_info)?;
-            // lint_capability_antipatterns removed - deprecated, superseded by copyable/droppable_capability
-            lint_non_transferable_fungible_object(&mut out, settings, &file_map, &typing_info)?;
-            lint_public_random_access_v2(&mut out, settings, &file_map, &typing_ast)?;
-            lint_missing_witness_drop_v2(&mut out, settings, &file_map, &typing_info)?;
-            // lint_invalid_otw removed - duplicates Sui Verifier's one_time_witness_verifier.rs
-            lint_witness_antipatterns(&mut out, settings, &file_map, &typing_info, &typing_ast)?;
-            // lint_stale_oracle_price_v2 removed - deprecated, use v3 in absint_lints
-            // Phase 4 security lints (type-based, preview)
-            if preview {
-                lint_shared_capability_object(&mut out, settings, &file_map, &typing_ast)?;
-                lint_capability_transfer_literal_address(
-                    &mut out,
-                    settings,
-                    &file_map,
-                    &typing_ast,
-                )?;
-                lint_mut_key_param_missing_authority(&mut out, settings, &file_map, &typing_ast)?;
-                lint_unbounded_iteration_over_param_vector(
+        out.extend(run_semantic_lints(
+            &package.typing_ast,
+            &package.typing_info,
+            &package.file_map,
+            settings,
+            preview,
+            experimental,
+        )?);
+
+        // Sui-delegated lints (type-based, production)
+        run_lint! {
+            lint_sui_visitors(&mut out, settings, &package.build_plan, &package.package_root)
+        }
+
+        // Filter Preview-group diagnostics when preview is disabled
+        if !preview {
+            out.retain(|d| d.lint.group != RuleGroup::Preview);
+        }
+
+        // Filter Experimental-group diagnostics when experimental is disabled
+        if !experimental {
+            out.retain(|d| d.lint.group != RuleGroup::Experimental);
+        }
+
+        append_unfulfilled_expectations(&mut out, &package.typing_ast, &package.file_map);
+
+        Ok(out)
+    }
+
+    /// Run the type-based lint passes over a typed program.
+    ///
+    /// This is the per-lint dispatch shared by every entry point; it does not include the
+    /// Phase II visitor output or the Sui-delegated lints, which need the compiler.
+    pub fn run_semantic_lints(
+        typing_ast: &T::Program,
+        typing_info: &TypingProgramInfo,
+        file_map: &MappedFiles,
+        settings: &LintSettings,
+        preview: bool,
+        experimental: bool,
+    ) -> ClippyResult<Vec<Diagnostic>> {
+        let mut out = Vec::new();
+
+        // Type-based naming lints
+        // Type-based security lints
+        run_lint! { lint_entry_function_returns_value(&mut out, settings, file_map, typing_ast) }
+        run_lint! { lint_private_entry_function(&mut out, settings, file_map, typing_ast) }
+        run_lint! { lint_entry_function_param_count(&mut out, settings, file_map, typing_ast) }
+        run_lint! { lint_event_emit_type_sanity(&mut out, settings, file_map, typing_ast) }
+        run_lint! { lint_event_past_tense(&mut out, settings, file_map, typing_ast) }
+        run_lint! { lint_copyable_capability(&mut out, settings, file_map, typing_info) }
+        run_lint! { lint_droppable_capability(&mut out, settings, file_map, typing_info) }
+        // lint_capability_antipatterns removed - deprecated, superseded by copyable/droppable_capability
+        run_lint! {
+            lint_non_transferable_fungible_object(&mut out, settings, file_map, typing_info)
+        }
+        run_lint! { lint_public_random_access_v2(&mut out, settings, file_map, typing_ast) }
+        run_lint! { lint_missing_witness_drop_v2(&mut out, settings, file_map, typing_info) }
+        // lint_invalid_otw removed - duplicates Sui Verifier's one_time_witness_verifier.rs
+        run_lint! {
+            lint_witness_antipatterns(&mut out, settings, file_map, typing_info, typing_ast)
+        }
+        // lint_stale_oracle_price_v2 removed - deprecated, use stale_oracle_price in absint_lints
+        // Phase 4 security lints (type-based, preview)
+        if preview {
+            run_lint! { lint_shared_capability_object(&mut out, settings, file_map, typing_ast) }
+            run_lint! {
+                lint_capability_transfer_literal_address(&mut out, settings, file_map, typing_ast)
+            }
+            run_lint! {
+                lint_mut_key_param_missing_authority(&mut out, settings, file_map, typing_ast)
+            }
+            run_lint! {
+                lint_unbounded_iteration_over_param_vector(&mut out, settings, file_map, typing_ast)
+            }
+            run_lint! { lint_exact_length_assert(&mut out, settings, file_map, typing_ast) }
+            run_lint! {
+                lint_duplicate_dynamic_field_add(&mut out, settings, file_map, typing_ast)
+            }
+            run_lint! {
+                lint_unused_function_type_parameter(&mut out, settings, file_map, typing_ast)
+            }
+            run_lint! {
+                lint_redundant_ability_constraint(
                     &mut out,
                     settings,
-                    &file_map,
-                    &typing_ast,
-                )?;
+                    file_map,
+                    typing_ast,
+                    typing_info,
+                )
             }
-            // Phase 4 security lints (type-based, experimental)
-            if experimental {
-                // lint_unchecked_division removed - experimental, obvious lint
-                lint_unused_return_value(&mut out, settings, &file_map, &typing_ast)?;
-                lint_share_owned_authority(&mut out, settings, &file_map, &typing_ast)?;
-                lint_droppable_hot_potato_v2(&mut out, settings, &file_map, &typing_info)?;
-                lint_droppable_flash_loan_receipt(&mut out, settings, &file_map, &typing_ast)?;
-                lint_receipt_missing_phantom_type(&mut out, settings, &file_map, &typing_ast)?;
-                lint_copyable_fungible_type(
+            run_lint! { lint_transfer_of_non_object(&mut out, settings, file_map, typing_ast) }
+            run_lint! {
+                lint_mut_param_only_read_for_return(&mut out, settings, file_map, typing_ast)
+            }
+            run_lint! {
+                lint_destroy_function_does_not_consume(&mut out, settings, file_map, typing_ast)
+            }
+            run_lint! { lint_public_returns_mut_ref(&mut out, settings, file_map, typing_ast) }
+            run_lint! { lint_loop_invariant_assert(&mut out, settings, file_map, typing_ast) }
+            run_lint! { lint_loop_never_progresses(&mut out, settings, file_map, typing_ast) }
+            run_lint! { lint_txcontext_not_last_param(&mut out, settings, file_map, typing_ast) }
+            run_lint! { lint_unnecessary_mut_tx_context(&mut out, settings, file_map, typing_ast) }
+            run_lint! { lint_unnecessary_mut_param(&mut out, settings, file_map, typing_ast) }
+            run_lint! { lint_exposes_uid(&mut out, settings, file_map, typing_ast) }
+            run_lint! { lint_sui_signer_param(&mut out, settings, file_map, typing_ast) }
+            run_lint! { lint_unused_clock_param(&mut out, settings, file_map, typing_ast) }
+            run_lint! {
+                lint_event_struct_missing_abilities(
                     &mut out,
                     settings,
-                    &file_map,
-                    &typing_ast,
-                    &typing_info,
-                )?;
-                lint_capability_transfer_v2(&mut out, settings, &file_map, &typing_ast)?;
-                lint_generic_type_witness_unused(&mut out, settings, &file_map, &typing_ast)?;
+                    file_map,
+                    typing_ast,
+                    typing_info,
+                )
             }
-            // Note: phantom_capability is implemented in absint_lints.rs (CFG-aware)
-
-            // Phase III: Cross-module analysis lints (type-based)
-            if experimental {
-                lint_cross_module_lints(&mut out, settings, &file_map, &typing_ast, &typing_info)?;
+            run_lint! {
+                lint_wrong_transfer_variant(&mut out, settings, file_map, typing_ast, typing_info)
             }
-
-            // Sui-delegated lints (type-based, production)
-            lint_sui_visitors(&mut out, settings, &build_plan, &package_root)?;
-
-            // Filter Preview-group diagnostics when preview is disabled
-            if !preview {
-                out.retain(|d| d.lint.group != RuleGroup::Preview);
+            run_lint! { lint_ambiguous_abort_code(&mut out, settings, file_map, typing_ast) }
+        }
+        // Phase 4 security lints (type-based, experimental)
+        if experimental {
+            // lint_unchecked_division removed - experimental, obvious lint
+            run_lint! { lint_unused_return_value(&mut out, settings, file_map, typing_ast) }
+            run_lint! { lint_share_owned_authority(&mut out, settings, file_map, typing_ast) }
+            run_lint! { lint_droppable_hot_potato_v2(&mut out, settings, file_map, typing_info) }
+            run_lint! {
+                lint_droppable_flash_loan_receipt(&mut out, settings, file_map, typing_ast)
             }
-
-            // Filter Experimental-group diagnostics when experimental is disabled
-            if !experimental {
-                out.retain(|d| d.lint.group != RuleGroup::Experimental);
+            run_lint! {
+                lint_receipt_missing_phantom_type(&mut out, settings, file_map, typing_ast)
+            }
+            run_lint! {
+                lint_copyable_fungible_type(&mut out, settings, file_map, typing_ast, typing_info)
+            }
+            run_lint! { lint_prefer_balance_field(&mut out, settings, file_map, typing_ast) }
+            run_lint! { lint_suspicious_coin_split(&mut out, settings, file_map, typing_ast) }
+            run_lint! {
+                lint_freeze_locks_value(&mut out, settings, file_map, typing_ast, typing_info)
             }
+            run_lint! { lint_capability_transfer_v2(&mut out, settings, file_map, typing_ast) }
+            run_lint! { lint_generic_type_witness_unused(&mut out, settings, file_map, typing_ast) }
+            run_lint! { lint_capability_passthrough(&mut out, settings, file_map, typing_ast) }
+            run_lint! { lint_index_may_exceed_length(&mut out, settings, file_map, typing_ast) }
+            run_lint! { lint_loop_counter_overflow_risk(&mut out, settings, file_map, typing_ast) }
+            run_lint! {
+                lint_copy_struct_with_object_field(&mut out, settings, file_map, typing_info)
+            }
+        }
+        // Note: phantom_capability is implemented in absint_lints.rs (CFG-aware)
 
-            append_unfulfilled_expectations(&mut out, &typing_ast, &file_map);
+        // Phase III: Cross-module analysis lints (type-based)
+        if experimental {
+            run_lint! {
+                lint_cross_module_lints(&mut out, settings, file_map, typing_ast, typing_info)
+            }
+        }
 
-            Ok(out)
-        })
+        Ok(out)
     }
 
     /// Run cross-module analysis lints (Phase III)
@@ -254,6 +435,32 @@ mod full {
             }
         }
 
+        // Run shared-object capability storage detection
+        let shared_cap_diags = cross_module_lints::lint_store_in_shared_cap(prog, info);
+        for compiler_diag in shared_cap_diags {
+            if let Some(diag) = convert_compiler_diagnostic(
+                &compiler_diag,
+                settings,
+                file_map,
+                &cross_module_lints::STORE_IN_SHARED_CAP,
+            ) {
+                out.push(diag);
+            }
+        }
+
+        // Run unused public(package) function detection
+        let unused_fn_diags = cross_module_lints::lint_unused_package_function(prog, info);
+        for compiler_diag in unused_fn_diags {
+            if let Some(diag) = convert_compiler_diagnostic(
+                &compiler_diag,
+                settings,
+                file_map,
+                &cross_module_lints::UNUSED_PACKAGE_FUNCTION,
+            ) {
+                out.push(diag);
+            }
+        }
+
         // NOTE: lint_price_manipulation_window removed - used name-based heuristics
 
         Ok(())
@@ -266,19 +473,23 @@ mod full {
     ) {
         use std::collections::{BTreeMap, BTreeSet};
 
-        let mut fired: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        // Lint names and categories that fired per file, with where they fired.
+        let mut fired: BTreeMap<String, Vec<(&'static str, &'static str, Span)>> = BTreeMap::new();
         for d in out.iter() {
             let Some(file) = d.file.as_deref() else {
                 continue;
             };
-            let entry = fired.entry(file.to_string()).or_default();
-            entry.insert(d.lint.name.to_string());
-            entry.insert(d.lint.category.as_str().to_string());
+            fired.entry(file.to_string()).or_default().push((
+                d.lint.name,
+                d.lint.category.as_str(),
+                d.span,
+            ));
         }
 
         let mut module_expected: BTreeMap<String, (std::sync::Arc<str>, BTreeSet<String>)> =
             BTreeMap::new();
-        let mut item_expected: BTreeMap<String, BTreeMap<usize, BTreeSet<String>>> =
+        // Per file: function start (the directive anchor) -> (function end, expected names).
+        let mut item_expected: BTreeMap<String, BTreeMap<usize, (usize, BTreeSet<String>)>> =
             BTreeMap::new();
 
         for (_mident, mdef) in prog.modules.key_cloned_iter() {
@@ -319,16 +530,19 @@ mod full {
                     .entry(file.clone())
                     .or_default()
                     .entry(anchor)
-                    .or_default()
+                    .or_insert_with(|| (fdef.loc.end() as usize, BTreeSet::new()))
+                    .1
                     .extend(expected);
             }
         }
 
         // Module-level unfulfilled expectations: require any matching lint or category in file.
         for (file, (contents, expected)) in module_expected {
-            let fired_set = fired.get(&file);
+            let fired_in_file = fired.get(&file).map(Vec::as_slice).unwrap_or_default();
             for name in expected {
-                let fired_any = fired_set.is_some_and(|s| s.contains(&name));
+                let fired_any = fired_in_file
+                    .iter()
+                    .any(|&(lint, category, _)| lint == name || category == name);
                 if fired_any {
                     continue;
                 }
@@ -349,16 +563,25 @@ mod full {
                         "Remove the `#![expect(...)]` directive or adjust the code/lint so it triggers."
                             .to_string(),
                     ),
-                    suggestion: None,
+                    suggestions: Vec::new(),
+                    notes: Vec::new(),
+                    labels: Vec::new(),
                 });
             }
 
-            // Item-level unfulfilled expectations: approximate by file-level fired set.
+            // Item-level unfulfilled expectations: require a matching diagnostic that starts
+            // inside the function, so a lint firing elsewhere in the file does not count.
             if let Some(anchors) = item_expected.get(&file) {
-                let fired_set = fired.get(&file);
-                for (&anchor, names) in anchors {
+                for (&anchor, (end, names)) in anchors {
+                    let scope = anchor..*end;
                     for name in names {
-                        let fired_any = fired_set.is_some_and(|s| s.contains(name));
+                        let fired_any = fired_in_file.iter().any(|&(lint, category, span)| {
+                            (lint == name || category == name)
+                                && span
+                                    .start
+                                    .to_byte_offset(contents.as_ref())
+                                    .is_some_and(|offset| scope.contains(&offset))
+                        });
                         if fired_any {
                             continue;
                         }
@@ -377,7 +600,9 @@ mod full {
                                 "Remove the `#[expect(...)]` directive or adjust the code/lint so it triggers."
                                     .to_string(),
                             ),
-                            suggestion: None,
+                            suggestions: Vec::new(),
+                            notes: Vec::new(),
+                            labels: Vec::new(),
                         });
                     }
                 }
@@ -387,7 +612,28 @@ mod full {
 }
 
 #[cfg(feature = "full")]
-pub use full::lint_package;
+pub use full::{
+    CompiledPackage, compile_package, compile_package_with_graph, lint_package,
+    lint_package_with_graph, run_semantic_lints,
+};
+
+/// The note reported instead of semantic diagnostics when linting `package_path` fails.
+///
+/// The note sits at the package root and has [`LintLevel::Note`], so it never fails a run.
+/// Its message keeps the full error, including the rendered compiler errors.
+pub fn compile_failure_note(package_path: &Path, err: &Error) -> Diagnostic {
+    Diagnostic {
+        lint: &crate::lint::PACKAGE_COMPILE_FAILED,
+        level: LintLevel::Note,
+        file: Some(package_path.display().to_string()),
+        span: Span::point(Position { row: 1, column: 1 }),
+        message: format!("skipped semantic lints: {err}"),
+        help: None,
+        suggestions: Vec::new(),
+        notes: Vec::new(),
+        labels: Vec::new(),
+    }
+}
 
 #[cfg(not(feature = "full"))]
 pub fn lint_package(
@@ -22,6 +22,30 @@ pub(super) fn convert_compiler_diagnostic(
     // Convert location to our span format
     let (file, span, contents) = diag_from_loc(file_map, &primary_loc)?;
 
+    // Secondary labels elsewhere in the primary file become labels. Labels on the primary
+    // location itself (used for help text) and labels in other files become notes.
+    let (_severity, _info_msg, _primary, secondary, compiler_notes) =
+        compiler_diag.clone().into_codespan_format();
+    let mut labels = Vec::new();
+    let mut notes = Vec::new();
+    for (loc, msg) in secondary {
+        if loc == primary_loc {
+            notes.push(msg);
+            continue;
+        }
+        match diag_from_loc(file_map, &loc) {
+            Some((label_file, label_span, _)) if label_file == file => {
+                labels.push((label_span, msg));
+            }
+            Some((label_file, label_span, _)) => notes.push(format!(
+                "{label_file}:{}:{}: {msg}",
+                label_span.start.row, label_span.start.column
+            )),
+            None => notes.push(msg),
+        }
+    }
+    notes.extend(compiler_notes);
+
     Some(Diagnostic {
         lint: descriptor,
         level: LintLevel::Warn,
@@ -29,7 +53,9 @@ pub(super) fn convert_compiler_diagnostic(
         span,
         message: primary_msg.to_string(),
         help: None,
-        suggestion: None,
+        suggestions: Vec::new(),
+        notes,
+        labels,
     })
 }
 
@@ -79,7 +105,9 @@ pub(super) fn push_diag(
         span,
         message,
         help: None,
-        suggestion: None,
+        suggestions: Vec::new(),
+        notes: Vec::new(),
+        labels: Vec::new(),
     });
 }
 
@@ -38,10 +38,128 @@ pub fn anchor_item_start_byte(node: Node) -> usize {
     node.start_byte()
 }
 
+/// A `// move-clippy:allow(..)` or `// move-clippy:expect(..)` line comment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentDirective {
+    Allow,
+    Expect,
+}
+
+/// Parse one line comment as a directive, returning it with the text between its parentheses.
+///
+/// Accepts `// move-clippy:allow(a, b)` with optional whitespace around the colon.
+fn parse_comment_directive_args(comment: &str) -> Option<(CommentDirective, &str)> {
+    let body = comment.trim_start_matches('/').trim();
+    let rest = body.strip_prefix("move-clippy")?.trim_start();
+    let rest = rest.strip_prefix(':')?.trim_start();
+
+    let (directive, args) = if let Some(args) = rest.strip_prefix("allow") {
+        (CommentDirective::Allow, args)
+    } else if let Some(args) = rest.strip_prefix("expect") {
+        (CommentDirective::Expect, args)
+    } else {
+        return None;
+    };
+    let args = args.trim_start().strip_prefix('(')?;
+    Some((directive, &args[..args.find(')')?]))
+}
+
+/// Lint names listed in a directive's arguments, without any `lint::` prefix.
+fn directive_lint_names(args: &str) -> impl Iterator<Item = &str> {
+    args.split(',')
+        .map(|name| name.trim())
+        .map(|name| name.strip_prefix("lint::").unwrap_or(name))
+}
+
+/// Find the directive on `line`, with its offset in `line`.
+///
+/// Every `//` on the line is tried, so a `//` inside a string literal before the
+/// comment does not hide the directive.
+fn directive_in_line(line: &str) -> Option<(CommentDirective, &str, usize)> {
+    line.match_indices("//").find_map(|(pos, _)| {
+        parse_comment_directive_args(&line[pos..]).map(|(directive, args)| (directive, args, pos))
+    })
+}
+
+/// Find a comment directive for `lint_name` around the code at `offset`.
+///
+/// Looks at a trailing `//` comment on the line containing `offset`, then at the run of
+/// comment and attribute lines directly above it.
+pub fn comment_directive_at(
+    source: &str,
+    offset: usize,
+    lint_name: &str,
+) -> Option<CommentDirective> {
+    find_comment_directive(source, offset, lint_name).map(|(directive, _)| directive)
+}
+
+/// Like [`comment_directive_at`], also returning the byte offset of the directive's `//`.
+pub fn find_comment_directive(
+    source: &str,
+    offset: usize,
+    lint_name: &str,
+) -> Option<(CommentDirective, usize)> {
+    let naming = |line: &str| {
+        directive_in_line(line)
+            .filter(|(_, args, _)| directive_lint_names(args).any(|name| name == lint_name))
+            .map(|(directive, _, pos)| (directive, pos))
+    };
+
+    let offset = offset.min(source.len());
+    let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = source[offset..]
+        .find('\n')
+        .map_or(source.len(), |i| offset + i);
+
+    if let Some((directive, pos)) = naming(&source[line_start..line_end]) {
+        return Some((directive, line_start + pos));
+    }
+
+    let mut end = line_start;
+    while end > 0 {
+        let start = source[..end - 1].rfind('\n').map_or(0, |i| i + 1);
+        let line = &source[start..end - 1];
+        end = start;
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("//") {
+            if let Some((directive, pos)) = naming(line) {
+                return Some((directive, start + pos));
+            }
+        } else if !trimmed.starts_with("#[") {
+            break;
+        }
+    }
+    None
+}
+
+/// Every `// move-clippy:expect(..)` comment in `source`: the byte offset of its `//` and
+/// the lint names it lists.
+pub fn comment_expectations(source: &str) -> Vec<(usize, Vec<String>)> {
+    let mut out = Vec::new();
+    let mut start = 0;
+    for line in source.split_inclusive('\n') {
+        if let Some((CommentDirective::Expect, args, pos)) = directive_in_line(line) {
+            out.push((
+                start + pos,
+                directive_lint_names(args)
+                    .filter(|name| !name.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            ));
+        }
+        start += line.len();
+    }
+    out
+}
+
 /// Check whether the item starting at `item_start_byte` is suppressed for `lint_name`.
 ///
-/// This checks the attribute/doc block *immediately preceding* the item.
+/// This checks the attribute/doc block *immediately preceding* the item, and a
+/// `// move-clippy:allow(..)` comment on or directly above its first line.
 pub fn is_suppressed_at(source: &str, item_start_byte: usize, lint_name: &str) -> bool {
+    if let Some(directive) = comment_directive_at(source, item_start_byte, lint_name) {
+        return directive == CommentDirective::Allow;
+    }
     let scope = annotations::item_scope(source, item_start_byte);
     if scope.is_denied(lint_name) || scope.is_expected(lint_name) {
         return false;
@@ -63,10 +181,14 @@ pub fn is_module_level_suppressed(source: &str, lint_name: &str) -> bool {
 
 /// Check whether `node` is suppressed for `lint_name`.
 ///
-/// This applies both:
+/// This applies:
+/// - a `// move-clippy:allow(...)` comment on or directly above the node's line,
 /// - item-level `#[allow(lint::...)]` anchored at the enclosing item, and
 /// - module/file-level `#![allow(lint::...)]` at the file header.
 pub fn is_suppressed(node: Node, source: &str, lint_name: &str) -> bool {
+    if let Some(directive) = comment_directive_at(source, node.start_byte(), lint_name) {
+        return directive == CommentDirective::Allow;
+    }
     let anchor = anchor_item_start_byte(node);
     is_suppressed_at(source, anchor, lint_name) || is_module_level_suppressed(source, lint_name)
 }
@@ -1,3 +1,8 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
 #[cfg(feature = "telemetry")]
 use std::sync::OnceLock;
 
@@ -34,3 +39,132 @@ macro_rules! instrument_block {
         }
     }};
 }
+
+/// Time an inline block as lint `$name` for `--timings`, inside an [`instrument_block!`] span.
+#[macro_export]
+macro_rules! instrument_lint {
+    ($name:expr, $block:block) => {{ $crate::telemetry::time_lint($name, || $crate::instrument_block!($name, $block)) }};
+}
+
+static TIMINGS_ENABLED: AtomicBool = AtomicBool::new(false);
+static TIMINGS: Mutex<BTreeMap<String, LintTiming>> = Mutex::new(BTreeMap::new());
+
+/// Accumulated wall time of one lint across a run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LintTiming {
+    pub total: Duration,
+    pub calls: u32,
+}
+
+impl LintTiming {
+    /// Mean wall time per call.
+    #[must_use]
+    pub fn average(&self) -> Duration {
+        self.total.checked_div(self.calls).unwrap_or_default()
+    }
+}
+
+/// Start recording per-lint timings (`--timings`). Off by default so normal runs skip the
+/// clock reads and the shared map.
+pub fn enable_timings() {
+    TIMINGS_ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Whether [`enable_timings`] has been called.
+#[must_use]
+pub fn timings_enabled() -> bool {
+    TIMINGS_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Run `f`, adding its wall time to lint `name` when timings are enabled.
+pub fn time_lint<R>(name: &str, f: impl FnOnce() -> R) -> R {
+    if !timings_enabled() {
+        return f();
+    }
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+
+    let mut timings = TIMINGS.lock().unwrap_or_else(PoisonError::into_inner);
+    let timing = timings.entry(name.to_string()).or_default();
+    timing.total += elapsed;
+    timing.calls += 1;
+    result
+}
+
+/// Timings recorded so far, slowest first.
+#[must_use]
+pub fn timings() -> Vec<(String, LintTiming)> {
+    let timings = TIMINGS.lock().unwrap_or_else(PoisonError::into_inner);
+    let mut sorted: Vec<_> = timings
+        .iter()
+        .map(|(name, timing)| (name.clone(), *timing))
+        .collect();
+    sorted.sort_by(|a, b| b.1.total.cmp(&a.1.total).then_with(|| a.0.cmp(&b.0)));
+    sorted
+}
+
+/// Render `timings` as the `--timings` table: lint, total ms, calls, average ms.
+#[must_use]
+pub fn render_timings(timings: &[(String, LintTiming)]) -> String {
+    let width = timings
+        .iter()
+        .map(|(name, _)| name.len())
+        .chain(std::iter::once("lint".len()))
+        .max()
+        .unwrap_or_default();
+    let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+
+    let mut out = format!(
+        "{:<width$}  {:>10}  {:>7}  {:>9}\n",
+        "lint", "total ms", "calls", "avg ms"
+    );
+    for (name, timing) in timings {
+        out.push_str(&format!(
+            "{:<width$}  {:>10.3}  {:>7}  {:>9.3}\n",
+            name,
+            ms(timing.total),
+            timing.calls,
+            ms(timing.average())
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timing_map_populated_after_run() {
+        enable_timings();
+        let engine = crate::LintEngine::builder().build().unwrap();
+        let _ = engine
+            .lint_source("module my_pkg::m {\n    public struct A has copy, drop {}\n}\n")
+            .unwrap();
+
+        let timings = timings();
+        let (_, modern_module) = timings
+            .iter()
+            .find(|(name, _)| name == "modern_module_syntax")
+            .expect("every rule is timed");
+        assert!(modern_module.calls >= 1);
+        assert!(render_timings(&timings).contains("modern_module_syntax"));
+    }
+
+    #[test]
+    fn test_render_timings_table() {
+        let timings = vec![(
+            "abilities_order".to_string(),
+            LintTiming {
+                total: Duration::from_millis(3),
+                calls: 2,
+            },
+        )];
+        assert_eq!(
+            render_timings(&timings),
+            "lint               total ms    calls     avg ms\n\
+             abilities_order       3.000        2      1.500\n"
+        );
+    }
+}
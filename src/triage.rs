@@ -21,6 +21,12 @@
 //!
 //! # Generate a report
 //! move-clippy triage report --format md
+//!
+//! # Compare two lint runs (e.g. before and after a PR)
+//! move-clippy triage diff --before main.json --after pr.json --fail-on-new
+//!
+//! # Merge triage work from several reviewers
+//! move-clippy triage merge --into master.json alice.json bob.json
 //! ```
 
 use chrono::{DateTime, Utc};
@@ -31,6 +37,8 @@ use std::fs;
 use std::path::Path;
 use thiserror::Error;
 
+pub use crate::diagnostics::format_snippet_with_lines;
+
 // ============================================================================
 // Error Types
 // ============================================================================
@@ -520,6 +528,264 @@ impl TriageDatabase {
         }
         groups
     }
+
+    /// Compare this database against another one.
+    ///
+    /// Findings are matched by their stable ID. The result lists findings that
+    /// only exist on one side and findings whose triage status disagrees.
+    pub fn diff<'a>(&'a self, other: &'a TriageDatabase) -> TriageDiff<'a> {
+        let mut diff = TriageDiff::default();
+
+        for (id, finding) in &self.findings {
+            match other.findings.get(id) {
+                None => diff.only_in_self.push(finding),
+                Some(theirs) if theirs.status != finding.status => {
+                    diff.conflicts.push((finding, theirs))
+                }
+                Some(_) => diff.matching += 1,
+            }
+        }
+
+        for (id, finding) in &other.findings {
+            if !self.findings.contains_key(id) {
+                diff.only_in_other.push(finding);
+            }
+        }
+
+        diff.only_in_self
+            .sort_by(|a, b| finding_sort_key(a).cmp(&finding_sort_key(b)));
+        diff.only_in_other
+            .sort_by(|a, b| finding_sort_key(a).cmp(&finding_sort_key(b)));
+        diff.conflicts
+            .sort_by(|a, b| finding_sort_key(a.0).cmp(&finding_sort_key(b.0)));
+
+        diff
+    }
+
+    /// Merge another database into this one.
+    ///
+    /// Findings are matched by their stable ID. Findings only present in `other`
+    /// are added as-is. When both databases contain a finding, a decided status
+    /// (confirmed, false_positive, wont_fix) wins over needs_review, and between
+    /// equally decided findings the later `reviewed_at` wins. If both sides have
+    /// a decided but different status, the finding is reported as a conflict and
+    /// keeps this database's status unless `prefer_other` is set. Notes from both
+    /// sides are preserved by concatenation.
+    pub fn merge(&mut self, other: &TriageDatabase, prefer_other: bool) -> TriageMerge {
+        let mut merge = TriageMerge::default();
+
+        for (id, theirs) in &other.findings {
+            let Some(ours) = self.findings.get_mut(id) else {
+                self.findings.insert(id.clone(), theirs.clone());
+                merge.added += 1;
+                continue;
+            };
+
+            let ours_decided = ours.status != TriageStatus::NeedsReview;
+            let theirs_decided = theirs.status != TriageStatus::NeedsReview;
+            let take_theirs = if ours_decided && theirs_decided && ours.status != theirs.status {
+                merge.conflicts.push(MergeConflict {
+                    id: id.clone(),
+                    ours: ours.status,
+                    theirs: theirs.status,
+                    resolved: if prefer_other {
+                        theirs.status
+                    } else {
+                        ours.status
+                    },
+                });
+                prefer_other
+            } else if ours_decided != theirs_decided {
+                theirs_decided
+            } else {
+                theirs.reviewed_at > ours.reviewed_at
+            };
+
+            let notes = merge_notes(ours.notes.take(), theirs.notes.as_deref());
+            let detected_at = ours.detected_at.min(theirs.detected_at);
+            if take_theirs {
+                *ours = theirs.clone();
+                merge.updated += 1;
+            }
+            ours.notes = notes;
+            ours.detected_at = detected_at;
+        }
+
+        merge.conflicts.sort_by(|a, b| a.id.cmp(&b.id));
+        self.summary_cache = None;
+        merge
+    }
+}
+
+/// Concatenate notes from two copies of a finding, skipping duplicates.
+fn merge_notes(ours: Option<String>, theirs: Option<&str>) -> Option<String> {
+    match (ours, theirs) {
+        (Some(ours), Some(theirs)) if ours.contains(theirs) => Some(ours),
+        (Some(ours), Some(theirs)) if !theirs.contains(&ours) => Some(format!("{ours}\n{theirs}")),
+        (_, Some(theirs)) => Some(theirs.to_string()),
+        (ours, None) => ours,
+    }
+}
+
+fn finding_sort_key(f: &Finding) -> (&str, &str, u32, &str) {
+    (&f.repo, &f.file, f.line, &f.lint)
+}
+
+// ============================================================================
+// Database Diff
+// ============================================================================
+
+/// Differences between two triage databases (see [`TriageDatabase::diff`]).
+#[derive(Debug, Default)]
+pub struct TriageDiff<'a> {
+    /// Findings present only in the left-hand database
+    pub only_in_self: Vec<&'a Finding>,
+    /// Findings present only in the right-hand database
+    pub only_in_other: Vec<&'a Finding>,
+    /// Findings present in both databases with different statuses (left, right)
+    pub conflicts: Vec<(&'a Finding, &'a Finding)>,
+    /// Number of findings present in both databases with the same status
+    pub matching: usize,
+}
+
+impl TriageDiff<'_> {
+    /// Returns true if both databases agree completely
+    pub fn is_empty(&self) -> bool {
+        self.only_in_self.is_empty() && self.only_in_other.is_empty() && self.conflicts.is_empty()
+    }
+}
+
+// ============================================================================
+// Database Merge
+// ============================================================================
+
+/// Outcome of merging one triage database into another (see [`TriageDatabase::merge`]).
+#[derive(Debug, Default)]
+pub struct TriageMerge {
+    /// Findings copied over because they were missing from the target database
+    pub added: usize,
+    /// Existing findings replaced by the other database's copy
+    pub updated: usize,
+    /// Findings where both databases recorded a different decided status
+    pub conflicts: Vec<MergeConflict>,
+}
+
+/// A finding whose decided status differs between two merged databases.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeConflict {
+    /// Stable finding ID
+    pub id: String,
+    /// Status in the target database
+    pub ours: TriageStatus,
+    /// Status in the database being merged in
+    pub theirs: TriageStatus,
+    /// Status kept after the merge
+    pub resolved: TriageStatus,
+}
+
+// ============================================================================
+// Lint Run Diff
+// ============================================================================
+
+/// One diagnostic from `move-clippy --format json` output.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LintRecord {
+    pub file: String,
+    pub row: u32,
+    pub column: u32,
+    #[serde(default)]
+    pub level: String,
+    pub lint: String,
+    pub message: String,
+}
+
+impl LintRecord {
+    /// Location + lint fingerprint; the same hash used for [`Finding::id`].
+    pub fn fingerprint(&self) -> String {
+        Finding::generate_id(&self.lint, "", &self.file, self.row)
+    }
+}
+
+/// JSON lint output, either a bare array or the `--summary` report object.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LintRun {
+    Records(Vec<LintRecord>),
+    Report { diagnostics: Vec<LintRecord> },
+}
+
+/// Load the diagnostics from a `move-clippy --format json` output file.
+pub fn load_lint_run(path: &Path) -> Result<Vec<LintRecord>, TriageError> {
+    let contents = fs::read_to_string(path)?;
+    Ok(match serde_json::from_str(&contents)? {
+        LintRun::Records(records)
+        | LintRun::Report {
+            diagnostics: records,
+        } => records,
+    })
+}
+
+/// Differences between two lint runs (see [`RunDiff::compute`]).
+#[derive(Debug, Default, Serialize)]
+pub struct RunDiff {
+    /// Findings only in the `after` run
+    pub new: Vec<LintRecord>,
+    /// Findings only in the `before` run
+    pub fixed: Vec<LintRecord>,
+    /// Findings in both runs (as they appear in `after`)
+    pub unchanged: Vec<LintRecord>,
+}
+
+impl RunDiff {
+    /// Match findings across runs by [`LintRecord::fingerprint`].
+    ///
+    /// Repeated fingerprints are matched one-for-one, so a second identical finding on the
+    /// same line still shows up as new. Records whose file matches `exclude` are dropped.
+    pub fn compute(before: Vec<LintRecord>, after: Vec<LintRecord>, exclude: &[String]) -> Self {
+        let keep = |r: &LintRecord| exclude.is_empty() || !should_exclude_path(&r.file, exclude);
+        let before: Vec<LintRecord> = before.into_iter().filter(|r| keep(r)).collect();
+        let after: Vec<LintRecord> = after.into_iter().filter(|r| keep(r)).collect();
+
+        let mut before_counts: HashMap<String, usize> = HashMap::new();
+        for r in &before {
+            *before_counts.entry(r.fingerprint()).or_default() += 1;
+        }
+        let mut after_counts: HashMap<String, usize> = HashMap::new();
+        for r in &after {
+            *after_counts.entry(r.fingerprint()).or_default() += 1;
+        }
+
+        let mut diff = RunDiff::default();
+        for r in after {
+            match before_counts.get_mut(&r.fingerprint()) {
+                Some(n) if *n > 0 => {
+                    *n -= 1;
+                    diff.unchanged.push(r);
+                }
+                _ => diff.new.push(r),
+            }
+        }
+        for r in before {
+            match after_counts.get_mut(&r.fingerprint()) {
+                Some(n) if *n > 0 => *n -= 1,
+                _ => diff.fixed.push(r),
+            }
+        }
+
+        for records in [&mut diff.new, &mut diff.fixed, &mut diff.unchanged] {
+            records.sort_by(|a, b| lint_record_sort_key(a).cmp(&lint_record_sort_key(b)));
+        }
+        diff
+    }
+
+    /// Returns true if the `after` run introduced findings
+    pub fn has_new(&self) -> bool {
+        !self.new.is_empty()
+    }
+}
+
+fn lint_record_sort_key(r: &LintRecord) -> (&str, u32, u32, &str) {
+    (&r.file, r.row, r.column, &r.lint)
 }
 
 // ============================================================================
@@ -534,6 +800,8 @@ pub struct FindingFilter {
     pub repo: Option<String>,
     pub severity: Option<Severity>,
     pub category: Option<String>,
+    pub detected_after: Option<DateTime<Utc>>,
+    pub detected_before: Option<DateTime<Utc>>,
 }
 
 impl FindingFilter {
@@ -566,6 +834,18 @@ impl FindingFilter {
         self
     }
 
+    /// Keep findings detected at or after `at`.
+    pub fn with_detected_after(mut self, at: DateTime<Utc>) -> Self {
+        self.detected_after = Some(at);
+        self
+    }
+
+    /// Keep findings detected at or before `at`.
+    pub fn with_detected_before(mut self, at: DateTime<Utc>) -> Self {
+        self.detected_before = Some(at);
+        self
+    }
+
     pub fn matches(&self, finding: &Finding) -> bool {
         if let Some(status) = self.status
             && finding.status != status
@@ -597,6 +877,18 @@ impl FindingFilter {
             return false;
         }
 
+        if let Some(after) = self.detected_after
+            && finding.detected_at < after
+        {
+            return false;
+        }
+
+        if let Some(before) = self.detected_before
+            && finding.detected_at > before
+        {
+            return false;
+        }
+
         true
     }
 }
@@ -842,6 +1134,132 @@ pub fn generate_text_report(db: &TriageDatabase) -> String {
     out
 }
 
+/// Generate a report for a [`RunDiff`] in the given format
+pub fn generate_run_diff_report(
+    diff: &RunDiff,
+    format: ReportFormat,
+) -> Result<String, serde_json::Error> {
+    let line = |r: &LintRecord| {
+        format!(
+            "{}:{}:{} [{}] {}",
+            r.file,
+            r.row,
+            r.column,
+            r.lint,
+            truncate(&r.message, 80)
+        )
+    };
+    let sections = [
+        ("New", &diff.new),
+        ("Fixed", &diff.fixed),
+        ("Unchanged", &diff.unchanged),
+    ];
+
+    let mut out = String::new();
+    match format {
+        ReportFormat::Json => {
+            #[derive(Serialize)]
+            struct Counts {
+                new: usize,
+                fixed: usize,
+                unchanged: usize,
+            }
+            #[derive(Serialize)]
+            struct JsonRunDiff<'a> {
+                summary: Counts,
+                #[serde(flatten)]
+                diff: &'a RunDiff,
+            }
+            return serde_json::to_string_pretty(&JsonRunDiff {
+                summary: Counts {
+                    new: diff.new.len(),
+                    fixed: diff.fixed.len(),
+                    unchanged: diff.unchanged.len(),
+                },
+                diff,
+            });
+        }
+        ReportFormat::Markdown => {
+            out.push_str("# Move Clippy Run Diff\n\n");
+            out.push_str("| New | Fixed | Unchanged |\n|-----|-------|-----------|\n");
+            out.push_str(&format!(
+                "| {} | {} | {} |\n",
+                diff.new.len(),
+                diff.fixed.len(),
+                diff.unchanged.len()
+            ));
+            // Unchanged findings are summarized only; they are noise in a PR review.
+            for (title, records) in &sections[..2] {
+                if records.is_empty() {
+                    continue;
+                }
+                out.push_str(&format!("\n## {title} ({})\n\n", records.len()));
+                for r in records.iter() {
+                    out.push_str(&format!("- `{}`\n", line(r)));
+                }
+            }
+        }
+        ReportFormat::Text => {
+            out.push_str("MOVE CLIPPY RUN DIFF\n");
+            out.push_str(&"=".repeat(60));
+            out.push('\n');
+            for (title, records) in &sections {
+                out.push_str(&format!("{:<11} {}\n", format!("{title}:"), records.len()));
+            }
+            for (title, records) in &sections[..2] {
+                if records.is_empty() {
+                    continue;
+                }
+                out.push_str(&format!("\n{}:\n", title.to_uppercase()));
+                for r in records.iter() {
+                    out.push_str(&format!("  {}\n", line(r)));
+                }
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Export findings as CSV for spreadsheet review.
+///
+/// Columns: id, status, lint, category, severity, repo, file, line, message. Fields are
+/// quoted per RFC 4180 when needed, and fields a spreadsheet would evaluate as a formula
+/// (leading `=`, `+`, `-`, `@`) are prefixed with `'`.
+pub fn generate_csv_export(findings: &[&Finding]) -> String {
+    let mut out = String::from("id,status,lint,category,severity,repo,file,line,message\n");
+    for f in findings {
+        let line = f.line.to_string();
+        let fields = [
+            f.id.as_str(),
+            f.status.as_str(),
+            f.lint.as_str(),
+            f.category.as_str(),
+            f.severity.as_str(),
+            f.repo.as_str(),
+            f.file.as_str(),
+            line.as_str(),
+            f.message.as_str(),
+        ];
+        let row: Vec<String> = fields.into_iter().map(csv_field).collect();
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@']) {
+        format!("'{value}")
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
 // ============================================================================
 // Utility Functions
 // ============================================================================
@@ -880,21 +1298,6 @@ pub fn extract_snippet(
     Some((snippet, start_line))
 }
 
-/// Format a snippet with line numbers for display.
-///
-/// The `highlight_line` is the 1-based line number to highlight with '>'.
-pub fn format_snippet_with_lines(snippet: &str, start_line: u32, highlight_line: u32) -> String {
-    let mut out = String::new();
-
-    for (i, line) in snippet.lines().enumerate() {
-        let line_num = start_line + i as u32;
-        let prefix = if line_num == highlight_line { ">" } else { " " };
-        out.push_str(&format!("{} {:>4} | {}\n", prefix, line_num, line));
-    }
-
-    out
-}
-
 // ============================================================================
 // Path Filtering
 // ============================================================================
@@ -1067,6 +1470,57 @@ mod tests {
         assert!(filter.matches(&finding));
     }
 
+    #[test]
+    fn test_finding_filter_detection_window() {
+        let day = |d: u32| {
+            chrono::NaiveDate::from_ymd_opt(2025, 3, d)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap()
+                .and_utc()
+        };
+
+        let mut db = TriageDatabase::new();
+        for d in [1, 3, 5, 7, 9] {
+            let mut finding = Finding::new(
+                "test_lint".to_string(),
+                "security".to_string(),
+                "repo1".to_string(),
+                format!("day{d}.move"),
+                10,
+                1,
+                "Test message".to_string(),
+            );
+            finding.detected_at = day(d);
+            db.add_or_update(finding);
+        }
+        let files = |filter: &FindingFilter| {
+            let mut files: Vec<_> = db
+                .filter(filter)
+                .into_iter()
+                .map(|f| f.file.clone())
+                .collect();
+            files.sort();
+            files
+        };
+
+        // Both boundaries are inclusive
+        let window = FindingFilter::new()
+            .with_detected_after(day(3))
+            .with_detected_before(day(7));
+        assert_eq!(files(&window), ["day3.move", "day5.move", "day7.move"]);
+
+        let since = FindingFilter::new().with_detected_after(day(6));
+        assert_eq!(files(&since), ["day7.move", "day9.move"]);
+
+        let until = FindingFilter::new().with_detected_before(day(1));
+        assert_eq!(files(&until), ["day1.move"]);
+
+        // Combines with the other criteria
+        let other_lint = window.with_lint("other_lint");
+        assert!(files(&other_lint).is_empty());
+    }
+
     #[test]
     fn test_database_add_or_update() {
         let mut db = TriageDatabase::new();
@@ -1143,6 +1597,237 @@ mod tests {
         assert_eq!(summary.wont_fix, 2);
     }
 
+    #[test]
+    fn test_database_diff() {
+        let make = |file: &str, status: TriageStatus| {
+            let mut finding = Finding::new(
+                "test_lint".to_string(),
+                "security".to_string(),
+                "repo1".to_string(),
+                file.to_string(),
+                10,
+                1,
+                "Test message".to_string(),
+            );
+            finding.status = status;
+            finding
+        };
+
+        let mut db_a = TriageDatabase::new();
+        db_a.add_or_update(make("shared.move", TriageStatus::Confirmed));
+        db_a.add_or_update(make("conflict.move", TriageStatus::Confirmed));
+        db_a.add_or_update(make("only_a.move", TriageStatus::NeedsReview));
+
+        let mut db_b = TriageDatabase::new();
+        db_b.add_or_update(make("shared.move", TriageStatus::Confirmed));
+        db_b.add_or_update(make("conflict.move", TriageStatus::FalsePositive));
+        db_b.add_or_update(make("only_b.move", TriageStatus::WontFix));
+
+        let diff = db_a.diff(&db_b);
+        assert!(!diff.is_empty());
+        assert_eq!(diff.matching, 1);
+
+        assert_eq!(diff.only_in_self.len(), 1);
+        assert_eq!(diff.only_in_self[0].file, "only_a.move");
+
+        assert_eq!(diff.only_in_other.len(), 1);
+        assert_eq!(diff.only_in_other[0].file, "only_b.move");
+
+        assert_eq!(diff.conflicts.len(), 1);
+        let (ours, theirs) = diff.conflicts[0];
+        assert_eq!(ours.file, "conflict.move");
+        assert_eq!(ours.status, TriageStatus::Confirmed);
+        assert_eq!(theirs.status, TriageStatus::FalsePositive);
+
+        // Diffing a database against itself yields nothing
+        assert!(db_a.diff(&db_a).is_empty());
+    }
+
+    #[test]
+    fn test_database_merge_matrix() {
+        use TriageStatus::*;
+        use chrono::Duration;
+
+        let earlier = Utc::now() - Duration::hours(1);
+        let later = Utc::now();
+        let make = |file: &str, status: TriageStatus, reviewed_at: Option<DateTime<Utc>>| {
+            let mut finding = Finding::new(
+                "test_lint".to_string(),
+                "security".to_string(),
+                "repo1".to_string(),
+                file.to_string(),
+                10,
+                1,
+                "Test message".to_string(),
+            );
+            finding.status = status;
+            finding.reviewed_at = reviewed_at;
+            finding
+        };
+
+        // (file, ours, theirs, expected status, expected conflict)
+        let cases = [
+            (
+                "review_review.move",
+                (NeedsReview, None),
+                (NeedsReview, None),
+                NeedsReview,
+                false,
+            ),
+            (
+                "review_decided.move",
+                (NeedsReview, None),
+                (WontFix, Some(earlier)),
+                WontFix,
+                false,
+            ),
+            (
+                "decided_review.move",
+                (Confirmed, Some(earlier)),
+                (NeedsReview, Some(later)),
+                Confirmed,
+                false,
+            ),
+            (
+                "same_older.move",
+                (Confirmed, Some(later)),
+                (Confirmed, Some(earlier)),
+                Confirmed,
+                false,
+            ),
+            (
+                "conflict.move",
+                (Confirmed, Some(earlier)),
+                (FalsePositive, Some(later)),
+                Confirmed,
+                true,
+            ),
+        ];
+
+        let mut ours = TriageDatabase::new();
+        let mut theirs = TriageDatabase::new();
+        for (file, (os, ot), (ts, tt), _, _) in &cases {
+            ours.add_or_update(make(file, *os, *ot));
+            theirs.add_or_update(make(file, *ts, *tt));
+        }
+        theirs.add_or_update(make("only_theirs.move", FalsePositive, Some(later)));
+
+        let mut merged = ours.clone();
+        let result = merged.merge(&theirs, false);
+        assert_eq!(result.added, 1);
+        assert_eq!(result.conflicts.len(), 1);
+        for (file, _, _, expected, conflict) in &cases {
+            let id = Finding::generate_id("test_lint", "repo1", file, 10);
+            assert_eq!(merged.get(&id).unwrap().status, *expected, "{file}");
+            assert_eq!(
+                result.conflicts.iter().any(|c| c.id == id),
+                *conflict,
+                "{file}"
+            );
+        }
+        let only_id = Finding::generate_id("test_lint", "repo1", "only_theirs.move", 10);
+        assert_eq!(merged.get(&only_id).unwrap().status, FalsePositive);
+
+        let conflict = &result.conflicts[0];
+        assert_eq!(conflict.ours, Confirmed);
+        assert_eq!(conflict.theirs, FalsePositive);
+        assert_eq!(conflict.resolved, Confirmed);
+
+        // Same decided status on both sides: the later review wins
+        let same_id = Finding::generate_id("test_lint", "repo1", "same_older.move", 10);
+        assert_eq!(merged.get(&same_id).unwrap().reviewed_at, Some(later));
+
+        // --prefer flips only the decided-vs-decided conflicts
+        let mut preferred = ours.clone();
+        let result = preferred.merge(&theirs, true);
+        assert_eq!(result.conflicts.len(), 1);
+        assert_eq!(result.conflicts[0].resolved, FalsePositive);
+        let conflict_id = Finding::generate_id("test_lint", "repo1", "conflict.move", 10);
+        assert_eq!(preferred.get(&conflict_id).unwrap().status, FalsePositive);
+        let decided_id = Finding::generate_id("test_lint", "repo1", "decided_review.move", 10);
+        assert_eq!(preferred.get(&decided_id).unwrap().status, Confirmed);
+    }
+
+    #[test]
+    fn test_database_merge_notes() {
+        let mut finding = Finding::new(
+            "test_lint".to_string(),
+            "security".to_string(),
+            "repo1".to_string(),
+            "notes.move".to_string(),
+            10,
+            1,
+            "Test message".to_string(),
+        );
+        let id = finding.id.clone();
+
+        let mut ours = TriageDatabase::new();
+        finding.update_status(TriageStatus::Confirmed, Some("Real bug".to_string()));
+        ours.add_or_update(finding.clone());
+
+        let mut theirs = TriageDatabase::new();
+        finding.update_status(TriageStatus::Confirmed, Some("Exploitable".to_string()));
+        theirs.add_or_update(finding);
+
+        ours.merge(&theirs, false);
+        let notes = ours.get(&id).unwrap().notes.clone();
+        assert_eq!(notes.as_deref(), Some("Real bug\nExploitable"));
+
+        // Merging the same database again does not duplicate notes
+        ours.merge(&theirs, false);
+        assert_eq!(ours.get(&id).unwrap().notes, notes);
+    }
+
+    #[test]
+    fn test_run_diff() {
+        let record = |file: &str, row: u32, lint: &str| LintRecord {
+            file: file.to_string(),
+            row,
+            column: 1,
+            level: "warning".to_string(),
+            lint: lint.to_string(),
+            message: "msg".to_string(),
+        };
+        let before = vec![
+            record("a.move", 3, "abilities_order"),
+            record("a.move", 9, "prefer_to_string"),
+            record("tests/a_test.move", 1, "abilities_order"),
+        ];
+        let after = vec![
+            record("a.move", 3, "abilities_order"),
+            record("a.move", 3, "abilities_order"),
+            record("b.move", 1, "while_true_to_loop"),
+            record("b_test.move", 1, "while_true_to_loop"),
+        ];
+        let exclude = vec!["**/*_test.move".to_string()];
+
+        let diff = RunDiff::compute(before, after, &exclude);
+        assert!(diff.has_new());
+        assert_eq!(diff.unchanged.len(), 1);
+        // The duplicate on line 3 has no counterpart in `before`.
+        assert_eq!(
+            diff.new,
+            vec![
+                record("a.move", 3, "abilities_order"),
+                record("b.move", 1, "while_true_to_loop")
+            ]
+        );
+        assert_eq!(diff.fixed, vec![record("a.move", 9, "prefer_to_string")]);
+
+        let text = generate_run_diff_report(&diff, ReportFormat::Text).unwrap();
+        assert!(text.contains("New:        2"));
+        assert!(text.contains("b.move:1:1 [while_true_to_loop]"));
+        let json: serde_json::Value =
+            serde_json::from_str(&generate_run_diff_report(&diff, ReportFormat::Json).unwrap())
+                .unwrap();
+        assert_eq!(json["summary"]["fixed"], 1);
+        assert_eq!(json["new"].as_array().unwrap().len(), 2);
+
+        let same = RunDiff::compute(diff.unchanged.clone(), diff.unchanged.clone(), &[]);
+        assert!(!same.has_new());
+        assert!(same.fixed.is_empty());
+    }
+
     #[test]
     fn test_path_filtering() {
         // Test exact segment matching
@@ -1190,4 +1875,55 @@ mod tests {
         // This should NOT be excluded (tests dir pattern removed)
         assert!(!should_exclude_path("/project/tests/unit.move", &patterns));
     }
+
+    #[test]
+    fn test_csv_export_round_trips_through_csv_reader() {
+        let mut finding = Finding::new(
+            "unchecked_amount".to_string(),
+            "security".to_string(),
+            "repo, with comma".to_string(),
+            "sources/vault.move".to_string(),
+            12,
+            4,
+            "Value \"amount\" is used, then\nchecked".to_string(),
+        );
+        finding.status = TriageStatus::Confirmed;
+        let formula = Finding::new(
+            "style_lint".to_string(),
+            "style".to_string(),
+            "repo".to_string(),
+            "sources/a.move".to_string(),
+            3,
+            1,
+            "=HYPERLINK(\"http://example.com\")".to_string(),
+        );
+
+        let csv = generate_csv_export(&[&finding, &formula]);
+        let mut reader = csv::Reader::from_reader(csv.as_bytes());
+        assert_eq!(
+            reader.headers().unwrap(),
+            vec![
+                "id", "status", "lint", "category", "severity", "repo", "file", "line", "message"
+            ]
+        );
+        let rows: Vec<csv::StringRecord> = reader.records().collect::<Result<_, _>>().unwrap();
+        assert_eq!(rows.len(), 2);
+
+        assert_eq!(
+            rows[0],
+            vec![
+                finding.id.as_str(),
+                "confirmed",
+                "unchecked_amount",
+                "security",
+                finding.severity.as_str(),
+                "repo, with comma",
+                "sources/vault.move",
+                "12",
+                "Value \"amount\" is used, then\nchecked",
+            ]
+        );
+        // Formula-like fields are neutralized rather than evaluated by spreadsheets.
+        assert_eq!(&rows[1][8], "'=HYPERLINK(\"http://example.com\")");
+    }
 }
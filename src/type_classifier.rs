@@ -14,9 +14,11 @@
 //! | Event        | ✗   | ✗     | ✓    | ✓    | Emitted events |
 //! | Config       | ✗   | ✓     | ✓    | ✓    | Configuration structs |
 
-use move_compiler::expansion::ast::AbilitySet;
+use move_compiler::expansion::ast::{AbilitySet, Address, ModuleIdent};
 use move_compiler::naming::ast as N;
 use move_compiler::parser::ast::Ability_;
+use move_compiler::shared::{Identifier, Name};
+use move_compiler::typing::ast as T;
 
 // ============================================================================
 // Core Ability Extraction
@@ -190,6 +192,138 @@ pub fn has_store_ability(abilities: &AbilitySet) -> bool {
     abilities.has_ability_(Ability_::Store)
 }
 
+// ============================================================================
+// Framework Module Resolution
+// ============================================================================
+
+/// Check if an address is the Sui framework address (`0x2` / `sui`).
+pub fn is_sui_framework_address(addr: &Address) -> bool {
+    match addr {
+        Address::Numerical {
+            value: addr_value, ..
+        } => {
+            let bytes = addr_value.value.into_bytes();
+            bytes.iter().take(31).all(|&b| b == 0) && bytes[31] == 2
+        }
+        Address::NamedUnassigned(name) => {
+            name.value.as_str() == "sui" || name.value.as_str() == "0x2"
+        }
+    }
+}
+
+/// Check if a module identifier resolves to the Sui framework module `sui::<module>`.
+///
+/// `use` aliases are resolved during expansion, so `mident` is the module the alias
+/// points at. Checking the address as well as the name means `use my_pkg::evil as transfer`
+/// (or a package module that happens to be named `transfer`) is not mistaken for
+/// `sui::transfer`.
+pub fn is_sui_framework_module(mident: &ModuleIdent, module: &str) -> bool {
+    is_sui_framework_address(&mident.value.address)
+        && mident.value.module.value().as_str() == module
+}
+
+/// Check if a call targets `sui::<module>::<function>` after alias resolution.
+pub fn is_sui_framework_call(call: &T::ModuleCall, module: &str, function: &str) -> bool {
+    is_sui_framework_module(&call.module, module) && call.name.value().as_str() == function
+}
+
+/// Check if an address is the Move standard library address (`0x1` / `std`).
+pub fn is_move_stdlib_address(addr: &Address) -> bool {
+    match addr {
+        Address::Numerical {
+            value: addr_value, ..
+        } => {
+            let bytes = addr_value.value.into_bytes();
+            bytes.iter().take(31).all(|&b| b == 0) && bytes[31] == 1
+        }
+        Address::NamedUnassigned(name) => {
+            name.value.as_str() == "std" || name.value.as_str() == "0x1"
+        }
+    }
+}
+
+/// The named address a module was published under (e.g. `pyth`), if the source used one.
+pub fn named_address(addr: &Address) -> Option<&Name> {
+    match addr {
+        Address::Numerical { name, .. } => name.as_ref(),
+        Address::NamedUnassigned(name) => Some(name),
+    }
+}
+
+/// Check if an address belongs to `package`.
+///
+/// `sui` and `std` are matched by their fixed framework addresses; any other package
+/// (e.g. `pyth`) is published at a different address per network, so it is matched by
+/// the named address the source was compiled with.
+pub fn is_package_address(addr: &Address, package: &str) -> bool {
+    match package {
+        "sui" => is_sui_framework_address(addr),
+        "std" => is_move_stdlib_address(addr),
+        _ => named_address(addr).is_some_and(|name| name.value.as_str() == package),
+    }
+}
+
+/// Check if a call targets `<package>::<module>::<function>` after alias resolution.
+pub fn is_package_call(call: &T::ModuleCall, package: &str, module: &str, function: &str) -> bool {
+    is_package_address(&call.module.value.address, package)
+        && call.module.value.module.value().as_str() == module
+        && call.name.value().as_str() == function
+}
+
+/// Check if a type is the Sui framework struct `sui::<module>::<name>` (looking through
+/// references).
+pub fn is_sui_framework_type(ty: &N::Type_, module: &str, name: &str) -> bool {
+    match ty {
+        N::Type_::Apply(_, type_name, _) => matches!(
+            &type_name.value,
+            N::TypeName_::ModuleType(mident, struct_name)
+                if is_sui_framework_module(mident, module) && struct_name.value().as_str() == name
+        ),
+        N::Type_::Ref(_, inner) => is_sui_framework_type(&inner.value, module, name),
+        _ => false,
+    }
+}
+
+/// Check if a type is `sui::clock::Clock` (looking through references).
+pub fn is_clock_type(ty: &N::Type_) -> bool {
+    is_sui_framework_type(ty, "clock", "Clock")
+}
+
+// ============================================================================
+// Call Type Arguments
+// ============================================================================
+
+/// Check if a type is `sui::coin::Coin<T>` (looking through references).
+///
+/// Coins share the capability ability pattern (key + store, no copy/drop) but are
+/// value tokens, so capability lints exclude them.
+pub fn is_coin_type(ty: &N::Type_) -> bool {
+    is_sui_framework_type(ty, "coin", "Coin")
+}
+
+/// Return the `idx`-th explicit type argument of a call (e.g. `T` in `transfer::transfer<T>`).
+pub fn call_type_arg(call: &T::ModuleCall, idx: usize) -> Option<&N::Type> {
+    call.type_arguments.get(idx)
+}
+
+/// Abilities of the `idx`-th type argument in `type_args`, looking through references.
+pub fn abilities_of_type_arg(type_args: &[N::Type], idx: usize) -> Option<AbilitySet> {
+    type_args
+        .get(idx)
+        .and_then(|ty| abilities_of_type(&ty.value))
+}
+
+/// Abilities of the `idx`-th type argument of a call, looking through references.
+pub fn abilities_of_call_type_arg(call: &T::ModuleCall, idx: usize) -> Option<AbilitySet> {
+    abilities_of_type_arg(&call.type_arguments, idx)
+}
+
+/// Return the `idx`-th type argument of a call if it is a capability that is not a `Coin`.
+pub fn capability_call_type_arg(call: &T::ModuleCall, idx: usize) -> Option<&N::Type> {
+    call_type_arg(call, idx)
+        .filter(|ty| !is_coin_type(&ty.value) && is_capability_type_from_ty(&ty.value))
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -213,6 +347,49 @@ mod tests {
         set
     }
 
+    fn make_type(abilities: &[Ability_]) -> N::Type {
+        let type_name = Spanned {
+            loc: Loc::invalid(),
+            value: N::TypeName_::Builtin(Spanned {
+                loc: Loc::invalid(),
+                value: N::BuiltinTypeName_::U64,
+            }),
+        };
+        Spanned {
+            loc: Loc::invalid(),
+            value: N::Type_::Apply(Some(make_abilities(abilities)), type_name, vec![]),
+        }
+    }
+
+    fn make_ref(inner: N::Type) -> N::Type {
+        Spanned {
+            loc: Loc::invalid(),
+            value: N::Type_::Ref(true, Box::new(inner)),
+        }
+    }
+
+    #[test]
+    fn test_abilities_of_type_arg_missing() {
+        assert!(abilities_of_type_arg(&[], 0).is_none());
+
+        let args = vec![make_type(&[Ability_::Key, Ability_::Store])];
+        assert!(abilities_of_type_arg(&args, 1).is_none());
+    }
+
+    #[test]
+    fn test_abilities_of_type_arg_through_reference() {
+        let args = vec![
+            make_type(&[Ability_::Copy, Ability_::Drop]),
+            make_ref(make_type(&[Ability_::Key, Ability_::Store])),
+        ];
+
+        let first = abilities_of_type_arg(&args, 0).expect("direct type has abilities");
+        assert!(is_event_type(&first));
+
+        let second = abilities_of_type_arg(&args, 1).expect("reference is looked through");
+        assert!(is_capability_type(&second));
+    }
+
     #[test]
     fn test_capability_type() {
         // Capability: key + store, no copy, no drop
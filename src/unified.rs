@@ -11,7 +11,10 @@
 //! - Unified diagnostic output across all lint phases
 //! - Single point of registration for all lint types
 
-use crate::lint::{AnalysisKind, LintCategory, LintDescriptor, LintRegistry, RuleGroup};
+use crate::lint::{
+    AnalysisKind, LintCategory, LintDescriptor, LintRegistry, RuleGroup, TypeSystemGap,
+};
+use serde::Serialize;
 use std::collections::HashMap;
 use std::sync::OnceLock;
 
@@ -69,6 +72,13 @@ impl LintPhase {
     }
 }
 
+/// Serializes as [`LintPhase::as_str`].
+impl Serialize for LintPhase {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
 /// Unified registry for all lint types across all phases.
 #[derive(Debug, Default)]
 pub struct UnifiedLintRegistry {
@@ -248,10 +258,20 @@ pub(crate) fn build_syntactic_registry() -> LintRegistry {
         .with_rule(crate::rules::EmptyVectorLiteralLint)
         .with_rule(crate::rules::TypedAbortCodeLint)
         .with_rule(crate::rules::ErrorConstNamingLint)
+        .with_rule(crate::rules::RedundantBindingLint)
+        .with_rule(crate::rules::NeedlessBoolLint)
         // Security lints (audit-backed)
         .with_rule(crate::rules::SuspiciousOverflowCheckLint)
         // Preview/experimental lints
         .with_rule(crate::rules::FreshAddressReuseLint)
+        .with_rule(crate::rules::LoopWithLeadingBreakToWhileLint)
+        .with_rule(crate::rules::ShadowedFrameworkAliasLint)
+        .with_rule(crate::rules::HardcodedAddressLint)
+        .with_rule(crate::rules::ManualVectorBuildLint)
+        .with_rule(crate::rules::ConstantAssertConditionLint)
+        .with_rule(crate::rules::BoolComparisonLint)
+        .with_rule(crate::rules::ExcessiveNestingLint)
+        .with_rule(crate::rules::GlobalStorageOperatorLint)
         // REMOVED deprecated/superseded/obvious lints:
         // - StaleOraclePriceLint, SingleStepOwnershipTransferLint, MissingWitnessDropLint
         // - PublicRandomAccessLint, IgnoredBooleanReturnLint, UncheckedCoinSplitLint
@@ -314,6 +334,72 @@ pub fn build_unified_registry() -> UnifiedLintRegistry {
     registry
 }
 
+/// Structured, serializable snapshot of every lint in the unified registry.
+///
+/// Intended for docs generation; prefer this over parsing `list-rules` output.
+#[derive(Debug, Clone, Serialize)]
+pub struct CatalogJson {
+    /// move-clippy version that produced the catalog.
+    pub version: &'static str,
+    /// All lints, sorted by name.
+    pub lints: Vec<CatalogLint>,
+}
+
+/// One lint entry in a [`CatalogJson`].
+#[derive(Debug, Clone, Serialize)]
+pub struct CatalogLint {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub phase: LintPhase,
+    pub category: &'static str,
+    pub group: &'static str,
+    pub analysis: AnalysisKind,
+    /// CLI mode needed to run the lint, e.g. `--mode full`; `None` for fast mode.
+    pub required_mode: Option<&'static str>,
+    pub fix: CatalogFix,
+    pub gap: Option<TypeSystemGap>,
+}
+
+/// Auto-fix metadata for a [`CatalogLint`].
+#[derive(Debug, Clone, Serialize)]
+pub struct CatalogFix {
+    pub available: bool,
+    pub safety: &'static str,
+    pub description: &'static str,
+}
+
+/// Export the unified registry as a [`CatalogJson`].
+#[must_use]
+pub fn export_catalog() -> CatalogJson {
+    let mut lints: Vec<CatalogLint> = unified_registry()
+        .all()
+        .map(|lint| {
+            let d = lint.descriptor;
+            CatalogLint {
+                name: d.name,
+                description: d.description,
+                phase: lint.phase,
+                category: d.category.as_str(),
+                group: d.group.as_str(),
+                analysis: d.analysis,
+                required_mode: lint.phase.required_mode(),
+                fix: CatalogFix {
+                    available: d.fix.available,
+                    safety: d.fix.safety.as_str(),
+                    description: d.fix.description,
+                },
+                gap: d.gap,
+            }
+        })
+        .collect();
+    lints.sort_by_key(|l| l.name);
+
+    CatalogJson {
+        version: env!("CARGO_PKG_VERSION"),
+        lints,
+    }
+}
+
 /// Print a summary of all registered lints.
 pub fn print_lint_summary(registry: &UnifiedLintRegistry) {
     println!("=== Unified Lint Registry Summary ===\n");
@@ -469,4 +555,32 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_export_catalog_round_trips_names() {
+        let catalog = export_catalog();
+        assert!(!catalog.lints.is_empty(), "Catalog should have lints");
+
+        for lint in &catalog.lints {
+            let canonical = crate::lint::resolve_lint_alias(lint.name);
+            assert_eq!(canonical, lint.name, "catalog names must be canonical");
+            assert!(
+                unified_registry().get(canonical).is_some(),
+                "{} should resolve in the unified registry",
+                lint.name
+            );
+        }
+
+        let json = serde_json::to_value(&catalog).unwrap();
+        let entry = json["lints"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|l| l["name"] == "abilities_order")
+            .unwrap();
+        assert_eq!(entry["phase"], "syntactic");
+        assert_eq!(entry["analysis"], "syntactic");
+        assert_eq!(entry["group"], "stable");
+        assert!(entry["gap"].is_null());
+    }
 }
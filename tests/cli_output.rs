@@ -0,0 +1,621 @@
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// A consumer that reads stdout slowly must still receive every file's output, and the
+/// per-file flushes must not block the linter once the pipe drains.
+#[test]
+fn pretty_output_streams_to_slow_consumer() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let files = 200;
+    for i in 0..files {
+        std::fs::write(
+            dir.path().join(format!("m{i}.move")),
+            format!("module my_pkg::m{i} {{\n    public struct A has copy, drop {{}}\n}}\n"),
+        )
+        .expect("write fixture");
+    }
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_move-clippy"))
+        .arg(dir.path())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("spawn move-clippy");
+
+    let stdout = child.stdout.take().expect("stdout");
+    let mut summaries = 0;
+    for (n, line) in BufReader::new(stdout).lines().enumerate() {
+        let line = line.expect("read line");
+        if line.contains(" diagnostics for ") {
+            summaries += 1;
+        }
+        if n % 50 == 0 {
+            std::thread::sleep(Duration::from_millis(20));
+        }
+    }
+
+    let status = child.wait().expect("wait for move-clippy");
+    assert_eq!(summaries, files, "every file should report its diagnostics");
+    assert!(status.code().is_some(), "linter should exit normally");
+}
+
+/// `--max-diagnostics` caps what is printed across all files, but truncated errors still
+/// fail the run.
+#[test]
+fn max_diagnostics_truncates_across_files_and_keeps_exit_code() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let files = 5;
+    for i in 0..files {
+        std::fs::write(
+            dir.path().join(format!("m{i}.move")),
+            format!("module my_pkg::m{i} {{\n    public struct A has copy, drop {{}}\n}}\n"),
+        )
+        .expect("write fixture");
+    }
+    let config = dir.path().join("move-clippy.toml");
+    std::fs::write(&config, "[lints]\nmodern_module_syntax = \"error\"\n").expect("write config");
+
+    let run = |max: &str| {
+        Command::new(env!("CARGO_BIN_EXE_move-clippy"))
+            .arg(dir.path())
+            .args(["--only", "modern_module_syntax", "--max-diagnostics", max])
+            .arg("--config")
+            .arg(&config)
+            .output()
+            .expect("run move-clippy")
+    };
+
+    let output = run("2");
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    let shown = stdout
+        .lines()
+        .filter(|l| l.contains(": modern_module_syntax:"))
+        .count();
+    assert_eq!(shown, 2, "{stdout}");
+    assert!(
+        stdout.contains("... 3 more diagnostics suppressed (--max-diagnostics 2)"),
+        "{stdout}"
+    );
+    assert_eq!(output.status.code(), Some(1));
+
+    let output = run("0");
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    assert!(!stdout.contains(": modern_module_syntax:"), "{stdout}");
+    assert_eq!(
+        output.status.code(),
+        Some(1),
+        "suppressed errors must still fail the run"
+    );
+}
+
+/// `--format rich` prints the source line with a caret underline and the help text.
+#[test]
+fn rich_format_renders_source_excerpt() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_move-clippy"))
+        .args(["--format", "rich", "--only", "modern_module_syntax"])
+        .env_remove("NO_COLOR")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("spawn move-clippy");
+    child
+        .stdin
+        .take()
+        .expect("stdin")
+        .write_all(b"module my_pkg::m {\n}\n")
+        .expect("write stdin");
+
+    let output = child.wait_with_output().expect("wait for move-clippy");
+    // Stdout is a pipe, so no ANSI codes are expected.
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    insta::assert_snapshot!(stdout);
+}
+
+/// `--fail-on-category` fails the run on any finding in the listed categories, and rejects
+/// names that are not lint categories.
+#[test]
+fn fail_on_category_gates_exit_code() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("m.move");
+    std::fs::write(&path, "module my_pkg::m {\n}\n").expect("write fixture");
+
+    let run = |categories: &str| {
+        Command::new(env!("CARGO_BIN_EXE_move-clippy"))
+            .arg(&path)
+            .args(["--only", "modern_module_syntax"])
+            .args(["--fail-on-category", categories])
+            .output()
+            .expect("run move-clippy")
+    };
+
+    // `modern_module_syntax` is a modernization warning.
+    assert_eq!(run("security,style").status.code(), Some(0));
+    assert_eq!(run("security,modernization").status.code(), Some(1));
+
+    let output = run("correctness");
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).expect("utf8 stderr");
+    assert!(
+        stderr.contains("unknown lint category for --fail-on-category: correctness"),
+        "{stderr}"
+    );
+}
+
+/// `--quiet` prints only failing diagnostics in pretty mode, keeps the exit code, and leaves
+/// JSON untouched.
+#[test]
+fn quiet_prints_only_failures() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        dir.path().join("m.move"),
+        "module my_pkg::m {\n    public struct A has drop, copy {}\n}\n",
+    )
+    .expect("write fixture");
+    let config = dir.path().join("move-clippy.toml");
+    std::fs::write(&config, "[lints]\nmodern_module_syntax = \"error\"\n").expect("write config");
+
+    let run = |extra: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_move-clippy"))
+            .arg(dir.path())
+            .args(["--only", "modern_module_syntax,abilities_order", "--quiet"])
+            .args(extra)
+            .arg("--config")
+            .arg(&config)
+            .output()
+            .expect("run move-clippy");
+        let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+        (stdout, output.status.code())
+    };
+
+    let (stdout, code) = run(&[]);
+    assert!(
+        stdout.contains(": error: modern_module_syntax:"),
+        "{stdout}"
+    );
+    assert!(!stdout.contains("abilities_order"), "{stdout}");
+    assert!(!stdout.contains(" diagnostics for "), "{stdout}");
+    assert_eq!(code, Some(1));
+
+    let (stdout, code) = run(&["--deny-warnings"]);
+    assert!(stdout.contains(": warning: abilities_order:"), "{stdout}");
+    assert_eq!(code, Some(1));
+
+    let (stdout, _) = run(&["--format", "json"]);
+    assert!(stdout.contains("\"abilities_order\""), "{stdout}");
+}
+
+/// A config `[aliases]` entry can be used in place of the lint name with `--only`.
+#[test]
+fn config_alias_selects_lint_with_only() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("m.move");
+    std::fs::write(&path, "module my_pkg::m {\n}\n").expect("write fixture");
+    std::fs::write(
+        dir.path().join("move-clippy.toml"),
+        "[aliases]\nlabel_syntax = \"modern_module_syntax\"\n",
+    )
+    .expect("write config");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_move-clippy"))
+        .arg(&path)
+        .args(["--only", "label_syntax"])
+        .output()
+        .expect("run move-clippy");
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    assert!(stdout.contains("modern_module_syntax"), "{stdout}");
+    assert!(stdout.contains("1 diagnostics for"), "{stdout}");
+}
+
+/// A `--rulelist` lint runs even in a directory whose discovered config disables it.
+#[test]
+fn rulelist_overrides_nested_config_disabled() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let sub = dir.path().join("sub");
+    std::fs::create_dir(&sub).expect("create sub");
+    std::fs::write(sub.join("m.move"), "module my_pkg::m {\n}\n").expect("write fixture");
+    std::fs::write(
+        sub.join("move-clippy.toml"),
+        "[lints]\ndisabled = [\"modern_module_syntax\"]\n",
+    )
+    .expect("write config");
+    let rulelist = dir.path().join("rules.txt");
+    std::fs::write(&rulelist, "modern_module_syntax\n").expect("write rulelist");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_move-clippy"))
+        .arg(dir.path())
+        .arg("--rulelist")
+        .arg(&rulelist)
+        .output()
+        .expect("run move-clippy");
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    assert!(
+        stdout.contains("warning: modern_module_syntax:"),
+        "{stdout}"
+    );
+}
+
+/// `config-check` prints each problem with its line and fails only on unknown names.
+#[test]
+fn config_check_exit_code_follows_errors() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let config = dir.path().join("move-clippy.toml");
+    let run = || {
+        Command::new(env!("CARGO_BIN_EXE_move-clippy"))
+            .args(["config-check", "--config"])
+            .arg(&config)
+            .output()
+            .expect("run move-clippy")
+    };
+
+    std::fs::write(&config, "[extra]\n[lints]\nabilities_order = \"error\"\n")
+        .expect("write config");
+    let output = run();
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    assert_eq!(output.status.code(), Some(0), "{stdout}");
+    assert!(
+        stdout.contains(":1: warning: unknown key `extra`"),
+        "{stdout}"
+    );
+
+    std::fs::write(&config, "[lints]\nabilities_ordr = \"error\"\n").expect("write config");
+    let output = run();
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    assert_eq!(output.status.code(), Some(1), "{stdout}");
+    assert!(
+        stdout.contains(":2: error: unknown lint: abilities_ordr"),
+        "{stdout}"
+    );
+}
+
+/// `--dedup` collapses a finding repeated across files into one entry, without changing
+/// the exit code or the default output.
+#[test]
+fn dedup_collapses_identical_findings_across_files() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    for i in 0..3 {
+        std::fs::write(
+            dir.path().join(format!("m{i}.move")),
+            "module my_pkg::generated {\n}\n",
+        )
+        .expect("write fixture");
+    }
+    let config = dir.path().join("move-clippy.toml");
+    std::fs::write(&config, "[lints]\nmodern_module_syntax = \"error\"\n").expect("write config");
+
+    let run = |extra: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_move-clippy"))
+            .arg(dir.path())
+            .args(["--only", "modern_module_syntax"])
+            .args(extra)
+            .arg("--config")
+            .arg(&config)
+            .output()
+            .expect("run move-clippy")
+    };
+    let findings = |stdout: &str| {
+        stdout
+            .lines()
+            .filter(|l| l.contains(": modern_module_syntax:"))
+            .count()
+    };
+
+    let output = run(&[]);
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    assert_eq!(findings(&stdout), 3, "{stdout}");
+
+    let output = run(&["--dedup"]);
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    assert_eq!(findings(&stdout), 1, "{stdout}");
+    assert!(stdout.contains("  ... and 2 more locations"), "{stdout}");
+    assert_eq!(output.status.code(), Some(1));
+
+    let output = run(&["--dedup", "--format", "json"]);
+    let records: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    let records = records.as_array().expect("array");
+    assert_eq!(records.len(), 1, "{records:?}");
+    let occurrences = records[0]["occurrences"].as_array().expect("occurrences");
+    assert_eq!(occurrences.len(), 3);
+    assert_eq!(occurrences[0]["row"], 1);
+
+    let output = run(&["--dedup", "--format", "github"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--dedup"));
+}
+
+/// `--timings` prints a per-lint table to stderr, and nothing is printed without it.
+#[test]
+fn timings_report_lists_rules() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let file = dir.path().join("m.move");
+    std::fs::write(&file, "module my_pkg::m {\n}\n").expect("write fixture");
+
+    let run = |extra: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_move-clippy"))
+            .arg(&file)
+            .args(extra)
+            .output()
+            .expect("run move-clippy")
+    };
+
+    let stderr = String::from_utf8(run(&["--timings"]).stderr).expect("utf8 stderr");
+    assert!(stderr.contains("total ms"), "{stderr}");
+    assert!(
+        stderr
+            .lines()
+            .any(|l| l.starts_with("modern_module_syntax ")),
+        "{stderr}"
+    );
+
+    let stderr = String::from_utf8(run(&[]).stderr).expect("utf8 stderr");
+    assert!(!stderr.contains("total ms"), "{stderr}");
+}
+
+#[test]
+fn print_patch_round_trips_through_git_apply() {
+    let sources = [
+        (
+            "sources/a.move",
+            "module my_pkg::a;\n\npublic fun empty(): vector<u64> {\n    vector::empty<u64>()\n}\n",
+        ),
+        (
+            "sources/b.move",
+            "module my_pkg::b;\n\npublic struct B has drop, copy {}\n",
+        ),
+    ];
+    let patched = tempfile::tempdir().expect("tempdir");
+    let fixed = tempfile::tempdir().expect("tempdir");
+    for dir in [&patched, &fixed] {
+        std::fs::create_dir(dir.path().join("sources")).expect("create sources");
+        for (path, source) in sources {
+            std::fs::write(dir.path().join(path), source).expect("write fixture");
+        }
+    }
+
+    let output = Command::new(env!("CARGO_BIN_EXE_move-clippy"))
+        .current_dir(patched.path())
+        .args(["--fix", "--print-patch", "sources"])
+        .output()
+        .expect("run move-clippy");
+    assert!(output.status.success());
+    let patch = String::from_utf8(output.stdout).expect("utf8 stdout");
+    assert!(
+        patch.contains("--- a/sources/a.move\n+++ b/sources/a.move\n"),
+        "{patch}"
+    );
+    assert!(
+        patch.contains("--- a/sources/b.move\n+++ b/sources/b.move\n"),
+        "{patch}"
+    );
+    for (path, source) in sources {
+        let on_disk = std::fs::read_to_string(patched.path().join(path)).expect("read source");
+        assert_eq!(on_disk, source, "--print-patch must not write {path}");
+    }
+    assert!(!patched.path().join("sources/a.move.bak").exists());
+
+    let status = Command::new(env!("CARGO_BIN_EXE_move-clippy"))
+        .current_dir(fixed.path())
+        .args(["--fix", "--no-backup", "sources"])
+        .status()
+        .expect("run move-clippy");
+    assert!(status.success());
+
+    let mut git = Command::new("git")
+        .current_dir(patched.path())
+        .args(["apply", "-"])
+        .stdin(Stdio::piped())
+        .spawn()
+        .expect("run git apply");
+    git.stdin
+        .take()
+        .expect("stdin")
+        .write_all(patch.as_bytes())
+        .expect("write patch");
+    assert!(git.wait().expect("git apply").success(), "{patch}");
+
+    for (path, source) in sources {
+        let applied = std::fs::read_to_string(patched.path().join(path)).expect("read patched");
+        let expected = std::fs::read_to_string(fixed.path().join(path)).expect("read fixed");
+        assert_eq!(applied, expected, "{path}");
+        assert_ne!(applied, source, "{path} should have been fixed");
+    }
+}
+
+#[test]
+fn max_file_size_skips_large_files_with_a_note() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let small = "module my_pkg::small;\n\npublic fun f(): vector<u64> { vector::empty() }\n";
+    let large = format!("{small}{}", "// generated\n".repeat(100));
+    std::fs::write(dir.path().join("small.move"), small).expect("write fixture");
+    std::fs::write(dir.path().join("large.move"), &large).expect("write fixture");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_move-clippy"))
+        .arg(dir.path())
+        .args(["--max-file-size", &(small.len() + 1).to_string()])
+        .output()
+        .expect("run move-clippy");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    let notes: Vec<_> = stdout
+        .lines()
+        .filter(|l| l.contains(": note: file_too_large: skipped: file exceeds max size"))
+        .collect();
+    assert_eq!(notes.len(), 1, "{stdout}");
+    assert!(notes[0].contains("large.move:1:1:"), "{stdout}");
+    assert!(
+        stdout
+            .lines()
+            .any(|l| l.contains("small.move") && l.contains("empty_vector_literal")),
+        "files under the limit are still linted: {stdout}"
+    );
+}
+
+/// The `file_too_large` note does not fail a `--deny-warnings` run.
+#[test]
+fn max_file_size_note_passes_deny_warnings() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        dir.path().join("large.move"),
+        format!("module my_pkg::large;\n{}", "// generated\n".repeat(100)),
+    )
+    .expect("write fixture");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_move-clippy"))
+        .arg(dir.path())
+        .args(["--max-file-size", "64", "--deny-warnings"])
+        .output()
+        .expect("run move-clippy");
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    assert!(stdout.contains(": note: file_too_large:"), "{stdout}");
+    assert!(output.status.success(), "{stdout}");
+}
+
+/// `--stdin-filename` labels stdin diagnostics with the given path in every output format.
+#[test]
+fn stdin_filename_labels_stdin_diagnostics() {
+    let run = |format: &str| {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_move-clippy"))
+            .args(["--format", format, "--only", "modern_module_syntax"])
+            .args(["--stdin-filename", "sources/m.move"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("spawn move-clippy");
+        child
+            .stdin
+            .take()
+            .expect("stdin")
+            .write_all(b"module my_pkg::m {\n}\n")
+            .expect("write stdin");
+        let output = child.wait_with_output().expect("wait for move-clippy");
+        String::from_utf8(output.stdout).expect("utf8 stdout")
+    };
+
+    let pretty = run("pretty");
+    assert!(
+        pretty.contains("sources/m.move:1:1: warning: modern_module_syntax:"),
+        "{pretty}"
+    );
+    assert!(
+        pretty.contains("1 diagnostics for sources/m.move"),
+        "{pretty}"
+    );
+
+    let github = run("github");
+    assert!(github.contains("file=sources/m.move,line=1"), "{github}");
+
+    let json = run("json");
+    let records: serde_json::Value = serde_json::from_str(&json).expect("json");
+    let records = records.as_array().expect("array");
+    assert_eq!(records.len(), 1, "{records:?}");
+    assert_eq!(records[0]["file"], "sources/m.move");
+
+    let status = Command::new(env!("CARGO_BIN_EXE_move-clippy"))
+        .args(["--stdin-filename", "sources/m.move", "sources"])
+        .stderr(Stdio::null())
+        .status()
+        .expect("run move-clippy");
+    assert_eq!(
+        status.code(),
+        Some(2),
+        "--stdin-filename conflicts with PATH"
+    );
+}
+
+/// Unparsable stdin is reported as a `parse_error` diagnostic, which fails the run.
+#[test]
+fn stdin_parse_error_is_reported_as_diagnostic() {
+    let run = |format: &str| {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_move-clippy"))
+            .args(["--format", format])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("spawn move-clippy");
+        child
+            .stdin
+            .take()
+            .expect("stdin")
+            .write_all(b"@@@ ??? ;;;\n")
+            .expect("write stdin");
+        child.wait_with_output().expect("wait for move-clippy")
+    };
+
+    let output = run("json");
+    assert_eq!(
+        output.status.code(),
+        Some(1),
+        "parse errors are error-level"
+    );
+    let json = String::from_utf8(output.stdout).expect("utf8 stdout");
+    let records: serde_json::Value = serde_json::from_str(&json).expect("json");
+    let records = records.as_array().expect("array");
+    assert_eq!(records.len(), 1, "{records:?}");
+    assert_eq!(records[0]["lint"], "parse_error");
+    assert_eq!(records[0]["level"], "error");
+    assert_eq!(records[0]["file"], "stdin");
+
+    let output = run("pretty");
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    assert!(
+        stdout.contains(": error: parse_error: skipped: syntax error near"),
+        "{stdout}"
+    );
+}
+
+/// `--continue-on-error` reports a package that fails to compile as a note instead of
+/// aborting the run.
+#[test]
+fn continue_on_error_reports_compile_failure_as_note() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        dir.path().join("Move.toml"),
+        "[package]\nname = \"broken\"\nedition = \"2024\"\n\n[addresses]\nbroken = \"0x0\"\n",
+    )
+    .expect("write manifest");
+    std::fs::create_dir(dir.path().join("sources")).expect("create sources");
+    std::fs::write(
+        dir.path().join("sources/broken.move"),
+        "module broken::m;\n\npublic fun f(): u64 { true }\n",
+    )
+    .expect("write fixture");
+
+    let run = |extra: &[&str]| {
+        Command::new(env!("CARGO_BIN_EXE_move-clippy"))
+            .args(["--mode", "full"])
+            .args(extra)
+            .arg(dir.path())
+            .output()
+            .expect("run move-clippy")
+    };
+
+    let output = run(&[]);
+    assert_eq!(
+        output.status.code(),
+        Some(2),
+        "compile failures abort by default"
+    );
+
+    let output = run(&["--continue-on-error"]);
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    assert!(output.status.success(), "{stdout}");
+    let note = stdout
+        .lines()
+        .find(|l| l.contains(": note: package_compile_failed: skipped semantic lints: "))
+        .unwrap_or_else(|| panic!("expected a compile failure note: {stdout}"));
+    assert!(
+        note.starts_with(&format!("{}:1:1:", dir.path().display())),
+        "{note}"
+    );
+
+    let output = run(&["--continue-on-error", "--deny-warnings"]);
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    assert!(
+        output.status.success(),
+        "the compile failure note is not a warning: {stdout}"
+    );
+}
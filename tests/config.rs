@@ -1,7 +1,7 @@
 use move_clippy::LintEngine;
 use move_clippy::config;
 use move_clippy::level::LintLevel;
-use move_clippy::lint::{LintRegistry, LintSettings};
+use move_clippy::lint::{LintRegistry, LintSettings, Thresholds, resolve_lint_alias_with};
 use std::path::Path;
 
 #[test]
@@ -30,6 +30,38 @@ fn config_can_promote_lint_to_error() {
     );
 }
 
+#[test]
+fn config_can_set_lint_to_deny() {
+    let cfg_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/config/deny_level/move-clippy.toml");
+    let cfg = config::load_config_file(&cfg_path).expect("config should load");
+
+    let empty: Vec<String> = Vec::new();
+    let registry = LintRegistry::default_rules_filtered(
+        &empty,
+        &empty,
+        &cfg.lints.disabled,
+        false,
+        cfg.lints.preview,
+    )
+    .expect("registry");
+    let settings = LintSettings::default().with_config_levels(cfg.lints.levels);
+    let engine = LintEngine::new_with_settings(registry, settings);
+
+    let src = include_str!("fixtures/prefer_vector_methods/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+
+    let denied = diags
+        .iter()
+        .find(|d| d.lint.name == "prefer_vector_methods")
+        .expect("prefer_vector_methods should fire");
+    // Deny stays distinguishable from a hard error but still fails the run.
+    assert_eq!(denied.level, LintLevel::Deny);
+    assert_eq!(denied.level.as_str(), "deny");
+    assert!(denied.level.fails_run());
+    assert!(!LintLevel::Warn.fails_run());
+}
+
 #[test]
 fn config_can_disable_lint() {
     let cfg_path = Path::new(env!("CARGO_MANIFEST_DIR"))
@@ -51,3 +83,414 @@ fn config_can_disable_lint() {
 
     assert!(!diags.iter().any(|d| d.lint.name == "prefer_vector_methods"));
 }
+
+#[test]
+fn config_known_addresses_merge_with_defaults() {
+    let cfg_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/config/known_addresses/move-clippy.toml");
+    let cfg = config::load_config_file(&cfg_path).expect("config should load");
+
+    // The table is not mistaken for a lint level.
+    assert_eq!(cfg.lints.levels.len(), 1);
+
+    let settings = LintSettings::default()
+        .with_config_levels(cfg.lints.levels)
+        .with_known_addresses(cfg.lints.known_addresses.into_values());
+
+    // Built-in framework defaults
+    assert!(settings.is_known_address("@0x2"));
+    assert!(
+        settings
+            .is_known_address("0x0000000000000000000000000000000000000000000000000000000000000001")
+    );
+    // Configured addresses, compared case- and zero-padding-insensitively
+    assert!(settings.is_known_address("@0xDEE9"));
+    assert!(settings.is_known_address("@0x8d9"));
+    // Anything else is unknown
+    assert!(!settings.is_known_address("@0xabc"));
+}
+
+#[test]
+fn config_known_addresses_silence_hardcoded_address() {
+    let cfg_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/config/known_addresses/move-clippy.toml");
+    let cfg = config::load_config_file(&cfg_path).expect("config should load");
+
+    let src = r#"
+module example::pool {
+    public fun venues(): vector<address> {
+        vector[@0xdee9, @0xabc]
+    }
+}
+"#;
+    let lint = |settings: LintSettings| -> Vec<String> {
+        let empty: Vec<String> = Vec::new();
+        let registry = LintRegistry::default_rules_filtered(
+            &["hardcoded_address".to_string()],
+            &empty,
+            &empty,
+            false,
+            true,
+        )
+        .expect("registry");
+        LintEngine::new_with_settings(registry, settings)
+            .lint_source(src)
+            .expect("linting should succeed")
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    };
+
+    let without = lint(LintSettings::default());
+    assert_eq!(without.len(), 2, "{without:?}");
+
+    let with =
+        lint(LintSettings::default().with_known_addresses(cfg.lints.known_addresses.into_values()));
+    assert_eq!(with.len(), 1, "{with:?}");
+    assert!(with[0].contains("@0xabc"), "{with:?}");
+}
+
+#[test]
+fn config_category_level_sets_default_for_lints_in_category() {
+    let cfg_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/config/category_levels/move-clippy.toml");
+    let cfg = config::load_config_file(&cfg_path).expect("config should load");
+
+    // The table is not mistaken for a lint level.
+    assert_eq!(cfg.lints.levels.len(), 1);
+
+    let empty: Vec<String> = Vec::new();
+    let registry = LintRegistry::default_rules_filtered(&empty, &empty, &empty, false, false)
+        .expect("registry");
+    let settings = LintSettings::default()
+        .with_config_levels(cfg.lints.levels.clone())
+        .with_category_levels(cfg.lints.parsed_category_levels().expect("categories"));
+
+    // Category default applies when no per-lint level is set...
+    assert_eq!(
+        settings.level_for("prefer_vector_methods"),
+        LintLevel::Error
+    );
+    // ...but an explicit per-lint level wins.
+    assert_eq!(settings.level_for("modern_method_syntax"), LintLevel::Warn);
+    // Other categories keep the built-in default.
+    assert_eq!(settings.level_for("abilities_order"), LintLevel::Warn);
+
+    let engine = LintEngine::new_with_settings(registry, settings);
+    let src = include_str!("fixtures/prefer_vector_methods/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.lint.name == "prefer_vector_methods" && d.level == LintLevel::Error)
+    );
+}
+
+#[test]
+fn with_severity_overrides_level_below_directives() {
+    let mut settings = LintSettings::default()
+        .with_config_levels([("prefer_vector_methods".to_string(), LintLevel::Allow)].into());
+    settings
+        .with_severity("prefer_vector_methods", LintLevel::Error)
+        .expect("known lint");
+    assert!(
+        settings
+            .with_severity("not_a_real_lint", LintLevel::Error)
+            .is_err()
+    );
+
+    let empty: Vec<String> = Vec::new();
+    let registry = LintRegistry::default_rules_filtered(&empty, &empty, &empty, false, false)
+        .expect("registry");
+    let engine = LintEngine::new_with_settings(registry, settings);
+
+    let src = include_str!("fixtures/prefer_vector_methods/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    assert!(
+        diags
+            .iter()
+            .any(|d| d.lint.name == "prefer_vector_methods" && d.level == LintLevel::Error)
+    );
+
+    // A module directive still wins over the programmatic override.
+    let allowed = format!("#![allow(lint::prefer_vector_methods)]\n{src}");
+    let diags = engine
+        .lint_source(&allowed)
+        .expect("linting should succeed");
+    assert!(!diags.iter().any(|d| d.lint.name == "prefer_vector_methods"));
+}
+
+#[test]
+fn config_category_levels_reject_unknown_category() {
+    let cfg: config::MoveClippyConfig =
+        toml::from_str("[lints.category_levels]\nsecurty = \"error\"\n").expect("config parses");
+    assert!(cfg.lints.parsed_category_levels().is_err());
+}
+
+#[test]
+fn config_discovery_merges_nested_configs_nearest_first() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/config/inherit");
+    let sub = root.join("sub");
+
+    let paths = config::find_config_files(&sub);
+    assert_eq!(
+        paths[..2],
+        [sub.join(".move-clippy.toml"), root.join("move-clippy.toml")]
+    );
+
+    let cfg = config::load_merged_config(&paths[..2]).expect("configs should merge");
+    // Disabled lists are unioned.
+    assert_eq!(
+        cfg.lints.disabled,
+        vec![
+            "abilities_order".to_string(),
+            "while_true_to_loop".to_string()
+        ]
+    );
+    // Levels: the nearer file wins, farther-only entries are inherited.
+    assert_eq!(
+        cfg.lints.levels.get("prefer_vector_methods"),
+        Some(&LintLevel::Warn)
+    );
+    assert_eq!(
+        cfg.lints.levels.get("modern_method_syntax"),
+        Some(&LintLevel::Error)
+    );
+    // Preview is OR-ed: enabled by the root even though `sub` does not set it.
+    assert!(cfg.lints.preview);
+    assert_eq!(
+        cfg.lints
+            .known_addresses
+            .get("deepbook")
+            .map(String::as_str),
+        Some("0xdee8")
+    );
+
+    // Discovery from the root directory only sees the root config.
+    let (nearest, root_cfg) = config::load_config(None, &root)
+        .expect("config should load")
+        .expect("config should be found");
+    assert_eq!(nearest, root.join("move-clippy.toml"));
+    assert_eq!(
+        root_cfg.lints.levels.get("prefer_vector_methods"),
+        Some(&LintLevel::Error)
+    );
+}
+
+#[test]
+fn config_explicit_path_disables_discovery() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/config/inherit");
+    let explicit = root.join("sub/.move-clippy.toml");
+
+    let (path, cfg) = config::load_config(Some(&explicit), &root.join("sub"))
+        .expect("config should load")
+        .expect("explicit config is always returned");
+    assert_eq!(path, explicit);
+    assert_eq!(cfg.lints.disabled, vec!["while_true_to_loop".to_string()]);
+    assert!(!cfg.lints.preview);
+    assert!(!cfg.lints.levels.contains_key("modern_method_syntax"));
+}
+
+#[test]
+fn config_template_parses_back_as_default_config() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        dir.path().join(config::HIDDEN_CONFIG_FILE_NAME),
+        config::config_template(),
+    )
+    .expect("write template");
+
+    let (path, cfg) = config::load_config(None, dir.path())
+        .expect("template should parse")
+        .expect("template should be discovered");
+    assert_eq!(path, dir.path().join(config::HIDDEN_CONFIG_FILE_NAME));
+    assert!(!cfg.lints.preview);
+    assert!(!cfg.lints.unsafe_fixes);
+    assert!(cfg.lints.disabled.is_empty());
+    assert!(cfg.lints.levels.is_empty());
+    assert!(cfg.lints.parsed_category_levels().unwrap().is_empty());
+    assert!(cfg.lints.known_addresses.is_empty());
+    assert_eq!(cfg.lints.thresholds, Thresholds::default());
+    assert!(cfg.aliases.is_empty());
+}
+
+#[test]
+fn config_template_examples_parse_when_uncommented() {
+    let uncommented: String = config::config_template()
+        .lines()
+        .map(|line| match line.strip_prefix("# ") {
+            Some(rest) if rest.contains(" = ") => rest,
+            _ => line,
+        })
+        .filter(|line| !line.starts_with("disabled = []"))
+        .map(|line| format!("{line}\n"))
+        .collect();
+    let cfg: config::MoveClippyConfig = toml::from_str(&uncommented).expect("examples parse");
+    assert_eq!(cfg.lints.disabled.len(), 1);
+    assert_eq!(cfg.lints.levels.len(), 1);
+    assert_eq!(cfg.lints.parsed_category_levels().unwrap().len(), 1);
+    assert_eq!(cfg.lints.known_addresses.len(), 1);
+    assert_eq!(cfg.lints.thresholds.entry_max_params, Some(8));
+    assert_eq!(cfg.lints.thresholds.max_nesting_depth, Some(5));
+    assert_eq!(cfg.lints.thresholds.max_file_size, Some(1_048_576));
+    assert_eq!(cfg.aliases.len(), 1);
+    cfg.validate_aliases()
+        .expect("example alias targets a known lint");
+}
+
+#[test]
+fn config_aliases_resolve_to_canonical_lints() {
+    let cfg: config::MoveClippyConfig = toml::from_str(
+        "[aliases]
+vecs = \"prefer_vector_methods\"\n",
+    )
+    .expect("config parses");
+    cfg.validate_aliases().expect("alias targets a known lint");
+    // The table is not mistaken for lint settings.
+    assert!(cfg.lints.levels.is_empty());
+
+    assert_eq!(
+        resolve_lint_alias_with("vecs", &cfg.aliases),
+        "prefer_vector_methods"
+    );
+    assert_eq!(
+        resolve_lint_alias_with("abilities_order", &cfg.aliases),
+        "abilities_order"
+    );
+}
+
+#[test]
+fn config_aliases_reject_unknown_targets_at_load() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join(config::DEFAULT_CONFIG_FILE_NAME);
+
+    std::fs::write(&path, "[aliases]\nvecs = \"prefer_vectr_methods\"\n").expect("write config");
+    let err = config::load_config_file(&path).expect_err("unknown target should be rejected");
+    assert!(
+        format!("{err:#}")
+            .contains("alias `vecs` in [aliases] points at unknown lint `prefer_vectr_methods`"),
+        "{err:#}"
+    );
+
+    std::fs::write(
+        &path,
+        "[aliases]\nabilities_order = \"prefer_vector_methods\"\n",
+    )
+    .expect("write config");
+    let err = config::load_config_file(&path).expect_err("shadowing alias should be rejected");
+    assert!(format!("{err:#}").contains("shadows"), "{err:#}");
+}
+
+#[test]
+fn config_thresholds_set_entry_max_params() {
+    let cfg: config::MoveClippyConfig =
+        toml::from_str("[lints.thresholds]\nentry_max_params = 12\n").expect("config parses");
+    // The table is not mistaken for a lint level.
+    assert!(cfg.lints.levels.is_empty());
+    assert_eq!(cfg.lints.thresholds.entry_max_params, Some(12));
+
+    assert_eq!(LintSettings::default().entry_max_params(), 8);
+    let settings = LintSettings::default().with_entry_max_params(12);
+    assert_eq!(settings.entry_max_params(), 12);
+
+    // Nearer files override the limit; unset keys are inherited.
+    let farther = cfg;
+    let nearer: config::MoveClippyConfig =
+        toml::from_str("[lints.thresholds]\nentry_max_params = 4\n").expect("config parses");
+    assert_eq!(
+        farther.merge(nearer).lints.thresholds.entry_max_params,
+        Some(4)
+    );
+    let farther: config::MoveClippyConfig =
+        toml::from_str("[lints.thresholds]\nentry_max_params = 12\n").expect("config parses");
+    let merged = farther.merge(config::MoveClippyConfig::default());
+    assert_eq!(merged.lints.thresholds.entry_max_params, Some(12));
+
+    assert!(
+        toml::from_str::<config::MoveClippyConfig>("[lints.thresholds]\nentry_max_param = 3\n")
+            .is_err(),
+        "misspelled threshold keys are rejected"
+    );
+}
+
+#[test]
+fn config_thresholds_change_which_files_are_linted() {
+    let cfg: config::MoveClippyConfig =
+        toml::from_str("[lints.thresholds]\nmax_file_size = 16\nmax_nesting_depth = 2\n")
+            .expect("config parses");
+    assert_eq!(
+        cfg.lints.thresholds,
+        Thresholds {
+            entry_max_params: None,
+            max_nesting_depth: Some(2),
+            max_file_size: Some(16),
+        }
+    );
+
+    let settings = LintSettings::default().with_thresholds(cfg.lints.thresholds);
+    assert_eq!(settings.max_file_size(), Some(16));
+    assert_eq!(settings.max_nesting_depth(), 2);
+    // Unset limits keep their defaults.
+    assert_eq!(settings.entry_max_params(), 8);
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("big.move");
+    std::fs::write(&path, "module 0x1::big;\n\npublic fun f() {}\n").expect("write source");
+
+    let default_engine =
+        LintEngine::new_with_settings(LintRegistry::default_rules(), LintSettings::default());
+    assert!(default_engine.oversized_file_note(&path).unwrap().is_none());
+
+    let engine = LintEngine::new_with_settings(LintRegistry::default_rules(), settings);
+    let note = engine
+        .oversized_file_note(&path)
+        .unwrap()
+        .expect("file over the configured limit is skipped");
+    assert!(note.message.contains("limit 16"), "{}", note.message);
+}
+
+#[test]
+fn config_check_reports_unknown_names_with_lines() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join(config::DEFAULT_CONFIG_FILE_NAME);
+    std::fs::write(
+        &path,
+        "[lint]\npreview = true\n\n[lints]\ndisabled = [\"abilities_order\", \"abilites_order\"]\nprefer_vectr_methods = \"error\"\n\n[lints.category_levels]\nsecurty = \"deny\"\n",
+    )
+    .expect("write config");
+
+    let problems = config::check_config_file(&path).expect("config parses");
+    let summary: Vec<_> = problems
+        .iter()
+        .map(|p| (p.line, p.is_error, p.message.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (Some(1), false, "unknown key `lint`"),
+            (
+                Some(5),
+                true,
+                "unknown lint: abilites_order (in `disabled`)"
+            ),
+            (
+                Some(6),
+                true,
+                "unknown lint: prefer_vectr_methods (in `[lints]`)"
+            ),
+            (
+                Some(9),
+                true,
+                "unknown lint category: securty (in `[lints.category_levels]`)"
+            ),
+        ]
+    );
+
+    std::fs::write(&path, config::config_template()).expect("write template");
+    assert!(
+        config::check_config_file(&path)
+            .expect("template parses")
+            .is_empty()
+    );
+}
@@ -0,0 +1,112 @@
+//! Spec tests for the `entry_function_param_count` lint.
+//!
+//! The lint fires when an `entry` function takes more than `entry_max_params` parameters
+//! (default 8), not counting `TxContext` or `Clock`.
+//!
+//! Test matrix (default limit):
+//! | # | Counted params | TxContext/Clock | Expected |
+//! |---|----------------|-----------------|----------|
+//! | 0 | 8              | no              | NO       |
+//! | 1 | 9              | no              | WARN     |
+//! | 2 | 8              | both            | NO       |
+//! | 3 | 9              | not entry       | NO       |
+
+#[cfg(feature = "full")]
+mod support;
+
+#[cfg(feature = "full")]
+mod full {
+    use crate::support::semantic_spec_harness::create_temp_package;
+    use move_clippy::diagnostics::Diagnostic;
+    use move_clippy::lint::LintSettings;
+    use move_clippy::semantic;
+
+    const MOVE_TOML: &str = r#"
+[package]
+name = "spec_test"
+edition = "2024"
+
+[addresses]
+spec_test = "0x0"
+sui = "0x2"
+"#;
+
+    const SOURCE: &str = r#"
+module sui::tx_context {
+    public struct TxContext has drop {}
+}
+
+module sui::object {
+    public struct UID has store {
+        id: address,
+    }
+}
+
+module sui::clock {
+    use sui::object::UID;
+
+    public struct Clock has key {
+        id: UID,
+    }
+}
+
+module spec_test::params {
+    use sui::clock::Clock;
+    use sui::tx_context::TxContext;
+
+    // Case 0: exactly at the limit - NO WARN
+    public entry fun at_limit(a: u64, b: u64, c: u64, d: u64, e: u64, f: u64, g: u64, h: u64) {}
+
+    // Case 1: one over the limit - WARN
+    public entry fun over_limit(
+        a: u64, b: u64, c: u64, d: u64, e: u64, f: u64, g: u64, h: u64, i: u64,
+    ) {}
+
+    // Case 2: at the limit plus runtime-supplied params - NO WARN
+    public entry fun with_clock_and_ctx(
+        a: u64, b: u64, c: u64, d: u64, e: u64, f: u64, g: u64, h: u64,
+        clock: &Clock, ctx: &mut TxContext,
+    ) {}
+
+    // Case 3: over the limit but not entry - NO WARN
+    public fun not_entry(
+        a: u64, b: u64, c: u64, d: u64, e: u64, f: u64, g: u64, h: u64, i: u64,
+    ) {}
+}
+"#;
+
+    fn param_count_diags(settings: &LintSettings) -> Vec<Diagnostic> {
+        let temp_dir =
+            create_temp_package(MOVE_TOML, &[("main.move", SOURCE)]).expect("setup should succeed");
+        semantic::lint_package(temp_dir.path(), settings, true, false)
+            .expect("lint_package should succeed")
+            .into_iter()
+            .filter(|d| d.lint.name == "entry_function_param_count")
+            .collect()
+    }
+
+    #[test]
+    fn spec_entry_function_param_count_default_limit() {
+        let diags = param_count_diags(&LintSettings::default());
+
+        assert_eq!(diags.len(), 1, "expected only over_limit: {diags:#?}");
+        let msg = &diags[0].message;
+        assert!(msg.contains("`over_limit`"), "got: {msg}");
+        assert!(msg.contains("takes 9 parameters"), "got: {msg}");
+        assert!(msg.contains("limit 8"), "got: {msg}");
+    }
+
+    #[test]
+    fn spec_entry_function_param_count_configured_limit() {
+        let settings = LintSettings::default().with_entry_max_params(10);
+        assert!(param_count_diags(&settings).is_empty());
+
+        let settings = LintSettings::default().with_entry_max_params(7);
+        let mut names: Vec<_> = param_count_diags(&settings)
+            .iter()
+            .map(|d| d.message.split('`').nth(1).unwrap_or_default().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(names, ["at_limit", "over_limit", "with_clock_and_ctx"]);
+    }
+}
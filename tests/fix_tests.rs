@@ -20,7 +20,7 @@ fn get_first_fix(source: &str) -> Option<String> {
     diagnostics
         .into_iter()
         .filter(|d| d.lint.name != "modern_module_syntax")
-        .find_map(|d| d.suggestion.map(|s| s.replacement))
+        .find_map(|d| d.suggestions.into_iter().next().map(|s| s.replacement))
 }
 
 /// Helper to apply the first fix suggestion found.
@@ -32,7 +32,7 @@ fn apply_first_fix(source: &str) -> Option<String> {
     let diagnostics = engine.lint_source(source).unwrap();
 
     for diag in diagnostics {
-        if let Some(suggestion) = diag.suggestion {
+        if let Some(suggestion) = diag.suggestions.into_iter().next() {
             // The suggestion.replacement contains the full replacement text
             // We need to construct a TextEdit based on the diagnostic span
             let edit = TextEdit::replace(
@@ -284,6 +284,46 @@ fn modern_method_syntax_table() {
     );
 }
 
+#[test]
+fn modern_method_syntax_resolves_framework_alias() {
+    let source = r#"
+        module test::m {
+            use sui::coin::{Self as c, Coin};
+            fun test(coin: &Coin<SUI>): u64 {
+                c::value(coin)
+            }
+        }
+    "#;
+
+    let fix = get_first_fix(source);
+    assert!(
+        fix.is_some_and(|fix| fix.contains("coin.value()")),
+        "An alias of `sui::coin` should still get method syntax"
+    );
+}
+
+#[test]
+fn modern_method_syntax_skips_shadowing_alias() {
+    let source = r#"
+        module test::m {
+            use test::fake_coin as coin;
+            fun test(c: &Thing): u64 {
+                coin::value(c)
+            }
+        }
+    "#;
+
+    let registry = LintRegistry::default_rules();
+    let engine = LintEngine::new_with_settings(registry, LintSettings::default());
+    let diagnostics = engine.lint_source(source).unwrap();
+    assert!(
+        !diagnostics
+            .iter()
+            .any(|d| d.lint.name == "modern_method_syntax"),
+        "`coin` here is not `sui::coin`, so method syntax could call a different function"
+    );
+}
+
 // ============================================================================
 // prefer_vector_methods Tests
 // ============================================================================
@@ -419,8 +459,8 @@ fn empty_vector_fix_is_machine_applicable() {
     for diag in diagnostics {
         if diag.lint.name == "empty_vector_literal" {
             found_empty_vector = true;
-            assert!(diag.suggestion.is_some(), "Should have a suggestion");
-            let suggestion = diag.suggestion.unwrap();
+            assert_eq!(diag.suggestions.len(), 1, "Should have one suggestion");
+            let suggestion = diag.suggestions.into_iter().next().unwrap();
             assert_eq!(
                 suggestion.applicability,
                 move_clippy::diagnostics::Applicability::MachineApplicable,
@@ -606,3 +646,148 @@ fn unneeded_return_no_fix_for_implicit_return() {
         "Implicit return should not trigger lint"
     );
 }
+
+// ============================================================================
+// redundant_binding Tests
+// ============================================================================
+
+#[test]
+fn redundant_binding_fix_removes_statement_lines() {
+    let source = "module example::test;\n\npublic fun f(x: u64): u64 {\n    let x = x;\n    x\n}\n";
+    let registry = LintRegistry::default_rules_filtered(
+        &["redundant_binding".to_string()],
+        &[],
+        &[],
+        false,
+        true,
+    )
+    .unwrap();
+    let engine = LintEngine::new_with_settings(registry, LintSettings::default());
+    let diagnostics = engine.lint_source(source).unwrap();
+    assert_eq!(diagnostics.len(), 1);
+
+    let result = move_clippy::fixer::apply_fixes(source, &diagnostics, false).unwrap();
+    assert_eq!(
+        result.fixed_source,
+        "module example::test;\n\npublic fun f(x: u64): u64 {\n    x\n}\n"
+    );
+}
+
+// ============================================================================
+// needless_bool Tests
+// ============================================================================
+
+#[test]
+fn needless_bool_fix_negates_condition() {
+    let source = "module example::test;\n\npublic fun f(a: bool, b: bool): bool {\n    if (!a && b) false else true\n}\n";
+    let registry =
+        LintRegistry::default_rules_filtered(&["needless_bool".to_string()], &[], &[], false, true)
+            .unwrap();
+    let engine = LintEngine::new_with_settings(registry, LintSettings::default());
+    let diagnostics = engine.lint_source(source).unwrap();
+    assert_eq!(diagnostics.len(), 1);
+
+    let result = move_clippy::fixer::apply_fixes(source, &diagnostics, false).unwrap();
+    assert_eq!(
+        result.fixed_source,
+        "module example::test;\n\npublic fun f(a: bool, b: bool): bool {\n    !(!a && b)\n}\n"
+    );
+}
+
+// ============================================================================
+// manual_vector_build Tests
+// ============================================================================
+
+#[test]
+fn manual_vector_build_fix_replaces_pushes_with_literal() {
+    let source = "module example::test;\n\npublic fun f(a: u64, b: u64): vector<u64> {\n    let mut v = vector::empty();\n    vector::push_back(&mut v, a);\n    v.push_back(b);\n    v\n}\n";
+    let registry = LintRegistry::default_rules_filtered(
+        &["manual_vector_build".to_string()],
+        &[],
+        &[],
+        false,
+        true,
+    )
+    .unwrap();
+    let engine = LintEngine::new_with_settings(registry, LintSettings::default());
+    let diagnostics = engine.lint_source(source).unwrap();
+    assert_eq!(diagnostics.len(), 1);
+
+    let result = move_clippy::fixer::apply_fixes(source, &diagnostics, false).unwrap();
+    assert_eq!(
+        result.fixed_source,
+        "module example::test;\n\npublic fun f(a: u64, b: u64): vector<u64> {\n    let v = vector[a, b];\n    v\n}\n"
+    );
+}
+
+#[test]
+fn manual_vector_build_fix_keeps_mut_when_mutated_later() {
+    let source = "module example::test;\n\npublic fun f(a: u64): vector<u64> {\n    let mut v = vector::empty();\n    v.push_back(a);\n    vector::reverse(&mut v);\n    v\n}\n";
+    let registry = LintRegistry::default_rules_filtered(
+        &["manual_vector_build".to_string()],
+        &[],
+        &[],
+        false,
+        true,
+    )
+    .unwrap();
+    let engine = LintEngine::new_with_settings(registry, LintSettings::default());
+    let diagnostics = engine.lint_source(source).unwrap();
+    assert_eq!(diagnostics.len(), 1);
+
+    let result = move_clippy::fixer::apply_fixes(source, &diagnostics, false).unwrap();
+    assert_eq!(
+        result.fixed_source,
+        "module example::test;\n\npublic fun f(a: u64): vector<u64> {\n    let mut v = vector[a];\n    vector::reverse(&mut v);\n    v\n}\n"
+    );
+}
+
+// ============================================================================
+// bool_comparison Tests
+// ============================================================================
+
+#[test]
+fn bool_comparison_fix_keeps_precedence() {
+    let source =
+        "module example::test;\n\npublic fun f(a: bool, b: bool): bool {\n    a && b == false\n}\n";
+    let registry = LintRegistry::default_rules_filtered(
+        &["bool_comparison".to_string()],
+        &[],
+        &[],
+        false,
+        true,
+    )
+    .unwrap();
+    let engine = LintEngine::new_with_settings(registry, LintSettings::default());
+    let diagnostics = engine.lint_source(source).unwrap();
+    assert_eq!(diagnostics.len(), 1);
+
+    let result = move_clippy::fixer::apply_fixes(source, &diagnostics, false).unwrap();
+    assert_eq!(
+        result.fixed_source,
+        "module example::test;\n\npublic fun f(a: bool, b: bool): bool {\n    a && !b\n}\n"
+    );
+}
+
+#[test]
+fn bool_comparison_fix_after_non_ascii_literal() {
+    // Columns are byte offsets, so multi-byte text earlier on the line must not shift the edit.
+    let source = "module example::test;\n\npublic fun f(a: bool, b: bool): bool {\n    let _s = b\"🦀é\"; a && b == false\n}\n";
+    let registry = LintRegistry::default_rules_filtered(
+        &["bool_comparison".to_string()],
+        &[],
+        &[],
+        false,
+        true,
+    )
+    .unwrap();
+    let engine = LintEngine::new_with_settings(registry, LintSettings::default());
+    let diagnostics = engine.lint_source(source).unwrap();
+    assert_eq!(diagnostics.len(), 1);
+
+    let result = move_clippy::fixer::apply_fixes(source, &diagnostics, false).unwrap();
+    assert_eq!(
+        result.fixed_source,
+        "module example::test;\n\npublic fun f(a: bool, b: bool): bool {\n    let _s = b\"🦀é\"; a && !b\n}\n"
+    );
+}
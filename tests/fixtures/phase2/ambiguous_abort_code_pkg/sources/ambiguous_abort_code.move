@@ -0,0 +1,58 @@
+// Test fixture for ambiguous_abort_code lint
+
+module ambiguous_abort_code_pkg::vault {
+    const E_INVALID: u64 = 0;
+    const E_ZERO_AMOUNT: u64 = 1;
+    const E_NOT_OWNER: u64 = 2;
+    const E_OUT_OF_RANGE: u64 = 3;
+
+    const MIN_AMOUNT: u64 = 10;
+    const MAX_AMOUNT: u64 = 1000;
+
+    public struct Vault has drop {
+        owner: address,
+        balance: u64,
+    }
+
+    // SHOULD WARN: E_INVALID guards both a zero-check and an ownership check
+    public fun withdraw(vault: &mut Vault, amount: u64, sender: address) {
+        assert!(amount > 0, E_INVALID);
+        assert!(vault.owner == sender, E_INVALID);
+        vault.balance = vault.balance - amount;
+    }
+
+    // SHOULD WARN: same ambiguity with `if (..) abort`
+    public fun deposit(vault: &mut Vault, amount: u64, sender: address) {
+        if (amount == 0) abort E_INVALID;
+        if (vault.owner != sender) {
+            abort E_INVALID
+        };
+        vault.balance = vault.balance + amount;
+    }
+
+    // SHOULD WARN: a literal code, written with and without a suffix, guards both checks
+    public fun transfer_to(vault: &mut Vault, amount: u64, sender: address) {
+        assert!(amount > 0, 7);
+        assert!(vault.owner == sender, 7u64);
+        vault.balance = vault.balance - amount;
+    }
+
+    // SHOULD NOT WARN: each failure mode has its own code
+    public fun withdraw_distinct(vault: &mut Vault, amount: u64, sender: address) {
+        assert!(amount > 0, E_ZERO_AMOUNT);
+        assert!(vault.owner == sender, E_NOT_OWNER);
+        vault.balance = vault.balance - amount;
+    }
+
+    // SHOULD NOT WARN: both halves of a range check on the same value
+    public fun set_balance(vault: &mut Vault, amount: u64) {
+        assert!(amount >= MIN_AMOUNT, E_OUT_OF_RANGE);
+        assert!(amount <= MAX_AMOUNT, E_OUT_OF_RANGE);
+        vault.balance = amount;
+    }
+
+    // SHOULD NOT WARN: the code is reused across functions, not within one
+    public fun check_owner(vault: &Vault, sender: address) {
+        assert!(vault.owner == sender, E_INVALID);
+    }
+}
@@ -0,0 +1,60 @@
+// Test fixture for capability_passthrough lint
+
+module sui::object {
+    public struct UID has store {
+        id: address,
+    }
+}
+
+module capability_passthrough_pkg::admin {
+    use sui::object::UID;
+
+    public struct AdminCap has key, store {
+        id: UID,
+    }
+
+    public struct Config has key, store {
+        id: UID,
+        fee: u64,
+    }
+
+    // Positive: capability comes back untouched
+    public fun relay(cap: AdminCap): AdminCap {
+        cap
+    }
+
+    // Positive: returned untouched alongside another value
+    public fun relay_with_fee(cap: AdminCap, fee: u64): (AdminCap, u64) {
+        let doubled = fee * 2;
+        (cap, doubled)
+    }
+
+    // Negative: capability gates a mutation before being returned
+    public fun set_fee(cap: AdminCap, config: &mut Config, fee: u64): AdminCap {
+        check(&cap);
+        config.fee = fee;
+        cap
+    }
+
+    // Negative: not public
+    fun internal_relay(cap: AdminCap): AdminCap {
+        cap
+    }
+
+    // Negative: taken by reference
+    public fun peek(cap: &AdminCap): &AdminCap {
+        cap
+    }
+
+    // Negative: taken by mutable reference
+    public fun peek_mut(cap: &mut AdminCap): &mut AdminCap {
+        cap
+    }
+
+    fun check(_cap: &AdminCap) {}
+
+    // Negative: handed to another call rather than returned directly
+    public fun use_internal(cap: AdminCap): AdminCap {
+        internal_relay(cap)
+    }
+}
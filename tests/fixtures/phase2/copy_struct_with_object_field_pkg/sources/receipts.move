@@ -0,0 +1,63 @@
+// Test fixture for copy_struct_with_object_field lint
+
+module sui::object {
+    public struct UID has store {
+        id: address,
+    }
+
+    public struct ID has copy, drop, store {
+        bytes: address,
+    }
+}
+
+module copy_struct_with_object_field_pkg::receipts {
+    use sui::object::{ID, UID};
+
+    public struct Pool has key {
+        id: UID,
+        reserve: u64,
+    }
+
+    /// Carries a type tag without holding a value.
+    public struct Marker<phantom T> has copy, drop, store {}
+
+    /// Typed object ID: the `key` bound is on the phantom parameter, not a field.
+    public struct TypedId<phantom T: key> has copy, drop, store {
+        id: ID,
+    }
+
+    // SHOULD WARN: phantom argument names a `key` object
+    public struct PoolReceipt has copy, drop {
+        pool: Marker<Pool>,
+        amount: u64,
+    }
+
+    // SHOULD WARN: field of a type parameter constrained by `key`
+    public struct Snapshot<T: key + copy> has copy, drop {
+        object: T,
+    }
+
+    // SHOULD NOT WARN: stores the pool's ID
+    public struct PoolRef has copy, drop {
+        pool_id: ID,
+    }
+
+    // SHOULD NOT WARN: not `copy`
+    public struct Position has store {
+        pool: Marker<Pool>,
+    }
+
+    // SHOULD NOT WARN: unconstrained type parameter
+    public struct Pair<T: copy> has copy, drop {
+        left: T,
+        right: T,
+    }
+
+    public fun receipt(amount: u64): PoolReceipt {
+        PoolReceipt { pool: Marker {}, amount }
+    }
+
+    public fun typed_id(id: ID): TypedId<Pool> {
+        TypedId { id }
+    }
+}
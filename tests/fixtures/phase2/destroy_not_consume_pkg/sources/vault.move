@@ -0,0 +1,55 @@
+// Test fixture for destroy_function_does_not_consume lint
+
+module sui::object {
+    public struct UID has store {
+        id: address,
+    }
+
+    public fun delete(id: UID) {
+        let UID { id: _ } = id;
+    }
+}
+
+module destroy_not_consume_pkg::vault {
+    use sui::object::{Self, UID};
+
+    public struct Vault has key {
+        id: UID,
+        amount: u64,
+    }
+
+    public struct Ticket has drop {
+        amount: u64,
+    }
+
+    // SHOULD FIRE: takes `&Vault`, so it cannot destroy it.
+    public fun destroy(vault: &Vault): u64 {
+        vault.amount
+    }
+
+    // SHOULD FIRE: takes `Vault` by value but only hands back its parts via a getter.
+    public fun destroy_empty(vault: Vault): Vault {
+        assert!(vault.amount == 0, 0);
+        vault
+    }
+
+    // SHOULD NOT FIRE: unpacks and deletes.
+    public fun delete(vault: Vault): u64 {
+        let Vault { id, amount } = vault;
+        object::delete(id);
+        amount
+    }
+
+    // SHOULD NOT FIRE: hands the value to another destroy function.
+    public fun destroy_zero(vault: Vault) {
+        delete(vault);
+    }
+
+    // SHOULD NOT FIRE: `drop` values are destroyed by going out of scope.
+    public fun destroy_ticket(_ticket: Ticket) {}
+
+    // SHOULD NOT FIRE: not a destroy function.
+    public fun amount(vault: &Vault): u64 {
+        vault.amount
+    }
+}
@@ -0,0 +1,73 @@
+// Test fixture for duplicate_dynamic_field_add lint
+
+module sui::object {
+    public struct UID has store {
+        id: address,
+    }
+}
+
+module sui::dynamic_field {
+    use sui::object::UID;
+
+    public fun add<K: copy + drop + store, V: store>(_object: &mut UID, _name: K, _value: V) {
+        abort 0
+    }
+
+    public fun remove<K: copy + drop + store, V: store>(_object: &mut UID, _name: K): V {
+        abort 0
+    }
+}
+
+module duplicate_dynamic_field_add_pkg::duplicate_add {
+    use sui::dynamic_field;
+    use sui::object::UID;
+
+    const CONFIG_KEY: u64 = 7;
+
+    public struct Holder has store {
+        id: UID,
+    }
+
+    public struct GoodKey has copy, drop, store {}
+
+    // SHOULD WARN: the same literal key is added twice
+    public fun duplicate_literal_key(holder: &mut Holder) {
+        dynamic_field::add(&mut holder.id, b"balance", 1u64);
+        dynamic_field::add(&mut holder.id, b"balance", 2u64);
+    }
+
+    // SHOULD WARN: the same constant key is added twice
+    public fun duplicate_constant_key(holder: &mut Holder) {
+        dynamic_field::add(&mut holder.id, CONFIG_KEY, 1u64);
+        dynamic_field::add(&mut holder.id, CONFIG_KEY, 2u64);
+    }
+
+    // SHOULD NOT WARN: distinct keys
+    public fun distinct_keys(holder: &mut Holder) {
+        dynamic_field::add(&mut holder.id, GoodKey {}, 1u64);
+        dynamic_field::add(&mut holder.id, b"a", 1u64);
+        dynamic_field::add(&mut holder.id, b"b", 2u64);
+    }
+
+    // SHOULD NOT WARN: same key on different objects
+    public fun same_key_different_objects(a: &mut Holder, b: &mut Holder) {
+        dynamic_field::add(&mut a.id, b"balance", 1u64);
+        dynamic_field::add(&mut b.id, b"balance", 1u64);
+    }
+
+    // SHOULD NOT WARN: the adds sit in exclusive branches
+    public fun add_in_exclusive_branches(holder: &mut Holder, flag: bool) {
+        if (flag) {
+            dynamic_field::add(&mut holder.id, b"balance", 1u64);
+        } else {
+            dynamic_field::add(&mut holder.id, b"balance", 2u64);
+        }
+    }
+
+    // SHOULD NOT WARN: the field is removed before it is added again
+    public fun add_after_remove(holder: &mut Holder) {
+        dynamic_field::add(&mut holder.id, b"balance", 1u64);
+        let _old: u64 = dynamic_field::remove(&mut holder.id, b"balance");
+        dynamic_field::add(&mut holder.id, b"balance", 2u64);
+    }
+}
@@ -0,0 +1,57 @@
+/// Fixture package for the `event_struct_missing_abilities` semantic lint.
+
+module sui::object {
+    public struct UID has store {
+        id: address,
+    }
+}
+
+module sui::event {
+    // Unconstrained so the fixture can emit a `key` struct.
+    public fun emit<T>(_event: T) {
+        abort 0
+    }
+}
+
+module event_struct_missing_abilities_pkg::events {
+    use sui::event;
+    use sui::object::UID;
+
+    // Emitted as an event but declared as an object.
+    public struct ItemMinted has key {
+        id: UID,
+    }
+
+    // Emitted as an event but cannot be copied.
+    public struct ItemBurned has drop {
+        amount: u64,
+    }
+
+    // Named like an event but never emitted.
+    public struct TradeEvent has copy {
+        amount: u64,
+    }
+
+    // Correct event declarations.
+    public struct ItemCreated has copy, drop {
+        amount: u64,
+    }
+
+    public struct ListingEvent has copy, drop {
+        amount: u64,
+    }
+
+    public fun mint(id: UID) {
+        event::emit(ItemMinted { id });
+    }
+
+    public fun burn(amount: u64) {
+        event::emit(ItemBurned { amount });
+        event::emit(ItemCreated { amount });
+        event::emit(ListingEvent { amount });
+    }
+
+    public fun trade(amount: u64): TradeEvent {
+        TradeEvent { amount }
+    }
+}
@@ -0,0 +1,28 @@
+// Test fixture for exact_length_assert lint
+
+module exact_length_assert_pkg::exact_length_assert {
+    const E_BAD_LENGTH: u64 = 1;
+    const EXPECTED_LEN: u64 = 3;
+
+    // SHOULD WARN: exact length against a literal
+    public fun exact_literal(v: &vector<u64>): u64 {
+        assert!(vector::length(v) == 2, E_BAD_LENGTH);
+        *vector::borrow(v, 0) + *vector::borrow(v, 1)
+    }
+
+    // SHOULD WARN: exact length against a constant, operands swapped
+    public fun exact_constant(v: &vector<u64>) {
+        assert!(EXPECTED_LEN == vector::length(v), E_BAD_LENGTH);
+    }
+
+    // SHOULD NOT WARN: minimum length check
+    public fun minimum_length(v: &vector<u64>): u64 {
+        assert!(vector::length(v) >= 2, E_BAD_LENGTH);
+        *vector::borrow(v, 0) + *vector::borrow(v, 1)
+    }
+
+    // SHOULD NOT WARN: comparing two lengths
+    public fun same_length(a: &vector<u64>, b: &vector<u64>) {
+        assert!(vector::length(a) == vector::length(b), E_BAD_LENGTH);
+    }
+}
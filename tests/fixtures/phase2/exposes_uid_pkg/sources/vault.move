@@ -0,0 +1,92 @@
+// Test fixture for exposes_uid lint
+
+module sui::tx_context {
+    public struct TxContext has drop {
+        sender: address,
+    }
+}
+
+module sui::object {
+    use sui::tx_context::TxContext;
+
+    public struct UID has store {
+        id: address,
+    }
+
+    public struct ID has copy, drop, store {
+        bytes: address,
+    }
+
+    public fun new(_ctx: &mut TxContext): UID {
+        UID { id: @0x0 }
+    }
+
+    public fun uid_to_inner(uid: &UID): ID {
+        ID { bytes: uid.id }
+    }
+
+    public fun delete(id: UID) {
+        let UID { id: _ } = id;
+    }
+}
+
+module exposes_uid_pkg::vault {
+    use sui::object::{Self, ID, UID};
+    use sui::tx_context::TxContext;
+
+    public struct Vault has key {
+        id: UID,
+        balance: u64,
+    }
+
+    public struct Wrapper has key, store {
+        id: UID,
+        inner_id: UID,
+    }
+
+    // SHOULD WARN: hands out the vault's own UID by reference
+    public fun uid(vault: &Vault): &UID {
+        &vault.id
+    }
+
+    // SHOULD WARN: returns the UID unpacked from the vault
+    public fun into_uid(vault: Vault): UID {
+        let Vault { id, balance: _ } = vault;
+        id
+    }
+
+    // SHOULD NOT WARN: returning an ID is fine
+    public fun id(vault: &Vault): ID {
+        object::uid_to_inner(&vault.id)
+    }
+
+    // SHOULD NOT WARN: package-internal accessor
+    public(package) fun uid_internal(vault: &Vault): &UID {
+        &vault.id
+    }
+
+    // SHOULD NOT WARN: a fresh UID, not an existing object's identity
+    public fun fresh_uid(ctx: &mut TxContext): UID {
+        object::new(ctx)
+    }
+
+    // SHOULD NOT WARN: UID passed in by the caller
+    public fun pass_through(uid: &UID): &UID {
+        uid
+    }
+
+    public fun new(ctx: &mut TxContext): Vault {
+        Vault { id: object::new(ctx), balance: 0 }
+    }
+
+    public fun destroy(vault: Vault) {
+        let Vault { id, balance: _ } = vault;
+        object::delete(id);
+    }
+
+    public fun destroy_wrapper(wrapper: Wrapper) {
+        let Wrapper { id, inner_id } = wrapper;
+        object::delete(id);
+        object::delete(inner_id);
+    }
+}
@@ -0,0 +1,43 @@
+/// Fixture for framework call resolution through `use` aliases.
+///
+/// Semantic lints must match the module an alias resolves to, not the local name:
+/// a renamed `sui::transfer` is still the framework, and a package module imported
+/// as `transfer` is not.
+
+module sui::object {
+    public struct UID has store {
+        id: address,
+    }
+}
+
+module sui::transfer {
+    public native fun transfer<T: key>(obj: T, recipient: address);
+    public native fun public_transfer<T: key + store>(obj: T, recipient: address);
+}
+
+module framework_alias_pkg::evil {
+    /// Looks like `sui::transfer::public_transfer` at the call site, but keeps the object.
+    public fun public_transfer<T: key + store>(obj: T, _recipient: address) {
+        sui::transfer::public_transfer(obj, @framework_alias_pkg);
+    }
+}
+
+module framework_alias_pkg::cases {
+    use framework_alias_pkg::evil as transfer;
+    use sui::object::UID;
+    use sui::transfer as xfer;
+
+    public struct AdminCap has key, store {
+        id: UID,
+    }
+
+    // POSITIVE: the framework module under another alias is still `sui::transfer`
+    public fun renamed_framework_alias(cap: AdminCap) {
+        xfer::public_transfer(cap, @0x42);
+    }
+
+    // NEGATIVE: `transfer` is aliased to a package module, not `sui::transfer`
+    public fun shadowing_alias(cap: AdminCap) {
+        transfer::public_transfer(cap, @0x42);
+    }
+}
@@ -0,0 +1,96 @@
+// Test fixture for freeze_locks_value lint
+
+module sui::tx_context {
+    public struct TxContext has drop {}
+}
+
+module sui::object {
+    use sui::tx_context::TxContext;
+
+    public struct UID has store {
+        id: address,
+    }
+
+    public fun new(_ctx: &mut TxContext): UID {
+        abort 0
+    }
+}
+
+module sui::sui {
+    public struct SUI has drop {}
+}
+
+module sui::balance {
+    public struct Balance<phantom T> has store {
+        value: u64,
+    }
+
+    public fun zero<T>(): Balance<T> {
+        Balance { value: 0 }
+    }
+}
+
+module sui::coin {
+    use sui::balance::Balance;
+    use sui::object::UID;
+
+    public struct Coin<phantom T> has key, store {
+        id: UID,
+        balance: Balance<T>,
+    }
+}
+
+module sui::transfer {
+    public fun freeze_object<T: key>(_obj: T) {
+        abort 0
+    }
+
+    public fun public_freeze_object<T: key + store>(_obj: T) {
+        abort 0
+    }
+}
+
+module freeze_locks_value_pkg::wallet {
+    use sui::balance::{Self, Balance};
+    use sui::coin::Coin;
+    use sui::object::{Self, UID};
+    use sui::sui::SUI;
+    use sui::transfer;
+    use sui::tx_context::TxContext;
+
+    public struct Wallet has key {
+        id: UID,
+        funds: Balance<SUI>,
+    }
+
+    public struct Reserve has store {
+        coins: vector<Coin<SUI>>,
+    }
+
+    public struct Vault has key, store {
+        id: UID,
+        reserve: Reserve,
+    }
+
+    public struct Config has key {
+        id: UID,
+        fee_bps: u64,
+    }
+
+    // SHOULD WARN: the balance can never be withdrawn
+    public fun freeze_wallet(ctx: &mut TxContext) {
+        let wallet = Wallet { id: object::new(ctx), funds: balance::zero() };
+        transfer::freeze_object(wallet);
+    }
+
+    // SHOULD WARN: coins nested one struct down
+    public fun freeze_vault(vault: Vault) {
+        transfer::public_freeze_object(vault);
+    }
+
+    // OK: configuration holds no value
+    public fun freeze_config(ctx: &mut TxContext) {
+        let config = Config { id: object::new(ctx), fee_bps: 30 };
+        transfer::freeze_object(config);
+    }
+}
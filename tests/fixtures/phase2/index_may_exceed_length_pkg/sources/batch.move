@@ -0,0 +1,61 @@
+// Test fixture for index_may_exceed_length lint
+
+module index_may_exceed_length_pkg::batch {
+    const E_LENGTH_MISMATCH: u64 = 1;
+
+    // SHOULD WARN: bounded by a separate `count`, borrows `items`
+    public fun sum_first(items: &vector<u64>, count: u64): u64 {
+        let mut sum = 0;
+        let mut i = 0;
+        while (i < count) {
+            sum = sum + *vector::borrow(items, i);
+            i = i + 1;
+        };
+        sum
+    }
+
+    // SHOULD WARN: bounded by `keys`, but also borrows `values` at the same index
+    public fun pair_up(keys: &vector<u64>, values: &mut vector<u64>) {
+        let n = vector::length(keys);
+        let mut i = 0;
+        while (i < n) {
+            let key = *vector::borrow(keys, i);
+            *vector::borrow_mut(values, i) = key;
+            i = i + 1;
+        };
+    }
+
+    // SHOULD NOT WARN: bounded by the borrowed vector's own length
+    public fun sum_all(items: &vector<u64>): u64 {
+        let mut sum = 0;
+        let mut i = 0;
+        while (i < vector::length(items)) {
+            sum = sum + *vector::borrow(items, i);
+            i = i + 1;
+        };
+        sum
+    }
+
+    // SHOULD NOT WARN: the lengths are asserted equal before the loop
+    public fun pair_up_checked(keys: &vector<u64>, values: &mut vector<u64>) {
+        let n = vector::length(keys);
+        assert!(vector::length(values) == n, E_LENGTH_MISMATCH);
+        let mut i = 0;
+        while (i < n) {
+            let key = *vector::borrow(keys, i);
+            *vector::borrow_mut(values, i) = key;
+            i = i + 1;
+        };
+    }
+
+    // SHOULD NOT WARN: indexed by something other than the loop counter
+    public fun first_repeated(items: &vector<u64>, count: u64): u64 {
+        let mut sum = 0;
+        let mut i = 0;
+        while (i < count) {
+            sum = sum + *vector::borrow(items, 0);
+            i = i + 1;
+        };
+        sum
+    }
+}
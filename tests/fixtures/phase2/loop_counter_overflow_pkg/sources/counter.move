@@ -0,0 +1,63 @@
+// Test fixture for loop_counter_overflow_risk lint
+
+module loop_counter_overflow_pkg::counter {
+    const E_TOO_LARGE: u64 = 1;
+    const MAX_ROUNDS: u64 = 1000;
+
+    // SHOULD WARN: inclusive bound from a parameter never exits when `n == u64::MAX`
+    public fun sum_to(n: u64): u64 {
+        let mut sum = 0;
+        let mut i = 0;
+        while (i <= n) {
+            sum = sum + i;
+            i = i + 1;
+        };
+        sum
+    }
+
+    // SHOULD WARN: a non-unit step can jump past the maximum
+    public fun count_by(n: u64, step: u64): u64 {
+        let mut count = 0;
+        let mut i = 0;
+        while (i < n) {
+            count = count + 1;
+            i = i + step;
+        };
+        count
+    }
+
+    // SHOULD NOT WARN: `i < n` with a step of 1 cannot overflow
+    public fun count_to(n: u64): u64 {
+        let mut count = 0;
+        let mut i = 0;
+        while (i < n) {
+            count = count + 1;
+            i = i + 1;
+        };
+        count
+    }
+
+    // SHOULD NOT WARN: the bound is capped before the loop
+    public fun sum_to_checked(n: u64): u64 {
+        assert!(n <= MAX_ROUNDS, E_TOO_LARGE);
+        let mut sum = 0;
+        let mut i = 0;
+        while (i <= n) {
+            sum = sum + i;
+            i = i + 1;
+        };
+        sum
+    }
+
+    // SHOULD NOT WARN: bounded by a vector length
+    public fun sum_items(items: &vector<u64>): u64 {
+        let len = vector::length(items);
+        let mut sum = 0;
+        let mut i = 0;
+        while (i <= len) {
+            if (i < len) sum = sum + *vector::borrow(items, i);
+            i = i + 1;
+        };
+        sum
+    }
+}
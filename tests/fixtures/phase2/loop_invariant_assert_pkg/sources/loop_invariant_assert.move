@@ -0,0 +1,62 @@
+// Test fixture for loop_invariant_assert lint
+
+module loop_invariant_assert_pkg::loop_invariant_assert {
+    const E_TOO_MANY: u64 = 1;
+    const E_EMPTY: u64 = 2;
+    const MAX_ITEMS: u64 = 100;
+
+    // SHOULD WARN: `limit` never changes inside the loop
+    public fun invariant_in_while(v: &vector<u64>, limit: u64): u64 {
+        let mut sum = 0;
+        let mut i = 0;
+        while (i < vector::length(v)) {
+            assert!(limit <= MAX_ITEMS, E_TOO_MANY);
+            sum = sum + *vector::borrow(v, i);
+            i = i + 1;
+        };
+        sum
+    }
+
+    // SHOULD WARN: `v` is only read inside the loop
+    public fun invariant_length_in_loop(v: &vector<u64>): u64 {
+        let mut i = 0;
+        loop {
+            assert!(vector::length(v) > 0, E_EMPTY);
+            if (i >= vector::length(v)) break;
+            i = i + 1;
+        };
+        i
+    }
+
+    // SHOULD NOT WARN: `sum` grows on every iteration
+    public fun changing_condition(v: &vector<u64>): u64 {
+        let mut sum = 0;
+        let mut i = 0;
+        while (i < vector::length(v)) {
+            sum = sum + *vector::borrow(v, i);
+            assert!(sum <= MAX_ITEMS, E_TOO_MANY);
+            i = i + 1;
+        };
+        sum
+    }
+
+    // SHOULD NOT WARN: the vector is modified through `&mut` in the loop
+    public fun mutated_vector(v: &mut vector<u64>, n: u64) {
+        let mut i = 0;
+        while (i < n) {
+            assert!(vector::length(v) < MAX_ITEMS, E_TOO_MANY);
+            vector::push_back(v, i);
+            i = i + 1;
+        };
+    }
+
+    // SHOULD NOT WARN: `x` is declared inside the loop body
+    public fun loop_local(v: &vector<u64>) {
+        let mut i = 0;
+        while (i < vector::length(v)) {
+            let x = *vector::borrow(v, i);
+            assert!(x <= MAX_ITEMS, E_TOO_MANY);
+            i = i + 1;
+        };
+    }
+}
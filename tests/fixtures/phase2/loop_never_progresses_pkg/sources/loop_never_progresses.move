@@ -0,0 +1,42 @@
+// Test fixture for loop_never_progresses lint
+
+module loop_never_progresses_pkg::loops {
+    // SHOULD WARN: `i` is never incremented
+    public fun sum_forever(n: u64): u64 {
+        let mut total = 0;
+        let i = 0;
+        while (i < n) {
+            total = total + 1;
+        };
+        total
+    }
+
+    // OK: the counter advances
+    public fun sum_to(n: u64): u64 {
+        let mut total = 0;
+        let mut i = 0;
+        while (i < n) {
+            total = total + i;
+            i = i + 1;
+        };
+        total
+    }
+
+    // OK: updated through a mutable reference
+    public fun drain(counter: &mut u64) {
+        while (*counter > 0) {
+            *counter = *counter - 1;
+        };
+    }
+
+    // OK: the body can break out
+    public fun capped(n: u64): u64 {
+        let mut total = 0;
+        let i = 0;
+        while (i < n) {
+            if (total >= 10) break;
+            total = total + 1;
+        };
+        total
+    }
+}
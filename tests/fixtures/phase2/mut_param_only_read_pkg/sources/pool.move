@@ -0,0 +1,58 @@
+// Test fixture for mut_param_only_read_for_return lint
+
+module sui::object {
+    public struct UID has store {
+        id: address,
+    }
+}
+
+module mut_param_only_read_pkg::pool {
+    use sui::object::UID;
+
+    public struct Pool has key {
+        id: UID,
+        reserve: u64,
+        fee_bps: u64,
+    }
+
+    fun fee_of(pool: &Pool, amount: u64): u64 {
+        amount * pool.fee_bps / 10_000
+    }
+
+    // SHOULD FIRE: only read to produce the return value.
+    public fun reserve(pool: &mut Pool): u64 {
+        pool.reserve
+    }
+
+    // SHOULD FIRE: passed to a `&Pool` parameter inside the returned expression.
+    public fun quote(pool: &mut Pool, amount: u64): u64 {
+        fee_of(pool, amount) + pool.reserve
+    }
+
+    // SHOULD NOT FIRE: mutated.
+    public fun withdraw(pool: &mut Pool, amount: u64): u64 {
+        pool.reserve = pool.reserve - amount;
+        amount
+    }
+
+    // SHOULD NOT FIRE: returns a mutable borrow into the object.
+    public fun reserve_mut(pool: &mut Pool): &mut u64 {
+        &mut pool.reserve
+    }
+
+    // SHOULD NOT FIRE: also used outside the returned expression.
+    public fun checked_reserve(pool: &mut Pool): u64 {
+        assert!(pool.fee_bps < 10_000, 0);
+        pool.reserve
+    }
+
+    // SHOULD NOT FIRE: already takes `&Pool`.
+    public fun fee_bps(pool: &Pool): u64 {
+        pool.fee_bps
+    }
+
+    // SHOULD NOT FIRE: not public.
+    fun internal_reserve(pool: &mut Pool): u64 {
+        pool.reserve
+    }
+}
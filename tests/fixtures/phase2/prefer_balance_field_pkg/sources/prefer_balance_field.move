@@ -0,0 +1,99 @@
+// Test fixture for prefer_balance_field lint
+
+module sui::tx_context {
+    public struct TxContext has drop {}
+}
+
+module sui::object {
+    public struct UID has store {
+        id: address,
+    }
+
+    public fun delete(_id: UID) {
+        abort 0
+    }
+}
+
+module sui::sui {
+    public struct SUI has drop {}
+}
+
+module sui::balance {
+    public struct Balance<phantom T> has store {
+        value: u64,
+    }
+}
+
+module sui::coin {
+    use sui::balance::Balance;
+    use sui::object::UID;
+    use sui::tx_context::TxContext;
+
+    public struct Coin<phantom T> has key, store {
+        id: UID,
+        balance: Balance<T>,
+    }
+
+    public fun value<T>(_self: &Coin<T>): u64 {
+        abort 0
+    }
+
+    public fun join<T>(_self: &mut Coin<T>, _c: Coin<T>) {
+        abort 0
+    }
+
+    public fun split<T>(_self: &mut Coin<T>, _amount: u64, _ctx: &mut TxContext): Coin<T> {
+        abort 0
+    }
+}
+
+module prefer_balance_field_pkg::pool {
+    use sui::coin::{Self, Coin};
+    use sui::object::{Self, UID};
+    use sui::sui::SUI;
+    use sui::tx_context::TxContext;
+
+    // SHOULD WARN: `reserve` is only read and topped up
+    public struct Pool has key {
+        id: UID,
+        reserve: Coin<SUI>,
+    }
+
+    // OK: `funds` is split into new coins for payouts
+    public struct Vault has key {
+        id: UID,
+        funds: Coin<SUI>,
+    }
+
+    // OK: `held` is moved out by destructuring
+    public struct Escrow has key {
+        id: UID,
+        held: Coin<SUI>,
+    }
+
+    public fun reserve_value(pool: &Pool): u64 {
+        coin::value(&pool.reserve)
+    }
+
+    public fun deposit(pool: &mut Pool, payment: Coin<SUI>) {
+        coin::join(&mut pool.reserve, payment);
+    }
+
+    public fun vault_value(vault: &Vault): u64 {
+        coin::value(&vault.funds)
+    }
+
+    public fun withdraw(vault: &mut Vault, amount: u64, ctx: &mut TxContext): Coin<SUI> {
+        coin::split(&mut vault.funds, amount, ctx)
+    }
+
+    public fun escrow_value(escrow: &Escrow): u64 {
+        coin::value(&escrow.held)
+    }
+
+    public fun release(escrow: Escrow): Coin<SUI> {
+        let Escrow { id, held } = escrow;
+        object::delete(id);
+        held
+    }
+}
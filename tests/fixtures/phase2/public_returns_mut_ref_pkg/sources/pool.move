@@ -0,0 +1,59 @@
+// Test fixture for public_returns_mut_ref lint
+
+module sui::object {
+    public struct UID has store {
+        id: address,
+    }
+}
+
+module sui::balance {
+    public struct Balance<phantom T> has store {
+        value: u64,
+    }
+}
+
+module sui::tx_context {
+    public struct TxContext has drop {
+        sender: address,
+    }
+}
+
+module public_returns_mut_ref_pkg::pool {
+    use sui::balance::Balance;
+    use sui::object::UID;
+    use sui::tx_context::TxContext;
+
+    public struct SUI has drop {}
+
+    public struct Pool has key {
+        id: UID,
+        balance: Balance<SUI>,
+        fee_bps: u64,
+    }
+
+    // SHOULD FIRE: hands out the pool's balance.
+    public fun balance_mut(self: &mut Pool): &mut Balance<SUI> {
+        &mut self.balance
+    }
+
+    // SHOULD FIRE: `&mut` member of a tuple return.
+    public fun parts_mut(self: &mut Pool): (&mut u64, u64) {
+        let fee = self.fee_bps;
+        (&mut self.fee_bps, fee)
+    }
+
+    // SHOULD NOT FIRE: immutable reference.
+    public fun balance(self: &Pool): &Balance<SUI> {
+        &self.balance
+    }
+
+    // SHOULD NOT FIRE: package-visible only.
+    public(package) fun fee_bps_mut(self: &mut Pool): &mut u64 {
+        &mut self.fee_bps
+    }
+
+    // SHOULD NOT FIRE: `&mut TxContext` is an input, not pool state.
+    public fun ctx_passthrough(_self: &mut Pool, ctx: &mut TxContext): &mut TxContext {
+        ctx
+    }
+}
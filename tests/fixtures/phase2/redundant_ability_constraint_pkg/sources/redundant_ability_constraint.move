@@ -0,0 +1,56 @@
+// Test fixture for redundant_ability_constraint lint
+
+module sui::object {
+    public struct ID has copy, drop, store {
+        bytes: address,
+    }
+
+    public struct UID has store {
+        id: ID,
+    }
+
+    public fun id<T: key>(_obj: &T): ID {
+        abort 0
+    }
+}
+
+module sui::transfer {
+    public native fun public_transfer<T: key + store>(obj: T, recipient: address);
+}
+
+module redundant_ability_constraint_pkg::registry {
+    use sui::object::{Self, ID, UID};
+    use sui::transfer;
+
+    public struct Slot<T: store> has store {
+        item: T,
+    }
+
+    public struct Box<T> has key, store {
+        id: UID,
+        item: T,
+    }
+
+    // SHOULD WARN: `object::id` only needs `key`
+    public fun id_of<T: key + store>(obj: &T): ID {
+        object::id(obj)
+    }
+
+    // SHOULD WARN: the value is only dropped
+    public fun discard<T: store + drop>(_item: T) {}
+
+    // OK: `public_transfer` requires `store`
+    public fun send<T: key + store>(obj: T, recipient: address) {
+        transfer::public_transfer(obj, recipient)
+    }
+
+    // OK: `Slot` declares `T: store`
+    public fun slot<T: store>(item: T): Slot<T> {
+        Slot { item }
+    }
+
+    // OK: `Box<T>` only has `key` when `T` has `store`
+    public fun box_id<T: store>(boxed: &Box<T>): ID {
+        object::id(boxed)
+    }
+}
@@ -0,0 +1,214 @@
+/// Test fixture for stale_oracle_price lint.
+///
+/// Oracle prices must not feed arithmetic unless a freshness assertion on the price's
+/// publish time dominates the use site (i.e. holds on every path leading to it).
+
+// =============================================================================
+// Mock Oracle / Framework Modules
+// =============================================================================
+
+module pyth::i64 {
+    public struct I64 has copy, drop, store {
+        negative: bool,
+        magnitude: u64,
+    }
+
+    public fun get_magnitude_if_positive(i: &I64): u64 {
+        assert!(!i.negative, 0);
+        i.magnitude
+    }
+}
+
+module pyth::price {
+    use pyth::i64::I64;
+
+    public struct Price has copy, drop, store {
+        price: I64,
+        conf: u64,
+        timestamp: u64,
+    }
+
+    public fun get_price(p: &Price): I64 {
+        p.price
+    }
+
+    public fun get_timestamp(p: &Price): u64 {
+        p.timestamp
+    }
+}
+
+module pyth::pyth {
+    use pyth::price::Price;
+
+    public struct PriceInfoObject has drop {
+        price: Price,
+    }
+
+    public fun get_price_unsafe(info: &PriceInfoObject): Price {
+        info.price
+    }
+}
+
+module sui::clock {
+    public struct Clock has drop {
+        timestamp_ms: u64,
+    }
+
+    public fun timestamp_ms(clock: &Clock): u64 {
+        clock.timestamp_ms
+    }
+}
+
+module switchboard::decimal {
+    public struct Decimal has copy, drop, store {
+        value: u128,
+        neg: bool,
+    }
+
+    public fun value(d: &Decimal): u128 {
+        d.value
+    }
+}
+
+module switchboard::current_result {
+    use switchboard::decimal::Decimal;
+
+    public struct CurrentResult has copy, drop, store {
+        result: Decimal,
+        timestamp_ms: u64,
+    }
+
+    public fun result(r: &CurrentResult): &Decimal {
+        &r.result
+    }
+
+    public fun timestamp_ms(r: &CurrentResult): u64 {
+        r.timestamp_ms
+    }
+}
+
+module switchboard::aggregator {
+    use switchboard::current_result::CurrentResult;
+
+    public struct Aggregator has drop {
+        current_result: CurrentResult,
+    }
+
+    public fun current_result(aggregator: &Aggregator): &CurrentResult {
+        &aggregator.current_result
+    }
+}
+
+// =============================================================================
+// Test Cases
+// =============================================================================
+
+module stale_oracle_price_pkg::lending {
+    use pyth::i64;
+    use pyth::price;
+    use pyth::pyth::{Self, PriceInfoObject};
+    use sui::clock::{Self, Clock};
+    use switchboard::aggregator::{Self, Aggregator};
+    use switchboard::current_result;
+    use switchboard::decimal;
+
+    const MAX_AGE_SECS: u64 = 60;
+    const MAX_AGE_MS: u64 = 60_000;
+    const E_STALE_PRICE: u64 = 1;
+
+    // POSITIVE: price feeds arithmetic with no freshness check at all
+    public fun collateral_value_unchecked(info: &PriceInfoObject, amount: u64): u64 {
+        let p = pyth::get_price_unsafe(info);
+        let unit_price = i64::get_magnitude_if_positive(&price::get_price(&p));
+        amount * unit_price
+    }
+
+    // POSITIVE: the freshness check only runs on one branch, so it does not dominate the use
+    public fun collateral_value_checked_on_one_branch(
+        info: &PriceInfoObject,
+        clock: &Clock,
+        amount: u64,
+        strict: bool,
+    ): u64 {
+        let p = pyth::get_price_unsafe(info);
+        if (strict) {
+            assert!(
+                clock::timestamp_ms(clock) / 1000 - price::get_timestamp(&p) <= MAX_AGE_SECS,
+                E_STALE_PRICE,
+            );
+        };
+        let unit_price = i64::get_magnitude_if_positive(&price::get_price(&p));
+        amount * unit_price
+    }
+
+    // POSITIVE: switchboard result used without checking its timestamp
+    public fun switchboard_value_unchecked(aggregator: &Aggregator, amount: u128): u128 {
+        let result = aggregator::current_result(aggregator);
+        amount * decimal::value(current_result::result(result))
+    }
+
+    // NEGATIVE: freshness assertion dominates the use
+    public fun collateral_value_checked(
+        info: &PriceInfoObject,
+        clock: &Clock,
+        amount: u64,
+    ): u64 {
+        let p = pyth::get_price_unsafe(info);
+        assert!(
+            clock::timestamp_ms(clock) / 1000 - price::get_timestamp(&p) <= MAX_AGE_SECS,
+            E_STALE_PRICE,
+        );
+        let unit_price = i64::get_magnitude_if_positive(&price::get_price(&p));
+        amount * unit_price
+    }
+
+    // NEGATIVE: age computed into a local first, checked with an early abort
+    public fun collateral_value_checked_via_local(
+        info: &PriceInfoObject,
+        clock: &Clock,
+        amount: u64,
+    ): u64 {
+        let p = pyth::get_price_unsafe(info);
+        let age = clock::timestamp_ms(clock) / 1000 - price::get_timestamp(&p);
+        if (age > MAX_AGE_SECS) abort E_STALE_PRICE;
+        let unit_price = i64::get_magnitude_if_positive(&price::get_price(&p));
+        amount * unit_price
+    }
+
+    // NEGATIVE: switchboard result checked against the clock before use
+    public fun switchboard_value_checked(
+        aggregator: &Aggregator,
+        clock: &Clock,
+        amount: u128,
+    ): u128 {
+        let result = aggregator::current_result(aggregator);
+        assert!(
+            clock::timestamp_ms(clock) - current_result::timestamp_ms(result) <= MAX_AGE_MS,
+            E_STALE_PRICE,
+        );
+        amount * decimal::value(current_result::result(result))
+    }
+
+    // NEGATIVE: price only compared, never used in arithmetic
+    public fun price_is_positive(info: &PriceInfoObject): bool {
+        let p = pyth::get_price_unsafe(info);
+        i64::get_magnitude_if_positive(&price::get_price(&p)) > 0
+    }
+    // POSITIVE: only the collateral price is checked, the debt price is used unchecked
+    public fun health_factor_checks_one_price(
+        collateral_info: &PriceInfoObject,
+        debt_info: &PriceInfoObject,
+        clock: &Clock,
+        amount: u64,
+    ): u64 {
+        let collateral = pyth::get_price_unsafe(collateral_info);
+        let debt = pyth::get_price_unsafe(debt_info);
+        assert!(
+            clock::timestamp_ms(clock) / 1000 - price::get_timestamp(&collateral) <= MAX_AGE_SECS,
+            E_STALE_PRICE,
+        );
+        let collateral_price = i64::get_magnitude_if_positive(&price::get_price(&collateral));
+        let debt_price = i64::get_magnitude_if_positive(&price::get_price(&debt));
+        amount * collateral_price / debt_price
+    }
+}
@@ -0,0 +1,50 @@
+// Test fixture for sui_signer_param lint
+
+module sui::tx_context {
+    public struct TxContext has drop {
+        sender: address,
+    }
+
+    public fun sender(self: &TxContext): address {
+        self.sender
+    }
+}
+
+module sui_signer_param_pkg::registry {
+    use sui::tx_context::TxContext;
+
+    public struct Registry has store {
+        admin: address,
+        entries: u64,
+    }
+
+    // SHOULD FIRE: ported from core Move, takes `&signer`
+    public fun register(_account: &signer, registry: &mut Registry) {
+        registry.entries = registry.entries + 1;
+    }
+
+    // SHOULD FIRE: takes a `signer` by value
+    fun init_registry(_account: signer, admin: address): Registry {
+        Registry { admin, entries: 0 }
+    }
+
+    // SHOULD NOT FIRE: uses the transaction sender
+    public fun register_sender(registry: &mut Registry, ctx: &TxContext) {
+        assert!(registry.admin == ctx.sender(), 0);
+        registry.entries = registry.entries + 1;
+    }
+
+    // SHOULD NOT FIRE: test-only helper
+    #[test_only]
+    public fun register_for_testing(_account: &signer, registry: &mut Registry) {
+        registry.entries = registry.entries + 1;
+    }
+
+    public fun new(admin: address): Registry {
+        init_registry_with(admin)
+    }
+
+    fun init_registry_with(admin: address): Registry {
+        Registry { admin, entries: 0 }
+    }
+}
@@ -0,0 +1,91 @@
+// Test fixture for suspicious_coin_split lint
+
+module sui::tx_context {
+    public struct TxContext has drop {}
+}
+
+module sui::object {
+    public struct UID has store {
+        id: address,
+    }
+}
+
+module sui::sui {
+    public struct SUI has drop {}
+}
+
+module sui::balance {
+    public struct Balance<phantom T> has store {
+        value: u64,
+    }
+}
+
+module sui::coin {
+    use sui::balance::Balance;
+    use sui::object::UID;
+    use sui::tx_context::TxContext;
+
+    public struct Coin<phantom T> has key, store {
+        id: UID,
+        balance: Balance<T>,
+    }
+
+    public fun value<T>(_self: &Coin<T>): u64 {
+        abort 0
+    }
+
+    public fun split<T>(_self: &mut Coin<T>, _amount: u64, _ctx: &mut TxContext): Coin<T> {
+        abort 0
+    }
+}
+
+module suspicious_coin_split_pkg::vault {
+    use sui::coin::{Self, Coin};
+    use sui::sui::SUI;
+    use sui::tx_context::TxContext;
+
+    const E_ZERO_AMOUNT: u64 = 0;
+    const E_INSUFFICIENT: u64 = 1;
+    const FEE: u64 = 100;
+
+    // SHOULD WARN: `requested` is never checked
+    public fun withdraw(funds: &mut Coin<SUI>, requested: u64, ctx: &mut TxContext): Coin<SUI> {
+        coin::split(funds, requested, ctx)
+    }
+
+    // SHOULD WARN: the guard comes after the split
+    public fun withdraw_late_check(
+        funds: &mut Coin<SUI>,
+        amount: u64,
+        ctx: &mut TxContext,
+    ): Coin<SUI> {
+        let out = coin::split(funds, amount, ctx);
+        assert!(amount > 0, E_ZERO_AMOUNT);
+        out
+    }
+
+    // OK: non-zero guard
+    public fun withdraw_nonzero(
+        funds: &mut Coin<SUI>,
+        amount: u64,
+        ctx: &mut TxContext,
+    ): Coin<SUI> {
+        assert!(amount > 0, E_ZERO_AMOUNT);
+        coin::split(funds, amount, ctx)
+    }
+
+    // OK: both bounds in one assertion
+    public fun withdraw_bounded(
+        funds: &mut Coin<SUI>,
+        amount: u64,
+        ctx: &mut TxContext,
+    ): Coin<SUI> {
+        assert!(amount > 0 && amount <= coin::value(funds), E_INSUFFICIENT);
+        coin::split(funds, amount, ctx)
+    }
+
+    // OK: amount is not a parameter
+    public fun take_fee(funds: &mut Coin<SUI>, ctx: &mut TxContext): Coin<SUI> {
+        coin::split(funds, FEE, ctx)
+    }
+}
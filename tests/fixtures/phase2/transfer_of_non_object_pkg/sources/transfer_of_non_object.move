@@ -0,0 +1,48 @@
+// Test fixture for transfer_of_non_object lint
+
+module sui::object {
+    public struct UID has store {
+        id: address,
+    }
+}
+
+// Minimal stub: the real functions require `T: key`, which would make the
+// positive cases below fail to compile. Dropping the bound keeps the fixture
+// compiling so the lint can report the mismatch.
+module sui::transfer {
+    public fun transfer<T>(_obj: T, _recipient: address) {
+        abort 0
+    }
+
+    public fun share_object<T>(_obj: T) {
+        abort 0
+    }
+}
+
+module transfer_of_non_object_pkg::transfer_of_non_object {
+    use sui::object::UID;
+    use sui::transfer;
+
+    public struct Receipt has store {
+        amount: u64,
+    }
+
+    public struct Vault has key {
+        id: UID,
+    }
+
+    // SHOULD WARN: Receipt lacks key
+    public fun send_receipt(receipt: Receipt, recipient: address) {
+        transfer::transfer(receipt, recipient);
+    }
+
+    // SHOULD WARN: Receipt lacks key
+    public fun share_receipt(receipt: Receipt) {
+        transfer::share_object(receipt);
+    }
+
+    // SHOULD NOT WARN: Vault has key
+    public fun share_vault(vault: Vault) {
+        transfer::share_object(vault);
+    }
+}
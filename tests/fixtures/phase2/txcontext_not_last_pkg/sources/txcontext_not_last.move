@@ -0,0 +1,36 @@
+// Test fixture for txcontext_not_last_param lint
+
+module sui::tx_context {
+    public struct TxContext has drop {}
+}
+
+module txcontext_not_last_pkg::counter {
+    use sui::tx_context::TxContext;
+
+    public struct Counter has drop {
+        value: u64,
+    }
+
+    // SHOULD WARN: ctx in the middle
+    public entry fun bump_ctx_middle(amount: u64, _ctx: &mut TxContext, times: u64) {
+        let _ = amount * times;
+    }
+
+    // SHOULD WARN: private entry with ctx first
+    entry fun ctx_first(_ctx: &TxContext, amount: u64) {
+        let _ = amount;
+    }
+
+    // SHOULD NOT WARN: ctx last
+    public entry fun bump(amount: u64, times: u64, _ctx: &mut TxContext) {
+        let _ = amount * times;
+    }
+
+    // SHOULD NOT WARN: ctx is the only parameter
+    public entry fun touch(_ctx: &mut TxContext) {}
+
+    // SHOULD NOT WARN: not an entry function
+    public fun helper(_ctx: &mut TxContext, amount: u64): Counter {
+        Counter { value: amount }
+    }
+}
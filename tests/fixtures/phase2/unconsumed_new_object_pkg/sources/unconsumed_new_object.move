@@ -0,0 +1,74 @@
+module sui::tx_context {
+    public struct TxContext has drop {
+        sender: address,
+    }
+
+    public fun sender(self: &TxContext): address {
+        self.sender
+    }
+}
+
+module sui::object {
+    use sui::tx_context::TxContext;
+
+    public struct UID has store {
+        id: address,
+    }
+
+    public fun new(_ctx: &mut TxContext): UID {
+        UID { id: @0x1 }
+    }
+
+    public fun delete(id: UID) {
+        let UID { id: _ } = id;
+    }
+}
+
+module sui::transfer {
+    public native fun transfer<T: key>(obj: T, recipient: address);
+    public native fun share_object<T: key>(obj: T);
+}
+
+module unconsumed_new_object_pkg::items {
+    use sui::object::{Self, UID};
+    use sui::transfer;
+    use sui::tx_context::TxContext;
+
+    public struct Item has key, store {
+        id: UID,
+        power: u64,
+    }
+
+    // SHOULD WARN: the `else` branch throws the new item away
+    public fun mint_if(lucky: bool, ctx: &mut TxContext) {
+        let item = Item { id: object::new(ctx), power: 1 };
+        if (lucky) {
+            transfer::transfer(item, ctx.sender());
+        } else {
+            let Item { id, power: _ } = item;
+            object::delete(id);
+        }
+    }
+
+    // OK: transferred on every path
+    public fun mint(ctx: &mut TxContext) {
+        let item = Item { id: object::new(ctx), power: 1 };
+        transfer::transfer(item, ctx.sender());
+    }
+
+    // OK: shared
+    public fun mint_shared(ctx: &mut TxContext) {
+        transfer::share_object(Item { id: object::new(ctx), power: 0 });
+    }
+
+    // OK: returned to the caller
+    public fun new_item(ctx: &mut TxContext): Item {
+        Item { id: object::new(ctx), power: 2 }
+    }
+
+    // OK: burns an existing object, not one created here
+    public fun burn(item: Item) {
+        let Item { id, power: _ } = item;
+        object::delete(id);
+    }
+}
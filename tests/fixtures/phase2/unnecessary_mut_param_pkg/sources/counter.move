@@ -0,0 +1,61 @@
+// Test fixture for unnecessary_mut_param lint
+
+module sui::tx_context {
+    public struct TxContext has drop {
+        sender: address,
+    }
+
+    public fun sender(self: &TxContext): address {
+        self.sender
+    }
+}
+
+module unnecessary_mut_param_pkg::counter {
+    use sui::tx_context::{Self, TxContext};
+
+    public struct Counter has drop {
+        value: u64,
+        owner: address,
+    }
+
+    const E_NOT_OWNER: u64 = 1;
+
+    // SHOULD WARN: only reads fields
+    public fun is_owner(counter: &mut Counter, who: address): bool {
+        assert!(counter.value < 1000, E_NOT_OWNER);
+        counter.owner == who
+    }
+
+    // SHOULD WARN: only passed where `&` is expected
+    fun doubled(counter: &mut Counter): u64 {
+        value(counter) * 2
+    }
+
+    // SHOULD NOT WARN: writes a field
+    public fun increment(counter: &mut Counter) {
+        counter.value = counter.value + 1;
+    }
+
+    // SHOULD NOT WARN: forwarded to a helper taking `&mut`
+    public fun increment_twice(counter: &mut Counter) {
+        increment(counter);
+        increment(counter);
+    }
+
+    // SHOULD NOT WARN: hands out a mutable borrow
+    public fun value_mut(counter: &mut Counter): &mut u64 {
+        &mut counter.value
+    }
+
+    // SHOULD NOT WARN: `&mut TxContext` is left to unnecessary_mut_tx_context
+    public fun claim(counter: &mut Counter, ctx: &mut TxContext) {
+        counter.owner = tx_context::sender(ctx);
+    }
+
+    // SHOULD NOT WARN: unused
+    public fun ignore(_counter: &mut Counter) {}
+
+    public fun value(counter: &Counter): u64 {
+        counter.value
+    }
+}
@@ -0,0 +1,73 @@
+// Test fixture for unnecessary_mut_tx_context lint
+
+module sui::tx_context {
+    public struct TxContext has drop {
+        sender: address,
+    }
+
+    public fun sender(self: &TxContext): address {
+        self.sender
+    }
+}
+
+module sui::object {
+    use sui::tx_context::TxContext;
+
+    public struct UID has store {
+        id: address,
+    }
+
+    public fun new(_ctx: &mut TxContext): UID {
+        UID { id: @0x0 }
+    }
+}
+
+module unnecessary_mut_tx_context_pkg::registry {
+    use sui::object::{Self, UID};
+    use sui::tx_context::{Self, TxContext};
+
+    public struct Entry has key {
+        id: UID,
+        owner: address,
+    }
+
+    public struct Log has drop {
+        who: address,
+    }
+
+    const E_NOT_OWNER: u64 = 1;
+
+    // SHOULD WARN: ctx is only read for the sender
+    entry fun check_owner(owner: address, ctx: &mut TxContext) {
+        assert!(tx_context::sender(ctx) == owner, E_NOT_OWNER);
+    }
+
+    // SHOULD WARN: package entry, read through method syntax
+    public(package) entry fun log_sender(ctx: &mut TxContext): Log {
+        Log { who: ctx.sender() }
+    }
+
+    // SHOULD NOT WARN: object::new needs `&mut TxContext`
+    entry fun create(ctx: &mut TxContext) {
+        let entry = Entry { id: object::new(ctx), owner: tx_context::sender(ctx) };
+        let Entry { id, owner: _ } = entry;
+        let UID { id: _ } = id;
+    }
+
+    // SHOULD NOT WARN: forwarded to a helper taking `&mut TxContext`
+    entry fun create_via_helper(ctx: &mut TxContext) {
+        let UID { id: _ } = new_uid(ctx);
+    }
+
+    // SHOULD NOT WARN: public entry keeps `&mut` per Sui's own lint
+    public entry fun public_check(owner: address, ctx: &mut TxContext) {
+        assert!(tx_context::sender(ctx) == owner, E_NOT_OWNER);
+    }
+
+    // SHOULD NOT WARN: unused context
+    entry fun noop(_ctx: &mut TxContext) {}
+
+    fun new_uid(ctx: &mut TxContext): UID {
+        object::new(ctx)
+    }
+}
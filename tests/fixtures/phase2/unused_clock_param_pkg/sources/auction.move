@@ -0,0 +1,49 @@
+// Test fixture for unused_clock_param lint
+
+module sui::clock {
+    public struct Clock has drop {
+        timestamp_ms: u64,
+    }
+
+    public fun timestamp_ms(clock: &Clock): u64 {
+        clock.timestamp_ms
+    }
+}
+
+module unused_clock_param_pkg::auction {
+    use sui::clock::{Self, Clock};
+
+    public struct Auction has store {
+        ends_at_ms: u64,
+        highest_bid: u64,
+    }
+
+    // SHOULD FIRE: accepts a clock but never reads the time
+    public fun bid(auction: &mut Auction, amount: u64, _clock: &Clock) {
+        assert!(amount > auction.highest_bid, 0);
+        auction.highest_bid = amount;
+    }
+
+    // SHOULD NOT FIRE: reads the time
+    public fun is_over(auction: &Auction, clock: &Clock): bool {
+        clock::timestamp_ms(clock) >= auction.ends_at_ms
+    }
+
+    // SHOULD NOT FIRE: method-call syntax reads the time
+    public fun remaining_ms(auction: &Auction, clock: &Clock): u64 {
+        let now = clock.timestamp_ms();
+        if (now >= auction.ends_at_ms) 0 else auction.ends_at_ms - now
+    }
+
+    // SHOULD NOT FIRE: forwards the clock to a function that reads it
+    public fun settle(auction: &mut Auction, clock: &Clock): u64 {
+        assert!(is_over(auction, clock), 1);
+        auction.highest_bid
+    }
+
+    // SHOULD NOT FIRE: test-only helper
+    #[test_only]
+    public fun bid_for_testing(auction: &mut Auction, amount: u64, _clock: &Clock) {
+        auction.highest_bid = amount;
+    }
+}
@@ -0,0 +1,24 @@
+module unused_type_param_pkg::generics;
+
+public struct Marker<phantom T> has drop {}
+
+// BAD: `T` appears nowhere
+public fun truly_unused<T>(x: u64): u64 {
+    x
+}
+
+// BAD: `U` appears nowhere, `T` is used in a parameter
+public fun second_unused<T: drop, U>(_value: T) {}
+
+// GOOD: used in a parameter type
+public fun in_param<T: drop>(_witness: T) {}
+
+// GOOD: used in the return type
+public fun in_return<T>(): Marker<T> {
+    Marker {}
+}
+
+// GOOD: type-level dispatch, `T` is only a type argument in the body
+public fun body_only<T>() {
+    let _marker = Marker<T> {};
+}
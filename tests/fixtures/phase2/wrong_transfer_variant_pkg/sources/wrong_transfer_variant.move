@@ -0,0 +1,104 @@
+// Test fixture for wrong_transfer_variant lint
+
+module sui::tx_context {
+    public struct TxContext has drop {
+        sender: address,
+    }
+
+    public fun sender(ctx: &TxContext): address {
+        ctx.sender
+    }
+}
+
+module sui::object {
+    use sui::tx_context::TxContext;
+
+    public struct UID has store {
+        id: address,
+    }
+
+    public fun new(_ctx: &mut TxContext): UID {
+        abort 0
+    }
+}
+
+module sui::transfer {
+    public fun transfer<T: key>(_obj: T, _recipient: address) {
+        abort 0
+    }
+
+    public fun public_transfer<T: key + store>(_obj: T, _recipient: address) {
+        abort 0
+    }
+
+    public fun share_object<T: key>(_obj: T) {
+        abort 0
+    }
+
+    public fun public_share_object<T: key + store>(_obj: T) {
+        abort 0
+    }
+
+    public fun freeze_object<T: key>(_obj: T) {
+        abort 0
+    }
+
+    public fun public_freeze_object<T: key + store>(_obj: T) {
+        abort 0
+    }
+}
+
+module wrong_transfer_variant_pkg::nft {
+    use sui::object::{Self, UID};
+    use sui::transfer;
+    use sui::tx_context::{Self, TxContext};
+
+    public struct Nft has key, store {
+        id: UID,
+    }
+
+    public struct Registry has key, store {
+        id: UID,
+    }
+
+    public fun new(ctx: &mut TxContext): Nft {
+        Nft { id: object::new(ctx) }
+    }
+
+    // SHOULD WARN: Nft is defined here, so `transfer::transfer` is available
+    public fun mint_to_sender(ctx: &mut TxContext) {
+        let nft = Nft { id: object::new(ctx) };
+        transfer::public_transfer(nft, tx_context::sender(ctx));
+    }
+
+    // SHOULD WARN: Registry is defined here, so `transfer::share_object` is available
+    public fun create_registry(ctx: &mut TxContext) {
+        transfer::public_share_object(Registry { id: object::new(ctx) });
+    }
+
+    // SHOULD WARN: Registry is defined here, so `transfer::freeze_object` is available
+    public fun freeze_registry(registry: Registry) {
+        transfer::public_freeze_object(registry);
+    }
+
+    // OK: already uses the private transfer
+    public fun mint_to(recipient: address, ctx: &mut TxContext) {
+        let nft = Nft { id: object::new(ctx) };
+        transfer::transfer(nft, recipient);
+    }
+}
+
+module wrong_transfer_variant_pkg::market {
+    use wrong_transfer_variant_pkg::nft::Nft;
+    use sui::transfer;
+
+    // OK: Nft is defined in another module, so only `public_transfer` is allowed here
+    public fun deliver(nft: Nft, buyer: address) {
+        transfer::public_transfer(nft, buyer);
+    }
+
+    // OK: generic objects are never module-local
+    public fun list<T: key + store>(item: T) {
+        transfer::public_share_object(item);
+    }
+}
@@ -0,0 +1,65 @@
+// Test fixture for store_in_shared_cap lint
+// Tests detection of capabilities stored in objects that are shared from another module
+
+// Minimal stubs so this fixture compiles without pulling in the full Sui framework.
+module sui::object {
+    public struct UID has store, drop {}
+
+    public fun new(): UID { UID {} }
+}
+
+module sui::transfer {
+    public fun share_object<T: key>(_obj: T) { abort 0 }
+
+    public fun transfer<T: key>(_obj: T, _recipient: address) { abort 0 }
+}
+
+module shared_cap_pkg::types {
+    use sui::object::{Self, UID};
+
+    /// Key+store auth token / capability-like resource.
+    public struct AdminCap has key, store {
+        id: UID,
+    }
+
+    /// Shared object holding the capability - anyone can reach `admin` through it.
+    public struct Vault has key {
+        id: UID,
+        admin: AdminCap,
+    }
+
+    /// Shared object that only records which capability administers it.
+    public struct Registry has key {
+        id: UID,
+        admin_count: u64,
+    }
+
+    public fun new_cap(): AdminCap {
+        AdminCap { id: object::new() }
+    }
+
+    public fun new_vault(admin: AdminCap): Vault {
+        Vault { id: object::new(), admin }
+    }
+
+    public fun new_registry(): Registry {
+        Registry { id: object::new(), admin_count: 1 }
+    }
+}
+
+module shared_cap_pkg::setup {
+    use shared_cap_pkg::types;
+    use sui::transfer;
+
+    /// Should trigger: the capability is packed in `types` and the vault shared here.
+    public fun create_vault() {
+        let cap = types::new_cap();
+        transfer::share_object(types::new_vault(cap));
+    }
+
+    /// Should NOT trigger: the shared registry holds no capability.
+    public fun create_registry(owner: address) {
+        transfer::share_object(types::new_registry());
+        transfer::transfer(types::new_cap(), owner);
+    }
+}
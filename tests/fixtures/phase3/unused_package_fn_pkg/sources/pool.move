@@ -0,0 +1,57 @@
+// Test fixture for unused_package_function lint
+// One public(package) function is called from a sibling module; the other is an orphan.
+
+module unused_package_fn_pkg::pool {
+    public struct Pool has drop {
+        balance: u64,
+    }
+
+    public fun new(): Pool {
+        Pool { balance: 0 }
+    }
+
+    // OK: called from `router`.
+    public(package) fun deposit(pool: &mut Pool, amount: u64) {
+        pool.balance = pool.balance + amount;
+    }
+
+    // SHOULD FIRE: no caller anywhere in the package.
+    public(package) fun drain(pool: &mut Pool): u64 {
+        let amount = pool.balance;
+        pool.balance = 0;
+        amount
+    }
+
+    // OK: called only inside `assert!` in `router`.
+    public(package) fun is_open(pool: &Pool): bool {
+        pool.balance < 1_000_000
+    }
+
+    // OK: entry functions are called from transactions.
+    public(package) entry fun reset(pool: &mut Pool) {
+        pool.balance = 0;
+    }
+
+    // OK: private functions are the compiler's business.
+    fun helper(): u64 {
+        0
+    }
+
+    public fun balance(pool: &Pool): u64 {
+        pool.balance + helper()
+    }
+}
+
+module unused_package_fn_pkg::router {
+    use unused_package_fn_pkg::pool::{Self, Pool};
+
+    public fun deposit_twice(pool: &mut Pool, amount: u64) {
+        pool::deposit(pool, amount);
+        pool.deposit(amount);
+    }
+
+    public fun checked_deposit(pool: &mut Pool, amount: u64) {
+        assert!(pool::is_open(pool), 0);
+        pool::deposit(pool, amount);
+    }
+}
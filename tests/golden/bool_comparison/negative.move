@@ -0,0 +1,25 @@
+// Negative cases for bool_comparison: no boolean literal operand.
+module 0x1::bool_comparison_negative;
+
+public fun compare_flags(a: bool, b: bool): bool {
+    a == b
+}
+
+public fun compare_numbers(x: u64): bool {
+    x != 0
+}
+
+public fun plain(paused: bool): bool {
+    paused && !paused
+}
+
+public fun assign(): bool {
+    let mut flag = false;
+    flag = true;
+    flag
+}
+
+// Literal against literal is constant_assert_condition's business.
+public fun constant(): bool {
+    true == false
+}
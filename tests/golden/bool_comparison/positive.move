@@ -0,0 +1,34 @@
+// Positive cases for bool_comparison: `==`/`!=` against a boolean literal.
+module 0x1::bool_comparison_positive;
+
+public fun eq_true(paused: bool): bool {
+    paused == true
+}
+
+public fun eq_false(paused: bool): bool {
+    paused == false
+}
+
+public fun ne_true(paused: bool): bool {
+    paused != true
+}
+
+public fun ne_false(paused: bool): bool {
+    paused != false
+}
+
+public fun literal_first(paused: bool): bool {
+    true == paused
+}
+
+public fun keeps_precedence(a: bool, b: bool): bool {
+    a && b == false
+}
+
+public fun complex_operand(x: u64, y: u64): bool {
+    (x < y || x == 0) == false
+}
+
+public fun method_operand(v: &vector<u64>): bool {
+    v.is_empty() != true
+}
@@ -0,0 +1,24 @@
+// Negative cases for constant_assert_condition: conditions that depend on runtime values.
+module 0x1::constant_assert_condition_negative;
+
+const E_ZERO: u64 = 0;
+const ENABLED: bool = true;
+
+public fun checks_amount(amount: u64): u64 {
+    assert!(amount > 0, E_ZERO);
+    amount
+}
+
+public fun checks_constant(amount: u64): u64 {
+    assert!(ENABLED, E_ZERO);
+    if (ENABLED == true) amount else 0
+}
+
+public fun checks_call(v: &vector<u64>): bool {
+    assert!(v.length() == 1, E_ZERO);
+    if (v.is_empty()) false else true
+}
+
+public fun explicit_abort() {
+    abort E_ZERO
+}
@@ -0,0 +1,26 @@
+// Positive cases for constant_assert_condition: conditions built only from literals.
+module 0x1::constant_assert_condition_positive;
+
+const E_UNREACHABLE: u64 = 0;
+
+public fun never_aborts(amount: u64): u64 {
+    assert!(true, E_UNREACHABLE);
+    amount
+}
+
+public fun always_aborts() {
+    assert!(false, 0);
+}
+
+public fun literal_comparison(amount: u64): u64 {
+    assert!(1 == 1 && !(2 < 1), E_UNREACHABLE);
+    amount
+}
+
+public fun dead_branch(amount: u64): u64 {
+    if (0x10 > 100u64) {
+        amount + 1
+    } else {
+        amount
+    }
+}
@@ -14,4 +14,18 @@ module 0x1::test {
 
     // GOOD: witness constants in PascalCase
     const WITNESS: bool = true;
+
+    // GOOD: error codes already prefixed are left alone
+    const E_NOT_OWNER: u64 = 4;
+    const ENotFound: u64 = 5;
+
+    // GOOD: used as an abort code but also as a value, so not only an error code
+    const LIMIT: u64 = 10;
+
+    public fun check(is_owner: bool, found: bool, amount: u64): u64 {
+        assert!(is_owner, E_NOT_OWNER);
+        if (!found) abort ENotFound;
+        if (amount > LIMIT) abort LIMIT;
+        LIMIT
+    }
 }
@@ -13,4 +13,11 @@ module 0x1::test {
 
     // BAD: camelCase
     const errorCode: u64 = 2;
+
+    // BAD: only used as an abort code, should be named like an error (ENotOwner)
+    const NOT_OWNER: u64 = 3;
+
+    public fun check_owner(is_owner: bool) {
+        assert!(is_owner, NOT_OWNER);
+    }
 }
@@ -0,0 +1,56 @@
+// Negative cases for excessive_nesting: nesting at or under the limit of 5.
+module 0x1::excessive_nesting_negative;
+
+public enum Action has drop {
+    Deposit,
+    Withdraw,
+}
+
+public fun five_deep(a: bool, b: bool, c: bool, d: bool, e: bool): u64 {
+    let mut total = 0;
+    if (a) {
+        if (b) {
+            while (c) {
+                if (d) {
+                    if (e) {
+                        total = total + 1;
+                    };
+                };
+            };
+        };
+    };
+    total
+}
+
+// Each `else if` is a sibling branch, not a deeper level.
+public fun long_chain(x: u64): u64 {
+    if (x == 0) {
+        0
+    } else if (x == 1) {
+        1
+    } else if (x == 2) {
+        2
+    } else if (x == 3) {
+        3
+    } else if (x == 4) {
+        4
+    } else {
+        5
+    }
+}
+
+// The `match` itself adds no level: the arm block plus four `if`s is five.
+public fun dispatch(action: Action, a: bool, b: bool, c: bool, d: bool): u64 {
+    match (action) {
+        Action::Deposit => {
+            if (a) {
+                if (b) {
+                    if (c) {
+                        if (d) { 1 } else { 2 }
+                    } else { 3 }
+                } else { 4 }
+            } else { 5 }
+        },
+        Action::Withdraw => 0,
+    }
+}
@@ -0,0 +1,20 @@
+// Positive cases for excessive_nesting: blocks nested six levels deep (limit 5).
+module 0x1::excessive_nesting_positive;
+
+public fun six_deep(a: bool, b: bool, c: bool, d: bool, e: bool, f: bool): u64 {
+    let mut total = 0;
+    if (a) {
+        if (b) {
+            while (c) {
+                if (d) {
+                    if (e) {
+                        if (f) {
+                            total = total + 1;
+                        };
+                    };
+                };
+            };
+        };
+    };
+    total
+}
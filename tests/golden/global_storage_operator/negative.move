@@ -0,0 +1,29 @@
+// Negative cases for global_storage_operator: Sui objects and qualified calls.
+module 0x1::global_storage_operator_negative;
+
+use sui::dynamic_field;
+
+public struct Counter has key, store {
+    id: UID,
+    value: u64,
+}
+
+public fun read(counter: &Counter): u64 {
+    counter.value
+}
+
+public fun increment(counter: &mut Counter) {
+    counter.value = counter.value + 1;
+}
+
+public fun has_entry(counter: &Counter, key: u64): bool {
+    dynamic_field::exists_(&counter.id, key)
+}
+
+public fun borrow_global_value(counter: &Counter): u64 {
+    counter.value
+}
+
+public fun uses_helper(counter: &Counter): u64 {
+    borrow_global_value(counter)
+}
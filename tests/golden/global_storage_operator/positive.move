@@ -0,0 +1,28 @@
+// Positive cases for global_storage_operator: core Move global storage in a Sui module.
+module 0x1::global_storage_operator_positive;
+
+public struct Counter has key, store {
+    value: u64,
+}
+
+public fun publish(account: &signer) {
+    move_to(account, Counter { value: 0 });
+}
+
+public fun read(addr: address): u64 {
+    borrow_global<Counter>(addr).value
+}
+
+public fun increment(addr: address) {
+    let counter = borrow_global_mut<Counter>(addr);
+    counter.value = counter.value + 1;
+}
+
+public fun remove(addr: address): u64 {
+    let Counter { value } = move_from<Counter>(addr);
+    value
+}
+
+public fun is_published(addr: address): bool {
+    exists<Counter>(addr)
+}
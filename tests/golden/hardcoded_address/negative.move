@@ -0,0 +1,27 @@
+// Golden test: hardcoded_address - NEGATIVE (should NOT trigger lint)
+// Description: named constants, framework addresses, and test code
+
+module my_pkg::payout {
+    use sui::coin::Coin;
+    use sui::sui::SUI;
+
+    const TREASURY: address = @0xABC;
+
+    public fun pay_fee(fee: Coin<SUI>) {
+        transfer::public_transfer(fee, TREASURY);
+    }
+
+    public fun is_framework(addr: address): bool {
+        addr == @0x2 || addr == @0x1
+    }
+
+    public fun is_named(addr: address): bool {
+        addr == @my_pkg
+    }
+
+    #[test]
+    fun test_pay_fee() {
+        let user = @0xBEEF;
+        assert!(user != TREASURY, 0);
+    }
+}
@@ -0,0 +1,17 @@
+// Golden test: hardcoded_address - POSITIVE (should trigger lint)
+// Description: numeric address literals written inline in function bodies
+
+module my_pkg::payout {
+    use sui::coin::Coin;
+    use sui::sui::SUI;
+
+    public fun pay_fee(fee: Coin<SUI>) {
+        // BAD: fee recipient is hidden inside the function body
+        transfer::public_transfer(fee, @0xABC);
+    }
+
+    public fun is_admin(addr: address): bool {
+        // BAD: inline address in a comparison
+        addr == @0x1234
+    }
+}
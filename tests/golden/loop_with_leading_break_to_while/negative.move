@@ -0,0 +1,74 @@
+// Golden test: loop_with_leading_break_to_while - NEGATIVE (should NOT trigger lint)
+// Description: loops where the leading break is not the only exit, or not leading at all
+
+module 0x1::test {
+    // GOOD: already a while loop
+    public fun count_up(n: u64): u64 {
+        let mut i = 0;
+        while (i < n) {
+            i = i + 1;
+        };
+        i
+    }
+
+    // GOOD: break is not the first statement
+    public fun trailing_break(n: u64): u64 {
+        let mut i = 0;
+        loop {
+            i = i + 1;
+            if (i >= n) break;
+        };
+        i
+    }
+
+    // GOOD: a second break exits the same loop
+    public fun two_exits(n: u64, stop: u64): u64 {
+        let mut i = 0;
+        loop {
+            if (i >= n) break;
+            if (i == stop) {
+                break
+            };
+            i = i + 1;
+        };
+        i
+    }
+
+    // GOOD: the leading if-break lives in a nested loop and exits only that loop
+    public fun nested_inner_break(n: u64): u64 {
+        let mut i = 0;
+        let mut total = 0;
+        loop {
+            while (i < n) {
+                if (i == 3) break;
+                i = i + 1;
+            };
+            total = total + i;
+            if (total > 100) return total;
+            i = 0;
+        }
+    }
+
+    // GOOD: a labeled break in a nested loop may exit the outer loop
+    public fun nested_labeled_break(n: u64): u64 {
+        let mut i = 0;
+        'outer: loop {
+            if (i >= n) break;
+            loop {
+                if (i == 7) break 'outer;
+                i = i + 1;
+                if (i % 2 == 0) continue 'outer;
+            };
+        };
+        i
+    }
+
+    // GOOD: else branch means the condition is not a plain guard
+    public fun with_else(n: u64): u64 {
+        let mut i = 0;
+        loop {
+            if (i >= n) break else i = i + 1;
+        };
+        i
+    }
+}
@@ -0,0 +1,27 @@
+// Golden test: loop_with_leading_break_to_while - POSITIVE (should trigger lint)
+// Description: `loop` whose first statement is `if (cond) break`
+
+module 0x1::test {
+    public fun count_up(n: u64): u64 {
+        let mut i = 0;
+        loop {
+            if (i >= n) break;
+            i = i + 1;
+        };
+        i
+    }
+
+    public fun drain(v: &mut vector<u64>) {
+        loop {
+            if (v.is_empty()) { break };
+            v.pop_back();
+        };
+    }
+
+    public fun until_done(done: &mut bool) {
+        loop {
+            if (*done) break;
+            *done = true;
+        };
+    }
+}
@@ -0,0 +1,36 @@
+// Negative cases for manual_vector_build: vectors that are not built by straight-line pushes.
+module 0x1::manual_vector_build_negative;
+
+public fun conditional_push(a: u64, b: u64, c: u64, flag: bool): vector<u64> {
+    let mut v = vector::empty();
+    v.push_back(a);
+    if (flag) v.push_back(b);
+    v.push_back(c);
+    v
+}
+
+public fun reads_between(a: u64, b: u64): vector<u64> {
+    let mut v = vector::empty();
+    v.push_back(a);
+    let n = v.length();
+    v.push_back(b + n);
+    v
+}
+
+public fun element_reads_vector(a: u64): vector<u64> {
+    let mut v = vector::empty();
+    v.push_back(a);
+    v.push_back(v.length());
+    v
+}
+
+public fun other_vector(w: &mut vector<u64>, a: u64): vector<u64> {
+    let v = vector::empty();
+    w.push_back(a);
+    v
+}
+
+public fun no_pushes(): vector<u64> {
+    let v = vector::empty();
+    v
+}
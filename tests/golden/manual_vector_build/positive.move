@@ -0,0 +1,24 @@
+// Positive cases for manual_vector_build: `vector::empty()` followed only by pushes.
+module 0x1::manual_vector_build_positive;
+
+public fun fees(protocol_fee: u64, referral_fee: u64): vector<u64> {
+    let mut fees = vector::empty();
+    vector::push_back(&mut fees, protocol_fee);
+    vector::push_back(&mut fees, referral_fee);
+    fees
+}
+
+public fun header(): vector<u8> {
+    let mut bytes = vector::empty<u8>();
+    bytes.push_back(1);
+    bytes.push_back(2);
+    bytes.push_back(3);
+    bytes
+}
+
+public fun owners(owner: address): vector<address> {
+    let mut owners: vector<address> = vector::empty();
+    owners.push_back(owner);
+    owners.reverse();
+    owners
+}
@@ -0,0 +1,31 @@
+// Negative cases for needless_bool: branches that do more than yield a literal.
+module 0x1::needless_bool_negative;
+
+public fun same_literal(amount: u64): bool {
+    if (amount > 0) true else true
+}
+
+public fun side_effect(counter: &mut u64, amount: u64): bool {
+    if (amount > 0) {
+        *counter = *counter + 1;
+        true
+    } else {
+        false
+    }
+}
+
+public fun mixed_return(amount: u64): bool {
+    if (amount > 0) {
+        return true
+    } else {
+        false
+    }
+}
+
+public fun no_else(amount: u64, flag: &mut bool) {
+    if (amount > 0) *flag = true;
+}
+
+public fun already_direct(amount: u64): bool {
+    amount > 0
+}
@@ -0,0 +1,23 @@
+// Positive cases for needless_bool: `if` branches that are only boolean literals.
+module 0x1::needless_bool_positive;
+
+public fun is_positive(amount: u64): bool {
+    if (amount > 0) true else false
+}
+
+public fun is_empty(amount: u64): bool {
+    if (amount > 0) { false } else { true }
+}
+
+public fun is_paused(paused: bool, admin: bool): bool {
+    if (paused && !admin) {
+        return true
+    } else {
+        return false
+    }
+}
+
+public fun is_open(paused: bool, admin: bool): bool {
+    let open = if (!paused && admin) false else true;
+    open
+}
@@ -0,0 +1,42 @@
+// Negative cases for redundant_binding: near misses that change the binding.
+module 0x1::redundant_binding_negative;
+
+public struct Pool has drop {
+    value: u64,
+    other: u64,
+}
+
+// A type annotation may change the type.
+public fun annotated(amount: u64): u128 {
+    let amount: u128 = (amount as u128);
+    amount
+}
+
+public fun annotated_same(amount: u64): u64 {
+    let amount: u64 = amount;
+    amount
+}
+
+// `let mut` changes mutability.
+public fun make_mut(count: u64): u64 {
+    let mut count = count;
+    count = count + 1;
+    count
+}
+
+// Different names, derefs, and calls are real work.
+public fun different(a: u64, b: &u64): u64 {
+    let x = a;
+    let y = *b;
+    let z = copy x;
+    x + y + z
+}
+
+public fun other_field(pool: &mut Pool) {
+    pool.value = pool.other;
+}
+
+public fun comparison(a: u64): bool {
+    let same = a == a;
+    same
+}
@@ -0,0 +1,26 @@
+// Positive cases for redundant_binding.
+module 0x1::redundant_binding_positive;
+
+public struct Pool has drop {
+    value: u64,
+}
+
+public fun rebind(amount: u64): u64 {
+    let amount = amount;
+    amount
+}
+
+public fun rebind_move(pool: Pool): Pool {
+    let pool = move pool;
+    pool
+}
+
+public fun self_assign(mut count: u64): u64 {
+    count = count;
+    count = count + 1;
+    count
+}
+
+public fun field_self_assign(pool: &mut Pool) {
+    pool.value = pool.value;
+}
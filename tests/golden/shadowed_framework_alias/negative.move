@@ -0,0 +1,19 @@
+// Golden test: shadowed_framework_alias - NEGATIVE (should NOT trigger lint)
+// Description: framework imports and aliases that do not shadow framework names
+
+module my_pkg::vault {
+    use sui::transfer;
+    use sui::event;
+    use sui::coin::{Self, Coin};
+    use sui::balance as balance;
+    use my_pkg::evil as evil_transfer;
+    use my_pkg::{fake_event as my_event};
+
+    public struct AdminCap has key, store {
+        id: UID,
+    }
+
+    public fun publish(cap: AdminCap) {
+        transfer::share_object(cap);
+    }
+}
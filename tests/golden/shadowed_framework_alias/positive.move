@@ -0,0 +1,20 @@
+// Golden test: shadowed_framework_alias - POSITIVE (should trigger lint)
+// Description: `use` aliases that redirect framework module names to other modules
+
+module my_pkg::vault {
+    // BAD: `transfer::share_object` below no longer reaches `sui::transfer`,
+    // though a reader (or a name-only matcher) would take it for the framework call.
+    use my_pkg::evil as transfer;
+    // BAD: aliased inside a member list
+    use my_pkg::{fake_event as event};
+    // BAD: `Self` aliased to a framework name
+    use my_pkg::fake_coin::{Self as coin};
+
+    public struct AdminCap has key, store {
+        id: UID,
+    }
+
+    public fun publish(cap: AdminCap) {
+        transfer::share_object(cap);
+    }
+}
@@ -12,7 +12,7 @@
 
 use move_clippy::create_default_engine;
 use move_clippy::diagnostics::Diagnostic;
-use move_clippy::lint::LintRegistry;
+use move_clippy::lint::{LintRegistry, LintSettings, Thresholds};
 use std::path::Path;
 
 /// Filter diagnostics to only those for a specific lint
@@ -35,6 +35,21 @@ fn create_experimental_engine() -> move_clippy::LintEngine {
     move_clippy::LintEngine::new(registry)
 }
 
+/// Create an engine with preview lints enabled
+fn create_preview_engine() -> move_clippy::LintEngine {
+    let registry = LintRegistry::default_rules_filtered_with_experimental(
+        &[],   // only
+        &[],   // skip
+        &[],   // disabled
+        false, // full_mode
+        true,  // preview
+        false, // experimental
+    )
+    .expect("Failed to create preview registry");
+
+    move_clippy::LintEngine::new(registry)
+}
+
 /// Format diagnostics for display
 fn format_diags(diags: &[Diagnostic]) -> String {
     let mut lines: Vec<String> = diags
@@ -175,6 +190,88 @@ fn golden_empty_vector_literal_negative() {
     );
 }
 
+#[test]
+fn golden_redundant_binding_positive() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/redundant_binding/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "redundant_binding");
+
+    assert_eq!(
+        filtered.len(),
+        4,
+        "Expected one finding per redundant statement.\nGot: {}",
+        format_diags(&diags)
+    );
+    assert!(
+        filtered
+            .iter()
+            .any(|d| d.message.contains("`let pool = move pool;`")),
+        "Expected `let x = move x;` to be reported.\nGot: {}",
+        format_diags(&diags)
+    );
+    assert!(
+        filtered.iter().any(|d| d
+            .message
+            .starts_with("Field assignment `pool.value = pool.value;`")),
+        "Expected the field self-assignment to be reported separately.\nGot: {}",
+        format_diags(&diags)
+    );
+}
+
+#[test]
+fn golden_redundant_binding_negative() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/redundant_binding/negative.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "redundant_binding");
+
+    assert!(
+        filtered.is_empty(),
+        "redundant_binding should NOT trigger on annotated, `mut`, or different bindings.\nGot: {}",
+        format_diags(&filtered.into_iter().cloned().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn golden_needless_bool_positive() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/needless_bool/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "needless_bool");
+
+    let replacements: Vec<_> = filtered
+        .iter()
+        .filter_map(|d| d.suggestions.first())
+        .map(|s| s.replacement.as_str())
+        .collect();
+    assert_eq!(
+        replacements,
+        vec![
+            "amount > 0",
+            "amount <= 0",
+            "return paused && !admin",
+            "!(!paused && admin)",
+        ],
+        "Expected both polarities to be rewritten.\nGot: {}",
+        format_diags(&diags)
+    );
+}
+
+#[test]
+fn golden_needless_bool_negative() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/needless_bool/negative.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "needless_bool");
+
+    assert!(
+        filtered.is_empty(),
+        "needless_bool should NOT trigger on branches with side effects or mixed shapes.\nGot: {}",
+        format_diags(&filtered.into_iter().cloned().collect::<Vec<_>>())
+    );
+}
+
 #[test]
 fn golden_unneeded_return_positive() {
     let engine = create_default_engine();
@@ -324,9 +421,29 @@ fn golden_constant_naming_negative() {
     );
 }
 
+#[test]
+fn golden_constant_naming_suggests_e_pascal_case_for_abort_codes() {
+    let engine = create_default_engine();
+    let src = include_str!("golden/constant_naming/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "constant_naming");
+
+    let hit = filtered
+        .iter()
+        .find(|d| d.message.contains("`NOT_OWNER`"))
+        .unwrap_or_else(|| {
+            panic!(
+                "constant_naming should suggest an EPascalCase name for abort-only constants.\nGot: {}",
+                format_diags(&diags)
+            )
+        });
+    let suggestion = hit.suggestions.first().expect("rename suggestion");
+    assert_eq!(suggestion.replacement, "ENotOwner");
+}
+
 #[test]
 fn golden_stale_oracle_price_positive() {
-    // DEPRECATED: This lint is now deprecated in favor of stale_oracle_price_v3 (CFG-aware)
+    // DEPRECATED: This lint is now deprecated in favor of stale_oracle_price (CFG-aware)
     // Deprecated lints require --experimental flag and don't fire by default
     let engine = create_default_engine();
     let src = include_str!("golden/stale_oracle_price/positive.move");
@@ -834,3 +951,450 @@ fn golden_typed_abort_code_negative() {
         "typed_abort_code should not trigger on negative.move"
     );
 }
+
+// ============================================================================
+// Preview Lint Tests - Require --preview Flag
+// ============================================================================
+
+#[test]
+fn preview_loop_with_leading_break_to_while_not_enabled_by_default() {
+    let engine = create_default_engine();
+    let src = include_str!("golden/loop_with_leading_break_to_while/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "loop_with_leading_break_to_while");
+
+    assert!(
+        filtered.is_empty(),
+        "loop_with_leading_break_to_while should NOT fire without --preview.\nGot: {}",
+        format_diags(&filtered.into_iter().cloned().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn preview_loop_with_leading_break_to_while_positive() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/loop_with_leading_break_to_while/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "loop_with_leading_break_to_while");
+
+    assert_eq!(
+        filtered.len(),
+        3,
+        "Expected one finding per leading-break loop.\nGot: {}",
+        format_diags(&diags)
+    );
+    assert!(
+        filtered.iter().any(|d| d.message.contains("while (i < n)")),
+        "Expected `i >= n` to be negated to `i < n`.\nGot: {}",
+        format_diags(&diags)
+    );
+}
+
+#[test]
+fn preview_loop_with_leading_break_to_while_negative() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/loop_with_leading_break_to_while/negative.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "loop_with_leading_break_to_while");
+
+    assert!(
+        filtered.is_empty(),
+        "loop_with_leading_break_to_while should NOT trigger on these loops.\nGot: {}",
+        format_diags(&filtered.into_iter().cloned().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn preview_shadowed_framework_alias_not_enabled_by_default() {
+    let engine = create_default_engine();
+    let src = include_str!("golden/shadowed_framework_alias/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "shadowed_framework_alias");
+
+    assert!(
+        filtered.is_empty(),
+        "shadowed_framework_alias should NOT fire without --preview.\nGot: {}",
+        format_diags(&filtered.into_iter().cloned().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn preview_shadowed_framework_alias_positive() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/shadowed_framework_alias/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "shadowed_framework_alias");
+
+    assert_eq!(
+        filtered.len(),
+        3,
+        "Expected one finding per shadowing alias.\nGot: {}",
+        format_diags(&diags)
+    );
+    assert!(
+        filtered.iter().any(|d| d
+            .message
+            .contains("`my_pkg::evil` is imported as `transfer`")),
+        "Expected the `transfer` alias to be reported.\nGot: {}",
+        format_diags(&diags)
+    );
+}
+
+#[test]
+fn preview_shadowed_framework_alias_negative() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/shadowed_framework_alias/negative.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "shadowed_framework_alias");
+
+    assert!(
+        filtered.is_empty(),
+        "shadowed_framework_alias should NOT trigger on framework imports.\nGot: {}",
+        format_diags(&filtered.into_iter().cloned().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn preview_hardcoded_address_not_enabled_by_default() {
+    let engine = create_default_engine();
+    let src = include_str!("golden/hardcoded_address/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "hardcoded_address");
+
+    assert!(
+        filtered.is_empty(),
+        "hardcoded_address should NOT fire without --preview.\nGot: {}",
+        format_diags(&filtered.into_iter().cloned().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn preview_hardcoded_address_positive() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/hardcoded_address/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "hardcoded_address");
+
+    assert_eq!(
+        filtered.len(),
+        2,
+        "Expected one finding per inline address.\nGot: {}",
+        format_diags(&diags)
+    );
+    assert!(
+        filtered
+            .iter()
+            .any(|d| d.message.contains("`@0xABC` in `pay_fee`")),
+        "Expected the transfer recipient to be reported.\nGot: {}",
+        format_diags(&diags)
+    );
+}
+
+#[test]
+fn preview_hardcoded_address_negative() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/hardcoded_address/negative.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "hardcoded_address");
+
+    assert!(
+        filtered.is_empty(),
+        "hardcoded_address should NOT trigger on constants, framework addresses, or tests.\nGot: {}",
+        format_diags(&filtered.into_iter().cloned().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn preview_hardcoded_address_respects_known_addresses() {
+    let registry = LintRegistry::default_rules_filtered(
+        &["hardcoded_address".to_string()],
+        &[],
+        &[],
+        false,
+        true,
+    )
+    .expect("registry");
+    let settings = move_clippy::LintSettings::default()
+        .with_known_addresses(["0xabc".to_string(), "@0x1234".to_string()]);
+    let engine = move_clippy::LintEngine::new_with_settings(registry, settings);
+    let src = include_str!("golden/hardcoded_address/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+
+    assert!(
+        filter_lint(&diags, "hardcoded_address").is_empty(),
+        "configured known addresses should not be flagged.\nGot: {}",
+        format_diags(&diags)
+    );
+}
+
+#[test]
+fn preview_manual_vector_build_not_enabled_by_default() {
+    let engine = create_default_engine();
+    let src = include_str!("golden/manual_vector_build/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "manual_vector_build");
+
+    assert!(
+        filtered.is_empty(),
+        "manual_vector_build should NOT fire without --preview.\nGot: {}",
+        format_diags(&filtered.into_iter().cloned().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn preview_manual_vector_build_positive() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/manual_vector_build/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "manual_vector_build");
+
+    let replacements: Vec<_> = filtered
+        .iter()
+        .filter_map(|d| d.suggestions.first())
+        .map(|s| s.replacement.as_str())
+        .collect();
+    assert_eq!(
+        replacements,
+        vec![
+            "let fees = vector[protocol_fee, referral_fee];",
+            "let bytes = vector<u8>[1, 2, 3];",
+            "let mut owners: vector<address> = vector[owner];",
+        ],
+        "Expected each push sequence to become a literal, keeping `mut` only if still needed.\nGot: {}",
+        format_diags(&diags)
+    );
+}
+
+#[test]
+fn preview_manual_vector_build_negative() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/manual_vector_build/negative.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "manual_vector_build");
+
+    assert!(
+        filtered.is_empty(),
+        "manual_vector_build should NOT trigger on conditional or interleaved pushes.\nGot: {}",
+        format_diags(&filtered.into_iter().cloned().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn preview_constant_assert_condition_not_enabled_by_default() {
+    let engine = create_default_engine();
+    let src = include_str!("golden/constant_assert_condition/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "constant_assert_condition");
+
+    assert!(
+        filtered.is_empty(),
+        "constant_assert_condition should NOT fire without --preview.\nGot: {}",
+        format_diags(&filtered.into_iter().cloned().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn preview_constant_assert_condition_positive() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/constant_assert_condition/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "constant_assert_condition");
+
+    let messages: Vec<_> = filtered.iter().map(|d| d.message.as_str()).collect();
+    assert_eq!(messages.len(), 4, "Got: {}", format_diags(&diags));
+    assert!(messages[0].starts_with("`assert!` condition `true` is always true"));
+    assert!(messages[0].contains("never aborts"));
+    assert!(messages[1].starts_with("`assert!` condition `false` is always false"));
+    assert!(messages[1].contains("always aborts"));
+    assert!(messages[2].contains("`1 == 1 && !(2 < 1)` is always true"));
+    assert!(messages[3].starts_with("`if` condition"));
+    assert!(messages[3].contains("is always false"));
+}
+
+#[test]
+fn preview_constant_assert_condition_negative() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/constant_assert_condition/negative.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "constant_assert_condition");
+
+    assert!(
+        filtered.is_empty(),
+        "constant_assert_condition should NOT trigger on conditions that read values.\nGot: {}",
+        format_diags(&filtered.into_iter().cloned().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn preview_bool_comparison_not_enabled_by_default() {
+    let engine = create_default_engine();
+    let src = include_str!("golden/bool_comparison/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "bool_comparison");
+
+    assert!(
+        filtered.is_empty(),
+        "bool_comparison should NOT fire without --preview.\nGot: {}",
+        format_diags(&filtered.into_iter().cloned().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn preview_bool_comparison_positive() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/bool_comparison/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "bool_comparison");
+
+    let replacements: Vec<_> = filtered
+        .iter()
+        .filter_map(|d| d.suggestions.first())
+        .map(|s| s.replacement.as_str())
+        .collect();
+    assert_eq!(
+        replacements,
+        vec![
+            "paused",
+            "!paused",
+            "!paused",
+            "paused",
+            "paused",
+            "!b",
+            "!(x < y || x == 0)",
+            "!v.is_empty()",
+        ],
+        "Expected each comparison to simplify with the right polarity.\nGot: {}",
+        format_diags(&diags)
+    );
+}
+
+#[test]
+fn preview_bool_comparison_negative() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/bool_comparison/negative.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "bool_comparison");
+
+    assert!(
+        filtered.is_empty(),
+        "bool_comparison should NOT trigger without a boolean literal operand.\nGot: {}",
+        format_diags(&filtered.into_iter().cloned().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn preview_excessive_nesting_not_enabled_by_default() {
+    let engine = create_default_engine();
+    let src = include_str!("golden/excessive_nesting/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "excessive_nesting");
+
+    assert!(
+        filtered.is_empty(),
+        "excessive_nesting should NOT fire without --preview.\nGot: {}",
+        format_diags(&filtered.into_iter().cloned().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn preview_excessive_nesting_positive() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/excessive_nesting/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "excessive_nesting");
+
+    assert_eq!(filtered.len(), 1, "Got: {}", format_diags(&diags));
+    assert_eq!(
+        filtered[0].message,
+        "`six_deep` nests blocks 6 levels deep (limit 5)"
+    );
+    // Reported at the innermost block, `if (f) { ... }`.
+    assert_eq!(filtered[0].span.start.row, 11);
+}
+
+#[test]
+fn preview_excessive_nesting_negative() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/excessive_nesting/negative.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "excessive_nesting");
+
+    assert!(
+        filtered.is_empty(),
+        "excessive_nesting should NOT trigger at or under the limit.\nGot: {}",
+        format_diags(&filtered.into_iter().cloned().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn preview_excessive_nesting_respects_configured_limit() {
+    let nesting_diags = |max_nesting_depth: usize, src: &str| {
+        let settings = LintSettings::default().with_thresholds(Thresholds {
+            max_nesting_depth: Some(max_nesting_depth),
+            ..Thresholds::default()
+        });
+        let registry = LintRegistry::default_rules_filtered(
+            &["excessive_nesting".to_string()],
+            &[],
+            &[],
+            false,
+            true,
+        )
+        .expect("registry");
+        move_clippy::LintEngine::new_with_settings(registry, settings)
+            .lint_source(src)
+            .expect("linting should succeed")
+            .len()
+    };
+
+    let positive = include_str!("golden/excessive_nesting/positive.move");
+    assert_eq!(nesting_diags(6, positive), 0);
+    let negative = include_str!("golden/excessive_nesting/negative.move");
+    assert_eq!(nesting_diags(4, negative), 2, "five_deep and dispatch");
+}
+
+#[test]
+fn preview_global_storage_operator_not_enabled_by_default() {
+    let engine = create_default_engine();
+    let src = include_str!("golden/global_storage_operator/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "global_storage_operator");
+
+    assert!(
+        filtered.is_empty(),
+        "global_storage_operator should NOT fire without --preview.\nGot: {}",
+        format_diags(&filtered.into_iter().cloned().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn preview_global_storage_operator_positive() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/global_storage_operator/positive.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "global_storage_operator");
+
+    let messages: Vec<_> = filtered.iter().map(|d| d.message.as_str()).collect();
+    assert_eq!(messages.len(), 5, "Got: {}", format_diags(&diags));
+    assert!(messages[0].starts_with("`move_to` is core Move global storage"));
+    assert!(messages[0].contains("`dynamic_field::add`"));
+    assert!(messages[1].starts_with("`borrow_global` is"));
+    assert!(messages[2].starts_with("`borrow_global_mut` is"));
+    assert!(messages[3].starts_with("`move_from` is"));
+    assert!(messages[4].starts_with("`exists` is"));
+    assert!(messages[4].contains("`dynamic_field::exists_`"));
+}
+
+#[test]
+fn preview_global_storage_operator_negative() {
+    let engine = create_preview_engine();
+    let src = include_str!("golden/global_storage_operator/negative.move");
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let filtered = filter_lint(&diags, "global_storage_operator");
+
+    assert!(
+        filtered.is_empty(),
+        "global_storage_operator should NOT trigger on objects or qualified calls.\nGot: {}",
+        format_diags(&filtered.into_iter().cloned().collect::<Vec<_>>())
+    );
+}
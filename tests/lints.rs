@@ -1,5 +1,9 @@
 use move_clippy::create_default_engine;
-use move_clippy::lint::{all_known_lints_with_aliases, is_lint_alias, resolve_lint_alias};
+use move_clippy::lint::{
+    DEPRECATED_LINTS, LintCategory, LintRegistry, LintSelection, RuleGroup,
+    all_known_lints_with_aliases, check_lint_name, deprecation_hint, is_lint_alias,
+    resolve_lint_alias,
+};
 
 #[test]
 fn modern_module_syntax_flags_legacy_block_form() {
@@ -94,3 +98,138 @@ fn all_known_lints_with_aliases_includes_canonical() {
     assert!(known.contains("constant_naming"));
     assert!(known.contains("share_owned")); // semantic lint
 }
+
+#[test]
+fn lint_selection_parses_categories_and_groups() {
+    let selection = LintSelection::parse(
+        &["security".to_string(), "Style".to_string()],
+        &["stable".to_string()],
+    )
+    .expect("selection should parse");
+    assert_eq!(
+        selection.categories,
+        vec![LintCategory::Security, LintCategory::Style]
+    );
+    assert_eq!(selection.groups, vec![RuleGroup::Stable]);
+
+    assert!(LintSelection::parse(&["nope".to_string()], &[]).is_err());
+    assert!(LintSelection::parse(&[], &["nope".to_string()]).is_err());
+}
+
+#[test]
+fn lint_selection_filters_registry_by_category() {
+    let selection =
+        LintSelection::parse(&["security".to_string()], &[]).expect("selection should parse");
+    let registry =
+        LintRegistry::default_rules_filtered_with_experimental(&[], &[], &[], false, true, true)
+            .expect("registry")
+            .retain_selected(&selection);
+
+    let descriptors: Vec<_> = registry.descriptors().collect();
+    assert!(!descriptors.is_empty());
+    assert!(
+        descriptors
+            .iter()
+            .all(|d| d.category == LintCategory::Security)
+    );
+}
+
+#[test]
+fn lint_selection_intersects_with_only() {
+    let only = vec![
+        "prefer_to_string".to_string(),
+        "suspicious_overflow_check".to_string(),
+    ];
+    let selection = LintSelection::parse(&["security".to_string()], &["stable".to_string()])
+        .expect("selection should parse");
+    let registry = LintRegistry::default_rules_filtered(&only, &[], &[], false, false)
+        .expect("registry")
+        .retain_selected(&selection);
+
+    let names: Vec<_> = registry.descriptors().map(|d| d.name).collect();
+    assert_eq!(names, vec!["suspicious_overflow_check"]);
+}
+
+#[test]
+fn retain_fixable_keeps_only_lints_with_fixes() {
+    let registry =
+        LintRegistry::default_rules_filtered_with_experimental(&[], &[], &[], false, true, true)
+            .expect("registry");
+    let total = registry.descriptors().count();
+    let fixable: Vec<_> = registry.retain_fixable().descriptors().collect();
+
+    assert!(!fixable.is_empty());
+    assert!(fixable.len() < total);
+    assert!(fixable.iter().all(|d| d.fix.available));
+    assert!(fixable.iter().any(|d| d.name == "modern_module_syntax"));
+}
+
+// ============================================================================
+// Deprecated Lint Tests
+// ============================================================================
+
+#[test]
+fn deprecation_hint_names_the_replacement() {
+    assert_eq!(
+        deprecation_hint("stale_oracle_price_v2").as_deref(),
+        Some("deprecated: use stale_oracle_price")
+    );
+    assert_eq!(
+        deprecation_hint("invalid_otw").as_deref(),
+        Some("deprecated: removed without a replacement")
+    );
+    assert_eq!(deprecation_hint("modern_module_syntax"), None);
+}
+
+#[test]
+fn registered_deprecated_lints_take_hint_from_table() {
+    assert_eq!(
+        deprecation_hint("capability_antipatterns").as_deref(),
+        Some("deprecated: use copyable_capability")
+    );
+    assert_eq!(
+        deprecation_hint("share_owned_authority").as_deref(),
+        Some("deprecated without a replacement")
+    );
+    assert_eq!(
+        deprecation_hint("stale_oracle_price_v3").as_deref(),
+        Some("deprecated: use stale_oracle_price")
+    );
+
+    let registry = move_clippy::unified_registry();
+    for lint in registry.descriptors() {
+        if lint.group == RuleGroup::Deprecated {
+            assert!(
+                DEPRECATED_LINTS.iter().any(|(name, _)| *name == lint.name),
+                "{} is deprecated but missing from DEPRECATED_LINTS",
+                lint.name
+            );
+        }
+    }
+    for (name, replacement) in DEPRECATED_LINTS {
+        if let Some(replacement) = replacement {
+            let lint = registry
+                .get(replacement)
+                .unwrap_or_else(|| panic!("{name} is replaced by unknown lint {replacement}"));
+            assert_ne!(lint.descriptor.group, RuleGroup::Deprecated, "{name}");
+        }
+    }
+}
+
+#[test]
+fn removed_lint_reports_deprecation_instead_of_unknown() {
+    let err = check_lint_name("price_manipulation_window").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "lint price_manipulation_window is deprecated: removed without a replacement"
+    );
+
+    let err =
+        LintRegistry::default_rules_filtered(&[], &[], &["invalid_otw".to_string()], false, false)
+            .err()
+            .expect("removed lint should be rejected");
+    assert!(err.to_string().contains("deprecated"), "got: {err}");
+
+    let err = check_lint_name("not_a_lint").unwrap_err();
+    assert_eq!(err.to_string(), "unknown lint: not_a_lint");
+}
@@ -133,15 +133,15 @@ mod phase2 {
         let visitors = absint_lints::create_visitors(true, false);
         assert_eq!(
             visitors.len(),
-            5,
-            "Should create 5 Phase II preview visitors (including stale_oracle_price_v3)"
+            4,
+            "Should create 4 Phase II preview visitors"
         );
 
         let visitors = absint_lints::create_visitors(true, true);
         assert_eq!(
             visitors.len(),
             7,
-            "Should create 7 Phase II visitors when experimental is enabled (5 preview + 2 experimental)"
+            "Should create 7 Phase II visitors when experimental is enabled (4 preview + 3 experimental)"
         );
     }
 }
@@ -167,6 +167,8 @@ mod phase3 {
         let names: Vec<&str> = descriptors.iter().map(|d| d.name).collect();
         assert!(names.contains(&"transitive_capability_leak"));
         assert!(names.contains(&"flashloan_without_repay"));
+        assert!(names.contains(&"store_in_shared_cap"));
+        assert!(names.contains(&"unused_package_function"));
         // Note: price_manipulation_window removed (used name-based heuristics)
     }
 
@@ -218,6 +220,37 @@ mod phase3 {
         assert!(!findings.is_empty(), "{findings:?}");
     }
 
+    #[test]
+    fn test_phase3_store_in_shared_cap_fixture_fires() {
+        let findings = lint_fixture_package("phase3", "shared_cap_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "{findings:?}"
+        );
+        let shared: Vec<&String> = findings
+            .iter()
+            .filter(|f| f.starts_with("[store_in_shared_cap]"))
+            .collect();
+        assert_eq!(shared.len(), 1, "{findings:?}");
+        assert!(shared[0].contains("`Vault`"), "{findings:?}");
+        assert!(shared[0].contains("`admin`"), "{findings:?}");
+    }
+
+    #[test]
+    fn test_phase3_unused_package_function_fixture_fires() {
+        let findings = lint_fixture_package("phase3", "unused_package_fn_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "{findings:?}"
+        );
+        let unused: Vec<&String> = findings
+            .iter()
+            .filter(|f| f.starts_with("[unused_package_function]"))
+            .collect();
+        assert_eq!(unused.len(), 1, "{findings:?}");
+        assert!(unused[0].contains("::drain`"), "{findings:?}");
+    }
+
     #[test]
     fn test_phase3_package_scoping_excludes_dependency_calls() {
         // This fixture invokes a dependency module that "looks like" a Phase III issue.
@@ -286,6 +319,801 @@ mod share_owned_authority_tests {
     }
 }
 
+// ============================================================================
+// Type-Based Preview Lint Tests
+// ============================================================================
+
+mod type_based_preview {
+    use super::*;
+
+    #[test]
+    fn test_exact_length_assert_fixture() {
+        let findings = lint_fixture_package("phase2", "exact_length_assert_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[exact_length_assert]"))
+            .collect();
+        assert_eq!(
+            hits.len(),
+            2,
+            "expected two exact-length asserts: {:?}",
+            hits
+        );
+        assert!(hits.iter().any(|f| f.contains("exact_literal")));
+        assert!(hits.iter().any(|f| f.contains("exact_constant")));
+        assert!(
+            !hits
+                .iter()
+                .any(|f| f.contains("minimum_length") || f.contains("same_length")),
+            "minimum-length and length-vs-length asserts must not be flagged: {:?}",
+            hits
+        );
+    }
+
+    #[test]
+    fn test_loop_never_progresses_fixture() {
+        let findings = lint_fixture_package("phase2", "loop_never_progresses_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[loop_never_progresses]"))
+            .collect();
+        assert_eq!(hits.len(), 1, "expected only sum_forever: {:?}", hits);
+        assert!(hits[0].contains("`sum_forever`"));
+    }
+
+    #[test]
+    fn test_loop_invariant_assert_fixture() {
+        let findings = lint_fixture_package("phase2", "loop_invariant_assert_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[loop_invariant_assert]"))
+            .collect();
+        assert_eq!(
+            hits.len(),
+            2,
+            "expected two loop-invariant asserts: {:?}",
+            hits
+        );
+        assert!(hits.iter().any(|f| f.contains("invariant_in_while")));
+        assert!(hits.iter().any(|f| f.contains("invariant_length_in_loop")));
+        assert!(
+            !hits.iter().any(|f| f.contains("changing_condition")
+                || f.contains("mutated_vector")
+                || f.contains("loop_local")),
+            "asserts on values changed by the loop must not be flagged: {:?}",
+            hits
+        );
+    }
+
+    #[test]
+    fn test_txcontext_not_last_param_fixture() {
+        let findings = lint_fixture_package("phase2", "txcontext_not_last_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[txcontext_not_last_param]"))
+            .collect();
+        assert_eq!(
+            hits.len(),
+            2,
+            "expected two misplaced TxContext params: {:?}",
+            hits
+        );
+        assert!(hits.iter().any(|f| f.contains("bump_ctx_middle")));
+        assert!(hits.iter().any(|f| f.contains("ctx_first")));
+        assert!(
+            !hits
+                .iter()
+                .any(|f| f.contains("`bump`") || f.contains("touch") || f.contains("helper")),
+            "TxContext in last position or outside entry functions must not be flagged: {:?}",
+            hits
+        );
+    }
+
+    #[test]
+    fn test_unnecessary_mut_tx_context_fixture() {
+        let findings = lint_fixture_package("phase2", "unnecessary_mut_tx_context_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[unnecessary_mut_tx_context]"))
+            .collect();
+        assert_eq!(
+            hits.len(),
+            2,
+            "expected two read-only `&mut TxContext` params: {:?}",
+            hits
+        );
+        assert!(hits.iter().any(|f| f.contains("check_owner")));
+        assert!(hits.iter().any(|f| f.contains("log_sender")));
+        assert!(
+            !hits
+                .iter()
+                .any(|f| f.contains("`create") || f.contains("public_check") || f.contains("noop")),
+            "contexts that need `&mut` must not be flagged: {:?}",
+            hits
+        );
+    }
+
+    #[test]
+    fn test_unnecessary_mut_param_fixture() {
+        let findings = lint_fixture_package("phase2", "unnecessary_mut_param_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[unnecessary_mut_param]"))
+            .collect();
+        assert_eq!(
+            hits.len(),
+            2,
+            "expected two read-only `&mut` params: {:?}",
+            hits
+        );
+        assert!(hits.iter().any(|f| f.contains("`is_owner`")));
+        assert!(hits.iter().any(|f| f.contains("`doubled`")));
+        assert!(
+            !hits.iter().any(|f| f.contains("`ctx`")),
+            "`&mut TxContext` must be left to unnecessary_mut_tx_context: {:?}",
+            hits
+        );
+    }
+
+    #[test]
+    fn test_exposes_uid_fixture() {
+        let findings = lint_fixture_package("phase2", "exposes_uid_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[exposes_uid]"))
+            .collect();
+        assert_eq!(hits.len(), 2, "expected two leaked UIDs: {:?}", hits);
+        assert!(hits.iter().any(|f| f.contains("`uid`")));
+        assert!(hits.iter().any(|f| f.contains("`into_uid`")));
+        assert!(
+            !hits.iter().any(|f| f.contains("`id`")
+                || f.contains("uid_internal")
+                || f.contains("fresh_uid")
+                || f.contains("pass_through")),
+            "IDs, package functions, and UIDs not owned by the module must not be flagged: {:?}",
+            hits
+        );
+    }
+
+    #[test]
+    fn test_sui_signer_param_fixture() {
+        let findings = lint_fixture_package("phase2", "sui_signer_param_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[sui_signer_param]"))
+            .collect();
+        assert_eq!(hits.len(), 2, "expected two signer parameters: {:?}", hits);
+        assert!(
+            hits.iter()
+                .any(|f| f.contains("`register` takes `_account: &signer`"))
+        );
+        assert!(
+            hits.iter()
+                .any(|f| f.contains("`init_registry` takes `_account: signer`"))
+        );
+        assert!(
+            !hits.iter().any(|f| f.contains("register_for_testing")),
+            "test-only functions must not be flagged: {:?}",
+            hits
+        );
+    }
+
+    #[test]
+    fn test_unused_clock_param_fixture() {
+        let findings = lint_fixture_package("phase2", "unused_clock_param_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[unused_clock_param]"))
+            .collect();
+        assert_eq!(hits.len(), 1, "expected one unused clock: {:?}", hits);
+        assert!(hits[0].contains("`bid` takes `_clock: &Clock`"));
+    }
+
+    #[test]
+    fn test_event_struct_missing_abilities_fixture() {
+        let findings = lint_fixture_package("phase2", "event_struct_missing_abilities_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[event_struct_missing_abilities]"))
+            .collect();
+        assert_eq!(
+            hits.len(),
+            3,
+            "expected three bad event structs: {:?}",
+            hits
+        );
+        assert!(
+            hits.iter()
+                .any(|f| f.contains("`ItemMinted`") && f.contains("has `key`"))
+        );
+        assert!(
+            hits.iter()
+                .any(|f| f.contains("`ItemBurned`") && f.contains("missing `copy`"))
+        );
+        assert!(
+            hits.iter()
+                .any(|f| f.contains("`TradeEvent`") && f.contains("named like an event"))
+        );
+        assert!(
+            !hits
+                .iter()
+                .any(|f| f.contains("`ItemCreated`") || f.contains("`ListingEvent`")),
+            "well-formed events must not be flagged: {:?}",
+            hits
+        );
+    }
+
+    #[test]
+    fn test_framework_alias_fixture() {
+        let findings = lint_fixture_package("phase2", "framework_alias_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let rows: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[capability_transfer_literal_address]"))
+            .filter_map(|f| f.split_whitespace().nth(1)?.split(':').next())
+            .collect();
+        // Only the renamed `sui::transfer`; the `transfer` alias to a package module is not
+        // the framework.
+        assert_eq!(
+            rows,
+            vec!["36"],
+            "unexpected capability_transfer_literal_address findings: {:?}",
+            findings
+        );
+    }
+
+    #[test]
+    fn test_stale_oracle_price_fixture() {
+        let findings = lint_fixture_package("phase2", "stale_oracle_price_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let mut rows: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[stale_oracle_price]"))
+            .filter_map(|f| f.split_whitespace().nth(1)?.split(':').next())
+            .collect();
+        rows.sort_unstable();
+        // Unchecked pyth use, pyth checked on one branch only, unchecked switchboard use,
+        // and a second price used after only the first one was checked.
+        assert_eq!(
+            rows,
+            vec!["123", "141", "147", "212"],
+            "unexpected stale_oracle_price findings: {:?}",
+            findings
+        );
+    }
+
+    #[test]
+    fn test_ambiguous_abort_code_fixture() {
+        let findings = lint_fixture_package("phase2", "ambiguous_abort_code_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[ambiguous_abort_code]"))
+            .collect();
+        assert_eq!(hits.len(), 3, "unexpected findings: {:?}", hits);
+        assert!(hits.iter().any(|f| f.contains("`E_INVALID` in `withdraw`")));
+        assert!(hits.iter().any(|f| f.contains("`E_INVALID` in `deposit`")));
+        assert!(
+            hits.iter().any(|f| f.contains("`7` in `transfer_to`")),
+            "literal codes are shown as written: {:?}",
+            hits
+        );
+    }
+
+    #[test]
+    fn test_wrong_transfer_variant_fixture() {
+        let findings = lint_fixture_package("phase2", "wrong_transfer_variant_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[wrong_transfer_variant]"))
+            .collect();
+        assert_eq!(hits.len(), 3, "expected three findings: {:?}", hits);
+        for (function, call) in [
+            ("`mint_to_sender`", "`transfer::public_transfer`"),
+            ("`create_registry`", "`transfer::public_share_object`"),
+            ("`freeze_registry`", "`transfer::public_freeze_object`"),
+        ] {
+            assert!(
+                hits.iter()
+                    .any(|h| h.contains(function) && h.contains(call)),
+                "{:?}",
+                hits
+            );
+        }
+    }
+
+    #[test]
+    fn test_prefer_balance_field_fixture() {
+        let findings =
+            lint_fixture_package_with_experimental("phase2", "prefer_balance_field_pkg", true);
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[prefer_balance_field]"))
+            .collect();
+        assert_eq!(hits.len(), 1, "expected only Pool.reserve: {:?}", hits);
+        assert!(hits[0].contains("`Pool.reserve`") && hits[0].contains("Balance<"));
+
+        let without_experimental =
+            lint_fixture_package_with_experimental("phase2", "prefer_balance_field_pkg", false);
+        assert!(
+            !without_experimental
+                .iter()
+                .any(|f| f.contains("[prefer_balance_field]")),
+            "experimental lint must be gated: {:?}",
+            without_experimental
+        );
+    }
+
+    #[test]
+    fn test_suspicious_coin_split_fixture() {
+        let findings =
+            lint_fixture_package_with_experimental("phase2", "suspicious_coin_split_pkg", true);
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[suspicious_coin_split]"))
+            .collect();
+        assert_eq!(
+            hits.len(),
+            2,
+            "expected withdraw and withdraw_late_check: {:?}",
+            hits
+        );
+        assert!(hits.iter().any(|h| h.contains("`withdraw`")));
+        assert!(hits.iter().any(|h| h.contains("`withdraw_late_check`")));
+        assert!(
+            hits.iter().any(|h| h.contains("`withdraw`")
+                && h.contains("`requested`")
+                && h.contains("requested <= coin::value(funds)")),
+            "message names the actual parameter and coin: {:?}",
+            hits
+        );
+
+        let without_experimental =
+            lint_fixture_package_with_experimental("phase2", "suspicious_coin_split_pkg", false);
+        assert!(
+            !without_experimental
+                .iter()
+                .any(|f| f.contains("[suspicious_coin_split]")),
+            "experimental lint must be gated: {:?}",
+            without_experimental
+        );
+    }
+
+    #[test]
+    fn test_unconsumed_new_object_fixture() {
+        let findings =
+            lint_fixture_package_with_experimental("phase2", "unconsumed_new_object_pkg", true);
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[unconsumed_new_object]"))
+            .collect();
+        assert_eq!(hits.len(), 1, "expected only mint_if: {:?}", hits);
+        assert!(hits[0].contains("deleted on this path"), "{:?}", hits);
+
+        let without_experimental =
+            lint_fixture_package_with_experimental("phase2", "unconsumed_new_object_pkg", false);
+        assert!(
+            !without_experimental
+                .iter()
+                .any(|f| f.contains("[unconsumed_new_object]")),
+            "experimental lint must be gated: {:?}",
+            without_experimental
+        );
+    }
+
+    #[test]
+    fn test_copy_struct_with_object_field_fixture() {
+        let findings = lint_fixture_package_with_experimental(
+            "phase2",
+            "copy_struct_with_object_field_pkg",
+            true,
+        );
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[copy_struct_with_object_field]"))
+            .collect();
+        assert_eq!(
+            hits.len(),
+            2,
+            "expected PoolReceipt and Snapshot: {:?}",
+            hits
+        );
+        assert!(hits.iter().any(|f| f.contains("`PoolReceipt`")));
+        assert!(hits.iter().any(|f| f.contains("`Snapshot`")));
+        assert!(
+            !hits.iter().any(|f| f.contains("TypedId")
+                || f.contains("PoolRef")
+                || f.contains("Position")
+                || f.contains("Pair")),
+            "{:?}",
+            hits
+        );
+
+        let without_experimental = lint_fixture_package_with_experimental(
+            "phase2",
+            "copy_struct_with_object_field_pkg",
+            false,
+        );
+        assert!(
+            !without_experimental
+                .iter()
+                .any(|f| f.contains("[copy_struct_with_object_field]")),
+            "experimental lint must be gated: {:?}",
+            without_experimental
+        );
+    }
+
+    #[test]
+    fn test_freeze_locks_value_fixture() {
+        let findings =
+            lint_fixture_package_with_experimental("phase2", "freeze_locks_value_pkg", true);
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[freeze_locks_value]"))
+            .collect();
+        assert_eq!(
+            hits.len(),
+            2,
+            "expected freeze_wallet and freeze_vault: {:?}",
+            hits
+        );
+        assert!(
+            hits.iter()
+                .any(|h| h.contains("`Wallet`") && h.contains("`funds`"))
+        );
+        assert!(hits.iter().any(|h| h.contains("`reserve.coins`")));
+        assert!(!hits.iter().any(|h| h.contains("`Config`")));
+
+        let without_experimental =
+            lint_fixture_package_with_experimental("phase2", "freeze_locks_value_pkg", false);
+        assert!(
+            !without_experimental
+                .iter()
+                .any(|f| f.contains("[freeze_locks_value]")),
+            "experimental lint must be gated: {:?}",
+            without_experimental
+        );
+    }
+
+    #[test]
+    fn test_capability_passthrough_fixture() {
+        let findings =
+            lint_fixture_package_with_experimental("phase2", "capability_passthrough_pkg", true);
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[capability_passthrough]"))
+            .collect();
+        assert_eq!(
+            hits.len(),
+            2,
+            "expected two pass-through capabilities: {:?}",
+            hits
+        );
+        assert!(hits.iter().any(|f| f.contains("`relay`")));
+        assert!(hits.iter().any(|f| f.contains("`relay_with_fee`")));
+        assert!(
+            !hits
+                .iter()
+                .any(|f| f.contains("`peek`") || f.contains("`peek_mut`"))
+        );
+
+        let preview_only = lint_fixture_package("phase2", "capability_passthrough_pkg");
+        assert!(
+            !preview_only
+                .iter()
+                .any(|f| f.contains("[capability_passthrough]")),
+            "experimental lint must not run without --experimental: {:?}",
+            preview_only
+        );
+    }
+
+    #[test]
+    fn test_index_may_exceed_length_fixture() {
+        let findings =
+            lint_fixture_package_with_experimental("phase2", "index_may_exceed_length_pkg", true);
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[index_may_exceed_length]"))
+            .collect();
+        assert_eq!(
+            hits.len(),
+            2,
+            "expected two borrows past an unrelated bound: {:?}",
+            hits
+        );
+        assert!(hits.iter().any(|f| f.contains("`sum_first`")));
+        assert!(hits.iter().any(|f| f.contains("`pair_up`")));
+    }
+
+    #[test]
+    fn test_loop_counter_overflow_risk_fixture() {
+        let findings =
+            lint_fixture_package_with_experimental("phase2", "loop_counter_overflow_pkg", true);
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[loop_counter_overflow_risk]"))
+            .collect();
+        assert_eq!(
+            hits.len(),
+            2,
+            "expected two parameter-bounded loops that can overflow: {:?}",
+            hits
+        );
+        assert!(hits.iter().any(|f| f.contains("`sum_to`")));
+        assert!(hits.iter().any(|f| f.contains("`count_by`")));
+    }
+
+    #[test]
+    fn test_duplicate_dynamic_field_add_fixture() {
+        let findings = lint_fixture_package("phase2", "duplicate_dynamic_field_add_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[duplicate_dynamic_field_add]"))
+            .collect();
+        assert_eq!(hits.len(), 2, "unexpected findings: {:?}", hits);
+        assert!(hits.iter().any(|f| f.contains("duplicate_literal_key")));
+        assert!(hits.iter().any(|f| f.contains("duplicate_constant_key")));
+    }
+
+    #[test]
+    fn test_redundant_ability_constraint_fixture() {
+        let findings = lint_fixture_package("phase2", "redundant_ability_constraint_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[redundant_ability_constraint]"))
+            .collect();
+        assert_eq!(hits.len(), 2, "expected id_of and discard: {:?}", hits);
+        assert!(hits.iter().any(|f| f.contains("`id_of`")));
+        assert!(hits.iter().any(|f| f.contains("`discard`")));
+    }
+
+    #[test]
+    fn test_unused_function_type_parameter_fixture() {
+        let findings = lint_fixture_package("phase2", "unused_type_param_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[unused_function_type_parameter]"))
+            .collect();
+        assert_eq!(hits.len(), 2, "unexpected findings: {:?}", hits);
+        assert!(hits.iter().any(|f| f.contains("`T` of `truly_unused`")));
+        assert!(hits.iter().any(|f| f.contains("`U` of `second_unused`")));
+    }
+
+    #[test]
+    fn test_transfer_of_non_object_fixture() {
+        let findings = lint_fixture_package("phase2", "transfer_of_non_object_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[transfer_of_non_object]"))
+            .collect();
+        assert_eq!(hits.len(), 2, "unexpected findings: {:?}", hits);
+        assert!(
+            hits.iter()
+                .all(|f| f.contains("Receipt") && f.contains("lacks `key`"))
+        );
+        assert!(hits.iter().any(|f| f.contains("send_receipt")));
+        assert!(hits.iter().any(|f| f.contains("share_receipt")));
+    }
+
+    #[test]
+    fn test_mut_param_only_read_for_return_fixture() {
+        let findings = lint_fixture_package("phase2", "mut_param_only_read_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[mut_param_only_read_for_return]"))
+            .collect();
+        assert_eq!(hits.len(), 2, "unexpected findings: {:?}", hits);
+        assert!(hits.iter().all(|f| f.contains("`&mut pool::Pool`")));
+        assert!(hits.iter().any(|f| f.contains("`reserve`")));
+        assert!(hits.iter().any(|f| f.contains("`quote`")));
+    }
+
+    #[test]
+    fn test_public_returns_mut_ref_fixture() {
+        let findings = lint_fixture_package("phase2", "public_returns_mut_ref_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[public_returns_mut_ref]"))
+            .collect();
+        assert_eq!(hits.len(), 2, "unexpected findings: {:?}", hits);
+        assert!(
+            hits.iter()
+                .any(|f| f.contains("`balance_mut`")
+                    && f.contains("&mut balance::Balance<pool::SUI>"))
+        );
+        assert!(hits.iter().any(|f| f.contains("`parts_mut`")));
+    }
+
+    #[test]
+    fn test_destroy_function_does_not_consume_fixture() {
+        let findings = lint_fixture_package("phase2", "destroy_not_consume_pkg");
+        assert!(
+            !findings.iter().any(|f| f.starts_with("ERROR:")),
+            "fixture should compile: {:?}",
+            findings
+        );
+
+        let hits: Vec<_> = findings
+            .iter()
+            .filter(|f| f.contains("[destroy_function_does_not_consume]"))
+            .collect();
+        assert_eq!(hits.len(), 2, "unexpected findings: {:?}", hits);
+        assert!(
+            hits.iter()
+                .any(|f| f.contains("`destroy`") && f.contains("by reference"))
+        );
+        assert!(
+            hits.iter()
+                .any(|f| f.contains("`destroy_empty`") && f.contains("never unpacks"))
+        );
+    }
+}
+
 // ============================================================================
 // Resource Kind Tests (Phase III Infrastructure)
 // ============================================================================
@@ -40,3 +40,44 @@ fn semantic_lints_fire_on_fixture_package() {
     let diags = result.unwrap();
     println!("Got {} semantic diagnostics from fixture", diags.len());
 }
+
+/// Linting a package compiled once must match `lint_package`, and be repeatable without
+/// recompiling.
+#[test]
+fn lint_package_with_graph_reuses_compiled_package() {
+    use move_clippy::semantic;
+
+    let fixture = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/phase2/loop_invariant_assert_pkg");
+    let settings = LintSettings::default();
+    let render = |diags: Vec<move_clippy::Diagnostic>| -> Vec<String> {
+        diags
+            .iter()
+            .map(|d| {
+                format!(
+                    "{}:{}:{} {}",
+                    d.file.as_deref().unwrap_or_default(),
+                    d.span.start.row,
+                    d.span.start.column,
+                    d.lint.name
+                )
+            })
+            .collect()
+    };
+
+    let expected =
+        render(semantic::lint_package(&fixture, &settings, true, false).expect("lint_package"));
+    assert!(
+        expected
+            .iter()
+            .any(|d| d.ends_with("loop_invariant_assert")),
+        "fixture should trigger a preview lint: {expected:?}"
+    );
+
+    let package = semantic::compile_package(&fixture, true, false).expect("compile");
+    for _ in 0..2 {
+        let diags = semantic::lint_package_with_graph(&package, &settings, true, false)
+            .expect("lint compiled package");
+        assert_eq!(render(diags), expected);
+    }
+}
@@ -106,3 +106,55 @@ module semantic_directives_pkg::m {
         "expected unfulfilled_expectation, got: {diags:?}"
     );
 }
+
+#[test]
+fn item_expectation_requires_the_lint_to_fire_in_that_function() {
+    // Each function expects the lint that fires in the *other* function.
+    let source = r#"
+module semantic_directives_pkg::m {
+    #[ext(move_clippy(expect(private_entry_function)))]
+    public entry fun returns_value(): u64 { 0 }
+
+    #[ext(move_clippy(expect(entry_function_returns_value)))]
+    entry fun private_entry() { }
+}
+"#;
+
+    let diags = lint_pkg(source, LintSettings::default());
+    let mut unfulfilled: Vec<(usize, &str)> = diags
+        .iter()
+        .filter(|d| d.lint.name == UNFULFILLED_EXPECTATION_NAME)
+        .map(|d| (d.span.start.row, d.message.as_str()))
+        .collect();
+    unfulfilled.sort();
+    assert_eq!(unfulfilled.len(), 2, "got: {diags:?}");
+    assert!(
+        unfulfilled[0].1.contains("lint::private_entry_function"),
+        "got: {diags:?}"
+    );
+    assert!(
+        unfulfilled[1]
+            .1
+            .contains("lint::entry_function_returns_value"),
+        "got: {diags:?}"
+    );
+
+    // With the expectations on the right functions, both are fulfilled.
+    let source = r#"
+module semantic_directives_pkg::m {
+    #[ext(move_clippy(expect(entry_function_returns_value)))]
+    public entry fun returns_value(): u64 { 0 }
+
+    #[ext(move_clippy(expect(private_entry_function)))]
+    entry fun private_entry() { }
+}
+"#;
+
+    let diags = lint_pkg(source, LintSettings::default());
+    assert!(
+        diags
+            .iter()
+            .all(|d| d.lint.name != UNFULFILLED_EXPECTATION_NAME),
+        "got: {diags:?}"
+    );
+}
@@ -44,3 +44,155 @@ public fun demo() {
         "expected prefer_vector_methods to fire without allow, got: {diags:#?}"
     );
 }
+
+#[test]
+fn comment_and_attribute_suppressions_mix_in_one_file() {
+    let engine = create_default_engine();
+
+    let src = r#"
+module my_pkg::m;
+
+use std::vector;
+
+#[allow(lint::prefer_vector_methods)]
+public fun attribute_allowed() {
+    let mut v = vector::empty<u64>();
+    vector::push_back(&mut v, 1);
+}
+
+public fun comment_above() {
+    let mut v = vector::empty<u64>();
+    // move-clippy: allow(prefer_vector_methods)
+    vector::push_back(&mut v, 1);
+}
+
+public fun trailing_comment() {
+    let mut v = vector::empty<u64>();
+    vector::push_back(&mut v, 1); // move-clippy:allow(prefer_vector_methods)
+}
+
+public fun unrelated_comment() {
+    let mut v = vector::empty<u64>();
+    // move-clippy:allow(while_true_to_loop)
+    vector::push_back(&mut v, 1);
+}
+
+public fun not_suppressed() {
+    let mut v = vector::empty<u64>();
+    vector::push_back(&mut v, 2);
+}
+"#;
+
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let rows: Vec<usize> = diags
+        .iter()
+        .filter(|d| d.lint.name == "prefer_vector_methods")
+        .map(|d| d.span.start.row)
+        .collect();
+    // Only `unrelated_comment` and `not_suppressed` keep their findings.
+    assert_eq!(rows, vec![26, 31], "got: {diags:#?}");
+}
+
+#[test]
+fn comment_suppression_only_covers_the_next_statement() {
+    let engine = create_default_engine();
+
+    let src = r#"
+module my_pkg::m;
+
+use std::vector;
+
+public fun demo() {
+    let mut v = vector::empty<u64>();
+    // move-clippy:allow(prefer_vector_methods)
+    vector::push_back(&mut v, 1);
+    vector::push_back(&mut v, 2);
+}
+"#;
+
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let rows: Vec<usize> = diags
+        .iter()
+        .filter(|d| d.lint.name == "prefer_vector_methods")
+        .map(|d| d.span.start.row)
+        .collect();
+    assert_eq!(rows, vec![10], "got: {diags:#?}");
+}
+
+#[test]
+fn comment_allow_after_string_containing_slashes() {
+    let engine = create_default_engine();
+
+    let src = r#"
+module my_pkg::m;
+
+use std::vector;
+
+public fun demo() {
+    let mut v = vector::empty<vector<u8>>();
+    vector::push_back(&mut v, b"a//b"); // move-clippy:allow(prefer_vector_methods)
+}
+"#;
+
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    assert!(
+        !diags.iter().any(|d| d.lint.name == "prefer_vector_methods"),
+        "got: {diags:#?}"
+    );
+}
+
+#[test]
+fn comment_expect_is_fulfilled_by_a_finding() {
+    let engine = create_default_engine();
+
+    let src = r#"
+module my_pkg::m;
+
+use std::vector;
+
+public fun demo() {
+    let mut v = vector::empty<u64>();
+    // move-clippy:expect(prefer_vector_methods)
+    vector::push_back(&mut v, 1);
+}
+"#;
+
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    assert!(
+        diags.iter().any(|d| d.lint.name == "prefer_vector_methods"),
+        "got: {diags:#?}"
+    );
+    assert!(
+        !diags
+            .iter()
+            .any(|d| d.lint.name == "unfulfilled_expectation"),
+        "got: {diags:#?}"
+    );
+}
+
+#[test]
+fn comment_expect_without_a_finding_is_unfulfilled() {
+    let engine = create_default_engine();
+
+    let src = r#"
+module my_pkg::m;
+
+public fun demo(): u64 {
+    // move-clippy:expect(prefer_vector_methods)
+    1
+}
+"#;
+
+    let diags = engine.lint_source(src).expect("linting should succeed");
+    let unfulfilled: Vec<_> = diags
+        .iter()
+        .filter(|d| d.lint.name == "unfulfilled_expectation")
+        .collect();
+    assert_eq!(unfulfilled.len(), 1, "got: {diags:#?}");
+    assert_eq!(unfulfilled[0].span.start.row, 5);
+    assert!(
+        unfulfilled[0]
+            .message
+            .contains("lint::prefer_vector_methods")
+    );
+}